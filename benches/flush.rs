@@ -0,0 +1,53 @@
+// (C) 2025 - Enzo Lombardi
+// Flush benchmark - idle frames on a large screen
+//
+// Flushes a 300x80 screen that did not change since the previous frame,
+// once with nothing written (the idle case) and once after every row was
+// rewritten with the same content (what flush had to diff before rows
+// were tracked), and prints the average time per flush.
+//
+// Usage: cargo bench --bench flush
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use turbo_vision::core::draw::Cell;
+use turbo_vision::core::palette::Attr;
+use turbo_vision::terminal::Terminal;
+
+const WIDTH: u16 = 300;
+const HEIGHT: u16 = 80;
+const FRAMES: u32 = 2000;
+
+fn time_flushes(terminal: &mut Terminal, rows: &[Vec<Cell>], rewrite: bool) -> Duration {
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        if rewrite {
+            for (y, row) in rows.iter().enumerate() {
+                terminal.write_line(0, y as u16, row);
+            }
+        }
+        terminal.flush().unwrap();
+        black_box(terminal.take_output());
+    }
+    start.elapsed() / FRAMES
+}
+
+fn main() {
+    let mut terminal = Terminal::new_headless(WIDTH, HEIGHT);
+    let rows: Vec<Vec<Cell>> = (0..HEIGHT)
+        .map(|y| (0..WIDTH).map(|x| Cell::new(char::from(b'a' + ((x + y) % 26) as u8), Attr::from_u8(0x1E))).collect())
+        .collect();
+    for (y, row) in rows.iter().enumerate() {
+        terminal.write_line(0, y as u16, row);
+    }
+    terminal.flush().unwrap();
+    terminal.take_output();
+
+    let rewritten = time_flushes(&mut terminal, &rows, true);
+    let idle = time_flushes(&mut terminal, &rows, false);
+
+    println!("{WIDTH}x{HEIGHT} screen, {FRAMES} unchanged frames");
+    println!("  every row rewritten: {rewritten:?} per flush");
+    println!("  nothing written:     {idle:?} per flush");
+    println!("  speedup:             {:.1}x", rewritten.as_secs_f64() / idle.as_secs_f64().max(1e-9));
+}
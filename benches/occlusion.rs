@@ -0,0 +1,61 @@
+// (C) 2025 - Enzo Lombardi
+// Occlusion culling benchmark - five stacked editor windows
+//
+// Draws a desktop holding five editor windows on top of each other, with
+// and without occlusion culling, and prints the average time per frame.
+// The four back windows, shadows included, lie inside the front one, so
+// culling skips four editors.
+//
+// Usage: cargo bench --bench occlusion
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use turbo_vision::core::geometry::Rect;
+use turbo_vision::terminal::Terminal;
+use turbo_vision::views::View;
+use turbo_vision::views::desktop::Desktop;
+use turbo_vision::views::edit_window::EditWindow;
+
+const FRAMES: u32 = 200;
+
+fn stacked_editors() -> Desktop {
+    let text: String = (0..2000)
+        .map(|i| format!("fn line_{i}() -> usize {{ {i} * 2 + 1 }} // some text to render\n"))
+        .collect();
+
+    let mut desktop = Desktop::new(Rect::new(0, 0, 120, 40));
+    for i in 0..5 {
+        // A window's shadow reaches one cell past its bounds: keep the back ones one cell smaller
+        let bounds = if i == 4 { Rect::new(0, 0, 118, 38) } else { Rect::new(0, 0, 117, 37) };
+        let window = EditWindow::new(bounds, &format!("Editor {}", i + 1));
+        window.editor_rc().borrow_mut().set_text(&text);
+        desktop.add(Box::new(window));
+    }
+    desktop
+}
+
+fn time_frames(desktop: &mut Desktop, terminal: &mut Terminal) -> Duration {
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        desktop.draw(terminal);
+        black_box(&*terminal);
+    }
+    start.elapsed() / FRAMES
+}
+
+fn main() {
+    let mut terminal = Terminal::new_headless(120, 40);
+    let mut desktop = stacked_editors();
+    assert!((0..4).all(|i| desktop.is_obscured(i)), "the back windows must be culled");
+    assert!(!desktop.is_obscured(4));
+
+    desktop.set_occlusion_culling(false);
+    let full = time_frames(&mut desktop, &mut terminal);
+    desktop.set_occlusion_culling(true);
+    let culled = time_frames(&mut desktop, &mut terminal);
+
+    println!("five stacked editors, {FRAMES} frames");
+    println!("  without culling: {full:?} per frame");
+    println!("  with culling:    {culled:?} per frame");
+    println!("  speedup:         {:.1}x", full.as_secs_f64() / culled.as_secs_f64());
+}
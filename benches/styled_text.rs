@@ -0,0 +1,57 @@
+// (C) 2025 - Enzo Lombardi
+// Styled text benchmark - a full screen of lines with a highlighted span
+//
+// Fills 60 lines of 200 columns, each with a 40-column selection in the
+// middle, once with the per-character loop views used to write (one
+// move_char() call and one attribute test per character) and once with
+// DrawBuffer::move_styled() and three attribute runs per line, and prints
+// the average time per screen.
+//
+// Usage: cargo bench --bench styled_text
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use turbo_vision::core::draw::DrawBuffer;
+use turbo_vision::core::palette::Attr;
+
+const WIDTH: usize = 200;
+const LINES: usize = 60;
+const FRAMES: u32 = 2000;
+const SELECTION: std::ops::Range<usize> = 80..120;
+
+fn time_screens(lines: &[String], draw: impl Fn(&mut DrawBuffer, &str)) -> Duration {
+    let mut buf = DrawBuffer::new(WIDTH);
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        for line in lines {
+            draw(&mut buf, line);
+            black_box(&buf.data);
+        }
+    }
+    start.elapsed() / FRAMES
+}
+
+fn main() {
+    let normal = Attr::from_u8(0x1E);
+    let selected = Attr::from_u8(0x71);
+    let lines: Vec<String> = (0..LINES)
+        .map(|y| (0..WIDTH).map(|x| char::from(b'a' + ((x + y) % 26) as u8)).collect())
+        .collect();
+
+    let per_char = time_screens(&lines, |buf, line| {
+        for (i, ch) in line.chars().enumerate() {
+            let attr = if SELECTION.contains(&i) { selected } else { normal };
+            buf.move_char(i, ch, attr, 1);
+        }
+    });
+    let runs = time_screens(&lines, |buf, line| {
+        let (before, rest) = line.split_at(SELECTION.start);
+        let (selection, after) = rest.split_at(SELECTION.len());
+        buf.move_styled(0, &[(before, normal), (selection, selected), (after, normal)]);
+    });
+
+    println!("{LINES} lines of {WIDTH} columns, {FRAMES} screens");
+    println!("  per-character loop: {per_char:?} per screen");
+    println!("  attribute runs:     {runs:?} per screen");
+    println!("  speedup:            {:.1}x", per_char.as_secs_f64() / runs.as_secs_f64().max(1e-9));
+}
@@ -0,0 +1,87 @@
+// (C) 2025 - Enzo Lombardi
+// Activity Indicator - spinner shown while a worker thread scans a directory
+//
+// This example demonstrates:
+// - ActivityIndicator placed in the status line as an overlay widget
+// - Starting/stopping it through an ActivityHandle
+// - Polling a worker thread from a custom event loop
+//
+// Usage: cargo run --example activity_indicator [DIRECTORY]
+// Press F3 to start a scan, Alt+X to exit.
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+use turbo_vision::app::ApplicationBuilder;
+use turbo_vision::core::command::CM_QUIT;
+use turbo_vision::core::event::{EventType, KB_ALT_X, KB_F3};
+use turbo_vision::core::geometry::Rect;
+use turbo_vision::views::activity_indicator::{ActivityIndicator, SpinnerStyle};
+use turbo_vision::views::msgbox::message_box_ok;
+use turbo_vision::views::status_line::StatusItem;
+
+const CMD_SCAN: u16 = 1000;
+
+/// Count files below `dir`, slowed down to stand in for a network drive
+fn count_files(dir: &Path) -> usize {
+    thread::sleep(Duration::from_millis(20));
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .map(|entry| match entry.file_type() {
+            Ok(kind) if kind.is_dir() => count_files(&entry.path()),
+            _ => 1,
+        })
+        .sum()
+}
+
+fn main() -> turbo_vision::core::error::Result<()> {
+    let root = PathBuf::from(std::env::args().nth(1).unwrap_or_else(|| ".".to_string()));
+
+    let mut app = ApplicationBuilder::new()
+        .status(vec![
+            StatusItem::new("~Alt-X~ Exit", KB_ALT_X, CM_QUIT),
+            StatusItem::new("~F3~ Scan", KB_F3, CMD_SCAN),
+        ])
+        .build()?;
+
+    // Right end of the status line
+    let (width, height) = app.terminal.size();
+    let mut spinner = ActivityIndicator::new(Rect::new(width - 14, height - 1, width, height));
+    spinner.set_style(SpinnerStyle::Braille);
+    spinner.set_label("Scanning");
+    let activity = spinner.handle();
+    app.add_overlay_widget(Box::new(spinner));
+
+    let mut worker: Option<mpsc::Receiver<usize>> = None;
+    app.running = true;
+
+    while app.running {
+        if let Some(mut event) = app.get_event() {
+            app.handle_event(&mut event);
+
+            if event.what == EventType::Command && event.command == CMD_SCAN && worker.is_none() {
+                let (tx, rx) = mpsc::channel();
+                let dir = root.clone();
+                thread::spawn(move || {
+                    let _ = tx.send(count_files(&dir));
+                });
+                worker = Some(rx);
+                activity.start();
+            }
+        }
+
+        let finished = worker.as_ref().and_then(|rx| rx.try_recv().ok());
+        if let Some(count) = finished {
+            worker = None;
+            activity.stop();
+            message_box_ok(&mut app, &format!("{} contains {count} files.", root.display()));
+        }
+    }
+
+    app.terminal.shutdown()?;
+    Ok(())
+}
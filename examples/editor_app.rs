@@ -0,0 +1,101 @@
+// (C) 2025 - Enzo Lombardi
+// Editor App - a small multi-file text editor driven by the command line
+//
+// This example demonstrates:
+// - CliArgs: opening the files passed on the command line in cascaded windows
+// - --theme, --no-mouse and --screenshot flags feeding the application
+// - Errors for unreadable files shown after startup instead of a crash
+// - A vi-style command bar (press ':' then q, w, tile, cascade or next)
+// - Application::new_file/open_file and the built-in CM_SAVE/CM_SAVE_AS handling
+//
+// The menus, status line and command bar are built by setup(), which the
+// snapshot checks (cargo run --example snapshots) call to render the same
+// first frame.
+//
+// Usage: cargo run --example editor_app -- [--theme dark] [--no-mouse] [FILE...]
+
+use turbo_vision::app::cli::CLI_OPTIONS_HELP;
+use turbo_vision::app::{Application, ApplicationBuilder, CliArgs};
+use turbo_vision::core::command::{CM_CASCADE, CM_NEW, CM_NEXT, CM_OPEN, CM_QUIT, CM_SAVE, CM_SAVE_AS, CM_TILE};
+use turbo_vision::core::event::{KB_ALT_X, KB_F2, KB_F3, KB_F5, KB_F6};
+use turbo_vision::core::geometry::Rect;
+use turbo_vision::core::menu_data::MenuBuilder;
+use turbo_vision::terminal::Terminal;
+use turbo_vision::views::command_bar::CommandBar;
+use turbo_vision::views::menu_bar::{MenuBar, SubMenu};
+use turbo_vision::views::status_line::{StatusItem, StatusLine};
+
+/// File/Window menus, status line and a vi-style command bar
+///
+/// Windows are left to the caller (files from the command line, or one empty buffer).
+pub fn setup(app: &mut Application) {
+    let (width, height) = app.terminal.size();
+
+    let mut menu_bar = MenuBar::new(Rect::new(0, 0, width, 1));
+    let file_menu = MenuBuilder::new()
+        .item("~N~ew", CM_NEW, 0)
+        .item_with_shortcut("~O~pen...", CM_OPEN, KB_F3, "F3")
+        .item_with_shortcut("~S~ave", CM_SAVE, KB_F2, "F2")
+        .item("Save ~a~s...", CM_SAVE_AS, 0)
+        .separator()
+        .item_with_shortcut("E~x~it", CM_QUIT, KB_ALT_X, "Alt+X")
+        .build();
+    let window_menu = MenuBuilder::new()
+        .item_with_shortcut("~T~ile", CM_TILE, KB_F5, "F5")
+        .item("C~a~scade", CM_CASCADE, 0)
+        .item_with_shortcut("~N~ext", CM_NEXT, KB_F6, "F6")
+        .build();
+    menu_bar.add_submenu(SubMenu::new("~F~ile", file_menu));
+    menu_bar.add_submenu(SubMenu::new("~W~indow", window_menu));
+    app.set_menu_bar(menu_bar);
+
+    app.set_status_line(StatusLine::new(
+        Rect::new(0, height - 1, width, height),
+        vec![
+            StatusItem::new("~Alt-X~ Exit", KB_ALT_X, CM_QUIT),
+            StatusItem::new("~F2~ Save", KB_F2, CM_SAVE),
+            StatusItem::new("~F3~ Open", KB_F3, CM_OPEN),
+            StatusItem::new("~F5~ Tile", KB_F5, CM_TILE),
+            StatusItem::new("~F6~ Next", KB_F6, CM_NEXT),
+        ],
+    ));
+
+    app.set_command_bar(CommandBar::new(':', |text| match text {
+        "q" | "quit" => Ok(CM_QUIT),
+        "w" | "write" => Ok(CM_SAVE),
+        "e" | "edit" => Ok(CM_OPEN),
+        "tile" => Ok(CM_TILE),
+        "cascade" => Ok(CM_CASCADE),
+        "n" | "next" => Ok(CM_NEXT),
+        other => Err(format!("Not an editor command: {other}")),
+    }));
+}
+
+fn main() -> turbo_vision::core::error::Result<()> {
+    if std::env::args().any(|arg| arg == "-h" || arg == "--help") {
+        println!("Usage: editor_app [OPTIONS] [FILE...]\n\n{CLI_OPTIONS_HELP}");
+        return Ok(());
+    }
+    let args = match CliArgs::from_env() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("editor_app: {e}\n\n{CLI_OPTIONS_HELP}");
+            std::process::exit(2);
+        }
+    };
+
+    let terminal = Terminal::init_with_options(args.terminal_options())?;
+    let mut app = ApplicationBuilder::new().terminal(terminal).build()?;
+    setup(&mut app);
+
+    args.apply(&mut app);
+
+    // Start with an empty buffer when no file was given
+    if args.files.is_empty() {
+        app.new_file();
+    }
+
+    app.run();
+    app.terminal.shutdown()?;
+    Ok(())
+}
@@ -5,10 +5,12 @@
 // - FileList for browsing files
 // - DirListBox for directory tree navigation
 // - Side-by-side directory tree and file list
+// - Mouse: click to select (and focus a panel), double-click to open, wheel to scroll
 
 use std::env;
 use std::time::Duration;
 use turbo_vision::app::Application;
+use turbo_vision::core::command::CM_FILE_DOUBLE_CLICKED;
 use turbo_vision::core::event::{EventType, KB_ESC_ESC, KB_TAB};
 use turbo_vision::core::geometry::Rect;
 use turbo_vision::views::dir_listbox::DirListBox;
@@ -39,7 +41,7 @@ fn main() -> turbo_vision::core::error::Result<()> {
     let status_bounds = Rect::new(0, h - 1, w, h);
     let mut status = StaticTextBuilder::new()
         .bounds(status_bounds)
-        .text(" File Browser Demo | TAB: Switch panels | Enter: Navigate | ESC ESC: Exit")
+        .text(" File Browser Demo | TAB/click: Switch panels | Enter/double-click: Navigate | ESC ESC: Exit")
         .build();
 
     // Event loop
@@ -75,6 +77,27 @@ fn main() -> turbo_vision::core::error::Result<()> {
                 break;
             }
 
+            // Clicking a panel focuses it; the wheel goes to the panel under the pointer
+            if event.what == EventType::MouseDown || event.is_wheel() {
+                let on_left = dir_bounds.contains(event.mouse.pos);
+                if !on_left && !file_bounds.contains(event.mouse.pos) {
+                    continue;
+                }
+                if event.what == EventType::MouseDown && on_left != focused_left {
+                    focused_left = on_left;
+                    dir_list.set_focus(focused_left);
+                    file_list.set_focus(!focused_left);
+                }
+                if event.is_wheel() {
+                    if on_left {
+                        dir_list.handle_event(&mut event);
+                    } else {
+                        file_list.handle_event(&mut event);
+                    }
+                    continue;
+                }
+            }
+
             // Let focused panel handle the event
             if focused_left {
                 dir_list.handle_event(&mut event);
@@ -86,6 +109,13 @@ fn main() -> turbo_vision::core::error::Result<()> {
             } else {
                 file_list.handle_event(&mut event);
 
+                // A file was double-clicked
+                if event.what == EventType::Broadcast && event.command == CM_FILE_DOUBLE_CLICKED {
+                    if let Some(path) = file_list.get_selected_file() {
+                        status.set_text(&format!(" Picked {} | ESC ESC: Exit", path.display()));
+                    }
+                }
+
                 // Sync directory list with file list (if directory changed)
                 if file_list.current_path() != dir_list.current_path() {
                     let _ = dir_list.change_dir(file_list.current_path());
@@ -12,6 +12,7 @@
 // - Arrow keys to navigate
 // - Enter to select files or navigate into folders
 // - Directory navigation: Enter on folders opens them, dialog stays open
+// - Save mode: new file names are accepted, overwriting asks first
 // - Customizable button labels (Open, Save, Export, etc.) via with_button_label()
 //
 // Wildcard patterns:
@@ -21,13 +22,13 @@
 // - etc.
 //
 // Examples:
-// - Open dialog (default): FileDialog::new(...).build()
-// - Save dialog: FileDialog::new(...).with_button_label("~S~ave").build()
-// - Export dialog: FileDialog::new(...).with_button_label("~E~xport").build()
+// - Open dialog: FileDialog::new(..., FileDialogMode::Open).build()
+// - Save dialog: FileDialog::new(..., FileDialogMode::Save).build()
+// - Export dialog: FileDialog::new(..., FileDialogMode::Save).with_button_label("~E~xport").build()
 
 use turbo_vision::app::Application;
 use turbo_vision::core::geometry::Rect;
-use turbo_vision::views::file_dialog::FileDialog;
+use turbo_vision::views::file_dialog::{FileDialog, FileDialogMode};
 
 fn main() -> turbo_vision::core::error::Result<()> {
     let mut app = Application::new()?;
@@ -51,6 +52,7 @@ fn main() -> turbo_vision::core::error::Result<()> {
         "Open File",
         "*",  // Wildcard: "*" = all files, "*.ext" = specific extension
         None, // Start in current directory
+        FileDialogMode::Open,
     )
     .build();
 
@@ -0,0 +1,77 @@
+// (C) 2025 - Enzo Lombardi
+// Key Diagnostic - shows the raw terminal event for every key you press
+//
+// This example demonstrates:
+// - Terminal::take_raw_key() to inspect the raw crossterm event
+// - The translated key code delivered to views
+// - A ready-to-paste key translation line for keys that arrive as dead keys (0x0000)
+//
+// Usage: cargo run --example key_diagnostic [translations.txt]
+// Press Ctrl+C to exit.
+
+use std::time::Duration;
+use turbo_vision::app::Application;
+use turbo_vision::core::draw::DrawBuffer;
+use turbo_vision::core::event::{EventType, KB_CTRL_C};
+use turbo_vision::core::palette::Attr;
+use turbo_vision::terminal::KeyPattern;
+use turbo_vision::views::View;
+use turbo_vision::views::view::write_line_to_terminal;
+
+const MAX_HISTORY: usize = 12;
+const BOX_WIDTH: usize = 72;
+
+fn main() -> turbo_vision::core::error::Result<()> {
+    let mut app = Application::new()?;
+
+    // Optional translation file, loaded on top of the built-in fixups and the settings file
+    if let Some(path) = std::env::args().nth(1) {
+        let mut table = app.terminal.key_translations().clone();
+        table.load(&path)?;
+        app.terminal.set_key_translations(table);
+    }
+
+    let mut history: Vec<String> = Vec::new();
+    app.running = true;
+
+    while app.running {
+        app.desktop.draw(&mut app.terminal);
+
+        let mut lines = vec![
+            " Key Diagnostic - press any key (Ctrl+C exits)".to_string(),
+            String::new(),
+            " Translation line          Key code  Raw event".to_string(),
+        ];
+        lines.extend(history.iter().cloned());
+
+        let (width, _) = app.terminal.size();
+        let box_x = (width - BOX_WIDTH as i16).max(0) / 2;
+        for (i, line) in lines.iter().enumerate() {
+            let attr = if i == 0 { Attr::from_u8(0x3F) } else { Attr::from_u8(0x0F) };
+            let mut buf = DrawBuffer::new(BOX_WIDTH);
+            buf.move_char(0, ' ', attr, BOX_WIDTH);
+            buf.move_str(0, line, attr);
+            write_line_to_terminal(&mut app.terminal, box_x, 2 + i as i16, &buf);
+        }
+        let _ = app.terminal.flush();
+
+        // Dead keys produce no event, so the raw key is checked either way
+        let event = app.terminal.poll_event(Duration::from_millis(50)).ok().flatten();
+        let key_code = match event {
+            Some(ref e) if e.what == EventType::Keyboard => e.key_code,
+            _ => 0,
+        };
+        if key_code == KB_CTRL_C {
+            app.running = false;
+            continue;
+        }
+
+        if let Some(raw) = app.terminal.take_raw_key() {
+            let fixup = format!("{} = ", KeyPattern::from_event(&raw));
+            history.insert(0, format!(" {:<25} 0x{:04X}    {:?} {:?}", fixup, key_code, raw.code, raw.modifiers));
+            history.truncate(MAX_HISTORY);
+        }
+    }
+
+    Ok(())
+}
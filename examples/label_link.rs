@@ -13,6 +13,7 @@ use turbo_vision::views::button::ButtonBuilder;
 use turbo_vision::views::dialog::DialogBuilder;
 use turbo_vision::views::input_line::InputLineBuilder;
 use turbo_vision::views::label::LabelBuilder;
+use turbo_vision::views::View;
 
 fn main() -> turbo_vision::core::error::Result<()> {
     let mut app = Application::new()?;
@@ -24,11 +25,16 @@ fn main() -> turbo_vision::core::error::Result<()> {
     dialog.add(Box::new(
         LabelBuilder::new().bounds(Rect::new(2, 2, 46, 2)).text("Click on the labels to focus the input fields").build(),
     ));
+    // Enter in a field presses the default OK button (InputLine leaves Enter
+    // to the dialog); use set_enter_behavior(EnterBehavior::Consume) to opt out
+    dialog.add(Box::new(
+        LabelBuilder::new().bounds(Rect::new(2, 3, 46, 3)).text("Press Enter in any field to accept").build(),
+    ));
 
     // First Name field with linked label
     let first_name_data = Rc::new(RefCell::new(String::new()));
     let mut first_name_label = LabelBuilder::new().bounds(Rect::new(2, 4, 15, 4)).text("~F~irst Name:").build();
-    first_name_label.set_link(dialog.add(Box::new(
+    first_name_label.set_link_id(dialog.add(Box::new(
         InputLineBuilder::new().bounds(Rect::new(15, 4, 35, 4)).max_length(20).data(Rc::clone(&first_name_data)).build(),
     )));
     dialog.add(Box::new(first_name_label));
@@ -36,7 +42,7 @@ fn main() -> turbo_vision::core::error::Result<()> {
     // Last Name field with linked label
     let last_name_data = Rc::new(RefCell::new(String::new()));
     let mut last_name_label = LabelBuilder::new().bounds(Rect::new(2, 6, 15, 6)).text("~L~ast Name:").build();
-    last_name_label.set_link(dialog.add(Box::new(
+    last_name_label.set_link_id(dialog.add(Box::new(
         InputLineBuilder::new().bounds(Rect::new(15, 6, 35, 6)).max_length(20).data(Rc::clone(&last_name_data)).build(),
     )));
     dialog.add(Box::new(last_name_label));
@@ -44,7 +50,7 @@ fn main() -> turbo_vision::core::error::Result<()> {
     // Email field with linked label
     let email_data = Rc::new(RefCell::new(String::new()));
     let mut email_label = LabelBuilder::new().bounds(Rect::new(2, 8, 15, 8)).text("~E~mail:").build();
-    email_label.set_link(dialog.add(Box::new(InputLineBuilder::new().bounds(Rect::new(15, 8, 35, 8)).max_length(20).data(Rc::clone(&email_data)).build())));
+    email_label.set_link_id(dialog.add(Box::new(InputLineBuilder::new().bounds(Rect::new(15, 8, 35, 8)).max_length(20).data(Rc::clone(&email_data)).build())));
     dialog.add(Box::new(email_label));
 
     // Buttons
@@ -43,9 +43,9 @@ fn main() -> turbo_vision::core::error::Result<()> {
     setup_status_line(&mut app, width, height);
     setup_welcome_message(&mut app, width, height);
 
-    // Initial draw and show welcome dialog
+    // Initial draw and show startup splash
     redraw_screen(&mut app);
-    show_about(&mut app);
+    show_startup_splash(&mut app);
     redraw_screen(&mut app);
 
     // Main event loop
@@ -288,15 +288,11 @@ fn show_popup_menu(app: &mut Application, position: Point) -> u16 {
 
 /// Show a simple message dialog
 fn show_message(app: &mut Application, title: &str, message: &str) {
-    let (term_width, term_height) = app.terminal.size();
-
     let dialog_width = 40;
     let dialog_height = 7;
-    let dialog_x = (term_width - dialog_width) / 2;
-    let dialog_y = (term_height - dialog_height) / 2;
 
     let mut dialog = DialogBuilder::new()
-        .bounds(Rect::new(dialog_x, dialog_y, dialog_x + dialog_width, dialog_y + dialog_height))
+        .bounds(Rect::centered(app.terminal.size(), dialog_width, dialog_height))
         .title(title)
         .build();
 
@@ -320,6 +316,21 @@ fn show_message(app: &mut Application, title: &str, message: &str) {
     dialog.execute(app);
 }
 
+/// Show a splash panel at startup, dismissed by any key after a short delay
+fn show_startup_splash(app: &mut Application) {
+    use std::time::Duration;
+    use turbo_vision::app::{show_splash_with, SplashOptions};
+
+    let options = SplashOptions::new(Duration::from_millis(800))
+        .dim_background(true)
+        .version(env!("CARGO_PKG_VERSION"));
+    show_splash_with(
+        app,
+        &["Welcome To Turbo Vision for Rust!", "", "Extended Menu Example", "", "Press any key to continue"],
+        &options,
+    );
+}
+
 /// Show the About dialog
 fn show_about(app: &mut Application) {
     use turbo_vision::helpers::msgbox::{message_box, MF_ABOUT, MF_OK_BUTTON};
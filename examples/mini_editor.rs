@@ -0,0 +1,30 @@
+// (C) 2025 - Enzo Lombardi
+// Mini Editor - a working multi-file editor in a few lines
+//
+// This example demonstrates:
+// - standard_menus(): File/Edit/Window menus wired to the standard commands,
+//   leaving out Search and Help (no find dialog or about box here)
+// - Application::new_file/open_file and the built-in CM_SAVE/CM_SAVE_AS handling
+// - Quitting with unsaved changes asks about every modified window
+//
+// Usage: cargo run --example mini_editor -- [FILE...]
+
+use turbo_vision::app::ApplicationBuilder;
+use turbo_vision::views::menu_bar::MenuFeatures;
+
+fn main() -> turbo_vision::core::error::Result<()> {
+    let features = MenuFeatures { search: false, help: false, ..MenuFeatures::default() };
+    let mut app = ApplicationBuilder::new().standard_menus_with(features).build()?;
+
+    let files: Vec<_> = std::env::args_os().skip(1).map(Into::into).collect();
+    if files.is_empty() {
+        app.new_file();
+    }
+    for path in files {
+        app.open_file(Some(path));
+    }
+
+    app.run();
+    app.terminal.shutdown()?;
+    Ok(())
+}
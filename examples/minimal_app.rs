@@ -2,6 +2,11 @@
 // Minimal Application Example
 // Demonstrates a stripped-down application similar to deriving from TProgram
 // instead of TApplication in Borland Turbo Vision.
+//
+// The UI is built by setup(), which the snapshot checks
+// (cargo run --example snapshots) call to render the same first frame.
+//
+// Usage: cargo run --example minimal_app [-- --screenshot FILE]
 
 use turbo_vision::app::Application;
 use turbo_vision::core::command::CM_QUIT;
@@ -11,12 +16,8 @@ use turbo_vision::views::label::LabelBuilder;
 use turbo_vision::views::status_line::{StatusItem, StatusLine};
 use turbo_vision::views::window::WindowBuilder;
 
-fn main() -> turbo_vision::core::error::Result<()> {
-    // Create a minimal application
-    // In Borland TV, this would be: class MinimalApp : public TProgram
-    let mut app = Application::new()?;
-
-    // Add minimal status line (no menu bar!)
+/// Minimal status line (no menu bar!) and an information window
+pub fn setup(app: &mut Application) {
     let (width, height) = app.terminal.size();
     let status_line = StatusLine::new(
         Rect::new(0, height - 1, width, height),
@@ -28,36 +29,36 @@ fn main() -> turbo_vision::core::error::Result<()> {
     );
     app.set_status_line(status_line);
 
-    // Create a simple information window
+    // Information window, with the keys that quit
     let mut window = WindowBuilder::new()
         .bounds(Rect::new(15, 5, 65, 15))
         .title("Minimal Application")
         .build();
+    let lines = [
+        (2, "Demonstrates a stripped-down application."),
+        (3, "No menu bar, just a status line."),
+        (5, "To exit: Alt-X, Esc-X, Esc-Esc, F10, Ctrl-C"),
+        (6, "macOS  : Esc-X works if Alt fails"),
+    ];
+    for (y, text) in lines {
+        window.add(Box::new(LabelBuilder::new().bounds(Rect::new(2, y, 46, y)).text(text).build()));
+    }
+    app.desktop.add(Box::new(window));
+}
 
-    // Add some text and make sure user know how to quit
-    let label1 = LabelBuilder::new()
-        .bounds(Rect::new(2, 2, 46, 2))
-        .text("Demonstrates a stripped-down application.")
-        .build();
-    let label2 = LabelBuilder::new()
-        .bounds(Rect::new(2, 3, 46, 3))
-        .text("No menu bar, just a status line.")
-        .build();
-    let label3 = LabelBuilder::new()
-        .bounds(Rect::new(2, 5, 46, 5))
-        .text("To exit: Alt-X, Esc-X, Esc-Esc, F10, Ctrl-C")
-        .build();
-    let label4 = LabelBuilder::new()
-        .bounds(Rect::new(2, 6, 46, 6))
-        .text("macOS  : Esc-X works if Alt fails")
-        .build();
+fn main() -> turbo_vision::core::error::Result<()> {
+    // Create a minimal application
+    // In Borland TV, this would be: class MinimalApp : public TProgram
+    let mut app = Application::new()?;
 
-    window.add(Box::new(label1));
-    window.add(Box::new(label2));
-    window.add(Box::new(label3));
-    window.add(Box::new(label4));
+    setup(&mut app);
 
-    app.desktop.add(Box::new(window));
+    let mut args = std::env::args().skip(1);
+    if args.next().as_deref() == Some("--screenshot") {
+        if let Some(path) = args.next() {
+            app.set_screenshot_path(path);
+        }
+    }
 
     // Run the application
     app.run();
@@ -384,15 +384,10 @@ fn create_status_line(width: i16, height: i16) -> StatusLine {
 }
 
 fn show_about_dialog(app: &mut Application) {
-    use turbo_vision::helpers::msgbox::{MF_ABOUT, MF_OK_BUTTON, message_box};
+    use turbo_vision::views::msgbox::about_box;
 
-    let message = "Turbo Vision Demo\n\
-                   Version 1.0\n\
-                   \n\
-                   A demonstration of the\n\
-                   Turbo Vision framework";
-
-    message_box(app, message, MF_ABOUT | MF_OK_BUTTON);
+    let info = turbo_vision::app_info!("Turbo Vision Demo").extra_line("A demonstration of the Turbo Vision framework");
+    about_box(app, &info);
 }
 
 // ASCII Table Window
@@ -0,0 +1,52 @@
+// (C) 2025 - Enzo Lombardi
+// Snapshots - visual regression check for the examples
+//
+// Renders the first frame of every example registered in
+// tests/snapshot/mod.rs headlessly at 80x25 and compares it with the
+// golden .ans files in tests/golden, listing every differing cell.
+//
+// Usage: cargo run --example snapshots [-- --check | --update]
+//   --check   compare against the goldens (default); exits with 1 on differences
+//   --update  rewrite the goldens from the current rendering
+
+#[path = "../tests/snapshot/mod.rs"]
+mod snapshot;
+
+use snapshot::{check_snapshot, example_snapshots, SnapshotOutcome};
+use std::path::Path;
+
+fn main() -> std::io::Result<()> {
+    let update = match std::env::args().nth(1).as_deref() {
+        None | Some("--check") => false,
+        Some("--update") => true,
+        Some(other) => {
+            eprintln!("snapshots: unknown option {other}\nUsage: snapshots [--check | --update]");
+            std::process::exit(2);
+        }
+    };
+    let golden_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+
+    let mut failed = false;
+    for snapshot in example_snapshots() {
+        match check_snapshot(snapshot, &golden_dir, update)? {
+            SnapshotOutcome::Match => println!("ok       {}", snapshot.name),
+            SnapshotOutcome::Updated => println!("updated  {}", snapshot.name),
+            SnapshotOutcome::Missing => {
+                failed = true;
+                println!("MISSING  {} (run with --update)", snapshot.name);
+            }
+            SnapshotOutcome::Differs(diffs) => {
+                failed = true;
+                println!("DIFFERS  {} ({} cells)", snapshot.name, diffs.len());
+                for diff in diffs {
+                    println!("    {diff}");
+                }
+            }
+        }
+    }
+
+    if failed {
+        std::process::exit(1);
+    }
+    Ok(())
+}
@@ -4,7 +4,7 @@
 use std::time::Duration;
 use turbo_vision::app::Application;
 use turbo_vision::core::command::CM_QUIT;
-use turbo_vision::core::event::EventType;
+use turbo_vision::core::event::{Event, EventType, KB_CTRL_Z};
 use turbo_vision::core::geometry::Rect;
 use turbo_vision::helpers::msgbox::{MF_INFORMATION, MF_OK_BUTTON, message_box};
 use turbo_vision::views::View;
@@ -24,9 +24,9 @@ fn main() -> turbo_vision::core::error::Result<()> {
             .bounds(Rect::new(2, 2, 53, 8))
             .text(
                 "Demonstrates the suspend/resume functionality.\n\n\
-                Click 'Suspend' to temporarily return to shell.\n\
-                The application will exit raw mode and show your\n\
-                shell prompt. Press 'Enter' to resume.",
+                Click 'Suspend' or press Ctrl+Z to return to the\n\
+                shell. The application is stopped like any job;\n\
+                type 'fg' to resume it.",
             )
             .build(),
     ));
@@ -43,27 +43,25 @@ fn main() -> turbo_vision::core::error::Result<()> {
         if let Some(mut event) = app.terminal.poll_event(Duration::from_millis(50)).ok().flatten() {
             app.desktop.handle_event(&mut event);
 
+            // Ctrl+Z suspends as well; Application::run() does this by itself
+            if event.what == EventType::Keyboard && event.key_code == KB_CTRL_Z {
+                event = Event::command(CMD_SUSPEND);
+            }
+
             if event.what == EventType::Command {
                 match event.command {
                     CMD_SUSPEND => {
-                        // Suspend the application
-                        app.suspend()?;
-
-                        // At this point, the terminal is in normal mode
-                        // The user can use the shell, and when they press 'Enter',
-                        // we'll continue here
-
-                        // For this demo, we'll immediately resume
-                        // In a real implementation with signal handlers,
-                        // the process would be stopped here (SIGSTOP)
-                        // and resumed later (SIGCONT)
-
-                        println!("Application suspended. Press Enter to resume...");
-                        let mut input = String::new();
-                        std::io::stdin().read_line(&mut input)?;
-
-                        // Resume the application
-                        app.resume()?;
+                        if app.terminal.supports_job_control() {
+                            // Leaves the TUI, raises SIGTSTP and returns after 'fg'
+                            app.stop()?;
+                        } else {
+                            // No job control (Windows): wait for Enter instead
+                            app.suspend()?;
+                            println!("Application suspended. Press Enter to resume...");
+                            let mut input = String::new();
+                            std::io::stdin().read_line(&mut input)?;
+                            app.resume()?;
+                        }
 
                         // Show a message that we're back
                         message_box(&mut app, "Welcome back! Application resumed.", MF_INFORMATION | MF_OK_BUTTON);
@@ -5,6 +5,8 @@
 // - FilterValidator (character filtering)
 // - RangeValidator (numeric ranges)
 // - PictureValidator (format masks)
+//
+// Each kind sits in its own framed Group inside the dialog.
 
 use turbo_vision::app::Application;
 use turbo_vision::core::geometry::Rect;
@@ -14,8 +16,9 @@ use turbo_vision::views::{
     button::ButtonBuilder,
     static_text::StaticTextBuilder,
     label::LabelBuilder,
-    input_line::InputLineBuilder,
-    validator::{FilterValidator, RangeValidator, Validator},
+    group::{FrameStyle, Group, GroupBuilder},
+    input_line::{InputLine, InputLineBuilder},
+    validator::{FilterValidator, RangeValidator, Validator, ValidatorRef},
     picture_validator::PictureValidator,
 };
 use std::rc::Rc;
@@ -30,12 +33,30 @@ fn main() -> turbo_vision::core::error::Result<()> {
     Ok(())
 }
 
+/// Adds a "label: [input] hint" row to a framed group
+fn add_field(group: &mut Group, y: i16, label: &str, input: InputLine, hint: &str) {
+    group.add(Box::new(LabelBuilder::new().bounds(Rect::new(1, y, 21, y + 1)).text(label).build()));
+    group.add(Box::new(input));
+    if !hint.is_empty() {
+        group.add(Box::new(StaticTextBuilder::new().bounds(Rect::new(41, y, 57, y + 1)).text(hint).build()));
+    }
+}
+
+fn input_at(y: i16, max_length: usize, data: &Rc<RefCell<String>>, validator: ValidatorRef) -> InputLine {
+    InputLineBuilder::new()
+        .bounds(Rect::new(22, y, 40, y + 1))
+        .max_length(max_length)
+        .data(data.clone())
+        .validator(validator)
+        .build()
+}
+
 fn demo_all_validators(app: &mut Application) {
     let (width, height) = app.terminal.size();
 
     // Create larger dialog to fit all validators
     let dialog_width = 65;
-    let dialog_height = 34;
+    let dialog_height = 28;
     let dialog_x = (width - dialog_width) / 2;
     let dialog_y = (height - dialog_height) / 2;
 
@@ -51,190 +72,64 @@ fn demo_all_validators(app: &mut Application) {
         .build();
     dialog.add(Box::new(instructions));
 
-    let mut y = 4;
-
-    // Section 1: Filter & Range Validators
-    let section1 = StaticTextBuilder::new()
-        .bounds(Rect::new(2, y, dialog_width - 4, y + 1))
-        .text("=== Filter & Range Validators ===")
-        .build();
-    dialog.add(Box::new(section1));
-    y += 2;
+    // Each kind of validator gets its own framed group of fields
+    let group_bounds = |y: i16, rows: i16| Rect::new(2, y, dialog_width - 4, y + rows + 2);
 
-    // Field 1: Digits only (FilterValidator)
-    let label1 = LabelBuilder::new()
-        .bounds(Rect::new(2, y, dialog_width - 4, y + 1))
-        .text("Digits only:")
+    // Group 1: FilterValidator (character filtering)
+    let mut filter_group = GroupBuilder::new()
+        .bounds(group_bounds(4, 1))
+        .frame(FrameStyle::Single, Some("Filter"))
         .build();
-    dialog.add(Box::new(label1));
-    y += 1;
-
     let field1_data = Rc::new(RefCell::new(String::from("12345")));
     let field1_validator = Rc::new(RefCell::new(FilterValidator::new("0123456789")));
-    let input1 = InputLineBuilder::new()
-        .bounds(Rect::new(2, y, dialog_width - 4, y + 1))
-        .max_length(20)
-        .data(field1_data.clone())
-        .validator(field1_validator.clone())
-        .build();
-    dialog.add(Box::new(input1));
-    y += 2;
+    add_field(&mut filter_group, 0, "Digits only:", input_at(0, 20, &field1_data, field1_validator.clone()), "0-9");
+    dialog.add(Box::new(filter_group));
 
-    // Field 2: Range 0-100 (RangeValidator)
-    let label2 = LabelBuilder::new()
-        .bounds(Rect::new(2, y, dialog_width - 4, y + 1))
-        .text("Number (0-100):")
+    // Group 2: RangeValidator (numeric ranges, negative and hex numbers)
+    let mut range_group = GroupBuilder::new()
+        .bounds(group_bounds(8, 3))
+        .frame(FrameStyle::Single, Some("Range"))
         .build();
-    dialog.add(Box::new(label2));
-    y += 1;
-
     let field2_data = Rc::new(RefCell::new(String::from("50")));
     let field2_validator = Rc::new(RefCell::new(RangeValidator::new(0, 100)));
-    let input2 = InputLineBuilder::new()
-        .bounds(Rect::new(2, y, dialog_width - 4, y + 1))
-        .max_length(20)
-        .data(field2_data.clone())
-        .validator(field2_validator.clone())
-        .build();
-    dialog.add(Box::new(input2));
-    y += 2;
-
-    // Field 3: Range -50 to 50 (negative numbers allowed)
-    let label3 = LabelBuilder::new()
-        .bounds(Rect::new(2, y, dialog_width - 4, y + 1))
-        .text("Number (-50 to 50):")
-        .build();
-    dialog.add(Box::new(label3));
-    y += 1;
+    add_field(&mut range_group, 0, "Number:", input_at(0, 20, &field2_data, field2_validator.clone()), "0 to 100");
 
     let field3_data = Rc::new(RefCell::new(String::from("-25")));
     let field3_validator = Rc::new(RefCell::new(RangeValidator::new(-50, 50)));
-    let input3 = InputLineBuilder::new()
-        .bounds(Rect::new(2, y, dialog_width - 4, y + 1))
-        .max_length(20)
-        .data(field3_data.clone())
-        .validator(field3_validator.clone())
-        .build();
-    dialog.add(Box::new(input3));
-    y += 2;
-
-    // Field 4: Hex numbers 0x00-0xFF (RangeValidator with hex support)
-    let label4 = LabelBuilder::new()
-        .bounds(Rect::new(2, y, dialog_width - 4, y + 1))
-        .text("Hex (0x00-0xFF):")
-        .build();
-    dialog.add(Box::new(label4));
-    y += 1;
+    add_field(&mut range_group, 1, "Signed number:", input_at(1, 20, &field3_data, field3_validator.clone()), "-50 to 50");
 
     let field4_data = Rc::new(RefCell::new(String::from("0xAB")));
     let field4_validator = Rc::new(RefCell::new(RangeValidator::new(0, 255)));
-    let input4 = InputLineBuilder::new()
-        .bounds(Rect::new(2, y, dialog_width - 4, y + 1))
-        .max_length(20)
-        .data(field4_data.clone())
-        .validator(field4_validator.clone())
-        .build();
-    dialog.add(Box::new(input4));
-    y += 3;
-
-    // Section 2: Picture Mask Validators
-    let section2 = StaticTextBuilder::new()
-        .bounds(Rect::new(2, y, dialog_width - 4, y + 1))
-        .text("=== Picture Mask Validators ===")
-        .build();
-    dialog.add(Box::new(section2));
-    y += 2;
+    add_field(&mut range_group, 2, "Hex:", input_at(2, 20, &field4_data, field4_validator.clone()), "0x00 to 0xFF");
+    dialog.add(Box::new(range_group));
 
-    // Phone number field with validator
-    let phone_label = LabelBuilder::new()
-        .bounds(Rect::new(2, y, 18, y + 1))
-        .text("~P~hone Number:")
+    // Group 3: PictureValidator (format masks)
+    let mut picture_group = GroupBuilder::new()
+        .bounds(group_bounds(14, 3))
+        .frame(FrameStyle::Single, Some("Picture Mask"))
         .build();
-    dialog.add(Box::new(phone_label));
-
     let phone_data = Rc::new(RefCell::new(String::new()));
-    let mut phone_input = InputLineBuilder::new()
-        .bounds(Rect::new(18, y, 35, y + 1))
-        .max_length(20)
-        .data(phone_data.clone())
-        .build();
-    phone_input.set_validator(
-        Rc::new(RefCell::new(
-            PictureValidator::new("(###) ###-####")
-        ))
-    );
-    dialog.add(Box::new(phone_input));
-
-    let phone_hint = StaticTextBuilder::new()
-        .bounds(Rect::new(36, y, 51, y + 1))
-        .text("(###) ###-####")
-        .build();
-    dialog.add(Box::new(phone_hint));
-    y += 2;
-
-    // Date field with validator
-    let date_label = LabelBuilder::new()
-        .bounds(Rect::new(2, y, 18, y + 1))
-        .text("~D~ate:")
-        .build();
-    dialog.add(Box::new(date_label));
+    let phone_validator = Rc::new(RefCell::new(PictureValidator::new("(###) ###-####")));
+    add_field(&mut picture_group, 0, "Phone Number:", input_at(0, 20, &phone_data, phone_validator), "(###) ###-####");
 
     let date_data = Rc::new(RefCell::new(String::new()));
-    let mut date_input = InputLineBuilder::new()
-        .bounds(Rect::new(18, y, 30, y + 1))
-        .max_length(10)
-        .data(date_data.clone())
-        .build();
-    date_input.set_validator(
-        Rc::new(RefCell::new(
-            PictureValidator::new("##/##/####")
-        ))
-    );
-    dialog.add(Box::new(date_input));
-
-    let date_hint = StaticTextBuilder::new()
-        .bounds(Rect::new(31, y, 51, y + 1))
-        .text("##/##/####")
-        .build();
-    dialog.add(Box::new(date_hint));
-    y += 2;
-
-    // Product code field
-    let code_label = LabelBuilder::new()
-        .bounds(Rect::new(2, y, 18, y + 1))
-        .text("Product ~C~ode:")
-        .build();
-    dialog.add(Box::new(code_label));
+    let date_validator = Rc::new(RefCell::new(PictureValidator::new("##/##/####")));
+    add_field(&mut picture_group, 1, "Date:", input_at(1, 10, &date_data, date_validator), "##/##/####");
 
     let code_data = Rc::new(RefCell::new(String::new()));
-    let mut code_input = InputLineBuilder::new()
-        .bounds(Rect::new(18, y, 31, y + 1))
-        .max_length(9)
-        .data(code_data.clone())
-        .build();
-    code_input.set_validator(
-        Rc::new(RefCell::new(
-            PictureValidator::new("@@@@-####")
-        ))
-    );
-    dialog.add(Box::new(code_input));
-
-    let code_hint = StaticTextBuilder::new()
-        .bounds(Rect::new(32, y, 51, y + 1))
-        .text("@@@@-####")
-        .build();
-    dialog.add(Box::new(code_hint));
-    y += 2;
+    let code_validator = Rc::new(RefCell::new(PictureValidator::new("@@@@-####")));
+    add_field(&mut picture_group, 2, "Product Code:", input_at(2, 9, &code_data, code_validator), "@@@@-####");
+    dialog.add(Box::new(picture_group));
 
     // Legend
     let legend = StaticTextBuilder::new()
-        .bounds(Rect::new(2, y, dialog_width - 4, y + 2))
+        .bounds(Rect::new(2, 20, dialog_width - 4, 22))
         .text("Legend: # = digit, @ = letter, ! = any\nLiterals (like /, -, ()) are inserted automatically")
         .build();
     dialog.add(Box::new(legend));
-    y += 3;
 
     // Buttons
+    let y = 23;
     let ok_button = ButtonBuilder::new()
         .bounds(Rect::new(20, y, 30, y + 2))
         .title("  OK  ")
@@ -283,13 +283,8 @@ All matching Borland TV!";
 /// Creates a centered dialog containing the given text and an OK button.
 /// The dialog is modal and blocks until the user dismisses it.
 fn show_msg(app: &mut Application, text: &str, title: &str, dialog_width: i16, dialog_height: i16) {
-    let (term_width, term_height) = app.terminal.size();
-
-    let dialog_x = (term_width - dialog_width) / 2;
-    let dialog_y = (term_height - dialog_height) / 2;
-
     let mut dialog = DialogBuilder::new()
-        .bounds(Rect::new(dialog_x, dialog_y, dialog_x + dialog_width, dialog_y + dialog_height))
+        .bounds(Rect::centered(app.terminal.size(), dialog_width, dialog_height))
         .title(title)
         .build();
 
@@ -4,14 +4,24 @@
 //! Manages the main application window, menu bar, status line, and desktop.
 //! Provides the central event loop and command dispatching system.
 
-use crate::core::command::{CM_CANCEL, CM_CASCADE, CM_COMMAND_SET_CHANGED, CM_QUIT, CM_TILE, CommandId};
+use crate::core::command::{CM_CANCEL, CM_CASCADE, CM_COMMAND_SET_CHANGED, CM_HELP, CM_NEW, CM_OPEN, CM_QUIT, CM_RELOAD_THEME, CM_SAVE, CM_SAVE_AS, CM_TILE, CM_YES, CommandId};
 use crate::core::command_set;
-use crate::core::error::Result;
-use crate::core::event::{Event, EventType, KB_ALT_X};
+use crate::core::draw::str_width;
+use crate::core::error::{Result, TurboVisionError};
+use crate::core::event::{Event, EventType, KB_ALT_X, KB_CTRL_Z, KB_F1};
 use crate::core::geometry::Rect;
-use crate::terminal::Terminal;
-use crate::views::{IdleView, View, desktop::Desktop, menu_bar::MenuBar, status_line::StatusLine};
-use std::time::Duration;
+use crate::core::theme::Theme;
+use crate::terminal::{CursorStyle, Terminal};
+use crate::views::{IdleView, View, ViewId, command_bar::CommandBar, desktop::Desktop, dialog::Dialog, file_dialog::{FileDialog, FileDialogMode}, file_editor::FileEditor, menu_bar::{standard_menus, MenuBar, MenuFeatures}, msgbox::{confirmation_box_yes_no, message_box_error, message_box_ok}, notify_strip::{Notification, NotifyStrip, Severity}, static_text::StaticText, status_line::{StatusItem, StatusLine}};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Number of paths kept by [`Application::recent_files()`]
+pub const MAX_RECENT_FILES: usize = 10;
+
+/// How long the event loops wait for input before calling `idle()`
+/// (matches magiblot's eventTimeoutMs)
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(20);
 
 pub struct Application {
     pub terminal: Terminal,
@@ -24,6 +34,22 @@ pub struct Application {
     /// These widgets continue to animate even during modal dialogs
     /// Matches Borland: TProgram::idle() continues running during execView()
     pub(crate) overlay_widgets: Vec<Box<dyn IdleView>>,
+    /// Theme file re-read on CM_RELOAD_THEME
+    theme_file: Option<PathBuf>,
+    /// Command line opened with `:` (see [`set_command_bar()`](Self::set_command_bar))
+    command_bar: Option<CommandBar>,
+    /// Notifications over the bottom desktop row (see [`notify()`](Self::notify))
+    notify_strip: NotifyStrip,
+    /// Errors shown in message boxes once [`run()`](Self::run) starts
+    startup_errors: Vec<String>,
+    /// Where to save the first frame before exiting (see [`set_screenshot_path()`](Self::set_screenshot_path))
+    screenshot_path: Option<PathBuf>,
+    /// FileEditor windows opened by [`open_file()`](Self::open_file) and [`new_file()`](Self::new_file)
+    editors: Vec<ViewId>,
+    /// Files opened or saved through the editor helpers, most recent first
+    recent_files: Vec<PathBuf>,
+    /// Poll timeout of the event loops (see [`set_poll_interval()`](Self::set_poll_interval))
+    poll_interval: Duration,
     // Note: Command set is now stored in thread-local static (command_set module)
     // This matches Borland's architecture where TView::curCommandSet is static
 }
@@ -60,6 +86,14 @@ impl Application {
     /// ```
     pub fn new() -> Result<Self> {
         let terminal = Terminal::init()?;
+        Ok(Self::with_terminal(terminal))
+    }
+
+    /// Creates a new application on an already initialized terminal.
+    ///
+    /// Useful for driving an application on a headless terminal
+    /// (see [`Terminal::new_headless()`](crate::Terminal::new_headless)).
+    pub fn with_terminal(terminal: Terminal) -> Self {
         let (width, height) = terminal.size();
 
         // Create Desktop with full screen bounds initially
@@ -78,6 +112,14 @@ impl Application {
             running: false,
             needs_redraw: true, // Initial draw needed
             overlay_widgets: Vec::new(),
+            theme_file: None,
+            command_bar: None,
+            notify_strip: NotifyStrip::new(Rect::new(0, 0, width, 1)),
+            startup_errors: Vec::new(),
+            screenshot_path: None,
+            editors: Vec::new(),
+            recent_files: Vec::new(),
+            poll_interval: DEFAULT_POLL_INTERVAL,
         };
 
         // Set initial Desktop bounds (adjusts for missing menu/status)
@@ -88,7 +130,20 @@ impl Application {
         // This sets up the owner chain so views can resolve colors through Desktop's CP_APP_COLOR palette
         app.desktop.init_palette_chain();
 
-        Ok(app)
+        app
+    }
+
+    /// How long the event loops wait for input before going idle
+    ///
+    /// Shorter intervals make animations and timers (idle processing) more
+    /// responsive at the cost of CPU; longer ones wake up less often.
+    /// Defaults to [`DEFAULT_POLL_INTERVAL`].
+    pub fn set_poll_interval(&mut self, interval: Duration) {
+        self.poll_interval = interval;
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        self.poll_interval
     }
 
     pub fn set_menu_bar(&mut self, menu_bar: MenuBar) {
@@ -155,9 +210,77 @@ impl Application {
             desktop_bounds.b.y -= 1;
         }
 
+        // The notification strip overlays the bottom desktop row
+        let strip_bounds = Rect::new(desktop_bounds.a.x, desktop_bounds.b.y - 1, desktop_bounds.b.x, desktop_bounds.b.y);
+        self.notify_strip.set_bounds(strip_bounds);
+
         self.desktop.set_bounds(desktop_bounds);
     }
 
+    /// Re-lay out the menu bar, status line and desktop after a terminal resize
+    /// The menu bar and status line are stretched to the new width, the status line
+    /// moves to the new bottom row, and the desktop fills the space in between.
+    pub fn handle_resize(&mut self) {
+        let (width, height) = self.terminal.size();
+
+        if let Some(ref mut menu_bar) = self.menu_bar {
+            let bounds = menu_bar.bounds();
+            menu_bar.set_bounds(Rect::new(0, 0, width, bounds.height()));
+        }
+
+        if let Some(ref mut status_line) = self.status_line {
+            let status_height = status_line.bounds().height();
+            status_line.set_bounds(Rect::new(0, height - status_height, width, height));
+        }
+
+        if let Some(ref mut command_bar) = self.command_bar {
+            command_bar.set_bounds(Rect::new(0, height - 1, width, height));
+        }
+
+        self.update_desktop_bounds();
+        self.needs_redraw = true;
+    }
+
+    /// Install a command bar, opened when the user presses `:` and no view
+    /// consumed the key. It is drawn over the bottom row while active.
+    pub fn set_command_bar(&mut self, mut command_bar: CommandBar) {
+        let (width, height) = self.terminal.size();
+        command_bar.set_bounds(Rect::new(0, height - 1, width, height));
+        self.command_bar = Some(command_bar);
+    }
+
+    pub fn command_bar(&self) -> Option<&CommandBar> {
+        self.command_bar.as_ref()
+    }
+
+    pub fn command_bar_mut(&mut self) -> Option<&mut CommandBar> {
+        self.command_bar.as_mut()
+    }
+
+    /// Show `message` in the notification strip above the status line for `duration`
+    ///
+    /// The strip overlays the bottom desktop row without taking the focus;
+    /// a click on it or ESC dismisses the newest message.
+    pub fn notify(&mut self, message: &str, severity: Severity, duration: Duration) {
+        self.notify_strip.push(message, severity, duration);
+        self.needs_redraw = true;
+    }
+
+    /// Notifications still shown, oldest first
+    pub fn notifications(&self) -> &[Notification] {
+        self.notify_strip.notifications()
+    }
+
+    pub fn clear_notifications(&mut self) {
+        self.notify_strip.clear();
+        self.needs_redraw = true;
+    }
+
+    /// The notification strip, e.g. to change its dismiss key
+    pub fn notify_strip_mut(&mut self) -> &mut NotifyStrip {
+        &mut self.notify_strip
+    }
+
     /// Request a full redraw on the next frame
     /// Call this after changing the palette or other global settings
     pub fn needs_redraw(&mut self) {
@@ -204,6 +327,58 @@ impl Application {
         }
     }
 
+    /// Apply a theme's application palette
+    /// Redraw is triggered automatically if the colors change.
+    pub fn set_theme(&mut self, theme: &Theme) {
+        self.set_palette(theme.palette().map(<[u8]>::to_vec));
+    }
+
+    /// Load and apply a theme file, and remember it for [`reload_theme()`](Self::reload_theme)
+    ///
+    /// On error the current colors are kept.
+    pub fn load_theme_file(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        self.theme_file = Some(path.to_path_buf());
+        let theme = Theme::load(path)?;
+        self.set_theme(&theme);
+        Ok(())
+    }
+
+    /// Re-read the theme file set with [`load_theme_file()`](Self::load_theme_file)
+    ///
+    /// Lets colors be tweaked without recompiling: edit the file, then send
+    /// CM_RELOAD_THEME (e.g. from a debug menu item or a timer). If the file
+    /// does not parse, the error names the line and the previous theme stays.
+    pub fn reload_theme(&mut self) -> Result<()> {
+        let path = self
+            .theme_file
+            .clone()
+            .ok_or_else(|| TurboVisionError::invalid_input("no theme file loaded"))?;
+        let theme = Theme::load(path)?;
+        self.set_theme(&theme);
+        Ok(())
+    }
+
+    /// Queue an error to show in a message box once [`run()`](Self::run) starts.
+    ///
+    /// Lets setup code (e.g. opening files named on the command line) report
+    /// problems to the user instead of failing before the UI is up.
+    pub fn add_startup_error(&mut self, message: impl Into<String>) {
+        self.startup_errors.push(message.into());
+    }
+
+    /// Errors queued with [`add_startup_error()`](Self::add_startup_error) and not shown yet
+    pub fn startup_errors(&self) -> &[String] {
+        &self.startup_errors
+    }
+
+    /// Make [`run()`](Self::run) save its first frame to `path` as ANSI text and exit.
+    ///
+    /// Useful for documentation screenshots and smoke tests.
+    pub fn set_screenshot_path(&mut self, path: impl Into<PathBuf>) {
+        self.screenshot_path = Some(path.into());
+    }
+
     /// Get an event (with drawing)
     /// Matches Borland/Magiblot: TProgram::getEvent() (tprogram.cc:105-174)
     /// This is called by modal views' execute() methods.
@@ -222,9 +397,9 @@ impl Application {
         self.draw();
         let _ = self.terminal.flush();
 
-        // Poll for event with 20ms timeout (matches magiblot's eventTimeoutMs)
+        // Poll for event with the poll interval (20ms by default, matches magiblot's eventTimeoutMs)
         // This blocks until an event arrives or timeout occurs
-        match self.terminal.poll_event(Duration::from_millis(20)).ok().flatten() {
+        match self.terminal.poll_event(self.poll_interval).ok().flatten() {
             Some(event) => {
                 // Event received - return it immediately without calling idle()
                 // Matches magiblot: idle() is NOT called when events are present
@@ -253,6 +428,11 @@ impl Application {
         // Check if view is modal
         let is_modal = (view.state() & SF_MODAL) != 0;
 
+        // The views already on the desktop are behind the modal view until it closes
+        if is_modal {
+            self.desktop.on_modal_enter();
+        }
+
         // Add view to desktop
         self.desktop.add(view);
         let view_index = self.desktop.child_count() - 1;
@@ -262,6 +442,10 @@ impl Application {
             return 0;
         }
 
+        // Save what's beneath the modal view so closing it restores the screen
+        let under = self.desktop.child_at(view_index).shadow_bounds();
+        self.terminal.save_under(under);
+
         // Modal view - run event loop
         // Matches Borland: TProgram::execView() runs modal loop (tprogram.cc:184-194)
         // Matches magiblot: Only calls idle() when no events (true event-driven)
@@ -273,8 +457,8 @@ impl Application {
             self.draw();
             let _ = self.terminal.flush();
 
-            // Poll for event with 20ms timeout (blocks until event or timeout)
-            match self.terminal.poll_event(Duration::from_millis(20)).ok().flatten() {
+            // Poll for event with the poll interval (blocks until event or timeout)
+            match self.terminal.poll_event(self.poll_interval).ok().flatten() {
                 Some(mut event) => {
                     // Event received - handle it immediately without calling idle()
                     self.handle_event(&mut event);
@@ -293,10 +477,14 @@ impl Application {
                     // Modal view wants to close
                     // Remove it from desktop and return the end state
                     self.desktop.remove_child(view_index);
+                    self.desktop.on_modal_leave();
+                    let _ = self.terminal.restore_under();
                     return end_state;
                 }
             } else {
                 // View was removed (closed externally)
+                self.desktop.on_modal_leave();
+                let _ = self.terminal.restore_under();
                 return CM_CANCEL;
             }
         }
@@ -310,6 +498,20 @@ impl Application {
         self.draw();
         let _ = self.terminal.flush();
 
+        if let Some(path) = self.screenshot_path.take() {
+            match self.terminal.dump_screen(&path.to_string_lossy()) {
+                Ok(()) => {
+                    self.running = false;
+                    return;
+                }
+                Err(e) => self.add_startup_error(format!("Cannot save screenshot {}: {e}", path.display())),
+            }
+        }
+
+        for message in std::mem::take(&mut self.startup_errors) {
+            crate::views::msgbox::message_box_error(self, &message);
+        }
+
         while self.running {
             // Optimized drawing strategy (matches Borland's approach):
             // Draw first, then wait for events
@@ -324,9 +526,9 @@ impl Application {
                 let _ = self.terminal.flush();
             }
 
-            // Poll for event with 20ms timeout (matches magiblot's eventTimeoutMs)
+            // Poll for event with the poll interval (20ms by default, matches magiblot's eventTimeoutMs)
             // This blocks until an event arrives or timeout occurs
-            match self.terminal.poll_event(Duration::from_millis(20)).ok().flatten() {
+            match self.terminal.poll_event(self.poll_interval).ok().flatten() {
                 Some(mut event) => {
                     // Event received - handle it immediately without calling idle()
                     // Matches magiblot: idle() is NOT called when events are present
@@ -390,6 +592,9 @@ impl Application {
         // Draw desktop first, then menu bar on top (so dropdown appears over desktop)
         self.desktop.draw(&mut self.terminal);
 
+        // Notifications cover the windows, dropdown menus cover the notifications
+        self.notify_strip.draw(&mut self.terminal);
+
         if let Some(ref mut menu_bar) = self.menu_bar {
             menu_bar.draw(&mut self.terminal);
         }
@@ -398,6 +603,10 @@ impl Application {
             status_line.draw(&mut self.terminal);
         }
 
+        if let Some(ref mut command_bar) = self.command_bar {
+            command_bar.draw(&mut self.terminal);
+        }
+
         // Draw overlay widgets on top of everything
         // These continue to animate even during modal dialogs
         for widget in &mut self.overlay_widgets {
@@ -405,11 +614,32 @@ impl Application {
         }
 
         // Update cursor after drawing all views
-        // Desktop contains windows/dialogs with focused controls
-        self.desktop.update_cursor(&mut self.terminal);
+        // Desktop contains windows/dialogs with focused controls; the one showing
+        // the cursor may ask for a style, others get the terminal's default
+        self.terminal.set_cursor_style(CursorStyle::Default);
+        match self.command_bar {
+            Some(ref command_bar) if command_bar.is_active() => command_bar.update_cursor(&mut self.terminal),
+            _ => self.desktop.update_cursor(&mut self.terminal),
+        }
     }
 
     pub fn handle_event(&mut self, event: &mut Event) {
+        // The terminal has already resized its buffers; re-lay out the views
+        if event.what == EventType::Resize {
+            self.handle_resize();
+            event.clear();
+            return;
+        }
+
+        // An open command bar takes all keyboard input; its parsed
+        // command continues through the normal command routing below
+        if let Some(ref mut command_bar) = self.command_bar {
+            command_bar.handle_event(event);
+            if event.what == EventType::Nothing {
+                return;
+            }
+        }
+
         // Menu bar gets first shot
         if let Some(ref mut menu_bar) = self.menu_bar {
             menu_bar.handle_event(event);
@@ -418,12 +648,35 @@ impl Application {
             }
         }
 
+        // F1 asks the focused view for help
+        if event.what == EventType::Keyboard && event.key_code == KB_F1 {
+            *event = Event::command(CM_HELP);
+        }
+
+        // Clicks on the notification strip never reach the windows beneath it
+        if event.what == EventType::MouseDown {
+            self.notify_strip.handle_event(event);
+            if event.what == EventType::Nothing {
+                self.needs_redraw = true;
+                return;
+            }
+        }
+
         // Desktop/windows
         self.desktop.handle_event(event);
         if event.what == EventType::Nothing {
             return;
         }
 
+        // The dismiss key, if no window wanted it
+        if event.what == EventType::Keyboard {
+            self.notify_strip.handle_event(event);
+            if event.what == EventType::Nothing {
+                self.needs_redraw = true;
+                return;
+            }
+        }
+
         // Status line
         if let Some(ref mut status_line) = self.status_line {
             status_line.handle_event(event);
@@ -432,12 +685,27 @@ impl Application {
             }
         }
 
+        // Menu item shortcuts, for keys no window wanted; the command goes back to the windows
+        // Matches Borland: TMenuBar is ofPostProcess and turns hot keys into commands
+        if event.what == EventType::Keyboard {
+            if let Some(command) = self.menu_bar.as_ref().and_then(|menu_bar| menu_bar.hot_key_command(event.key_code)) {
+                *event = Event::command(command);
+                self.desktop.handle_event(event);
+                if event.what == EventType::Nothing {
+                    return;
+                }
+            }
+        }
+
         // Application-level command handling
         if event.what == EventType::Command {
             match event.command {
+                // Windows with unsaved changes may cancel the quit
                 CM_QUIT => {
-                    self.running = false;
                     event.clear();
+                    if Desktop::close_all_with_prompt(self) {
+                        self.running = false;
+                    }
                 }
                 CM_TILE => {
                     self.tile();
@@ -447,6 +715,31 @@ impl Application {
                     self.cascade();
                     event.clear();
                 }
+                // No view had help to show
+                CM_HELP => {
+                    event.clear();
+                    message_box_ok(self, "No help available");
+                }
+                CM_RELOAD_THEME => {
+                    event.clear();
+                    if let Err(e) = self.reload_theme() {
+                        message_box_error(self, &e.to_string());
+                    }
+                }
+                CM_NEW => {
+                    event.clear();
+                    self.new_file();
+                }
+                CM_OPEN => {
+                    event.clear();
+                    self.open_file(None);
+                }
+                // Only when an editor window is in front; otherwise the command passes on
+                CM_SAVE | CM_SAVE_AS if self.top_editor().is_some() => {
+                    let save_as = event.command == CM_SAVE_AS;
+                    event.clear();
+                    self.save_editor(save_as);
+                }
                 _ => {}
             }
         }
@@ -455,7 +748,26 @@ impl Application {
         if event.what == EventType::Keyboard && (event.key_code == KB_ALT_X) {
             // Treat these as quit command
             *event = Event::command(CM_QUIT);
-            self.running = false;
+            if Desktop::close_all_with_prompt(self) {
+                self.running = false;
+            }
+        }
+
+        // A Ctrl+Z no view took (editors undo with it) stops the process, as in a shell
+        if event.what == EventType::Keyboard && event.key_code == KB_CTRL_Z && self.terminal.supports_job_control() {
+            event.clear();
+            if let Err(e) = self.stop() {
+                message_box_error(self, &e.to_string());
+            }
+        }
+
+        // An unconsumed ':' opens the command bar
+        if event.what == EventType::Keyboard && event.key_code == ':' as u16 {
+            if let Some(ref mut command_bar) = self.command_bar {
+                command_bar.activate();
+                event.clear();
+                self.needs_redraw = true;
+            }
         }
     }
 
@@ -484,6 +796,168 @@ impl Application {
         self.desktop.get_bounds()
     }
 
+    // Editor Window Methods
+    // Matches Borland: TEditorApp newWindow/fileOpen (tvedit.cc)
+
+    /// Open an untitled FileEditor window
+    /// Handles CM_NEW
+    pub fn new_file(&mut self) -> ViewId {
+        let editor = FileEditor::new(self.next_editor_bounds(), "Untitled");
+        self.add_editor(editor)
+    }
+
+    /// Open `path` in a FileEditor window, or ask for a file when `path` is None
+    /// Handles CM_OPEN
+    ///
+    /// A file that is already open has its window brought to the front
+    /// instead. Load errors are shown in a message box. Returns the window,
+    /// or None if the dialog was canceled or the file could not be read.
+    pub fn open_file(&mut self, path: Option<PathBuf>) -> Option<ViewId> {
+        let path = match path {
+            Some(path) => path,
+            None => self.ask_file_name("Open File", FileDialogMode::Open)?,
+        };
+
+        let id = match self.find_editor(&path) {
+            Some(id) => {
+                self.desktop.select_window(id);
+                id
+            }
+            None => {
+                let mut editor = FileEditor::new(self.next_editor_bounds(), "");
+                if let Err(e) = editor.load_file(path.clone()) {
+                    message_box_error(self, &format!("Cannot open {}: {e}", path.display()));
+                    return None;
+                }
+                if let Some(backup) = editor.recoverable_backup() {
+                    let message = format!("{} has a newer backup. Recover it?", backup.display());
+                    if confirmation_box_yes_no(self, &message) == CM_YES {
+                        if let Err(e) = editor.recover_backup() {
+                            message_box_error(self, &format!("Cannot recover {}: {e}", backup.display()));
+                        }
+                    }
+                }
+                editor.refresh_title();
+                self.add_editor(editor)
+            }
+        };
+        self.add_recent_file(path);
+        self.needs_redraw = true;
+        Some(id)
+    }
+
+    /// Add a FileEditor window that CM_SAVE/CM_SAVE_AS and `open_file()` know about
+    pub(crate) fn add_editor(&mut self, editor: FileEditor) -> ViewId {
+        if let Some(path) = editor.filename() {
+            self.add_recent_file(path.clone());
+        }
+        let id = self.desktop.add(Box::new(editor));
+        self.editors.push(id);
+        self.needs_redraw = true;
+        id
+    }
+
+    /// Save the front editor window
+    /// Handles CM_SAVE (`save_as` false) and CM_SAVE_AS (`save_as` true)
+    ///
+    /// Untitled buffers always ask for a file name. Errors are shown in a
+    /// message box. Returns true if the file was written.
+    pub fn save_editor(&mut self, save_as: bool) -> bool {
+        let Some(id) = self.top_editor() else {
+            return false;
+        };
+        let untitled = self.editor_mut(id).is_some_and(|editor| editor.filename().is_none());
+        let new_path = if save_as || untitled {
+            let Some(path) = self.ask_file_name("Save File As", FileDialogMode::Save) else {
+                return false;
+            };
+            Some(path)
+        } else {
+            None
+        };
+
+        let Some(editor) = self.editor_mut(id) else {
+            return false;
+        };
+        let result = match new_path {
+            Some(path) => editor.save_as(path),
+            None => editor.save().map(|_| ()),
+        };
+        let title = editor.get_title();
+        let saved_path = editor.filename().cloned();
+        editor.refresh_title();
+
+        match result {
+            Ok(()) => {
+                if let Some(path) = saved_path {
+                    self.add_recent_file(path);
+                }
+                self.needs_redraw = true;
+                true
+            }
+            Err(e) => {
+                message_box_error(self, &format!("Cannot save {title}: {e}"));
+                false
+            }
+        }
+    }
+
+    /// Files opened or saved through the editor helpers, most recent first
+    pub fn recent_files(&self) -> &[PathBuf] {
+        &self.recent_files
+    }
+
+    fn add_recent_file(&mut self, path: PathBuf) {
+        self.recent_files.retain(|existing| *existing != path);
+        self.recent_files.insert(0, path);
+        self.recent_files.truncate(MAX_RECENT_FILES);
+    }
+
+    /// The editor window with this id, if it is still open
+    fn editor_mut(&mut self, id: ViewId) -> Option<&mut FileEditor> {
+        self.desktop.window_by_id_mut(id)?.as_any_mut().downcast_mut::<FileEditor>()
+    }
+
+    /// The front window, if it is one of our editors
+    fn top_editor(&self) -> Option<ViewId> {
+        self.desktop.top_window_id().filter(|id| self.editors.contains(id))
+    }
+
+    /// The open editor window showing `path`
+    fn find_editor(&mut self, path: &Path) -> Option<ViewId> {
+        let wanted = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        // Forget editors whose windows were closed
+        self.editors.retain(|&id| self.desktop.window_by_id(id).is_some());
+
+        self.editors.iter().copied().find(|&id| {
+            self.desktop
+                .window_by_id(id)
+                .and_then(|window| window.as_any().downcast_ref::<FileEditor>())
+                .and_then(|editor| editor.filename().cloned())
+                .is_some_and(|open| std::fs::canonicalize(&open).unwrap_or(open) == wanted)
+        })
+    }
+
+    /// Bounds for a new editor window: 3/4 of the desktop, stepped one cell
+    /// down and right for each open window (relative to the desktop)
+    fn next_editor_bounds(&self) -> Rect {
+        let desktop = self.get_tile_rect();
+        let (width, height) = (desktop.width(), desktop.height());
+        let window_width = (width * 3 / 4).max(width.min(20));
+        let window_height = (height * 3 / 4).max(height.min(6));
+        let steps = (width - window_width).min(height - window_height).max(1);
+        let offset = (self.desktop.child_count() % steps as usize) as i16;
+        Rect::new(offset, offset, offset + window_width, offset + window_height)
+    }
+
+    /// Show a FileDialog and return the chosen path
+    pub(crate) fn ask_file_name(&mut self, title: &str, mode: FileDialogMode) -> Option<PathBuf> {
+        let (width, height) = self.terminal.size();
+        let bounds = Rect::centered((width, height), 62.min(width), 20.min(height));
+        let mut dialog = FileDialog::new(bounds, title, "*", None, mode).build();
+        dialog.execute(self)
+    }
+
     // Command Set Management
     // Delegates to global command set functions (command_set module)
     // Matches Borland's TView command set methods (tview.cc:161-389, 672-677)
@@ -513,6 +987,41 @@ impl Application {
         let _ = self.terminal.beep();
     }
 
+    /// Show `text` in a centered box while `f` runs, then erase the box
+    ///
+    /// For quick synchronous work that still takes a noticeable moment: the
+    /// box is drawn and flushed, `f` runs on the current thread, and the
+    /// screen beneath the box is restored. No events are handled while `f`
+    /// runs; use a worker thread for anything longer.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// # use turbo_vision::app::Application;
+    /// # let mut app = Application::new()?;
+    /// let lines = app.with_busy_message("Indexing files, please wait…", || {
+    ///     std::fs::read_to_string("big.log").map(|text| text.lines().count())
+    /// });
+    /// # Ok::<(), turbo_vision::core::error::TurboVisionError>(())
+    /// ```
+    pub fn with_busy_message<T>(&mut self, text: &str, f: impl FnOnce() -> T) -> T {
+        let (screen_width, _) = self.terminal.size();
+        let width = (str_width(text) as i16 + 6).max(24).min(screen_width);
+        let bounds = Rect::centered(self.terminal.size(), width, 5);
+
+        let mut dialog = Dialog::new(bounds, "");
+        dialog.add(Box::new(StaticText::new_centered(Rect::new(1, 1, width - 3, 2), text)));
+
+        self.terminal.save_under(dialog.shadow_bounds());
+        dialog.draw(&mut self.terminal);
+        let _ = self.terminal.flush();
+
+        let result = f();
+
+        let _ = self.terminal.restore_under();
+        let _ = self.terminal.flush();
+        result
+    }
+
     /// Set the ESC timeout in milliseconds
     ///
     /// This controls how long the terminal waits after ESC to detect ESC+letter sequences
@@ -554,6 +1063,27 @@ impl Application {
             widget.idle();
         }
 
+        // Autosave editor backups
+        for id in self.editors.clone() {
+            if let Some(editor) = self.editor_mut(id) {
+                editor.idle();
+            }
+        }
+
+        // Bring the status items back once a flashed message expires
+        if let Some(ref mut status_line) = self.status_line {
+            let flashing = status_line.is_flashing();
+            status_line.idle();
+            if flashing && !status_line.is_flashing() {
+                self.needs_redraw = true;
+            }
+        }
+
+        // Drop expired notifications
+        if self.notify_strip.expire(Instant::now()) {
+            self.needs_redraw = true;
+        }
+
         // Update tile/cascade command states based on desktop state
         // Matches Borland: TVDemo::idle() checks deskTop->firstThat(isTileable, 0)
         if self.desktop.has_tileable_windows() {
@@ -583,6 +1113,20 @@ impl Application {
         }
     }
 
+    /// Stop the process as a shell's Ctrl+Z would (Unix only)
+    ///
+    /// Suspends, raises SIGTSTP, and resumes with a full redraw once the shell
+    /// continues the process (`fg`). Called for a Ctrl+Z no view consumed.
+    /// Does nothing without job control: on Windows and headless terminals.
+    pub fn stop(&mut self) -> crate::core::error::Result<()> {
+        if !self.terminal.supports_job_control() {
+            return Ok(());
+        }
+        self.suspend()?;
+        crate::terminal::stop_process()?;
+        self.resume()
+    }
+
     /// Suspend the application (for Ctrl+Z handling)
     /// Matches Borland: TProgram::suspend() - temporarily exits TUI mode
     /// Restores terminal to normal mode, allowing user to return to shell
@@ -621,3 +1165,529 @@ impl Drop for Application {
         let _ = self.terminal.shutdown();
     }
 }
+
+type MenuFactory = Box<dyn FnOnce(Rect) -> MenuBar>;
+
+/// Builder for creating a fully wired application with a fluent API.
+///
+/// Takes care of creating the menu bar, status line and desktop in the right
+/// order. Defaults: no menu bar, a status line with a single "Alt-X Exit" item,
+/// the standard '░' desktop pattern and the Borland palette.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use turbo_vision::app::ApplicationBuilder;
+/// use turbo_vision::core::theme::Theme;
+///
+/// let mut app = ApplicationBuilder::new()
+///     .standard_menus()
+///     .desktop_background('▒')
+///     .min_size(80, 24)
+///     .theme(Theme::dark())
+///     .build()?;
+/// app.run();
+/// # Ok::<(), turbo_vision::core::error::TurboVisionError>(())
+/// ```
+#[derive(Default)]
+pub struct ApplicationBuilder {
+    terminal: Option<Terminal>,
+    menu: Option<MenuFactory>,
+    status_items: Option<Vec<StatusItem>>,
+    background: Option<char>,
+    min_size: Option<(i16, i16)>,
+    theme: Option<Theme>,
+    poll_interval: Option<Duration>,
+}
+
+impl ApplicationBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use an already initialized terminal instead of calling `Terminal::init()`
+    #[must_use]
+    pub fn terminal(mut self, terminal: Terminal) -> Self {
+        self.terminal = Some(terminal);
+        self
+    }
+
+    /// Sets the menu bar
+    #[must_use]
+    pub fn menu(mut self, menu_bar: MenuBar) -> Self {
+        self.menu = Some(Box::new(move |_| menu_bar));
+        self
+    }
+
+    /// Sets the menu bar from a closure receiving the menu bar bounds (top row)
+    #[must_use]
+    pub fn menu_with(mut self, factory: impl FnOnce(Rect) -> MenuBar + 'static) -> Self {
+        self.menu = Some(Box::new(factory));
+        self
+    }
+
+    /// Sets the classic File/Edit/Search/Window/Help menus, keeping those in `features`
+    ///
+    /// See [`standard_menus()`](crate::views::menu_bar::standard_menus).
+    #[must_use]
+    pub fn standard_menus_with(self, features: MenuFeatures) -> Self {
+        self.menu_with(move |bounds| standard_menus(bounds, features))
+    }
+
+    /// Sets the classic File/Edit/Search/Window/Help menus
+    ///
+    /// Same as [`standard_menus_with(MenuFeatures::default())`](Self::standard_menus_with).
+    #[must_use]
+    pub fn standard_menus(self) -> Self {
+        self.standard_menus_with(MenuFeatures::default())
+    }
+
+    /// Sets the status line items. An empty list means no status line.
+    #[must_use]
+    pub fn status(mut self, items: Vec<StatusItem>) -> Self {
+        self.status_items = Some(items);
+        self
+    }
+
+    /// Sets the character used to fill the desktop background
+    #[must_use]
+    pub fn desktop_background(mut self, pattern: char) -> Self {
+        self.background = Some(pattern);
+        self
+    }
+
+    /// Fail `build()` if the terminal is smaller than `width` x `height`
+    #[must_use]
+    pub fn min_size(mut self, width: i16, height: i16) -> Self {
+        self.min_size = Some((width, height));
+        self
+    }
+
+    /// Sets the color theme
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Sets the event loop poll timeout (see [`Application::set_poll_interval()`])
+    #[must_use]
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = Some(interval);
+        self
+    }
+
+    /// Initializes the terminal (unless one was given) and builds the application.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if terminal initialization fails or the terminal is
+    /// smaller than the size set with [`min_size()`](Self::min_size).
+    pub fn build(self) -> Result<Application> {
+        let terminal = match self.terminal {
+            Some(terminal) => terminal,
+            None => Terminal::init()?,
+        };
+
+        let (width, height) = terminal.size();
+        if let Some((min_width, min_height)) = self.min_size {
+            if width < min_width || height < min_height {
+                return Err(TurboVisionError::terminal_init(format!(
+                    "terminal is {width}x{height}, at least {min_width}x{min_height} is required"
+                )));
+            }
+        }
+
+        let mut app = Application::with_terminal(terminal);
+
+        if let Some(factory) = self.menu {
+            app.set_menu_bar(factory(Rect::new(0, 0, width, 1)));
+        }
+
+        let status_items = self
+            .status_items
+            .unwrap_or_else(|| vec![StatusItem::new("~Alt-X~ Exit", KB_ALT_X, CM_QUIT)]);
+        if !status_items.is_empty() {
+            app.set_status_line(StatusLine::new(Rect::new(0, height - 1, width, height), status_items));
+        }
+
+        if let Some(pattern) = self.background {
+            app.desktop.set_background_pattern(pattern);
+        }
+
+        if let Some(ref theme) = self.theme {
+            app.set_theme(theme);
+        }
+
+        if let Some(interval) = self.poll_interval {
+            app.set_poll_interval(interval);
+        }
+
+        Ok(app)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::palette::palettes;
+
+    fn headless() -> ApplicationBuilder {
+        ApplicationBuilder::new().terminal(Terminal::new_headless(80, 25))
+    }
+
+    #[test]
+    fn test_builder_defaults() {
+        let app = headless().build().unwrap();
+
+        assert!(app.menu_bar.is_none());
+        let status_line = app.status_line.as_ref().expect("default status line");
+        assert_eq!(status_line.bounds(), Rect::new(0, 24, 80, 25));
+        assert_eq!(app.desktop.get_bounds(), Rect::new(0, 1, 80, 24));
+    }
+
+    #[test]
+    fn test_builder_wires_menu_and_status() {
+        let app = headless()
+            .standard_menus()
+            .status(vec![])
+            .build()
+            .unwrap();
+
+        assert_eq!(app.menu_bar.as_ref().unwrap().bounds(), Rect::new(0, 0, 80, 1));
+        assert!(app.status_line.is_none());
+        assert_eq!(app.desktop.get_bounds(), Rect::new(0, 1, 80, 24));
+    }
+
+    #[test]
+    fn test_builder_min_size() {
+        let result = ApplicationBuilder::new()
+            .terminal(Terminal::new_headless(60, 20))
+            .min_size(80, 24)
+            .build();
+        assert!(result.err().unwrap().is_terminal_init());
+    }
+
+    #[test]
+    fn test_builder_background_and_theme() {
+        let mut app = headless()
+            .desktop_background('#')
+            .theme(Theme::dark())
+            .build()
+            .unwrap();
+        assert_eq!(palettes::get_app_palette(), Theme::dark().resolved_palette());
+
+        app.draw();
+        assert_eq!(app.terminal.read_cell(10, 10).unwrap().ch, '#');
+
+        app.set_theme(&Theme::borland());
+        assert_eq!(palettes::get_app_palette(), palettes::CP_APP_COLOR.to_vec());
+    }
+
+    #[test]
+    fn test_reload_theme_keeps_previous_on_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.theme");
+        std::fs::write(&path, "background = \"yellow on blue\"\n").unwrap();
+
+        let mut app = headless().build().unwrap();
+        app.load_theme_file(&path).unwrap();
+        assert_eq!(palettes::get_app_palette()[0], 0x1E);
+
+        // Edited file is picked up on reload
+        std::fs::write(&path, "background = \"white on red\"\n").unwrap();
+        app.reload_theme().unwrap();
+        assert_eq!(palettes::get_app_palette()[0], 0x4F);
+
+        // A broken edit reports the line and leaves the colors alone
+        std::fs::write(&path, "background = \"white on red\"\nmenu_normal = \"nope\"\n").unwrap();
+        let err = app.reload_theme().unwrap_err();
+        assert!(err.to_string().contains("line 2"), "{err}");
+        assert_eq!(palettes::get_app_palette()[0], 0x4F);
+
+        app.set_theme(&Theme::borland());
+    }
+
+    #[test]
+    fn test_busy_message_shown_while_running() {
+        let backend = crate::terminal::RecordingBackend::new(80, 25);
+        let recording = backend.recording();
+        let terminal = Terminal::with_backend(Box::new(backend), crate::terminal::TerminalOptions::default()).unwrap();
+        let mut app = ApplicationBuilder::new().terminal(terminal).build().unwrap();
+        app.draw();
+        let _ = app.terminal.flush();
+        let row = |y: usize| recording.borrow().screen_row(y);
+        let before = row(11);
+        assert!(!before.contains("Counting"));
+
+        // What the terminal shows while `f` runs
+        let result = app.with_busy_message("Counting", || {
+            assert!(row(12).contains("Counting"), "busy message on screen: {:?}", row(12));
+            42
+        });
+        assert_eq!(result, 42);
+        assert_eq!(row(12), before, "box erased");
+
+        // Wide characters take two columns each and the box grows to match
+        let text = "処理中です。".repeat(6);
+        app.with_busy_message(&text, || {
+            let shown: String = row(12).chars().filter(|&ch| ch != crate::core::draw::WIDE_CONTINUATION).collect();
+            assert!(shown.contains(&text), "whole message on screen: {shown:?}");
+        });
+    }
+
+    #[test]
+    fn test_command_bar_emits_parsed_command() {
+        use crate::core::command::CM_SAVE;
+        use crate::core::event::KB_ENTER;
+
+        let mut app = headless().build().unwrap();
+        let mut bar = CommandBar::new(':', |text| match text {
+            "w" => Ok(CM_SAVE),
+            other => Err(format!("Unknown: {other}")),
+        });
+        bar.set_history_id(0xC0E0);
+        app.set_command_bar(bar);
+        assert_eq!(app.command_bar().unwrap().bounds(), Rect::new(0, 24, 80, 25));
+
+        app.handle_event(&mut Event::keyboard(':' as u16));
+        assert!(app.command_bar().unwrap().is_active());
+
+        app.handle_event(&mut Event::keyboard('w' as u16));
+        let mut event = Event::keyboard(KB_ENTER);
+        app.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Command);
+        assert_eq!(event.command, CM_SAVE);
+        assert!(!app.command_bar().unwrap().is_active());
+    }
+
+    #[test]
+    fn test_open_file_reuses_window_and_saves() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = dir.path().join("first.txt");
+        let second = dir.path().join("second.txt");
+        std::fs::write(&first, "one\n").unwrap();
+        std::fs::write(&second, "two\n").unwrap();
+
+        let mut app = headless().build().unwrap();
+        let first_id = app.open_file(Some(first.clone())).unwrap();
+        let second_id = app.open_file(Some(second.clone())).unwrap();
+        assert_ne!(first_id, second_id);
+        assert_eq!(app.desktop.top_window_id(), Some(second_id));
+
+        // Opening it again (through another spelling of the path) focuses the existing window
+        let again = app.open_file(Some(dir.path().join(".").join("first.txt"))).unwrap();
+        assert_eq!(again, first_id);
+        assert_eq!(app.desktop.child_count(), 2);
+        assert_eq!(app.desktop.top_window_id(), Some(first_id));
+        assert_eq!(app.recent_files()[1], second);
+
+        // CM_SAVE goes to the front editor
+        app.editor_mut(first_id).unwrap().set_text("changed");
+        let mut event = Event::command(CM_SAVE);
+        app.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Nothing);
+        assert_eq!(std::fs::read_to_string(&first).unwrap().trim_end(), "changed");
+        assert_eq!(std::fs::read_to_string(&second).unwrap(), "two\n");
+    }
+
+    #[test]
+    fn test_save_passes_through_without_editor() {
+        let mut app = headless().build().unwrap();
+        let mut event = Event::command(CM_SAVE);
+        app.handle_event(&mut event);
+        assert_eq!(event.command, CM_SAVE);
+
+        let id = app.new_file();
+        assert_eq!(app.desktop.top_window_id(), Some(id));
+        assert_eq!(app.editor_mut(id).unwrap().get_title(), "Untitled");
+        assert!(app.recent_files().is_empty());
+    }
+
+    #[test]
+    fn test_standard_menu_shortcuts_and_edit_commands() {
+        use crate::core::command::{CM_CUT, CM_FIND, CM_SELECT_ALL};
+        use crate::core::event::{KB_CTRL_F, KB_F6};
+
+        let mut app = headless().standard_menus_with(MenuFeatures::default()).build().unwrap();
+        let first = app.new_file();
+        let second = app.new_file();
+        let editor = app.editor_mut(second).unwrap().edit_window().editor_rc();
+        editor.borrow_mut().set_text("hello");
+
+        // Edit commands from the menu act on the focused editor
+        for command in [CM_SELECT_ALL, CM_CUT] {
+            let mut event = Event::command(command);
+            app.handle_event(&mut event);
+            assert_eq!(event.what, EventType::Nothing);
+        }
+        assert_eq!(editor.borrow().get_text(), "");
+
+        // A shortcut no window wants becomes its menu command
+        app.handle_event(&mut Event::keyboard(KB_F6));
+        assert_eq!(app.desktop.top_window_id(), Some(first));
+
+        // Disabled commands keep their shortcut inert
+        command_set::disable_command(CM_FIND);
+        let mut event = Event::keyboard(KB_CTRL_F);
+        app.handle_event(&mut event);
+        assert_eq!((event.what, event.key_code), (EventType::Keyboard, KB_CTRL_F));
+        command_set::enable_command(CM_FIND);
+    }
+
+    #[test]
+    fn test_f1_shows_help_instead_of_opening_a_menu() {
+        use crate::core::event::KB_ESC;
+
+        let mut app = headless().standard_menus().build().unwrap();
+        app.terminal.take_output();
+        // Closes the message box
+        app.terminal.push_input(Event::keyboard(KB_ESC));
+
+        let mut event = Event::keyboard(KB_F1);
+        app.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Nothing);
+        assert!(String::from_utf8_lossy(&app.terminal.take_output()).contains("No help available"));
+    }
+
+    #[test]
+    fn test_ctrl_z_without_job_control_is_left_alone() {
+        let mut app = Application::with_terminal(Terminal::new_headless(80, 25));
+        assert!(!app.terminal.supports_job_control());
+        app.stop().unwrap();
+
+        let mut event = Event::keyboard(KB_CTRL_Z);
+        app.handle_event(&mut event);
+        assert_eq!((event.what, event.key_code), (EventType::Keyboard, KB_CTRL_Z));
+    }
+
+    #[test]
+    fn test_resize_relays_out_and_keeps_windows_on_screen() {
+        use crate::views::window::Window;
+
+        let mut app = headless().standard_menus().build().unwrap();
+        let id = app.desktop.add(Box::new(Window::new(Rect::new(50, 15, 75, 22), "Far")));
+
+        app.terminal.push_input(Event::resize(40, 12));
+        let mut event = app.terminal.poll_event(Duration::ZERO).unwrap().unwrap();
+        app.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Nothing);
+
+        assert_eq!(app.menu_bar.as_ref().unwrap().bounds(), Rect::new(0, 0, 40, 1));
+        assert_eq!(app.status_line.as_ref().unwrap().bounds(), Rect::new(0, 11, 40, 12));
+        assert_eq!(app.desktop.get_bounds(), Rect::new(0, 1, 40, 11));
+        // Same size, moved so the window and its shadow fit
+        assert_eq!(app.desktop.window_by_id(id).unwrap().bounds(), Rect::new(15, 3, 40, 10));
+
+        app.terminal.push_input(Event::resize(100, 30));
+        let mut event = app.terminal.poll_event(Duration::ZERO).unwrap().unwrap();
+        app.handle_event(&mut event);
+        assert_eq!(app.desktop.window_by_id(id).unwrap().bounds(), Rect::new(15, 3, 40, 10));
+    }
+
+    #[test]
+    fn test_notifications_overlay_windows_without_focus() {
+        use crate::core::event::MB_LEFT_BUTTON;
+        use crate::core::geometry::Point;
+        use crate::terminal::row_text;
+        use crate::views::window::Window;
+
+        let mut app = headless().standard_menus().build().unwrap();
+        app.desktop.add(Box::new(Window::new(Rect::new(0, 1, 80, 24), "Below")));
+        let front = app.desktop.add(Box::new(Window::new(Rect::new(10, 5, 50, 15), "Front")));
+
+        app.notify("Build finished", Severity::Info, Duration::from_secs(60));
+        app.notify("Tests failed", Severity::Error, Duration::from_secs(60));
+        app.draw();
+        let row = row_text(&app.terminal.buffer()[23]);
+        assert!(row.starts_with(" Tests failed"), "{row}");
+        assert!(row.ends_with(" +1 "));
+        assert_eq!(app.desktop.get_bounds(), Rect::new(0, 1, 80, 24), "the desktop keeps its size");
+
+        // A click on the strip dismisses a message; the window beneath stays in the back
+        let mut click = Event::mouse(EventType::MouseDown, Point::new(40, 23), MB_LEFT_BUTTON, false);
+        app.handle_event(&mut click);
+        assert_eq!(click.what, EventType::Nothing);
+        assert_eq!(app.notifications().len(), 1);
+        assert_eq!(app.notifications()[0].message, "Build finished");
+        assert_eq!(app.desktop.top_window_id(), Some(front));
+
+        app.clear_notifications();
+        app.draw();
+        assert!(!row_text(&app.terminal.buffer()[23]).contains("Build finished"));
+    }
+
+    #[test]
+    fn test_poll_interval_reaches_the_backend() {
+        use crossterm::event::{Event as CTEvent, KeyCode, KeyEvent, KeyModifiers};
+
+        let alt_x = CTEvent::Key(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::ALT));
+        let backend = crate::terminal::RecordingBackend::new(80, 25).poll_replies([None, Some(alt_x)]);
+        let recording = backend.recording();
+        let terminal = Terminal::with_backend(Box::new(backend), crate::terminal::TerminalOptions::default()).unwrap();
+        let mut app = ApplicationBuilder::new()
+            .terminal(terminal)
+            .poll_interval(Duration::from_millis(75))
+            .build()
+            .unwrap();
+        assert_eq!(app.poll_interval(), Duration::from_millis(75));
+
+        // First poll times out (idle), the second one returns Alt+X and quits
+        app.run();
+        assert_eq!(recording.borrow().poll_timeouts, vec![Duration::from_millis(75); 2]);
+
+        app.set_poll_interval(Duration::from_millis(5));
+        app.get_event();
+        assert_eq!(recording.borrow().poll_timeouts.last(), Some(&Duration::from_millis(5)));
+    }
+
+    /// Background view that records the modal notifications it receives
+    struct ModalRecorder {
+        bounds: Rect,
+        calls: std::rc::Rc<std::cell::RefCell<Vec<&'static str>>>,
+    }
+
+    impl View for ModalRecorder {
+        fn bounds(&self) -> Rect {
+            self.bounds
+        }
+        fn set_bounds(&mut self, bounds: Rect) {
+            self.bounds = bounds;
+        }
+        fn draw(&mut self, _terminal: &mut Terminal) {}
+        fn handle_event(&mut self, _event: &mut Event) {}
+        fn on_modal_enter(&mut self) {
+            self.calls.borrow_mut().push("enter");
+        }
+        fn on_modal_leave(&mut self) {
+            self.calls.borrow_mut().push("leave");
+        }
+        fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_views_behind_a_modal_are_notified() {
+        use crate::core::event::KB_ESC_ESC;
+        use crate::core::state::SF_MODAL;
+        use crate::views::window::Window;
+
+        let calls = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let mut app = headless().build().unwrap();
+        let mut window = Window::new(Rect::new(0, 0, 30, 10), "Background");
+        window.add(Box::new(ModalRecorder { bounds: Rect::new(1, 1, 5, 2), calls: std::rc::Rc::clone(&calls) }));
+        app.desktop.add(Box::new(window));
+
+        let mut dialog = Dialog::new(Rect::new(20, 5, 50, 12), "Modal");
+        app.terminal.push_input(Event::keyboard(KB_ESC_ESC));
+        assert_eq!(dialog.execute(&mut app), CM_CANCEL);
+        assert_eq!(*calls.borrow(), vec!["enter", "leave"]);
+
+        let mut dialog = Dialog::new(Rect::new(20, 5, 50, 12), "Modal");
+        dialog.set_state(dialog.state() | SF_MODAL);
+        app.terminal.push_input(Event::keyboard(KB_ESC_ESC));
+        assert_eq!(app.exec_view(Box::new(dialog)), CM_CANCEL);
+        assert_eq!(*calls.borrow(), vec!["enter", "leave", "enter", "leave"]);
+    }
+}
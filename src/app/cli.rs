@@ -0,0 +1,233 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Command-line arguments for file-opening applications.
+//!
+//! [`CliArgs`] parses the usual editor command line: file paths plus a few
+//! flags, without pulling in an argument parsing crate.
+//!
+//! ```text
+//! myedit [--theme NAME|FILE] [--no-mouse] [--screenshot FILE] [--] [FILE...]
+//! ```
+//!
+//! [`CliArgs::apply()`] then opens each file in a [`FileEditor`] window
+//! (cascaded on the desktop) and applies the flags. Files that cannot be
+//! opened are reported in an error box once the application starts.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use turbo_vision::app::{ApplicationBuilder, CliArgs};
+//! use turbo_vision::terminal::Terminal;
+//!
+//! let args = CliArgs::from_env()?;
+//! let terminal = Terminal::init_with_options(args.terminal_options())?;
+//! let mut app = ApplicationBuilder::new().terminal(terminal).build()?;
+//! args.apply(&mut app);
+//! app.run();
+//! # Ok::<(), turbo_vision::core::error::TurboVisionError>(())
+//! ```
+
+use super::Application;
+use crate::core::error::{Result, TurboVisionError};
+use crate::core::geometry::Rect;
+use crate::core::theme::Theme;
+use crate::terminal::TerminalOptions;
+use crate::views::file_editor::FileEditor;
+use std::path::PathBuf;
+
+/// Option summary for `--help` output
+pub const CLI_OPTIONS_HELP: &str = "\
+Options:
+  --theme NAME|FILE   Color theme: borland, dark, high-contrast, or a theme file
+  --no-mouse          Leave the mouse to the terminal (selection, scrollback)
+  --screenshot FILE   Save the first screen to FILE (ANSI text) and exit
+  --                  Treat all following arguments as file names";
+
+/// Parsed command line of a file-opening application
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliArgs {
+    /// Files to open, in command-line order
+    pub files: Vec<PathBuf>,
+    /// Built-in theme name or theme file (`--theme`)
+    pub theme: Option<String>,
+    /// Mouse capture (`--no-mouse` turns it off)
+    pub mouse: bool,
+    /// Where to save the first screen before exiting (`--screenshot`)
+    pub screenshot: Option<PathBuf>,
+}
+
+impl Default for CliArgs {
+    fn default() -> Self {
+        Self {
+            files: Vec::new(),
+            theme: None,
+            mouse: true,
+            screenshot: None,
+        }
+    }
+}
+
+impl CliArgs {
+    /// Parse the process arguments (skipping the program name)
+    ///
+    /// # Errors
+    ///
+    /// Returns an invalid input error for unknown flags or missing flag values.
+    pub fn from_env() -> Result<Self> {
+        Self::parse(std::env::args().skip(1))
+    }
+
+    /// Parse an argument list, not including the program name
+    ///
+    /// Flag values may be given as `--flag value` or `--flag=value`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an invalid input error for unknown flags or missing flag values.
+    pub fn parse<I, S>(args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter().map(Into::into);
+        let mut files_only = false;
+
+        while let Some(arg) = args.next() {
+            if files_only || arg == "-" || !arg.starts_with('-') {
+                parsed.files.push(PathBuf::from(arg));
+                continue;
+            }
+
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            let mut value = |name: &str| {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| TurboVisionError::invalid_input(format!("{name} requires a value")))
+            };
+
+            match flag.as_str() {
+                "--" => files_only = true,
+                "--theme" => parsed.theme = Some(value("--theme")?),
+                "--screenshot" => parsed.screenshot = Some(PathBuf::from(value("--screenshot")?)),
+                "--no-mouse" => parsed.mouse = false,
+                _ => return Err(TurboVisionError::invalid_input(format!("unknown option '{flag}'"))),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// Terminal options matching the flags, for `Terminal::init_with_options()`
+    pub fn terminal_options(&self) -> TerminalOptions {
+        TerminalOptions { mouse: self.mouse, ..TerminalOptions::default() }
+    }
+
+    /// Apply the flags to `app` and open each file in a cascaded editor window.
+    ///
+    /// Nothing here aborts: files that cannot be read and themes that cannot
+    /// be loaded are shown as error boxes when [`Application::run()`] starts.
+    pub fn apply(&self, app: &mut Application) {
+        if let Some(ref theme) = self.theme {
+            match builtin_theme(theme) {
+                Some(theme) => app.set_theme(&theme),
+                None => {
+                    if let Err(e) = app.load_theme_file(theme) {
+                        app.add_startup_error(format!("Cannot load theme: {e}"));
+                    }
+                }
+            }
+        }
+
+        if !self.mouse {
+            let _ = app.terminal.set_mouse_enabled(false);
+        }
+
+        if let Some(ref path) = self.screenshot {
+            app.set_screenshot_path(path);
+        }
+
+        let mut opened = 0;
+        for path in &self.files {
+            let mut editor = FileEditor::new(Rect::new(0, 0, 40, 10), "");
+            match editor.load_file(path.clone()) {
+                Ok(()) => {
+                    editor.refresh_title();
+                    app.add_editor(editor);
+                    opened += 1;
+                }
+                Err(e) => app.add_startup_error(format!("Cannot open {}: {e}", path.display())),
+            }
+        }
+        if opened > 0 {
+            app.cascade();
+        }
+    }
+}
+
+/// Built-in theme by name, ignoring case
+fn builtin_theme(name: &str) -> Option<Theme> {
+    match name.to_ascii_lowercase().as_str() {
+        "borland" => Some(Theme::borland()),
+        "dark" => Some(Theme::dark()),
+        "high-contrast" | "high_contrast" => Some(Theme::high_contrast()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::terminal::Terminal;
+
+    #[test]
+    fn test_parse_files_and_flags() {
+        let args = CliArgs::parse(["a.txt", "--theme", "dark", "--no-mouse", "--screenshot=shot.ans", "b.rs"]).unwrap();
+        assert_eq!(args.files, vec![PathBuf::from("a.txt"), PathBuf::from("b.rs")]);
+        assert_eq!(args.theme.as_deref(), Some("dark"));
+        assert!(!args.mouse);
+        assert_eq!(args.screenshot, Some(PathBuf::from("shot.ans")));
+        assert!(!args.terminal_options().mouse);
+
+        assert_eq!(CliArgs::parse(Vec::<String>::new()).unwrap(), CliArgs::default());
+    }
+
+    #[test]
+    fn test_double_dash_ends_flags() {
+        let args = CliArgs::parse(["--", "--no-mouse", "-"]).unwrap();
+        assert_eq!(args.files, vec![PathBuf::from("--no-mouse"), PathBuf::from("-")]);
+        assert!(args.mouse);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = CliArgs::parse(["--bogus"]).unwrap_err();
+        assert!(err.is_invalid_input());
+        assert!(err.to_string().contains("--bogus"), "{err}");
+
+        let err = CliArgs::parse(["file.txt", "--theme"]).unwrap_err();
+        assert!(err.to_string().contains("--theme requires a value"), "{err}");
+    }
+
+    #[test]
+    fn test_apply_opens_files_and_collects_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hello.txt");
+        std::fs::write(&path, "hello\n").unwrap();
+        let missing = dir.path().join("missing.txt");
+
+        let args = CliArgs::parse([path.to_str().unwrap(), missing.to_str().unwrap()]).unwrap();
+        let mut app = Application::with_terminal(Terminal::new_headless(80, 25));
+        args.apply(&mut app);
+
+        // One editor, cascaded to the desktop; the missing file is reported at startup
+        assert_eq!(app.desktop.child_count(), 1);
+        assert_eq!(app.desktop.child_at(0).bounds(), app.desktop.get_bounds());
+        assert_eq!(app.startup_errors().len(), 1);
+        assert!(app.startup_errors()[0].contains("missing.txt"));
+    }
+}
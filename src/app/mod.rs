@@ -61,5 +61,9 @@
 //! ```
 
 pub mod application;
+pub mod cli;
+pub mod splash;
 
-pub use application::Application;
+pub use application::{Application, ApplicationBuilder};
+pub use cli::CliArgs;
+pub use splash::{show_splash, show_splash_with, SplashOptions};
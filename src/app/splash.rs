@@ -0,0 +1,246 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Startup splash screen helper - centered framed panel with timed dismissal.
+
+use super::Application;
+use crate::core::event::EventType;
+use crate::core::geometry::Rect;
+use crate::views::dialog::Dialog;
+use crate::views::static_text::StaticText;
+use crate::views::View;
+use std::time::{Duration, Instant};
+
+/// Factor applied to the screen behind the splash panel when dimming is enabled.
+const DIM_FACTOR: f32 = 0.5;
+
+/// Options controlling how a splash screen is shown.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use turbo_vision::app::{Application, SplashOptions, show_splash_with};
+/// use std::time::Duration;
+///
+/// let mut app = Application::new().unwrap();
+/// let options = SplashOptions::new(Duration::from_millis(1500))
+///     .dim_background(true)
+///     .version(env!("CARGO_PKG_VERSION"));
+/// show_splash_with(&mut app, &["My Application", "", "Loading..."], &options);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SplashOptions {
+    /// Minimum time the splash stays on screen.
+    pub min_duration: Duration,
+    /// When true, any key or mouse click dismisses the splash once `min_duration`
+    /// has elapsed. When false, the splash closes as soon as `min_duration` elapses.
+    pub dismiss_on_key: bool,
+    /// Darken the screen behind the panel while the splash is visible.
+    pub dim_background: bool,
+    /// Optional version string shown on the last line of the panel
+    /// (typically `env!("CARGO_PKG_VERSION")`).
+    pub version: Option<String>,
+}
+
+impl SplashOptions {
+    /// Creates options with the given minimum duration, dismissable by key, no dimming.
+    pub fn new(min_duration: Duration) -> Self {
+        Self {
+            min_duration,
+            dismiss_on_key: true,
+            dim_background: false,
+            version: None,
+        }
+    }
+
+    /// Sets whether a key or click dismisses the splash after `min_duration`.
+    #[must_use]
+    pub fn dismiss_on_key(mut self, dismiss_on_key: bool) -> Self {
+        self.dismiss_on_key = dismiss_on_key;
+        self
+    }
+
+    /// Sets whether the screen behind the panel is dimmed.
+    #[must_use]
+    pub fn dim_background(mut self, dim_background: bool) -> Self {
+        self.dim_background = dim_background;
+        self
+    }
+
+    /// Sets the version string displayed as "Version x.y.z".
+    #[must_use]
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+}
+
+impl Default for SplashOptions {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(1))
+    }
+}
+
+/// Shows a splash screen centered on the desktop.
+///
+/// The panel stays up for at least `min_duration`. If `dismiss_on_key` is true,
+/// it then waits for any key press or mouse click; otherwise it closes as soon
+/// as `min_duration` has elapsed. The screen is redrawn when the splash closes.
+pub fn show_splash(app: &mut Application, lines: &[&str], min_duration: Duration, dismiss_on_key: bool) {
+    let options = SplashOptions::new(min_duration).dismiss_on_key(dismiss_on_key);
+    show_splash_with(app, lines, &options);
+}
+
+/// Shows a splash screen using the full set of [`SplashOptions`].
+pub fn show_splash_with(app: &mut Application, lines: &[&str], options: &SplashOptions) {
+    let mut all_lines: Vec<String> = lines.iter().map(|s| (*s).to_string()).collect();
+    if let Some(ref version) = options.version {
+        all_lines.push(String::new());
+        all_lines.push(format!("Version {version}"));
+    }
+
+    let mut panel = build_panel(app, &all_lines);
+    let start = Instant::now();
+
+    // Dimming also reaches cells no view redraws; keep them to put back afterwards
+    app.draw();
+    let (width, height) = app.terminal.size();
+    app.terminal.save_under(Rect::new(0, 0, width, height));
+
+    loop {
+        app.draw();
+        if options.dim_background {
+            dim_screen(app);
+        }
+        panel.draw(&mut app.terminal);
+        let _ = app.terminal.hide_cursor();
+        let _ = app.terminal.flush();
+
+        let elapsed = start.elapsed();
+        if !options.dismiss_on_key && elapsed >= options.min_duration {
+            break;
+        }
+
+        match app.terminal.poll_event(app.poll_interval()).ok().flatten() {
+            Some(event) => {
+                let dismiss = matches!(event.what, EventType::Keyboard | EventType::MouseDown);
+                if dismiss && options.dismiss_on_key && start.elapsed() >= options.min_duration {
+                    break;
+                }
+            }
+            None => app.idle(),
+        }
+    }
+
+    // Restore the screen underneath the panel
+    let _ = app.terminal.restore_under();
+    app.draw();
+    let _ = app.terminal.flush();
+}
+
+/// Builds the untitled framed panel holding the centered splash lines.
+fn build_panel(app: &Application, lines: &[String]) -> Dialog {
+    let desktop = app.desktop.get_bounds();
+    let text_width = lines
+        .iter()
+        .map(|l| unicode_width::UnicodeWidthStr::width(l.as_str()))
+        .max()
+        .unwrap_or(0) as i16;
+
+    // 2 for the frame plus 2 columns of padding on each side
+    let width = (text_width + 6).min(desktop.width()).max(10);
+    let height = (lines.len() as i16 + 4).min(desktop.height()).max(3);
+    let x = desktop.a.x + (desktop.width() - width) / 2;
+    let y = desktop.a.y + (desktop.height() - height) / 2;
+
+    let mut panel = Dialog::new(Rect::new(x, y, x + width, y + height), "");
+    let text = lines.join("\n");
+    panel.add(Box::new(StaticText::new_centered(
+        Rect::new(1, 1, width - 3, height - 3),
+        &text,
+    )));
+    panel
+}
+
+/// Darkens every cell currently in the terminal buffer.
+fn dim_screen(app: &mut Application) {
+    let (width, height) = app.terminal.size();
+    for y in 0..height {
+        for x in 0..width {
+            if let Some(mut cell) = app.terminal.read_cell(x, y) {
+                cell.attr = cell.attr.darken(DIM_FACTOR);
+                app.terminal.write_cell(x as u16, y as u16, cell);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::ApplicationBuilder;
+    use crate::terminal::{row_text, Recording, RecordingBackend, Terminal, TerminalOptions};
+    use crossterm::event::{Event as CTEvent, KeyCode, KeyEvent, KeyModifiers};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+    fn key() -> Option<CTEvent> {
+        Some(CTEvent::Key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE)))
+    }
+
+    /// Application on a recording backend whose polls answer with `replies`
+    fn app_with_replies(replies: Vec<Option<CTEvent>>) -> (Application, Rc<RefCell<Recording>>) {
+        let backend = RecordingBackend::new(80, 25).poll_replies(replies);
+        let recording = backend.recording();
+        let terminal = Terminal::with_backend(Box::new(backend), TerminalOptions::default()).unwrap();
+        let app = ApplicationBuilder::new().terminal(terminal).poll_interval(POLL_INTERVAL).build().unwrap();
+        (app, recording)
+    }
+
+    fn screen_text(app: &Application) -> Vec<String> {
+        app.terminal.buffer().iter().map(|row| row_text(row)).collect()
+    }
+
+    #[test]
+    fn test_key_before_min_duration_is_ignored() {
+        // The first key arrives at once; ten idle polls later a second one does
+        let mut replies = vec![key()];
+        replies.extend(std::iter::repeat_n(None, 10));
+        replies.push(key());
+        let (mut app, recording) = app_with_replies(replies);
+
+        let start = Instant::now();
+        show_splash(&mut app, &["Splash"], Duration::from_millis(50), true);
+        assert!(start.elapsed() >= Duration::from_millis(50));
+        assert_eq!(recording.borrow().poll_timeouts.len(), 12, "closed by the second key only");
+    }
+
+    #[test]
+    fn test_key_after_min_duration_dismisses() {
+        let (mut app, recording) = app_with_replies(vec![key()]);
+        show_splash(&mut app, &["Splash"], Duration::ZERO, true);
+        assert_eq!(recording.borrow().poll_timeouts.len(), 1);
+
+        // Without dismiss_on_key the splash closes on its own
+        let (mut app, recording) = app_with_replies(Vec::new());
+        show_splash(&mut app, &["Splash"], Duration::ZERO, false);
+        assert!(recording.borrow().poll_timeouts.is_empty());
+    }
+
+    #[test]
+    fn test_screen_beneath_is_restored() {
+        let (mut app, recording) = app_with_replies(vec![key()]);
+        app.draw();
+        let before = screen_text(&app);
+        let attrs_before: Vec<_> = app.terminal.buffer().iter().flatten().map(|cell| cell.attr).collect();
+
+        let options = SplashOptions::new(Duration::ZERO).dim_background(true).version("1.2.3");
+        show_splash_with(&mut app, &["Splash"], &options);
+
+        assert!(recording.borrow().runs.iter().any(|(_, _, text)| text.contains("Version 1.2.3")), "splash was shown");
+        assert_eq!(screen_text(&app), before);
+        let attrs_after: Vec<_> = app.terminal.buffer().iter().flatten().map(|cell| cell.attr).collect();
+        assert_eq!(attrs_after, attrs_before, "dimming undone");
+    }
+}
@@ -33,33 +33,16 @@
 //! cat debug_screen.ans
 //! less -R debug_screen.ans  # For scrollable viewing
 //! ```
+//!
+//! ## Loading ANSI art
+//! [`load_ansi_file()`] reads a dump (or any ANSI-art file using SGR colors)
+//! back into a cell grid, e.g. for a desktop wallpaper.
 
 use super::draw::Cell;
-use super::palette::TvColor;
+use super::palette::{Attr, AttrColor, StyleFlags, TvColor, STYLE_DIM, STYLE_ITALIC, STYLE_REVERSE, STYLE_SGR_CODES, STYLE_UNDERLINE};
 use std::io::{self, Write};
 use std::fs::File;
-
-/// Convert TvColor to RGB values for 24-bit ANSI codes
-fn color_to_rgb(color: TvColor) -> (u8, u8, u8) {
-    match color {
-        TvColor::Black => (0, 0, 0),
-        TvColor::Blue => (0, 0, 170),
-        TvColor::Green => (0, 170, 0),
-        TvColor::Cyan => (0, 170, 170),
-        TvColor::Red => (170, 0, 0),
-        TvColor::Magenta => (170, 0, 170),
-        TvColor::Brown => (170, 85, 0),
-        TvColor::LightGray => (170, 170, 170),
-        TvColor::DarkGray => (85, 85, 85),
-        TvColor::LightBlue => (85, 85, 255),
-        TvColor::LightGreen => (85, 255, 85),
-        TvColor::LightCyan => (85, 255, 255),
-        TvColor::LightRed => (255, 85, 85),
-        TvColor::LightMagenta => (255, 85, 255),
-        TvColor::Yellow => (255, 255, 85),
-        TvColor::White => (255, 255, 255),
-    }
-}
+use std::path::Path;
 
 /// Dump a buffer to an ANSI text file.
 ///
@@ -99,35 +82,47 @@ pub fn dump_buffer<W: Write>(
     for row in buffer.iter().take(height.min(buffer.len())) {
         let mut last_fg = None;
         let mut last_bg = None;
+        let mut last_style: StyleFlags = 0;
 
         for x in 0..width.min(row.len()) {
             let cell = row[x];
 
             // Only emit color codes when colors change
+            // A style change resets the colors too, so they are sent again
+            if cell.attr.style != last_style {
+                write_style(writer, cell.attr.style)?;
+                last_style = cell.attr.style;
+                last_fg = None;
+                last_bg = None;
+            }
+
             let need_fg_change = Some(cell.attr.fg) != last_fg;
             let need_bg_change = Some(cell.attr.bg) != last_bg;
 
             if need_fg_change || need_bg_change {
                 if need_fg_change && need_bg_change {
-                    let (fg_r, fg_g, fg_b) = color_to_rgb(cell.attr.fg);
-                    let (bg_r, bg_g, bg_b) = color_to_rgb(cell.attr.bg);
+                    let (fg_r, fg_g, fg_b) = cell.attr.fg.to_rgb();
+                    let (bg_r, bg_g, bg_b) = cell.attr.bg.to_rgb();
                     write!(
                         writer,
                         "\x1b[38;2;{};{};{};48;2;{};{};{}m",
                         fg_r, fg_g, fg_b, bg_r, bg_g, bg_b
                     )?;
                 } else if need_fg_change {
-                    let (fg_r, fg_g, fg_b) = color_to_rgb(cell.attr.fg);
+                    let (fg_r, fg_g, fg_b) = cell.attr.fg.to_rgb();
                     write!(writer, "\x1b[38;2;{};{};{}m", fg_r, fg_g, fg_b)?;
                 } else {
-                    let (bg_r, bg_g, bg_b) = color_to_rgb(cell.attr.bg);
+                    let (bg_r, bg_g, bg_b) = cell.attr.bg.to_rgb();
                     write!(writer, "\x1b[48;2;{};{};{}m", bg_r, bg_g, bg_b)?;
                 }
                 last_fg = Some(cell.attr.fg);
                 last_bg = Some(cell.attr.bg);
             }
 
-            write!(writer, "{}", cell.ch)?;
+            // The left half of a wide character already covers this column
+            if !cell.is_continuation() {
+                write!(writer, "{}", cell.ch)?;
+            }
         }
 
         // Reset colors at end of line
@@ -159,34 +154,46 @@ pub fn dump_buffer_region<W: Write>(
     for row in buffer.iter().take((y + height).min(buffer.len())).skip(y) {
         let mut last_fg = None;
         let mut last_bg = None;
+        let mut last_style: StyleFlags = 0;
 
         for col in x..(x + width).min(row.len()) {
             let cell = row[col];
 
+            // A style change resets the colors too, so they are sent again
+            if cell.attr.style != last_style {
+                write_style(writer, cell.attr.style)?;
+                last_style = cell.attr.style;
+                last_fg = None;
+                last_bg = None;
+            }
+
             let need_fg_change = Some(cell.attr.fg) != last_fg;
             let need_bg_change = Some(cell.attr.bg) != last_bg;
 
             if need_fg_change || need_bg_change {
                 if need_fg_change && need_bg_change {
-                    let (fg_r, fg_g, fg_b) = color_to_rgb(cell.attr.fg);
-                    let (bg_r, bg_g, bg_b) = color_to_rgb(cell.attr.bg);
+                    let (fg_r, fg_g, fg_b) = cell.attr.fg.to_rgb();
+                    let (bg_r, bg_g, bg_b) = cell.attr.bg.to_rgb();
                     write!(
                         writer,
                         "\x1b[38;2;{};{};{};48;2;{};{};{}m",
                         fg_r, fg_g, fg_b, bg_r, bg_g, bg_b
                     )?;
                 } else if need_fg_change {
-                    let (fg_r, fg_g, fg_b) = color_to_rgb(cell.attr.fg);
+                    let (fg_r, fg_g, fg_b) = cell.attr.fg.to_rgb();
                     write!(writer, "\x1b[38;2;{};{};{}m", fg_r, fg_g, fg_b)?;
                 } else {
-                    let (bg_r, bg_g, bg_b) = color_to_rgb(cell.attr.bg);
+                    let (bg_r, bg_g, bg_b) = cell.attr.bg.to_rgb();
                     write!(writer, "\x1b[48;2;{};{};{}m", bg_r, bg_g, bg_b)?;
                 }
                 last_fg = Some(cell.attr.fg);
                 last_bg = Some(cell.attr.bg);
             }
 
-            write!(writer, "{}", cell.ch)?;
+            // The left half of a wide character already covers this column
+            if !cell.is_continuation() {
+                write!(writer, "{}", cell.ch)?;
+            }
         }
 
         writeln!(writer, "\x1b[0m")?;
@@ -195,10 +202,166 @@ pub fn dump_buffer_region<W: Write>(
     Ok(())
 }
 
+/// Reset the text style and switch on the STYLE_* bits of `style`
+fn write_style<W: Write>(writer: &mut W, style: StyleFlags) -> io::Result<()> {
+    write!(writer, "\x1b[0")?;
+    for (flag, code) in STYLE_SGR_CODES {
+        if style & flag != 0 {
+            write!(writer, ";{}", code)?;
+        }
+    }
+    write!(writer, "m")
+}
+
+/// ANSI color order (SGR 30-37) to TvColor (CGA order)
+const ANSI_TO_TV: [u8; 8] = [0, 4, 2, 6, 1, 5, 3, 7];
+
+/// Parse ANSI text (SGR color codes) into a rectangular cell grid.
+///
+/// Supports the 8/16-color codes, bold as bright foreground, and the 256-color
+/// and 24-bit forms (mapped to the nearest [`TvColor`]). Dim, italic, underline
+/// and reverse set the matching style bits. Other escape sequences are skipped. Short rows are padded with spaces in `default_attr`.
+pub fn parse_ansi(text: &str, default_attr: Attr) -> Vec<Vec<Cell>> {
+    let mut rows: Vec<Vec<Cell>> = vec![Vec::new()];
+    let mut attr = default_attr;
+    let mut bold = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\x1b' => {
+                if chars.peek() != Some(&'[') {
+                    continue;
+                }
+                chars.next();
+                let mut params = String::new();
+                let mut command = None;
+                for c in chars.by_ref() {
+                    if ('\x40'..='\x7e').contains(&c) {
+                        command = Some(c);
+                        break;
+                    }
+                    params.push(c);
+                }
+                if command == Some('m') {
+                    apply_sgr(&params, &mut attr, &mut bold, default_attr);
+                }
+            }
+            '\n' => rows.push(Vec::new()),
+            '\t' => {
+                let row = rows.last_mut().unwrap();
+                let spaces = 8 - row.len() % 8;
+                row.extend(std::iter::repeat_n(Cell::new(' ', attr), spaces));
+            }
+            c if c.is_control() => {}
+            c => rows.last_mut().unwrap().push(Cell::new(c, displayed_attr(attr, bold))),
+        }
+    }
+
+    // A trailing newline does not start another row
+    if rows.len() > 1 && rows.last().is_some_and(Vec::is_empty) {
+        rows.pop();
+    }
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    for row in &mut rows {
+        row.resize(width, Cell::new(' ', default_attr));
+    }
+    rows
+}
+
+/// Load an ANSI-art file into a cell grid (see [`parse_ansi()`])
+pub fn load_ansi_file(path: impl AsRef<Path>, default_attr: Attr) -> io::Result<Vec<Vec<Cell>>> {
+    let bytes = std::fs::read(path)?;
+    Ok(parse_ansi(&String::from_utf8_lossy(&bytes), default_attr))
+}
+
+/// Bold brightens the 8 basic foreground colors
+fn displayed_attr(attr: Attr, bold: bool) -> Attr {
+    match attr.fg {
+        AttrColor::Palette(fg) if bold && (fg as u8) < 8 => Attr {
+            fg: TvColor::from_u8(fg as u8 | 8).into(),
+            ..attr
+        },
+        _ => attr,
+    }
+}
+
+fn apply_sgr(params: &str, attr: &mut Attr, bold: &mut bool, default_attr: Attr) {
+    let codes: Vec<u32> = params.split(';').map(|p| p.parse().unwrap_or(0)).collect();
+    let mut i = 0;
+    while i < codes.len() {
+        match codes[i] {
+            0 => {
+                *attr = default_attr;
+                *bold = false;
+            }
+            1 => *bold = true,
+            22 => {
+                *bold = false;
+                attr.set_style_flag(STYLE_DIM, false);
+            }
+            2 => attr.set_style_flag(STYLE_DIM, true),
+            3 => attr.set_style_flag(STYLE_ITALIC, true),
+            4 => attr.set_style_flag(STYLE_UNDERLINE, true),
+            7 => attr.set_style_flag(STYLE_REVERSE, true),
+            23 => attr.set_style_flag(STYLE_ITALIC, false),
+            24 => attr.set_style_flag(STYLE_UNDERLINE, false),
+            27 => attr.set_style_flag(STYLE_REVERSE, false),
+            c @ 30..=37 => attr.fg = TvColor::from_u8(ANSI_TO_TV[(c - 30) as usize]).into(),
+            39 => attr.fg = default_attr.fg,
+            c @ 40..=47 => attr.bg = TvColor::from_u8(ANSI_TO_TV[(c - 40) as usize]).into(),
+            49 => attr.bg = default_attr.bg,
+            c @ 90..=97 => attr.fg = TvColor::from_u8(ANSI_TO_TV[(c - 90) as usize] | 8).into(),
+            c @ 100..=107 => attr.bg = TvColor::from_u8(ANSI_TO_TV[(c - 100) as usize] | 8).into(),
+            c @ (38 | 48) => {
+                let color = match codes.get(i + 1) {
+                    Some(5) => {
+                        let color = codes.get(i + 2).map(|&n| color_from_256(n));
+                        i += 2;
+                        color
+                    }
+                    Some(2) => {
+                        let rgb = |k: usize| codes.get(i + k).copied().unwrap_or(0).min(255) as u8;
+                        let color = TvColor::from_rgb(rgb(2), rgb(3), rgb(4));
+                        i += 4;
+                        Some(color)
+                    }
+                    _ => None,
+                };
+                if let Some(color) = color {
+                    if c == 38 {
+                        attr.fg = color.into();
+                    } else {
+                        attr.bg = color.into();
+                    }
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+}
+
+/// Nearest TvColor for an xterm 256-color index
+fn color_from_256(n: u32) -> TvColor {
+    match n {
+        0..=7 => TvColor::from_u8(ANSI_TO_TV[n as usize]),
+        8..=15 => TvColor::from_u8(ANSI_TO_TV[n as usize - 8] | 8),
+        16..=231 => {
+            let level = |v: u32| if v == 0 { 0 } else { (55 + v * 40) as u8 };
+            let n = n - 16;
+            TvColor::from_rgb(level(n / 36), level(n / 6 % 6), level(n % 6))
+        }
+        _ => {
+            let gray = (8 + (n.min(255) - 232) * 10) as u8;
+            TvColor::from_rgb(gray, gray, gray)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::palette::Attr;
 
     #[test]
     fn test_dump_simple_buffer() {
@@ -216,4 +379,50 @@ mod tests {
         assert!(result.contains("Hi"));
         assert!(result.contains("\x1b[")); // Contains ANSI codes
     }
+
+    #[test]
+    fn test_parse_dump_round_trip() {
+        let buffer = vec![
+            vec![
+                Cell::new('A', Attr::new(TvColor::Yellow, TvColor::Blue)),
+                Cell::new('▓', Attr::new(TvColor::Brown, TvColor::DarkGray)),
+            ],
+            vec![
+                Cell::new('b', Attr::new(TvColor::LightCyan, TvColor::Red)),
+                Cell::new(' ', Attr::new(TvColor::LightCyan, TvColor::Red)),
+            ],
+        ];
+        let mut output = Vec::new();
+        dump_buffer(&mut output, &buffer, 2, 2).unwrap();
+
+        let parsed = parse_ansi(&String::from_utf8(output).unwrap(), Attr::from_u8(0x07));
+        assert_eq!(parsed, buffer);
+    }
+
+    #[test]
+    fn test_styles_are_dumped_as_sgr_and_parsed_back() {
+        let plain = Attr::new(TvColor::White, TvColor::Blue);
+        let buffer = vec![vec![
+            Cell::new('a', plain.with_style(STYLE_UNDERLINE)),
+            Cell::new('b', plain.with_style(STYLE_REVERSE | STYLE_DIM)),
+            Cell::new('c', plain),
+            Cell::new('d', plain.with_style(STYLE_ITALIC)),
+        ]];
+        let mut output = Vec::new();
+        dump_buffer(&mut output, &buffer, 4, 1).unwrap();
+        let text = String::from_utf8(output).unwrap();
+        assert!(text.contains("\x1b[0;4m") && text.contains("\x1b[0;2;7m"), "{text:?}");
+
+        assert_eq!(parse_ansi(&text, Attr::from_u8(0x07)), buffer);
+    }
+
+    #[test]
+    fn test_parse_basic_sgr_codes() {
+        let parsed = parse_ansi("\x1b[1;31;44mX\x1b[0mY\nZ", Attr::from_u8(0x07));
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0][0], Cell::new('X', Attr::new(TvColor::LightRed, TvColor::Blue)));
+        assert_eq!(parsed[0][1], Cell::new('Y', Attr::from_u8(0x07)));
+        // Short rows are padded
+        assert_eq!(parsed[1][1], Cell::new(' ', Attr::from_u8(0x07)));
+    }
 }
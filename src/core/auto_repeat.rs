@@ -0,0 +1,150 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Auto-repeat timing for held mouse buttons and keys.
+//!
+//! While a mouse button is held the terminal sends
+//! [`EventType::MouseAuto`](super::event::EventType::MouseAuto) events. Views
+//! that repeat an action (scrollbar arrows, spin buttons) use [`AutoRepeat`]
+//! to turn those ticks into steps: the first repeat comes after an initial
+//! delay, later ones every interval, optionally faster after a number of
+//! repeats.
+//!
+//! Matches Borland: TEventQueue repeatDelay / autoTicks (tevent.cc)
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::{Duration, Instant};
+//! use turbo_vision::core::auto_repeat::AutoRepeat;
+//!
+//! let mut repeat = AutoRepeat::new(Duration::from_millis(400), Duration::from_millis(100));
+//! let start = Instant::now();
+//! repeat.start(start);
+//! assert_eq!(repeat.poll(start + Duration::from_millis(300)), 0);
+//! assert_eq!(repeat.poll(start + Duration::from_millis(600)), 3); // 400, 500, 600 ms
+//! ```
+
+use std::time::{Duration, Instant};
+
+/// Default delay before the first repeat
+pub const DEFAULT_REPEAT_DELAY: Duration = Duration::from_millis(400);
+/// Default time between repeats
+pub const DEFAULT_REPEAT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Repeat schedule for a held button
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AutoRepeat {
+    delay: Duration,
+    interval: Duration,
+    /// Repeats after which `fast_interval` is used
+    fast_after: u32,
+    fast_interval: Duration,
+    started: Option<Instant>,
+    /// Repeats reported so far
+    steps: u32,
+}
+
+impl AutoRepeat {
+    /// Repeat after `delay`, then every `interval`
+    pub fn new(delay: Duration, interval: Duration) -> Self {
+        Self {
+            delay,
+            interval,
+            fast_after: u32::MAX,
+            fast_interval: interval,
+            started: None,
+            steps: 0,
+        }
+    }
+
+    /// After `after` repeats, repeat every `fast_interval` instead
+    #[must_use]
+    pub fn with_acceleration(mut self, after: u32, fast_interval: Duration) -> Self {
+        self.fast_after = after;
+        self.fast_interval = fast_interval;
+        self
+    }
+
+    /// Start timing from `now` (the initial press is not counted as a repeat)
+    pub fn start(&mut self, now: Instant) {
+        self.started = Some(now);
+        self.steps = 0;
+    }
+
+    pub fn stop(&mut self) {
+        self.started = None;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.started.is_some()
+    }
+
+    /// Number of repeats that became due since the last poll
+    pub fn poll(&mut self, now: Instant) -> u32 {
+        let Some(started) = self.started else {
+            return 0;
+        };
+        let elapsed = now.saturating_duration_since(started);
+
+        let mut due = self.steps;
+        while self.repeat_time(due + 1) <= elapsed {
+            due += 1;
+        }
+        let new_steps = due - self.steps;
+        self.steps = due;
+        new_steps
+    }
+
+    /// Time after the press at which repeat number `n` (1-based) is due
+    fn repeat_time(&self, n: u32) -> Duration {
+        let gaps = n.saturating_sub(1);
+        let slow = gaps.min(self.fast_after.saturating_sub(1));
+        let fast = gaps - slow;
+        self.delay + self.interval * slow + self.fast_interval * fast
+    }
+}
+
+impl Default for AutoRepeat {
+    /// Borland-like timing: 400 ms delay, 100 ms interval, twice as fast after 10 repeats
+    fn default() -> Self {
+        Self::new(DEFAULT_REPEAT_DELAY, DEFAULT_REPEAT_INTERVAL).with_acceleration(10, DEFAULT_REPEAT_INTERVAL / 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MS: Duration = Duration::from_millis(1);
+
+    #[test]
+    fn test_holding_for_n_intervals() {
+        let mut repeat = AutoRepeat::new(300 * MS, 50 * MS);
+        let start = Instant::now();
+        repeat.start(start);
+
+        // Nothing during the initial delay
+        assert_eq!(repeat.poll(start + 299 * MS), 0);
+        // Delay plus N intervals gives N + 1 repeats, however often it is polled
+        let mut total = 0;
+        for tick in 0..=40 {
+            total += repeat.poll(start + 300 * MS + tick * 5 * MS);
+        }
+        assert_eq!(total, 5);
+        assert_eq!(repeat.poll(start + 300 * MS + 10 * 50 * MS), 6);
+
+        repeat.stop();
+        assert_eq!(repeat.poll(start + 5000 * MS), 0);
+    }
+
+    #[test]
+    fn test_acceleration() {
+        let mut repeat = AutoRepeat::new(100 * MS, 100 * MS).with_acceleration(2, 10 * MS);
+        let start = Instant::now();
+        repeat.start(start);
+
+        // Repeats at 100, 200, then every 10 ms
+        assert_eq!(repeat.poll(start + 200 * MS), 2);
+        assert_eq!(repeat.poll(start + 250 * MS), 5);
+    }
+}
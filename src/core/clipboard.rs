@@ -1,9 +1,27 @@
 // (C) 2025 - Enzo Lombardi
 
 //! Clipboard support - global clipboard management with OS integration.
+//!
+//! Over SSH the OS clipboard is the remote machine's. With
+//! [`enable_osc52()`], [`set_clipboard()`] also queues an OSC 52 escape
+//! sequence that the next [`Terminal::flush()`](crate::terminal::Terminal::flush)
+//! sends; terminals that support it copy the text to the local clipboard,
+//! others ignore it.
 
+use std::cell::{Cell, RefCell};
 use std::sync::Mutex;
 
+/// Largest OSC 52 payload (base64 bytes) sent; longer copies are not sent
+/// since many terminals drop or truncate them
+pub const OSC52_MAX_PAYLOAD: usize = 100_000;
+
+// The UI thread sets the clipboard and flushes the terminal, so the OSC 52
+// state lives with it
+thread_local! {
+    static OSC52_ENABLED: Cell<bool> = const { Cell::new(false) };
+    static PENDING_OSC52: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
 /// Global clipboard for copy/cut/paste operations.
 ///
 /// Uses a global static for simplicity and consistency with Borland TV's global clipboard model.
@@ -74,6 +92,56 @@ pub fn set_clipboard(text: &str) {
     {
         let _ = set_os_clipboard(text);
     }
+
+    if osc52_enabled() {
+        PENDING_OSC52.with(|pending| *pending.borrow_mut() = osc52_sequence(text));
+    }
+}
+
+/// Also copy to the host terminal's clipboard with OSC 52 (off by default)
+///
+/// Applies to the calling thread, which should be the one running the application.
+pub fn enable_osc52(enabled: bool) {
+    OSC52_ENABLED.with(|flag| flag.set(enabled));
+    if !enabled {
+        PENDING_OSC52.with(|pending| pending.borrow_mut().take());
+    }
+}
+
+pub fn osc52_enabled() -> bool {
+    OSC52_ENABLED.with(Cell::get)
+}
+
+/// OSC 52 sequence writing `text` to the terminal's clipboard, or None if
+/// the payload would exceed [`OSC52_MAX_PAYLOAD`]
+pub fn osc52_sequence(text: &str) -> Option<String> {
+    if text.len().div_ceil(3) * 4 > OSC52_MAX_PAYLOAD {
+        return None;
+    }
+    Some(format!("\x1b]52;c;{}\x07", base64_encode(text.as_bytes())))
+}
+
+/// Take the OSC 52 sequence queued by the last [`set_clipboard()`], if any
+pub fn take_pending_osc52() -> Option<String> {
+    PENDING_OSC52.with(|pending| pending.borrow_mut().take())
+}
+
+/// Standard base64 with padding
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [chunk[0], chunk.get(1).copied().unwrap_or(0), chunk.get(2).copied().unwrap_or(0)];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i)) as usize & 0x3F] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
 }
 
 /// Get the clipboard content (prefers OS clipboard, falls back to in-memory)
@@ -180,6 +248,24 @@ mod tests {
         assert_eq!(in_mem, "In-memory test");
     }
 
+    #[test]
+    fn test_osc52_is_opt_in_and_capped() {
+        assert_eq!(base64_encode(b"Man"), "TWFu");
+        assert_eq!(base64_encode(b"Ma"), "TWE=");
+        assert_eq!(base64_encode(b"M"), "TQ==");
+        assert_eq!(osc52_sequence("hi").as_deref(), Some("\x1b]52;c;aGk=\x07"));
+        assert_eq!(osc52_sequence(&"x".repeat(OSC52_MAX_PAYLOAD)), None);
+
+        set_clipboard("not sent");
+        assert_eq!(take_pending_osc52(), None);
+
+        enable_osc52(true);
+        set_clipboard("hi");
+        assert_eq!(take_pending_osc52().as_deref(), Some("\x1b]52;c;aGk=\x07"));
+        assert_eq!(take_pending_osc52(), None);
+        enable_osc52(false);
+    }
+
     #[test]
     fn test_in_memory_clipboard() {
         // Test that in-memory clipboard works even if OS clipboard fails
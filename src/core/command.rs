@@ -21,6 +21,8 @@ pub const CM_CANCEL: CommandId = 11;
 pub const CM_YES: CommandId = 12;
 pub const CM_NO: CommandId = 13;
 pub const CM_DEFAULT: CommandId = 14;
+pub const CM_HELP: CommandId = 15;  // Borland: cmHelp - does not close dialogs
+pub const CM_RESET: CommandId = 16;  // Restore a dialog's captured initial values - does not close dialogs
 
 // Broadcast commands
 pub const CM_COMMAND_SET_CHANGED: CommandId = 52;  // Borland: cmCommandSetChanged
@@ -30,6 +32,8 @@ pub const CM_GRAB_DEFAULT: CommandId = 62;         // Borland: cmGrabDefault
 pub const CM_RELEASE_DEFAULT: CommandId = 63;      // Borland: cmReleaseDefault
 pub const CM_FILE_FOCUSED: CommandId = 64;         // Borland: cmFileFocused - file dialog selection changed
 pub const CM_FILE_DOUBLE_CLICKED: CommandId = 65;  // Borland: cmFileDoubleClicked - file double-clicked in list
+pub const CM_FOCUS_LINK: CommandId = 66;          // Label hotkey: owner group focuses the sender's link_id()
+pub const CM_RADIO_SELECTED: CommandId = 67;      // Radio button selected: owner group deselects the rest of its group_id
 
 // Custom commands (user defined)
 pub const CM_ABOUT: CommandId = 100;
@@ -55,6 +59,7 @@ pub const CM_SELECT_ALL: CommandId = 115;
 pub const CM_FIND: CommandId = 116;
 pub const CM_REPLACE: CommandId = 117;
 pub const CM_SEARCH_AGAIN: CommandId = 118;  // Borland: cmSearchAgain (F3) - find next
+pub const CM_BLOCK_SELECT: CommandId = 119;  // Toggle column (block) selection in the focused editor
 
 // Search menu commands
 pub const CM_FIND_IN_FILES: CommandId = 120;
@@ -65,6 +70,7 @@ pub const CM_ZOOM_IN: CommandId = 130;
 pub const CM_ZOOM_OUT: CommandId = 131;
 pub const CM_TOGGLE_SIDEBAR: CommandId = 132;
 pub const CM_TOGGLE_STATUSBAR: CommandId = 133;
+pub const CM_RELOAD_THEME: CommandId = 134;  // Re-read the theme file (see Application::load_theme_file)
 
 // Help menu commands
 pub const CM_HELP_INDEX: CommandId = 140;
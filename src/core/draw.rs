@@ -1,8 +1,74 @@
 // (C) 2025 - Enzo Lombardi
 
 //! Drawing primitives - Cell and DrawBuffer types for efficient line-based rendering.
+//!
+//! Wide characters (CJK, most emoji) take two columns: the character is
+//! stored in the first cell and the second holds [`WIDE_CONTINUATION`],
+//! which the terminal never prints.
 
 use super::palette::Attr;
+use unicode_width::UnicodeWidthChar;
+
+/// Character stored in the cell covered by the right half of a wide character
+pub const WIDE_CONTINUATION: char = '\0';
+
+/// Number of columns `ch` occupies on screen (1 or 2)
+/// Control and zero-width characters count as one column so every char keeps a cell
+pub fn char_width(ch: char) -> usize {
+    if ch.width() == Some(2) { 2 } else { 1 }
+}
+
+/// Number of columns `s` occupies on screen
+pub fn str_width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+/// Split a label with `~` shortcut markers into `(text, highlighted)` spans
+///
+/// - `~X~` highlights `X`; a label may have several marked spans
+/// - `~~` is a literal `~` (inside or outside a marked span)
+/// - an unclosed `~` highlights the rest of the label
+///
+/// Empty spans are left out. Used by every view that draws such labels, so
+/// hotkeys and rendering never disagree.
+pub fn shortcut_spans(s: &str) -> Vec<(String, bool)> {
+    let mut spans: Vec<(String, bool)> = Vec::new();
+    let mut current = String::new();
+    let mut highlighted = false;
+    let mut chars = s.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch != '~' {
+            current.push(ch);
+        } else if chars.peek() == Some(&'~') {
+            chars.next();
+            current.push('~');
+        } else {
+            if !current.is_empty() {
+                spans.push((std::mem::take(&mut current), highlighted));
+            }
+            highlighted = !highlighted;
+        }
+    }
+    if !current.is_empty() {
+        spans.push((current, highlighted));
+    }
+    spans
+}
+
+/// Hotkey of a `~`-marked label: the first highlighted character, as written
+///
+/// `"~F~ile"` gives `Some('F')`, `"a ~~ b"` gives `None`.
+pub fn shortcut_hotkey(s: &str) -> Option<char> {
+    shortcut_spans(s)
+        .into_iter()
+        .find(|(_, highlighted)| *highlighted)
+        .and_then(|(text, _)| text.chars().next())
+}
+
+/// Label text as displayed, without its `~` markers
+pub fn strip_shortcut_markers(s: &str) -> String {
+    shortcut_spans(s).into_iter().map(|(text, _)| text).collect()
+}
 
 /// A single character cell with attributes
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -15,6 +81,20 @@ impl Cell {
     pub const fn new(ch: char, attr: Attr) -> Self {
         Self { ch, attr }
     }
+
+    /// The cell covered by the right half of a wide character
+    pub const fn continuation(attr: Attr) -> Self {
+        Self { ch: WIDE_CONTINUATION, attr }
+    }
+
+    pub const fn is_continuation(&self) -> bool {
+        self.ch == WIDE_CONTINUATION
+    }
+
+    /// True if the character takes two columns
+    pub fn is_wide(&self) -> bool {
+        char_width(self.ch) == 2
+    }
 }
 
 /// Buffer for efficient line-based drawing
@@ -38,16 +118,64 @@ impl DrawBuffer {
         }
     }
 
+    /// Store `ch` at `pos`, followed by a continuation cell if it is wide
+    /// Returns the columns used, 0 if `pos` is at or past `end`. A wide
+    /// character that does not fit before `end` is replaced by a space.
+    fn put_glyph(&mut self, pos: usize, ch: char, attr: Attr, end: usize) -> usize {
+        let end = end.min(self.data.len());
+        if pos >= end {
+            return 0;
+        }
+        if char_width(ch) == 2 {
+            if pos + 1 < end {
+                self.data[pos] = Cell::new(ch, attr);
+                self.data[pos + 1] = Cell::continuation(attr);
+                return 2;
+            }
+            self.data[pos] = Cell::new(' ', attr);
+        } else {
+            self.data[pos] = Cell::new(ch, attr);
+        }
+        1
+    }
+
     /// Write a string with the given attribute
     pub fn move_str(&mut self, pos: usize, s: &str, attr: Attr) {
-        let mut i = pos;
+        self.move_str_clipped(pos, s, attr, usize::MAX);
+    }
+
+    /// Write at most `max_cols` columns of a string, returning the number
+    /// of columns written (also stops at the end of the buffer)
+    pub fn move_str_clipped(&mut self, pos: usize, s: &str, attr: Attr, max_cols: usize) -> usize {
+        let end = pos.saturating_add(max_cols);
+        let mut col = pos;
         for ch in s.chars() {
-            if i >= self.data.len() {
+            let used = self.put_glyph(col, ch, attr, end);
+            if used == 0 {
                 break;
             }
-            self.data[i] = Cell::new(ch, attr);
-            i += 1;
+            col += used;
+        }
+        col - pos
+    }
+
+    /// Write consecutive runs of text, each with its own attribute, returning
+    /// the number of columns written
+    ///
+    /// Replaces per-character `put_char` loops for styled text such as
+    /// selections and search highlights.
+    pub fn move_styled(&mut self, pos: usize, runs: &[(&str, Attr)]) -> usize {
+        let mut col = pos;
+        for &(text, attr) in runs {
+            for ch in text.chars() {
+                let used = self.put_glyph(col, ch, attr, usize::MAX);
+                if used == 0 {
+                    return col - pos;
+                }
+                col += used;
+            }
         }
+        col - pos
     }
 
     /// Copy cells from another buffer
@@ -56,10 +184,17 @@ impl DrawBuffer {
         self.data[pos..end].copy_from_slice(&src[..(end - pos)]);
     }
 
-    /// Put a single character at a position
+    /// Put a single character at a position (two cells if it is wide)
     pub fn put_char(&mut self, pos: usize, ch: char, attr: Attr) {
-        if pos < self.data.len() {
-            self.data[pos] = Cell::new(ch, attr);
+        self.put_glyph(pos, ch, attr, usize::MAX);
+    }
+
+    /// Recolor `count` cells starting at `pos`, keeping their characters
+    /// Matches Borland: TDrawBuffer::putAttribute() over a range
+    pub fn put_attribute(&mut self, pos: usize, attr: Attr, count: usize) {
+        let end = (pos + count).min(self.data.len());
+        for cell in self.data.iter_mut().take(end).skip(pos) {
+            cell.attr = attr;
         }
     }
 
@@ -76,34 +211,16 @@ impl DrawBuffer {
     /// Write a string with shortcut highlighting
     /// Format: "~X~" highlights X with shortcut_attr, rest uses normal_attr
     /// Example: "~F~ile" displays "File" with "F" highlighted
-    pub fn move_str_with_shortcut(&mut self, mut pos: usize, s: &str, normal_attr: Attr, shortcut_attr: Attr) -> usize {
-        let mut chars = s.chars();
-        let start_pos = pos;
-
-        // Parse string character by character, handling ~X~ shortcut highlighting
-        while let Some(ch) = chars.next() {
-            if pos >= self.data.len() {
-                break;
-            }
-
-            if ch == '~' {
-                // Read characters until closing ~ and render with shortcut color
-                while let Some(shortcut_ch) = chars.next() {
-                    if shortcut_ch == '~' {
-                        break;  // Found closing tilde
-                    }
-                    if pos < self.data.len() {
-                        self.data[pos] = Cell::new(shortcut_ch, shortcut_attr);
-                        pos += 1;
-                    }
-                }
-            } else {
-                self.data[pos] = Cell::new(ch, normal_attr);
-                pos += 1;
-            }
-        }
-
-        pos - start_pos  // Return number of characters written
+    ///
+    /// Markers are parsed by [`shortcut_spans()`]. Returns the number of
+    /// columns written and the label's hotkey (see [`shortcut_hotkey()`]).
+    pub fn move_str_with_shortcut(&mut self, pos: usize, s: &str, normal_attr: Attr, shortcut_attr: Attr) -> (usize, Option<char>) {
+        let spans = shortcut_spans(s);
+        let runs: Vec<(&str, Attr)> = spans
+            .iter()
+            .map(|(text, highlighted)| (text.as_str(), if *highlighted { shortcut_attr } else { normal_attr }))
+            .collect();
+        (self.move_styled(pos, &runs), shortcut_hotkey(s))
     }
 }
 
@@ -131,6 +248,73 @@ mod tests {
         assert_eq!(buf.data[5].ch, ' ');
     }
 
+    #[test]
+    fn test_move_str_clipped() {
+        let mut buf = DrawBuffer::new(10);
+        let attr = Attr::new(TvColor::White, TvColor::Black);
+        assert_eq!(buf.move_str_clipped(2, "Hello", attr, 3), 3);
+        assert_eq!(buf.data[4].ch, 'l');
+        assert_eq!(buf.data[5].ch, ' ');
+        // Clipped by the end of the buffer, and out of range is a no-op
+        assert_eq!(buf.move_str_clipped(8, "Hello", attr, 10), 2);
+        assert_eq!(buf.move_str_clipped(12, "Hello", attr, 10), 0);
+    }
+
+    #[test]
+    fn test_move_styled() {
+        let mut buf = DrawBuffer::new(8);
+        let normal = Attr::new(TvColor::White, TvColor::Black);
+        let selected = Attr::new(TvColor::Black, TvColor::White);
+        let written = buf.move_styled(1, &[("ab", normal), ("", selected), ("cd", selected), ("efgh", normal)]);
+        assert_eq!(written, 7);
+        assert_eq!(buf.data[0].ch, ' ');
+        assert_eq!((buf.data[2].ch, buf.data[2].attr), ('b', normal));
+        assert_eq!((buf.data[3].ch, buf.data[3].attr), ('c', selected));
+        assert_eq!((buf.data[7].ch, buf.data[7].attr), ('g', normal));
+    }
+
+    #[test]
+    fn test_wide_chars_take_two_columns() {
+        let mut buf = DrawBuffer::new(7);
+        let attr = Attr::new(TvColor::White, TvColor::Black);
+        assert_eq!(str_width("日本語"), 6);
+        assert_eq!(buf.move_str_clipped(0, "a日本語", attr, 7), 7);
+        assert_eq!(buf.data[1].ch, '日');
+        assert!(buf.data[2].is_continuation());
+        assert_eq!(buf.data[5].ch, '語');
+        assert!(buf.data[6].is_continuation());
+
+        // A wide char that would straddle the limit becomes a space
+        let mut buf = DrawBuffer::new(4);
+        assert_eq!(buf.move_str_with_shortcut(0, "~日~本語", attr, attr), (4, Some('日')));
+        buf.move_str(1, "語語", attr);
+        assert_eq!(buf.data[1].ch, '語');
+        assert_eq!(buf.data[3].ch, ' ');
+    }
+
+    #[test]
+    fn test_shortcut_marker_edge_cases() {
+        let plain = |s: &str| (s.to_string(), false);
+        let marked = |s: &str| (s.to_string(), true);
+
+        assert_eq!(shortcut_spans("~F~ile"), vec![marked("F"), plain("ile")]);
+        assert_eq!(shortcut_spans("a~~b"), vec![plain("a~b")]);
+        assert_eq!(shortcut_spans("~A~nd ~B~oth"), vec![marked("A"), plain("nd "), marked("B"), plain("oth")]);
+        assert_eq!(shortcut_spans("Open~X"), vec![plain("Open"), marked("X")], "unclosed marker runs to the end");
+        assert_eq!(shortcut_spans("~~Home~~"), vec![plain("~Home~")]);
+
+        assert_eq!(shortcut_hotkey("a ~~ b"), None);
+        assert_eq!(shortcut_hotkey("Save ~a~s"), Some('a'));
+        assert_eq!(strip_shortcut_markers("~C~opy ~~ ~P~aste"), "Copy ~ Paste");
+
+        let normal = Attr::new(TvColor::White, TvColor::Black);
+        let shortcut = Attr::new(TvColor::Yellow, TvColor::Black);
+        let mut buf = DrawBuffer::new(10);
+        assert_eq!(buf.move_str_with_shortcut(0, "~~x~Y~", normal, shortcut), (3, Some('Y')));
+        let attrs: Vec<Attr> = buf.data[..3].iter().map(|cell| cell.attr).collect();
+        assert_eq!(attrs, vec![normal, normal, shortcut]);
+    }
+
     #[test]
     fn test_move_str() {
         let mut buf = DrawBuffer::new(20);
@@ -3,9 +3,12 @@
 //! Event system - keyboard and mouse event handling with Borland-compatible key codes.
 
 use super::command::CommandId;
-use super::geometry::Point;
+use super::geometry::{Point, Rect};
 use crossterm::event::{KeyCode as CKC, KeyEvent, KeyModifiers};
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
 use std::fmt;
+use std::rc::Rc;
 use std::time::{Duration, Instant};
 
 /// Keyboard code (scan code + character)
@@ -31,7 +34,61 @@ pub const KB_F9: KeyCode = 0x4300;
 pub const KB_F10: KeyCode = 0x4400;
 pub const KB_F11: KeyCode = 0x8500;
 pub const KB_F12: KeyCode = 0x8600;
-pub const KB_SHIFT_F12: KeyCode = 0x8601; // Shift+F12 for active view dump
+
+// Shift/Ctrl/Alt + function keys (PC BIOS scan codes, as in Borland's TKeys)
+pub const KB_SHIFT_F1: KeyCode = 0x5400;
+pub const KB_SHIFT_F2: KeyCode = 0x5500;
+pub const KB_SHIFT_F3: KeyCode = 0x5600;
+pub const KB_SHIFT_F4: KeyCode = 0x5700;
+pub const KB_SHIFT_F5: KeyCode = 0x5800;
+pub const KB_SHIFT_F6: KeyCode = 0x5900;
+pub const KB_SHIFT_F7: KeyCode = 0x5A00;
+pub const KB_SHIFT_F8: KeyCode = 0x5B00;
+pub const KB_SHIFT_F9: KeyCode = 0x5C00;
+pub const KB_SHIFT_F10: KeyCode = 0x5D00;
+pub const KB_SHIFT_F11: KeyCode = 0x8700;
+pub const KB_SHIFT_F12: KeyCode = 0x8800; // Also dumps the active view (see Terminal)
+
+pub const KB_CTRL_F1: KeyCode = 0x5E00;
+pub const KB_CTRL_F2: KeyCode = 0x5F00;
+pub const KB_CTRL_F3: KeyCode = 0x6000;
+pub const KB_CTRL_F4: KeyCode = 0x6100;
+pub const KB_CTRL_F5: KeyCode = 0x6200;
+pub const KB_CTRL_F6: KeyCode = 0x6300;
+pub const KB_CTRL_F7: KeyCode = 0x6400;
+pub const KB_CTRL_F8: KeyCode = 0x6500;
+pub const KB_CTRL_F9: KeyCode = 0x6600;
+pub const KB_CTRL_F10: KeyCode = 0x6700;
+pub const KB_CTRL_F11: KeyCode = 0x8900;
+pub const KB_CTRL_F12: KeyCode = 0x8A00;
+
+pub const KB_ALT_F1: KeyCode = 0x6800;
+pub const KB_ALT_F2: KeyCode = 0x6900;
+pub const KB_ALT_F3: KeyCode = 0x6A00;
+pub const KB_ALT_F4: KeyCode = 0x6B00;
+pub const KB_ALT_F5: KeyCode = 0x6C00;
+pub const KB_ALT_F6: KeyCode = 0x6D00;
+pub const KB_ALT_F7: KeyCode = 0x6E00;
+pub const KB_ALT_F8: KeyCode = 0x6F00;
+pub const KB_ALT_F9: KeyCode = 0x7000;
+pub const KB_ALT_F10: KeyCode = 0x7100;
+pub const KB_ALT_F11: KeyCode = 0x8B00;
+pub const KB_ALT_F12: KeyCode = 0x8C00;
+
+const FUNCTION_KEYS: [KeyCode; 12] =
+    [KB_F1, KB_F2, KB_F3, KB_F4, KB_F5, KB_F6, KB_F7, KB_F8, KB_F9, KB_F10, KB_F11, KB_F12];
+const SHIFT_FUNCTION_KEYS: [KeyCode; 12] = [
+    KB_SHIFT_F1, KB_SHIFT_F2, KB_SHIFT_F3, KB_SHIFT_F4, KB_SHIFT_F5, KB_SHIFT_F6,
+    KB_SHIFT_F7, KB_SHIFT_F8, KB_SHIFT_F9, KB_SHIFT_F10, KB_SHIFT_F11, KB_SHIFT_F12,
+];
+const CTRL_FUNCTION_KEYS: [KeyCode; 12] = [
+    KB_CTRL_F1, KB_CTRL_F2, KB_CTRL_F3, KB_CTRL_F4, KB_CTRL_F5, KB_CTRL_F6,
+    KB_CTRL_F7, KB_CTRL_F8, KB_CTRL_F9, KB_CTRL_F10, KB_CTRL_F11, KB_CTRL_F12,
+];
+const ALT_FUNCTION_KEYS: [KeyCode; 12] = [
+    KB_ALT_F1, KB_ALT_F2, KB_ALT_F3, KB_ALT_F4, KB_ALT_F5, KB_ALT_F6,
+    KB_ALT_F7, KB_ALT_F8, KB_ALT_F9, KB_ALT_F10, KB_ALT_F11, KB_ALT_F12,
+];
 
 // Arrow keys
 pub const KB_UP: KeyCode = 0x4800;
@@ -39,6 +96,16 @@ pub const KB_DOWN: KeyCode = 0x5000;
 pub const KB_LEFT: KeyCode = 0x4B00;
 pub const KB_RIGHT: KeyCode = 0x4D00;
 
+// Alt + arrow keys (drop-down lists open on Alt+Down)
+pub const KB_ALT_UP: KeyCode = 0x9800;
+pub const KB_ALT_DOWN: KeyCode = 0xA000;
+
+// Shift + movement keys (text selection in InputLine)
+pub const KB_SHIFT_LEFT: KeyCode = 0x4B01;
+pub const KB_SHIFT_RIGHT: KeyCode = 0x4D01;
+pub const KB_SHIFT_HOME: KeyCode = 0x4701;
+pub const KB_SHIFT_END: KeyCode = 0x4F01;
+
 pub const KB_HOME: KeyCode = 0x4700;
 pub const KB_END: KeyCode = 0x4F00;
 pub const KB_PGUP: KeyCode = 0x4900;
@@ -73,7 +140,6 @@ pub const KB_ALT_W: KeyCode = 0x1100;
 pub const KB_ALT_X: KeyCode = 0x2D00;
 pub const KB_ALT_Y: KeyCode = 0x1500;
 pub const KB_ALT_Z: KeyCode = 0x2C00;
-pub const KB_ALT_F3: KeyCode = 0x6A00;
 
 // ESC + letter (for macOS Alt emulation)
 pub const KB_ESC_F: KeyCode = 0x2101; // ESC+F
@@ -128,6 +194,8 @@ pub enum EventType {
     MouseWheelDown, // Mouse wheel scrolled down
     Command,
     Broadcast,
+    Resize,         // Terminal was resized (new size in mouse.pos)
+    Paste,          // Text pasted into the terminal (see Event::paste_text())
 }
 
 // Event masks (for filtering)
@@ -149,12 +217,65 @@ pub const MB_LEFT_BUTTON: u8 = 0x01;
 pub const MB_MIDDLE_BUTTON: u8 = 0x02;
 pub const MB_RIGHT_BUTTON: u8 = 0x04;
 
+/// Default lines scrolled per mouse wheel notch (see `Terminal::set_wheel_lines()`)
+pub const WHEEL_SCROLL_LINES: i16 = 3;
+
 /// Mouse event data
 #[derive(Debug, Clone, Copy)]
 pub struct MouseEvent {
     pub pos: Point,
     pub buttons: u8, // button state (bit flags)
     pub double_click: bool,
+    /// Lines to scroll for MouseWheelUp/MouseWheelDown: negative up, positive down.
+    /// Several notches queued together arrive as one event with a larger delta.
+    pub wheel_delta: i16,
+    /// Shift/Ctrl/Alt held with the button (Ctrl+click, Shift+click)
+    pub modifiers: KeyModifiers,
+}
+
+/// Number of recent pastes whose text [`PasteText::get()`] still returns
+pub const PASTE_HISTORY: usize = 16;
+
+thread_local! {
+    /// Text of the most recent pastes created on this thread, by id
+    static PASTES: RefCell<VecDeque<(u32, Rc<str>)>> = const { RefCell::new(VecDeque::new()) };
+    static NEXT_PASTE_ID: Cell<u32> = const { Cell::new(1) };
+}
+
+/// Handle to the text of a paste event
+///
+/// Keeps [`Event`] `Copy`: the text stays with the thread that created the
+/// event, which is also the one handling it, and the event holds its id.
+/// The default handle holds no text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PasteText(u32);
+
+impl PasteText {
+    /// Keep `text` and return its handle, dropping the oldest of the kept pastes
+    fn store(text: String) -> Self {
+        let id = NEXT_PASTE_ID.with(|next| {
+            let id = next.get();
+            next.set(id.checked_add(1).unwrap_or(1));
+            id
+        });
+        PASTES.with(|pastes| {
+            let mut pastes = pastes.borrow_mut();
+            if pastes.len() == PASTE_HISTORY {
+                pastes.pop_front();
+            }
+            pastes.push_back((id, text.into()));
+        });
+        Self(id)
+    }
+
+    /// The pasted text; `None` for the default handle, or if the text was
+    /// pasted on another thread or more than [`PASTE_HISTORY`] pastes ago
+    pub fn get(self) -> Option<Rc<str>> {
+        if self.0 == 0 {
+            return None;
+        }
+        PASTES.with(|pastes| pastes.borrow().iter().find(|(id, _)| *id == self.0).map(|(_, text)| Rc::clone(text)))
+    }
 }
 
 /// A unified event structure
@@ -185,6 +306,8 @@ pub struct Event {
     pub key_modifiers: KeyModifiers,
     pub mouse: MouseEvent,
     pub command: CommandId,
+    /// Pasted text, for [`EventType::Paste`] events (see [`paste_text()`](Self::paste_text))
+    pub paste: PasteText,
 }
 
 impl Event {
@@ -197,8 +320,11 @@ impl Event {
                 pos: Point::zero(),
                 buttons: 0,
                 double_click: false,
+                wheel_delta: 0,
+                modifiers: KeyModifiers::empty(),
             },
             command: 0,
+            paste: PasteText::default(),
         }
     }
 
@@ -211,6 +337,23 @@ impl Event {
         }
     }
 
+    /// Keyboard event with the modifiers held, e.g. Shift with KB_LEFT
+    pub fn keyboard_with(key_code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self {
+            key_modifiers: modifiers,
+            ..Self::keyboard(key_code)
+        }
+    }
+
+    /// The key and the modifiers held with it
+    ///
+    /// Lets a view match `(KB_LEFT, KeyModifiers::SHIFT)` instead of needing a
+    /// composite key code for every combination. The composite codes (KB_ALT_X,
+    /// KB_CTRL_A, ...) are still produced, with the matching modifier set.
+    pub fn key(&self) -> (KeyCode, KeyModifiers) {
+        (self.key_code, self.key_modifiers)
+    }
+
     pub fn command(cmd: CommandId) -> Self {
         Self {
             what: EventType::Command,
@@ -230,11 +373,83 @@ impl Event {
     pub fn mouse(event_type: EventType, pos: Point, buttons: u8, double_click: bool) -> Self {
         Self {
             what: event_type,
-            mouse: MouseEvent { pos, buttons, double_click },
+            mouse: MouseEvent { pos, buttons, double_click, wheel_delta: 0, modifiers: KeyModifiers::empty() },
+            ..Self::nothing()
+        }
+    }
+
+    /// Mouse event with the modifier keys held, e.g. Ctrl for Ctrl+click
+    pub fn mouse_with(event_type: EventType, pos: Point, buttons: u8, modifiers: KeyModifiers) -> Self {
+        let mut event = Self::mouse(event_type, pos, buttons, false);
+        event.mouse.modifiers = modifiers;
+        event
+    }
+
+    /// Create a mouse wheel event scrolling `delta` lines, negative up and positive down
+    pub fn wheel(pos: Point, delta: i16) -> Self {
+        let what = if delta < 0 { EventType::MouseWheelUp } else { EventType::MouseWheelDown };
+        Self {
+            what,
+            mouse: MouseEvent { pos, buttons: 0, double_click: false, wheel_delta: delta, modifiers: KeyModifiers::empty() },
+            ..Self::nothing()
+        }
+    }
+
+    /// Mouse position relative to a view: (0, 0) is the view's top-left cell
+    /// Positions are already in logical screen coordinates (the terminal
+    /// removes any viewport offset), so only the view origin is subtracted.
+    /// Matches Borland: TView::makeLocal(event.mouse.where)
+    pub fn local_mouse(&self, view_bounds: Rect) -> Point {
+        Point::new(self.mouse.pos.x - view_bounds.a.x, self.mouse.pos.y - view_bounds.a.y)
+    }
+
+    /// True for mouse wheel events
+    pub fn is_wheel(&self) -> bool {
+        matches!(self.what, EventType::MouseWheelUp | EventType::MouseWheelDown)
+    }
+
+    /// Lines to scroll for a wheel event (negative scrolls up)
+    /// Events built without a delta scroll [`WHEEL_SCROLL_LINES`].
+    pub fn wheel_lines(&self) -> i16 {
+        match (self.what, self.mouse.wheel_delta) {
+            (EventType::MouseWheelUp, 0) => -WHEEL_SCROLL_LINES,
+            (EventType::MouseWheelDown, 0) => WHEEL_SCROLL_LINES,
+            (EventType::MouseWheelUp | EventType::MouseWheelDown, delta) => delta,
+            _ => 0,
+        }
+    }
+
+    /// Create a terminal resize event
+    /// The new size is carried in `mouse.pos` (x = width, y = height)
+    pub fn resize(width: u16, height: u16) -> Self {
+        Self {
+            what: EventType::Resize,
+            mouse: MouseEvent {
+                pos: Point::new(width as i16, height as i16),
+                buttons: 0,
+                double_click: false,
+                wheel_delta: 0,
+                modifiers: KeyModifiers::empty(),
+            },
+            ..Self::nothing()
+        }
+    }
+
+    /// Create a paste event carrying the whole pasted text
+    /// Sent when the terminal supports bracketed paste, instead of one key event per character.
+    pub fn paste(text: impl Into<String>) -> Self {
+        Self {
+            what: EventType::Paste,
+            paste: PasteText::store(text.into()),
             ..Self::nothing()
         }
     }
 
+    /// Text of a paste event, `None` for other events
+    pub fn paste_text(&self) -> Option<Rc<str>> {
+        self.paste.get()
+    }
+
     pub fn from_crossterm_key(key_event: KeyEvent) -> Self {
         let key_code = crossterm_to_keycode(key_event);
         Self {
@@ -278,10 +493,12 @@ impl fmt::Display for Event {
             EventType::MouseUp => write!(f, "Event::MouseUp({}, buttons={:#04x})", self.mouse.pos, self.mouse.buttons),
             EventType::MouseMove => write!(f, "Event::MouseMove({}, buttons={:#04x})", self.mouse.pos, self.mouse.buttons),
             EventType::MouseAuto => write!(f, "Event::MouseAuto({}, buttons={:#04x})", self.mouse.pos, self.mouse.buttons),
-            EventType::MouseWheelUp => write!(f, "Event::MouseWheelUp({})", self.mouse.pos),
-            EventType::MouseWheelDown => write!(f, "Event::MouseWheelDown({})", self.mouse.pos),
+            EventType::MouseWheelUp => write!(f, "Event::MouseWheelUp({}, delta={})", self.mouse.pos, self.mouse.wheel_delta),
+            EventType::MouseWheelDown => write!(f, "Event::MouseWheelDown({}, delta={})", self.mouse.pos, self.mouse.wheel_delta),
             EventType::Command => write!(f, "Event::Command({:#06x})", self.command),
             EventType::Broadcast => write!(f, "Event::Broadcast({:#06x})", self.command),
+            EventType::Resize => write!(f, "Event::Resize({}x{})", self.mouse.pos.x, self.mouse.pos.y),
+            EventType::Paste => write!(f, "Event::Paste({} chars)", self.paste_text().map_or(0, |text| text.chars().count())),
         }
     }
 }
@@ -345,6 +562,25 @@ impl EscSequenceTracker {
         self.timeout_ms = timeout_ms;
     }
 
+    /// Forget a pending ESC
+    pub fn reset(&mut self) {
+        self.last_esc_time = None;
+        self.waiting_for_char = false;
+    }
+
+    /// Process a key event like [`process_key()`](Self::process_key), also returning
+    /// the modifiers held
+    ///
+    /// ESC followed by a letter reports ALT, as the real Alt+letter does.
+    pub fn process_key_with_modifiers(&mut self, key: KeyEvent) -> (KeyCode, KeyModifiers) {
+        let key_code = self.process_key(key);
+        let mut modifiers = key.modifiers;
+        if matches!(key.code, CKC::Char(_)) && ('a'..='z').any(|c| char_to_alt_code(c) == Some(key_code)) {
+            modifiers |= KeyModifiers::ALT;
+        }
+        (key_code, modifiers)
+    }
+
     /// Process a key event, handling ESC sequences
     /// Returns the appropriate KeyCode
     pub fn process_key(&mut self, key: KeyEvent) -> KeyCode {
@@ -408,7 +644,7 @@ impl EscSequenceTracker {
 }
 
 /// Convert crossterm KeyEvent to our KeyCode
-fn crossterm_to_keycode(key: KeyEvent) -> KeyCode {
+pub(crate) fn crossterm_to_keycode(key: KeyEvent) -> KeyCode {
     match key.code {
         CKC::Char(c) => {
             // Check for Ctrl modifier first (Ctrl+letter generates ASCII control codes)
@@ -441,6 +677,8 @@ fn crossterm_to_keycode(key: KeyEvent) -> KeyCode {
         }
         CKC::BackTab => KB_SHIFT_TAB, // Some terminals send BackTab for Shift+Tab
         CKC::Esc => KB_ESC,
+        CKC::Up if key.modifiers.contains(KeyModifiers::ALT) => KB_ALT_UP,
+        CKC::Down if key.modifiers.contains(KeyModifiers::ALT) => KB_ALT_DOWN,
         CKC::Up => KB_UP,
         CKC::Down => KB_DOWN,
         CKC::Left => KB_LEFT,
@@ -451,30 +689,184 @@ fn crossterm_to_keycode(key: KeyEvent) -> KeyCode {
         CKC::PageDown => KB_PGDN,
         CKC::Insert => KB_INS,
         CKC::Delete => KB_DEL,
-        CKC::F(1) => KB_F1,
-        CKC::F(2) => KB_F2,
-        CKC::F(3) => {
-            if key.modifiers.contains(KeyModifiers::ALT) {
-                KB_ALT_F3
-            } else {
-                KB_F3
-            }
+        CKC::F(n @ 1..=12) => function_key_code(n, key.modifiers),
+        _ => 0,
+    }
+}
+
+/// Key code of function key `n` (1-12) with modifiers
+///
+/// Only one modifier is encoded, like on a PC keyboard: Ctrl wins over Alt,
+/// and Alt over Shift.
+fn function_key_code(n: u8, modifiers: KeyModifiers) -> KeyCode {
+    let keys = if modifiers.contains(KeyModifiers::CONTROL) {
+        &CTRL_FUNCTION_KEYS
+    } else if modifiers.contains(KeyModifiers::ALT) {
+        &ALT_FUNCTION_KEYS
+    } else if modifiers.contains(KeyModifiers::SHIFT) {
+        &SHIFT_FUNCTION_KEYS
+    } else {
+        &FUNCTION_KEYS
+    };
+    keys[n as usize - 1]
+}
+
+/// Display name of a key code, e.g. "F3", "Shift+F6", "Ctrl+O" or "Alt+X"
+///
+/// Used for the shortcut column of menus and for status line items built
+/// from a key code. Returns `None` for printable characters and unknown codes.
+pub fn key_name(key_code: KeyCode) -> Option<String> {
+    let function_keys = [("", &FUNCTION_KEYS), ("Shift+", &SHIFT_FUNCTION_KEYS), ("Ctrl+", &CTRL_FUNCTION_KEYS), ("Alt+", &ALT_FUNCTION_KEYS)];
+    for (prefix, keys) in function_keys {
+        if let Some(i) = keys.iter().position(|&k| k == key_code) {
+            return Some(format!("{prefix}F{}", i + 1));
         }
-        CKC::F(4) => KB_F4,
-        CKC::F(5) => KB_F5,
-        CKC::F(6) => KB_F6,
-        CKC::F(7) => KB_F7,
-        CKC::F(8) => KB_F8,
-        CKC::F(9) => KB_F9,
-        CKC::F(10) => KB_F10,
-        CKC::F(11) => KB_F11,
-        CKC::F(12) => {
-            if key.modifiers.contains(KeyModifiers::SHIFT) {
-                KB_SHIFT_F12
-            } else {
-                KB_F12
-            }
+    }
+    if let Some(letter) = ('a'..='z').find(|&c| char_to_alt_code(c) == Some(key_code)) {
+        return Some(format!("Alt+{}", letter.to_ascii_uppercase()));
+    }
+    let name = match key_code {
+        KB_ESC => "Esc",
+        KB_ENTER => "Enter",
+        KB_BACKSPACE => "Backspace",
+        KB_TAB => "Tab",
+        KB_SHIFT_TAB => "Shift+Tab",
+        KB_UP => "Up",
+        KB_DOWN => "Down",
+        KB_ALT_UP => "Alt+Up",
+        KB_ALT_DOWN => "Alt+Down",
+        KB_LEFT => "Left",
+        KB_RIGHT => "Right",
+        KB_HOME => "Home",
+        KB_END => "End",
+        KB_PGUP => "PgUp",
+        KB_PGDN => "PgDn",
+        KB_INS => "Ins",
+        KB_DEL => "Del",
+        KB_CTRL_A..=KB_CTRL_Z => return Some(format!("Ctrl+{}", (b'A' + key_code as u8 - 1) as char)),
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
+/// Character typed with a key code, for views that take text input
+///
+/// Key codes of typed characters are their Unicode code points, so any
+/// printable character qualifies ('é', '日'), except the codes of named keys
+/// (F6 is 0x4000, Shift+Left 0x4B01), which menus and the status line use as
+/// shortcuts. Returns `None` for those and for control characters.
+pub fn key_char(key_code: KeyCode) -> Option<char> {
+    let named_key = key_name(key_code).is_some()
+        || matches!(
+            key_code,
+            KB_SHIFT_LEFT | KB_SHIFT_RIGHT | KB_SHIFT_HOME | KB_SHIFT_END | KB_ESC_ESC | KB_ESC_A | KB_ESC_E | KB_ESC_F | KB_ESC_H | KB_ESC_O | KB_ESC_S | KB_ESC_V | KB_ESC_X
+        );
+    char::from_u32(u32::from(key_code)).filter(|ch| !named_key && !ch.is_control())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Key events as crossterm reports them, and the key code each must become.
+    /// Add a row here when a terminal's encoding of a key needs handling.
+    const KEY_FIXTURES: &[(CKC, KeyModifiers, KeyCode)] = &[
+        (CKC::F(1), KeyModifiers::NONE, KB_F1),
+        (CKC::F(2), KeyModifiers::NONE, KB_F2),
+        (CKC::F(10), KeyModifiers::NONE, KB_F10),
+        (CKC::F(12), KeyModifiers::NONE, KB_F12),
+        (CKC::F(1), KeyModifiers::SHIFT, KB_SHIFT_F1),
+        (CKC::F(6), KeyModifiers::SHIFT, KB_SHIFT_F6),
+        (CKC::F(12), KeyModifiers::SHIFT, KB_SHIFT_F12),
+        (CKC::F(4), KeyModifiers::CONTROL, KB_CTRL_F4),
+        (CKC::F(11), KeyModifiers::CONTROL, KB_CTRL_F11),
+        (CKC::F(3), KeyModifiers::ALT, KB_ALT_F3),
+        (CKC::F(12), KeyModifiers::ALT, KB_ALT_F12),
+        // Only one modifier is encoded: Ctrl, then Alt, then Shift
+        (CKC::F(5), KeyModifiers::CONTROL.union(KeyModifiers::SHIFT), KB_CTRL_F5),
+        (CKC::F(5), KeyModifiers::ALT.union(KeyModifiers::SHIFT), KB_ALT_F5),
+        // F13+ (Shift+F1.. on some terminals) are left to key translations
+        (CKC::F(13), KeyModifiers::NONE, 0),
+        (CKC::Char('x'), KeyModifiers::ALT, KB_ALT_X),
+        (CKC::Down, KeyModifiers::ALT, KB_ALT_DOWN),
+        (CKC::Down, KeyModifiers::NONE, KB_DOWN),
+        (CKC::Char('o'), KeyModifiers::CONTROL, KB_CTRL_O),
+        (CKC::BackTab, KeyModifiers::SHIFT, KB_SHIFT_TAB),
+    ];
+
+    #[test]
+    fn test_crossterm_key_conversion() {
+        for &(code, modifiers, expected) in KEY_FIXTURES {
+            let key = KeyEvent::new(code, modifiers);
+            assert_eq!(crossterm_to_keycode(key), expected, "{code:?} with {modifiers:?}");
         }
-        _ => 0,
+    }
+
+    #[test]
+    fn test_function_key_codes_are_distinct() {
+        let all: Vec<KeyCode> = [FUNCTION_KEYS, SHIFT_FUNCTION_KEYS, CTRL_FUNCTION_KEYS, ALT_FUNCTION_KEYS].concat();
+        let mut unique = all.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        assert_eq!(unique.len(), all.len());
+    }
+
+    #[test]
+    fn test_paste_text_survives_copies() {
+        let event = Event::paste("one\ntwo");
+        let copy = event;
+        assert_eq!(copy.paste_text().as_deref(), Some("one\ntwo"));
+        assert_eq!(event.paste_text().as_deref(), Some("one\ntwo"));
+        assert_eq!(Event::keyboard(KB_ENTER).paste_text(), None);
+
+        // Only the most recent pastes are kept
+        for i in 0..PASTE_HISTORY {
+            Event::paste(i.to_string());
+        }
+        assert_eq!(event.paste_text(), None);
+        let last = Event::paste("last");
+        assert_eq!(last.paste_text().as_deref(), Some("last"));
+        assert!(std::thread::spawn(move || last.paste_text().is_none()).join().unwrap(), "kept per thread");
+    }
+
+    #[test]
+    fn test_key_names() {
+        assert_eq!(key_name(KB_F3).as_deref(), Some("F3"));
+        assert_eq!(key_name(KB_SHIFT_F6).as_deref(), Some("Shift+F6"));
+        assert_eq!(key_name(KB_CTRL_F4).as_deref(), Some("Ctrl+F4"));
+        assert_eq!(key_name(KB_ALT_F12).as_deref(), Some("Alt+F12"));
+        assert_eq!(key_name(KB_ALT_X).as_deref(), Some("Alt+X"));
+        assert_eq!(key_name(KB_CTRL_S).as_deref(), Some("Ctrl+S"));
+        assert_eq!(key_name(KB_PGDN).as_deref(), Some("PgDn"));
+        assert_eq!(key_name('a' as KeyCode), None);
+    }
+
+    #[test]
+    fn test_key_char_accepts_printable_unicode_only() {
+        assert_eq!(key_char('a' as KeyCode), Some('a'));
+        assert_eq!(key_char('é' as KeyCode), Some('é'));
+        assert_eq!(key_char('ü' as KeyCode), Some('ü'));
+        assert_eq!(key_char('日' as KeyCode), Some('日'));
+        for key_code in [KB_F6, KB_LEFT, KB_SHIFT_LEFT, KB_ALT_X, KB_ESC_X, KB_ENTER, KB_TAB, KB_CTRL_A, 0x7F] {
+            assert_eq!(key_char(key_code), None, "{key_code:#06x}");
+        }
+    }
+
+    #[test]
+    fn test_esc_letter_reports_alt_modifier() {
+        let mut tracker = EscSequenceTracker::new();
+        let esc = KeyEvent::new(CKC::Esc, KeyModifiers::NONE);
+        let x = KeyEvent::new(CKC::Char('x'), KeyModifiers::NONE);
+
+        assert_eq!(tracker.process_key_with_modifiers(esc).0, 0);
+        assert_eq!(tracker.process_key_with_modifiers(x), (KB_ALT_X, KeyModifiers::ALT));
+        assert_eq!(tracker.process_key_with_modifiers(x), ('x' as KeyCode, KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn test_key_matches_code_and_modifiers() {
+        let event = Event::keyboard_with(KB_LEFT, KeyModifiers::SHIFT);
+        assert!(matches!(event.key(), (KB_LEFT, m) if m.contains(KeyModifiers::SHIFT)));
+        assert_eq!(Event::keyboard(KB_LEFT).key(), (KB_LEFT, KeyModifiers::NONE));
     }
 }
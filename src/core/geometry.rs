@@ -34,6 +34,13 @@ impl Point {
     }
 }
 
+impl From<(i16, i16)> for Point {
+    /// Converts a `(width, height)` pair, as returned by `Terminal::size()`
+    fn from((x, y): (i16, i16)) -> Self {
+        Self::new(x, y)
+    }
+}
+
 impl fmt::Display for Point {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "({}, {})", self.x, self.y)
@@ -89,6 +96,47 @@ impl Rect {
         }
     }
 
+    /// A `width` x `height` rectangle centered on a screen of the given size.
+    ///
+    /// The size is clamped to the screen so the rectangle stays on it. When
+    /// the margins are uneven the extra cell goes right/below.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use turbo_vision::core::geometry::Rect;
+    ///
+    /// assert_eq!(Rect::centered((80, 25), 40, 7), Rect::new(20, 9, 60, 16));
+    /// assert_eq!(Rect::centered((30, 10), 40, 7), Rect::new(0, 1, 30, 8));
+    /// ```
+    pub fn centered(screen: impl Into<Point>, width: i16, height: i16) -> Self {
+        let screen = screen.into();
+        let width = width.clamp(0, screen.x.max(0));
+        let height = height.clamp(0, screen.y.max(0));
+        Self::from_coords((screen.x - width) / 2, (screen.y - height) / 2, width, height)
+    }
+
+    /// A rectangle positioned and sized in percent of a screen of the given size.
+    ///
+    /// Percentages above 100 are treated as 100 and results are rounded to
+    /// the nearest cell. A rectangle that would run off the screen is moved
+    /// back onto it (and shrunk if it is larger than the screen).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use turbo_vision::core::geometry::Rect;
+    ///
+    /// // Right half of an 80x25 screen
+    /// assert_eq!(Rect::from_percent((80, 25), 50, 0, 50, 100), Rect::new(40, 0, 80, 25));
+    /// ```
+    pub fn from_percent(screen: impl Into<Point>, x: u16, y: u16, width: u16, height: u16) -> Self {
+        let screen = screen.into();
+        let (x, width) = percent_span(screen.x, x, width);
+        let (y, height) = percent_span(screen.y, y, height);
+        Self::from_coords(x, y, width, height)
+    }
+
     /// Move the rectangle by the given delta
     pub fn move_by(&mut self, dx: i16, dy: i16) {
         self.a.x += dx;
@@ -191,6 +239,53 @@ impl Rect {
             b: Point::new(self.b.x.max(other.b.x), self.b.y.max(other.b.y)),
         }
     }
+
+    /// Check if the union of `covers` hides every cell of this rectangle
+    /// An empty rectangle is always covered
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use turbo_vision::core::geometry::Rect;
+    ///
+    /// let r = Rect::new(0, 0, 10, 10);
+    /// assert!(r.is_covered_by(&[Rect::new(0, 0, 6, 10), Rect::new(5, 0, 12, 10)]));
+    /// assert!(!r.is_covered_by(&[Rect::new(0, 0, 10, 9)]));
+    /// ```
+    pub fn is_covered_by(&self, covers: &[Rect]) -> bool {
+        // Subtract each cover from the parts still visible
+        let mut visible = vec![*self];
+        for cover in covers {
+            let mut remaining = Vec::with_capacity(visible.len());
+            for part in visible {
+                if part.is_empty() {
+                    continue;
+                }
+                if !part.intersects(cover) {
+                    remaining.push(part);
+                    continue;
+                }
+                let hit = part.intersect(cover);
+                // Bands above and below the cover, then the sides next to it
+                remaining.push(Rect::new(part.a.x, part.a.y, part.b.x, hit.a.y));
+                remaining.push(Rect::new(part.a.x, hit.b.y, part.b.x, part.b.y));
+                remaining.push(Rect::new(part.a.x, hit.a.y, hit.a.x, hit.b.y));
+                remaining.push(Rect::new(hit.b.x, hit.a.y, part.b.x, hit.b.y));
+            }
+            visible = remaining;
+        }
+        visible.iter().all(Rect::is_empty)
+    }
+}
+
+/// Position and length in percent of `total`, clamped to `0..total`
+fn percent_span(total: i16, start: u16, length: u16) -> (i16, i16) {
+    let total = total.max(0);
+    // Round to nearest; the result never exceeds total so it fits in i16
+    let scale = |percent: u16| ((i32::from(total) * i32::from(percent.min(100)) + 50) / 100) as i16;
+    let length = scale(length);
+    let start = scale(start).min(total - length);
+    (start, length)
 }
 
 impl Default for Rect {
@@ -216,6 +311,25 @@ mod tests {
         assert_eq!(p.y, 20);
     }
 
+    #[test]
+    fn test_rect_centered_rounding_and_clamping() {
+        // Odd margins put the extra cell right/below
+        assert_eq!(Rect::centered((80, 25), 41, 8), Rect::new(19, 8, 60, 16));
+        // Larger than the screen: clamped to it
+        assert_eq!(Rect::centered((20, 5), 40, 7), Rect::new(0, 0, 20, 5));
+        assert_eq!(Rect::centered(Point::new(10, 10), -3, 0), Rect::new(5, 5, 5, 5));
+    }
+
+    #[test]
+    fn test_rect_from_percent_rounding_and_clamping() {
+        // 33% of 80 = 26.4 -> 26, 25% of 25 = 6.25 -> 6, 50% of 25 = 12.5 -> 13
+        assert_eq!(Rect::from_percent((80, 25), 33, 25, 33, 50), Rect::new(26, 6, 52, 19));
+        // Running off the right/bottom edge moves the rect back on screen
+        assert_eq!(Rect::from_percent((80, 25), 90, 90, 50, 50), Rect::new(40, 12, 80, 25));
+        // Over 100% is clamped
+        assert_eq!(Rect::from_percent((80, 25), 0, 0, 150, 200), Rect::new(0, 0, 80, 25));
+    }
+
     #[test]
     fn test_rect_basic() {
         let r = Rect::new(1, 2, 11, 12);
@@ -8,7 +8,8 @@
 // matching Borland's approach while being Rust-idiomatic.
 
 use crate::core::command::CommandId;
-use crate::core::event::KeyCode;
+use crate::core::draw::shortcut_hotkey;
+use crate::core::event::{key_name, KeyCode};
 
 /// Menu item - can be a regular command, a submenu, or a separator
 ///
@@ -141,16 +142,7 @@ impl MenuItem {
             Self::Separator => return None,
         };
 
-        let mut chars = text.chars();
-        while let Some(ch) = chars.next() {
-            if ch == '~' {
-                // Next char is the accelerator
-                if let Some(accel) = chars.next() {
-                    return Some(accel.to_ascii_lowercase());
-                }
-            }
-        }
-        None
+        shortcut_hotkey(text).map(|accel| accel.to_ascii_lowercase())
     }
 
     /// Get the display text (with ~ markers)
@@ -302,6 +294,16 @@ impl MenuBuilder {
         self
     }
 
+    /// Add a menu item whose shortcut column shows the name of `key_code` (e.g. "Shift+F6")
+    pub fn item_with_key(mut self, text: &str, command: CommandId, key_code: KeyCode) -> Self {
+        let item = match key_name(key_code) {
+            Some(name) => MenuItem::with_shortcut(text, command, key_code, &name, self.help_ctx),
+            None => MenuItem::new(text, command, key_code, self.help_ctx),
+        };
+        self.items.push(item);
+        self
+    }
+
     /// Add a disabled menu item
     pub fn item_disabled(mut self, text: &str, command: CommandId, key_code: KeyCode) -> Self {
         self.items.push(MenuItem::new_disabled(text, command, key_code, self.help_ctx));
@@ -13,6 +13,7 @@
 //! - **State management** ([`state`]): View state flags and constants
 //! - **Clipboard** ([`clipboard`]): Copy/paste support
 //! - **History** ([`history`]): Input history management
+//! - **Auto-repeat** ([`auto_repeat`]): Repeat timing for held mouse buttons
 //!
 //! # Examples
 //!
@@ -51,10 +52,12 @@ pub mod event;
 pub mod command;
 pub mod command_set;
 pub mod palette;
+pub mod theme;
 pub mod clipboard;
 pub mod state;
 pub mod ansi_dump;
 pub mod menu_data;
 pub mod status_data;
 pub mod history;
+pub mod auto_repeat;
 pub mod error;
@@ -58,6 +58,11 @@ pub const STATUSLINE_SHORTCUT: u8 = 2; // Shortcut letter
 pub const STATUSLINE_SELECTED: u8 = 3; // Selected item
 pub const STATUSLINE_SELECTED_SHORTCUT: u8 = 4; // Selected shortcut
 
+// NotifyStrip palette indices (maps to CP_NOTIFY_STRIP)
+pub const NOTIFY_INFO: u8 = 1; // Info notification
+pub const NOTIFY_WARNING: u8 = 2; // Warning notification
+pub const NOTIFY_ERROR: u8 = 3; // Error notification
+
 // Frame palette indices (maps to Window/Dialog palette based on frame type)
 // Borland: cFrame values use these palette indices
 pub const FRAME_INACTIVE: u8 = 1; // Inactive frame (both fg and bg)
@@ -254,7 +259,84 @@ impl TvColor {
     }
 }
 
-/// Text attributes (foreground and background colors)
+/// A foreground or background color: one of the 16 palette colors, or true color
+///
+/// Palette colors keep the classic look and fit in the attribute byte used by
+/// palettes and themes; RGB colors are sent to the terminal as they are.
+///
+/// # Examples
+///
+/// ```
+/// use turbo_vision::core::palette::{AttrColor, TvColor};
+///
+/// let orange = AttrColor::Rgb(255, 128, 0);
+/// assert_eq!(orange.to_rgb(), (255, 128, 0));
+/// assert_eq!(orange.to_palette(), TvColor::Brown);
+/// assert_eq!(AttrColor::from(TvColor::Blue), TvColor::Blue);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttrColor {
+    Palette(TvColor),
+    Rgb(u8, u8, u8),
+}
+
+impl AttrColor {
+    /// Converts to a crossterm color; RGB colors are emitted directly
+    pub fn to_crossterm(self) -> Color {
+        match self {
+            AttrColor::Palette(color) => color.to_crossterm(),
+            AttrColor::Rgb(r, g, b) => Color::Rgb { r, g, b },
+        }
+    }
+
+    /// Gets the RGB components of this color
+    pub fn to_rgb(self) -> (u8, u8, u8) {
+        match self {
+            AttrColor::Palette(color) => color.to_rgb(),
+            AttrColor::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+
+    /// The palette color itself, or the closest one for RGB colors
+    pub fn to_palette(self) -> TvColor {
+        match self {
+            AttrColor::Palette(color) => color,
+            AttrColor::Rgb(r, g, b) => TvColor::from_rgb(r, g, b),
+        }
+    }
+}
+
+impl From<TvColor> for AttrColor {
+    fn from(color: TvColor) -> Self {
+        AttrColor::Palette(color)
+    }
+}
+
+impl PartialEq<TvColor> for AttrColor {
+    fn eq(&self, other: &TvColor) -> bool {
+        *self == AttrColor::Palette(*other)
+    }
+}
+
+/// Text style bits carried by [`Attr`] (bold, underline, ...)
+pub type StyleFlags = u8;
+
+pub const STYLE_BOLD: StyleFlags = 0x01;
+pub const STYLE_UNDERLINE: StyleFlags = 0x02;
+pub const STYLE_REVERSE: StyleFlags = 0x04;
+pub const STYLE_ITALIC: StyleFlags = 0x08;
+pub const STYLE_DIM: StyleFlags = 0x10;
+
+/// SGR parameter for each style bit, in the order they are emitted
+pub const STYLE_SGR_CODES: [(StyleFlags, u8); 5] = [
+    (STYLE_BOLD, 1),
+    (STYLE_DIM, 2),
+    (STYLE_ITALIC, 3),
+    (STYLE_UNDERLINE, 4),
+    (STYLE_REVERSE, 7),
+];
+
+/// Text attributes (foreground and background colors, text style)
 ///
 /// # Examples
 ///
@@ -275,27 +357,83 @@ impl TvColor {
 /// let byte = attr.to_u8();
 /// let restored = Attr::from_u8(byte);
 /// assert_eq!(attr, restored);
+///
+/// // True color, e.g. for an editor theme
+/// let comment = Attr::rgb((106, 153, 85), (30, 30, 30));
+/// assert_eq!(comment.to_u8(), Attr::new(TvColor::DarkGray, TvColor::Black).to_u8());
+///
+/// // Text style, e.g. an underlined link; the attribute byte ignores it
+/// use turbo_vision::core::palette::STYLE_UNDERLINE;
+/// let link = attr.with_style(STYLE_UNDERLINE);
+/// assert_eq!(link.to_u8(), attr.to_u8());
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Attr {
-    pub fg: TvColor,
-    pub bg: TvColor,
+    pub fg: AttrColor,
+    pub bg: AttrColor,
+    /// STYLE_* bits
+    pub style: StyleFlags,
 }
 
 impl Attr {
+    /// Attribute from two palette colors
     pub const fn new(fg: TvColor, bg: TvColor) -> Self {
-        Self { fg, bg }
+        Self {
+            fg: AttrColor::Palette(fg),
+            bg: AttrColor::Palette(bg),
+            style: 0,
+        }
     }
 
-    pub fn from_u8(byte: u8) -> Self {
+    /// Attribute from any mix of palette and RGB colors
+    pub const fn with_colors(fg: AttrColor, bg: AttrColor) -> Self {
+        Self { fg, bg, style: 0 }
+    }
+
+    /// Attribute from two RGB colors
+    pub const fn rgb(fg: (u8, u8, u8), bg: (u8, u8, u8)) -> Self {
         Self {
-            fg: TvColor::from_u8(byte & 0x0F),
-            bg: TvColor::from_u8((byte >> 4) & 0x0F),
+            fg: AttrColor::Rgb(fg.0, fg.1, fg.2),
+            bg: AttrColor::Rgb(bg.0, bg.1, bg.2),
+            style: 0,
         }
     }
 
+    pub fn from_u8(byte: u8) -> Self {
+        Self::new(TvColor::from_u8(byte & 0x0F), TvColor::from_u8((byte >> 4) & 0x0F))
+    }
+
+    /// Attribute byte; RGB colors are mapped to the closest palette color
+    /// and the style bits are dropped
     pub fn to_u8(self) -> u8 {
-        (self.fg as u8) | ((self.bg as u8) << 4)
+        (self.fg.to_palette() as u8) | ((self.bg.to_palette() as u8) << 4)
+    }
+
+    /// Same colors with the given STYLE_* bits (replacing any previous style)
+    pub const fn with_style(self, style: StyleFlags) -> Self {
+        Self { style, ..self }
+    }
+
+    /// True if all bits of `style` are set
+    pub fn has_style(self, style: StyleFlags) -> bool {
+        self.style & style == style
+    }
+
+    /// Set or clear STYLE_* bits
+    pub fn set_style_flag(&mut self, style: StyleFlags, enable: bool) {
+        if enable {
+            self.style |= style;
+        } else {
+            self.style &= !style;
+        }
+    }
+
+    pub fn set_bold(&mut self, bold: bool) {
+        self.set_style_flag(STYLE_BOLD, bold);
+    }
+
+    pub fn set_underline(&mut self, underline: bool) {
+        self.set_style_flag(STYLE_UNDERLINE, underline);
     }
 
     /// Swaps foreground and background colors
@@ -304,25 +442,54 @@ impl Attr {
         Self {
             fg: self.bg,
             bg: self.fg,
+            style: self.style,
         }
     }
 
     /// Creates a darkened version of this attribute (for semi-transparent shadows)
     /// Reduces RGB values by the given factor (0.0 = black, 1.0 = unchanged)
     /// Default shadow factor is 0.5 (50% darker)
+    /// Palette colors stay palette colors (the closest match); RGB colors stay exact.
     pub fn darken(&self, factor: f32) -> Self {
-        let darken_color = |color: TvColor| -> TvColor {
+        let darken_color = |color: AttrColor| -> AttrColor {
             let (r, g, b) = color.to_rgb();
             let new_r = ((r as f32) * factor).min(255.0) as u8;
             let new_g = ((g as f32) * factor).min(255.0) as u8;
             let new_b = ((b as f32) * factor).min(255.0) as u8;
-            TvColor::from_rgb(new_r, new_g, new_b)
+            match color {
+                AttrColor::Palette(_) => AttrColor::Palette(TvColor::from_rgb(new_r, new_g, new_b)),
+                AttrColor::Rgb(..) => AttrColor::Rgb(new_r, new_g, new_b),
+            }
         };
 
         Self {
             fg: darken_color(self.fg),
             bg: darken_color(self.bg),
+            style: self.style,
+        }
+    }
+
+    /// Text style conveying this attribute on a terminal without colors
+    ///
+    /// Text darker than its background (menus, dialogs, selections on a light
+    /// background) becomes reverse video, a bright foreground becomes bold and
+    /// a dark gray one (disabled items) dim. The attribute's own style is kept.
+    pub fn mono_style(self) -> StyleFlags {
+        let luminance = |color: AttrColor| {
+            let (r, g, b) = color.to_rgb();
+            299 * r as u32 + 587 * g as u32 + 114 * b as u32
+        };
+
+        let mut style = self.style;
+        if luminance(self.fg) <= luminance(self.bg) {
+            style |= STYLE_REVERSE;
         }
+        match self.fg.to_palette() {
+            TvColor::DarkGray => style |= STYLE_DIM,
+            color if color as u8 >= 8 => style |= STYLE_BOLD,
+            _ => {}
+        }
+        style
     }
 }
 
@@ -598,6 +765,13 @@ pub mod palettes {
         2, 4, 45, 41,  // 1-4: Normal, shortcut, selected, selected_shortcut
     ];
 
+    // Notification strip palette (no Borland equivalent)
+    // Reuses app entries: Black/Cyan, Yellow/Blue, Red/LightGray
+    #[rustfmt::skip]
+    pub const CP_NOTIFY_STRIP: &[u8] = &[
+        47, 13, 4,  // 1-3: Info, Warning, Error
+    ];
+
     // MenuBar palette (gray background, matching desktop colors)
     #[rustfmt::skip]
     pub const CP_MENU_BAR: &[u8] = &[
@@ -39,6 +39,10 @@ pub const OF_VALIDATE: u16 = 0x400;  // View should be validated on focus releas
 /// Matches Borland: shadows are 1 column wide on right, 1 row tall on bottom
 pub const SHADOW_SIZE: (i16, i16) = (1, 1);
 
+/// Shadow offset (x, y): the bottom strip starts this far right of the view,
+/// the right strip this far below it, so the shadow looks cast down-right
+pub const SHADOW_OFFSET: (i16, i16) = (1, 1);
+
 /// Shadow attribute (darkened color)
 pub const SHADOW_ATTR: u8 = 0x08;
 
@@ -8,7 +8,8 @@
 // matching Borland's approach while being Rust-idiomatic.
 
 use crate::core::command::CommandId;
-use crate::core::event::KeyCode;
+use crate::core::draw::shortcut_hotkey;
+use crate::core::event::{key_name, KeyCode};
 
 /// Status line item - displays text and responds to keyboard shortcuts
 ///
@@ -44,18 +45,21 @@ impl StatusItem {
         }
     }
 
+    /// Create a status item whose highlighted key name comes from `key_code`
+    ///
+    /// `StatusItem::with_key("Next", KB_SHIFT_F6, CM_PREV)` shows `Shift+F6 Next`.
+    /// Key codes without a name (see [`key_name()`]) show `label` alone.
+    pub fn with_key(label: &str, key_code: KeyCode, command: CommandId) -> Self {
+        let text = match key_name(key_code) {
+            Some(name) => format!("~{name}~ {label}"),
+            None => label.to_string(),
+        };
+        Self::new(&text, key_code, command)
+    }
+
     /// Extract the accelerator key from the text (character between ~ marks)
     pub fn get_accelerator(&self) -> Option<char> {
-        let mut chars = self.text.chars();
-        while let Some(ch) = chars.next() {
-            if ch == '~' {
-                // Next char is the accelerator
-                if let Some(accel) = chars.next() {
-                    return Some(accel.to_ascii_lowercase());
-                }
-            }
-        }
-        None
+        shortcut_hotkey(&self.text).map(|accel| accel.to_ascii_lowercase())
     }
 }
 
@@ -376,6 +380,7 @@ impl Default for StatusLineBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::event::{KB_CTRL_F4, KB_SHIFT_F6};
 
     #[test]
     fn test_status_def() {
@@ -420,4 +425,13 @@ mod tests {
         let item = StatusItem::new("~F1~ Help", 0x3B00, 100);
         assert_eq!(item.get_accelerator(), Some('f'));
     }
+
+    #[test]
+    fn test_with_key_names_the_key() {
+        let item = StatusItem::with_key("Prev", KB_SHIFT_F6, 100);
+        assert_eq!(item.text, "~Shift+F6~ Prev");
+        assert_eq!(item.key_code, KB_SHIFT_F6);
+        assert_eq!(StatusItem::with_key("Close", KB_CTRL_F4, 101).text, "~Ctrl+F4~ Close");
+        assert_eq!(StatusItem::with_key("Idle", 0, 102).text, "Idle");
+    }
 }
@@ -0,0 +1,259 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Themes - named application palettes that can be applied in one call.
+//!
+//! A theme wraps the 63-entry application palette (see [`palettes::CP_APP_COLOR`]).
+//! Each entry encodes a color as `(background << 4) | foreground`.
+//!
+//! Themes can also be loaded from and saved to a simple text file, one
+//! palette entry per line (see [`PALETTE_ENTRY_NAMES`] for the keys):
+//!
+//! ```text
+//! # My theme
+//! name = "Midnight"
+//! background = "lightgray on blue"
+//! menu_normal = "black on lightgray"
+//! ```
+//!
+//! Colors are [`TvColor`] names, case-insensitive. Entries not listed keep
+//! their default Borland colors.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use turbo_vision::app::Application;
+//! use turbo_vision::core::theme::Theme;
+//!
+//! let mut app = Application::new()?;
+//! app.set_theme(&Theme::dark());
+//! app.set_theme(&Theme::load("midnight.theme")?);
+//! # Ok::<(), turbo_vision::core::error::TurboVisionError>(())
+//! ```
+
+use super::error::{Result, TurboVisionError};
+use super::palette::{palettes, TvColor};
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// Theme file keys for the 63 application palette entries, in palette order
+/// Matches Borland: cpAppColor layout (program.h)
+#[rustfmt::skip]
+pub const PALETTE_ENTRY_NAMES: [&str; 63] = [
+    "background",
+    "menu_normal", "menu_disabled", "menu_shortcut", "menu_selected",
+    "menu_selected_disabled", "menu_shortcut_selected",
+    "blue_window_frame_passive", "blue_window_frame_active", "blue_window_frame_icon",
+    "blue_window_scrollbar_page", "blue_window_scrollbar_controls",
+    "blue_window_scroller_normal", "blue_window_scroller_selected", "blue_window_reserved",
+    "cyan_window_frame_passive", "cyan_window_frame_active", "cyan_window_frame_icon",
+    "cyan_window_scrollbar_page", "cyan_window_scrollbar_controls",
+    "cyan_window_scroller_normal", "cyan_window_scroller_selected", "cyan_window_reserved",
+    "gray_window_frame_passive", "gray_window_frame_active", "gray_window_frame_icon",
+    "gray_window_scrollbar_page", "gray_window_scrollbar_controls",
+    "gray_window_scroller_normal", "gray_window_scroller_selected", "gray_window_reserved",
+    "dialog_frame_passive", "dialog_frame_active", "dialog_frame_icon",
+    "dialog_scrollbar_page", "dialog_scrollbar_controls", "dialog_static_text",
+    "dialog_label_normal", "dialog_label_selected", "dialog_label_shortcut",
+    "dialog_button_normal", "dialog_button_default", "dialog_button_selected",
+    "dialog_button_disabled", "dialog_button_shortcut", "dialog_button_shadow",
+    "dialog_cluster_normal", "dialog_cluster_selected", "dialog_cluster_shortcut",
+    "dialog_input_normal", "dialog_input_selected", "dialog_input_arrows",
+    "dialog_history_arrow", "dialog_history_sides",
+    "dialog_history_scrollbar_page", "dialog_history_scrollbar_controls",
+    "dialog_list_normal", "dialog_list_focused", "dialog_list_selected", "dialog_list_divider",
+    "dialog_info_pane", "dialog_cluster_disabled", "dialog_reserved",
+];
+
+/// A named application palette
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    name: String,
+    /// Custom application palette, or `None` for the default Borland colors
+    palette: Option<Vec<u8>>,
+}
+
+impl Theme {
+    /// Creates a theme from a full 63-entry application palette
+    pub fn new(name: impl Into<String>, palette: Vec<u8>) -> Self {
+        Self {
+            name: name.into(),
+            palette: Some(palette),
+        }
+    }
+
+    /// Classic Borland Turbo Vision colors
+    pub fn borland() -> Self {
+        Self {
+            name: "Borland".to_string(),
+            palette: None,
+        }
+    }
+
+    /// Dark backgrounds with bright text, for low-light environments
+    #[rustfmt::skip]
+    pub fn dark() -> Self {
+        Self::new("Dark", vec![
+            0x08, 0x0F, 0x08, 0x0E, 0x0B, 0x0A, 0x0C, 0x01, // 1-8: Desktop (DarkGray bg)
+            0xF1, 0xE1, 0xF3, 0xF3, 0xF1, 0x08, 0x00,       // 9-15: Menu (bright on dark)
+            0xF1, 0xE1, 0xA1, 0xF3, 0xF3, 0xF1, 0xE1, 0x00, // 16-23: Cyan Window
+            0x08, 0xF8, 0xA8, 0xF3, 0xF3, 0x08, 0xF8, 0x00, // 24-31: Gray Window (dark)
+            0x08, 0xF8, 0xA8, 0xF3, 0xF3, 0x08, 0x08, 0xF8, // 32-39: Dialog (dark gray)
+            0xF8, 0xE2, 0xE2, 0xA2, 0x08, 0xA8, 0x08, 0xF3, // 40-47: Dialog controls
+            0xF2, 0xA2, 0xF1, 0xA1, 0xE1, 0xE2, 0x08, 0xF3, // 48-55: InputLine, Button
+            0xF3, 0xF3, 0xA1, 0xF2, 0xF3, 0xF3, 0x08, 0x00, // 56-63: Dialog remaining
+        ])
+    }
+
+    /// Black/white/yellow for maximum visibility
+    #[rustfmt::skip]
+    pub fn high_contrast() -> Self {
+        Self::new("High Contrast", vec![
+            0x0F, 0xF0, 0x0F, 0xE0, 0xF0, 0xE0, 0xF0, 0xF0, // 1-8: Desktop
+            0x0F, 0xE0, 0x0F, 0x0F, 0x0F, 0x0F, 0x00,       // 9-15: Menu
+            0x0F, 0x0F, 0xE0, 0x0F, 0x0F, 0x0F, 0x0F, 0x00, // 16-23: Cyan Window
+            0xF0, 0x0F, 0xE0, 0x0F, 0x0F, 0xF0, 0x0F, 0x00, // 24-31: Gray Window
+            0xF0, 0x0F, 0xE0, 0x0F, 0x0F, 0xF0, 0xF0, 0x0F, // 32-39: Dialog
+            0x0F, 0xE0, 0xF0, 0xE0, 0xF0, 0xE0, 0xF0, 0x0F, // 40-47: Dialog controls
+            0x0F, 0xE0, 0x0F, 0xE0, 0xE0, 0xE0, 0xF0, 0x0F, // 48-55: InputLine, Button
+            0x0F, 0x0F, 0xE0, 0x0F, 0x0F, 0x0F, 0xF0, 0x00, // 56-63: Dialog remaining
+        ])
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Custom application palette, or `None` for the default colors
+    pub fn palette(&self) -> Option<&[u8]> {
+        self.palette.as_deref()
+    }
+
+    /// The full application palette this theme resolves to
+    pub fn resolved_palette(&self) -> Vec<u8> {
+        self.palette.clone().unwrap_or_else(|| palettes::CP_APP_COLOR.to_vec())
+    }
+
+    /// Parse a theme from text in the theme file format.
+    /// Errors name the offending line.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut name = "Custom".to_string();
+        let mut palette = palettes::CP_APP_COLOR.to_vec();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |msg: String| TurboVisionError::parse(format!("line {}: {msg}", index + 1));
+
+            let (key, value) = line.split_once('=').ok_or_else(|| error("expected 'key = \"fg on bg\"'".to_string()))?;
+            let key = key.trim();
+            let value = unquote(value.trim());
+
+            if key == "name" {
+                name = value.to_string();
+                continue;
+            }
+            let entry = PALETTE_ENTRY_NAMES
+                .iter()
+                .position(|n| *n == key)
+                .ok_or_else(|| error(format!("unknown palette entry '{key}'")))?;
+            palette[entry] = parse_attr(value).ok_or_else(|| error(format!("invalid color '{value}', expected 'fg on bg'")))?;
+        }
+
+        Ok(Self::new(name, palette))
+    }
+
+    /// Theme file text for this theme, listing every palette entry
+    pub fn to_file_string(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "name = \"{}\"", self.name);
+        for (key, value) in PALETTE_ENTRY_NAMES.iter().zip(self.resolved_palette()) {
+            let _ = writeln!(out, "{key} = \"{} on {}\"", color_name(value & 0x0F), color_name(value >> 4));
+        }
+        out
+    }
+
+    /// Load a theme file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|e| TurboVisionError::file_operation(path, e))?;
+        Self::parse(&text).map_err(|e| TurboVisionError::parse(format!("{}: {e}", path.display())))
+    }
+
+    /// Save this theme to a file
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_file_string()).map_err(|e| TurboVisionError::file_operation(path, e))
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::borland()
+    }
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+/// Lowercase TvColor name, e.g. `lightgray`
+fn color_name(value: u8) -> String {
+    format!("{:?}", TvColor::from_u8(value)).to_ascii_lowercase()
+}
+
+/// Parse a TvColor name, ignoring case, spaces, `_` and `-`
+fn parse_color(name: &str) -> Option<u8> {
+    let name = name
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '_' | '-'))
+        .collect::<String>()
+        .to_ascii_lowercase();
+    (0..16).find(|&value| color_name(value) == name)
+}
+
+/// Parse `fg on bg` into an attribute byte
+fn parse_attr(value: &str) -> Option<u8> {
+    let (fg, bg) = value.split_once(" on ")?;
+    Some((parse_color(bg)? << 4) | parse_color(fg)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_load_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("dark.theme");
+        let theme = Theme::dark();
+        theme.save(&path).unwrap();
+        let loaded = Theme::load(&path).unwrap();
+
+        assert_eq!(loaded, theme);
+    }
+
+    #[test]
+    fn test_parse_partial_file() {
+        let theme = Theme::parse("# comment\n\nbackground = \"yellow on blue\"\nmenu_normal = Black on Light_Gray\n").unwrap();
+        let palette = theme.palette().unwrap();
+        assert_eq!(palette[0], 0x1E);
+        assert_eq!(palette[1], 0x70);
+        // Unlisted entries keep the Borland defaults
+        assert_eq!(&palette[2..], &palettes::CP_APP_COLOR[2..]);
+    }
+
+    #[test]
+    fn test_malformed_file_reports_line() {
+        let err = Theme::parse("background = \"white on blue\"\nmenu_normal = purple on gray\n").unwrap_err();
+        assert!(err.is_parse());
+        assert!(err.to_string().contains("line 2"), "{err}");
+
+        let err = Theme::parse("\nno_such_entry = \"white on blue\"").unwrap_err();
+        assert!(err.to_string().contains("line 2"), "{err}");
+    }
+}
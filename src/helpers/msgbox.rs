@@ -4,15 +4,14 @@
 //! Matches Borland: msgbox.h functions (messageBox, inputBox)
 
 use crate::app::Application;
-use crate::core::command::{CM_CANCEL, CM_NO, CM_OK, CM_YES, CommandId};
+use crate::core::command::CommandId;
 use crate::core::geometry::Rect;
+use crate::core::state::OF_CENTERED;
 use crate::views::View;
-use crate::views::button::Button;
-use crate::views::dialog::Dialog;
+use crate::views::dialog::{ButtonSet, Dialog, StandardButton, standard_button_row_with_default, standard_buttons};
 use crate::views::input_line::InputLine;
 use crate::views::static_text::StaticText;
-use std::cell::RefCell;
-use std::rc::Rc;
+use crate::views::text_buffer::TextBuffer;
 
 // Message box type flags (matches Borland: mfWarning, mfError, etc.)
 pub const MF_WARNING: u16 = 0x0000;
@@ -76,12 +75,18 @@ pub fn message_box(app: &mut Application, msg: &str, options: u16) -> CommandId
         dialog_y + dialog_height,
     );
 
-    message_box_rect(app, bounds, msg, options)
+    message_box_impl(app, bounds, msg, options, true)
 }
 
 /// Display a message box in the given rectangle
 /// Matches Borland: messageBoxRect(const TRect &r, const char *msg, ushort aOptions)
 pub fn message_box_rect(app: &mut Application, bounds: Rect, msg: &str, options: u16) -> CommandId {
+    message_box_impl(app, bounds, msg, options, false)
+}
+
+/// Shared implementation of message_box() and message_box_rect()
+/// `centered` marks the dialog to be re-centered when the terminal is resized
+fn message_box_impl(app: &mut Application, bounds: Rect, msg: &str, options: u16, centered: bool) -> CommandId {
     // Get title based on type (lower 4 bits)
     let title = match options & 0x0F {
         MF_WARNING => "Warning ⚠️",
@@ -93,41 +98,29 @@ pub fn message_box_rect(app: &mut Application, bounds: Rect, msg: &str, options:
     };
 
     let mut dialog = Dialog::new(bounds, title);
+    if centered {
+        dialog.set_options(dialog.options() | OF_CENTERED);
+    }
 
     // Add static text for message (inset by 1 from left/top, 2 from right/bottom)
     let text_bounds = Rect::new(1, 1, bounds.width() - 2, bounds.height() - 3);
     dialog.add(Box::new(StaticText::new(text_bounds, msg)));
 
-    // Collect buttons to add
-    let button_specs = [
-        (MF_YES_BUTTON, "~Y~es", CM_YES),
-        (MF_NO_BUTTON, "~N~o", CM_NO),
-        (MF_OK_BUTTON, "O~K~", CM_OK),
-        (MF_CANCEL_BUTTON, "Cancel", CM_CANCEL),
+    // Add the button row (10x2 buttons, matches Borland)
+    let button_flags = [
+        (MF_YES_BUTTON, StandardButton::Yes),
+        (MF_NO_BUTTON, StandardButton::No),
+        (MF_OK_BUTTON, StandardButton::Ok),
+        (MF_CANCEL_BUTTON, StandardButton::Cancel),
     ];
-
-    let mut buttons = Vec::new();
-    let mut total_width = -2i16; // Start at -2 to account for first button spacing
-
-    for (flag, label, command) in button_specs.iter() {
-        if (options & flag) != 0 {
-            // Button is 10 wide, 2 tall (matches Borland)
-            let button = Button::new(Rect::new(0, 0, 10, 2), label, *command, buttons.is_empty());
-            total_width += 10 + 2; // Button width + spacing
-            buttons.push((button, *command));
-        }
-    }
-
-    // Center buttons horizontally
-    let mut x = (bounds.width() - total_width) / 2;
-    let y = bounds.height() - 4; // Position buttons one row lower
-
-    for (mut button, _cmd) in buttons {
-        // Position button
-        let button_bounds = Rect::new(x, y, x + 10, y + 2);
-        button.set_bounds(button_bounds);
-        dialog.add(Box::new(button));
-        x += 12; // Button width (10) + spacing (2)
+    let buttons: Vec<StandardButton> = button_flags
+        .iter()
+        .filter(|(flag, _)| (options & flag) != 0)
+        .map(|(_, button)| *button)
+        .collect();
+    // The first button is the default, as it always was here
+    if let Some(&first) = buttons.first() {
+        standard_button_row_with_default(&mut dialog, &buttons, first);
     }
 
     dialog.set_initial_focus();
@@ -170,7 +163,7 @@ pub fn input_box(
         dialog_y + dialog_height,
     );
 
-    input_box_rect(app, bounds, title, label, default, limit)
+    input_box_impl(app, bounds, title, label, default, limit, true)
 }
 
 /// Display an input box in the given rectangle
@@ -182,11 +175,27 @@ pub fn input_box_rect(
     label: &str,
     default: &str,
     limit: usize,
+) -> (CommandId, String) {
+    input_box_impl(app, bounds, title, label, default, limit, false)
+}
+
+/// Shared implementation of input_box() and input_box_rect()
+fn input_box_impl(
+    app: &mut Application,
+    bounds: Rect,
+    title: &str,
+    label: &str,
+    default: &str,
+    limit: usize,
+    centered: bool,
 ) -> (CommandId, String) {
     let mut dialog = Dialog::new(bounds, title);
+    if centered {
+        dialog.set_options(dialog.options() | OF_CENTERED);
+    }
 
     // Create shared data for the input line
-    let input_data = Rc::new(RefCell::new(default.to_string()));
+    let input_data = TextBuffer::new(default);
 
     // Add label (if provided)
     if !label.is_empty() {
@@ -201,42 +210,17 @@ pub fn input_box_rect(
         3
     };
     let input_bounds = Rect::new(input_x, 2, bounds.width() - 3, 3);
-    let input = InputLine::new(input_bounds, limit, Rc::clone(&input_data));
+    let input = InputLine::with_buffer(input_bounds, limit, input_data.clone());
     dialog.add(Box::new(input));
 
-    // Add OK button
-    let ok_button = Button::new(
-        Rect::new(
-            bounds.width() / 2 - 12,
-            bounds.height() - 4,
-            bounds.width() / 2 - 2,
-            bounds.height() - 2,
-        ),
-        "O~K~",
-        CM_OK,
-        true, // default button
-    );
-    dialog.add(Box::new(ok_button));
-
-    // Add Cancel button
-    let cancel_button = Button::new(
-        Rect::new(
-            bounds.width() / 2 + 2,
-            bounds.height() - 4,
-            bounds.width() / 2 + 12,
-            bounds.height() - 2,
-        ),
-        "Cancel",
-        CM_CANCEL,
-        false,
-    );
-    dialog.add(Box::new(cancel_button));
+    // Add OK/Cancel buttons
+    standard_buttons(&mut dialog, ButtonSet::OkCancel);
 
     dialog.set_initial_focus();
     let result = dialog.execute(app);
 
     // Get the input text from the shared data
-    let text = input_data.borrow().clone();
+    let text = input_data.get();
 
     (result, text)
 }
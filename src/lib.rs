@@ -99,7 +99,8 @@
 //!   - [`Application`](app::Application) - Main application coordinator
 //!
 //! - **[`terminal`]** - Terminal abstraction layer
-//!   - [`Terminal`](terminal::Terminal) - Crossterm-based rendering backend
+//!   - [`Terminal`](terminal::Terminal) - Double-buffered screen with diffing flush
+//!   - [`Backend`](terminal::Backend) - Terminal I/O ([`CrosstermBackend`](terminal::CrosstermBackend) by default)
 //!
 //! ## Application Structure
 //!
@@ -285,5 +286,5 @@ pub mod prelude {
     };
 
     pub use crate::views::View;
-    pub use crate::app::Application;
+    pub use crate::app::{Application, ApplicationBuilder};
 }
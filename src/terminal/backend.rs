@@ -0,0 +1,638 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Backend - the I/O half of the terminal.
+//!
+//! [`Terminal`](super::Terminal) owns the screen buffers, diffs them on
+//! `flush()`, clips, translates keys and mouse positions. Everything that
+//! touches the outside world goes through a [`Backend`]: entering and leaving
+//! TUI mode, writing runs of changed cells, the cursor, and reading raw input
+//! events.
+//!
+//! Two backends are provided:
+//! - [`CrosstermBackend`] - the real terminal (stdout, raw mode), used by
+//!   [`Terminal::init()`](super::Terminal::init)
+//! - [`HeadlessBackend`] - never touches the TTY and captures the ANSI output
+//!   in memory, used by [`Terminal::new_headless()`](super::Terminal::new_headless)
+//!
+//! Implement the trait to embed the toolkit in another renderer; input is
+//! reported as crossterm events, so the key and mouse handling stays shared.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use turbo_vision::terminal::{CrosstermBackend, Terminal, TerminalOptions};
+//!
+//! let terminal = Terminal::with_backend(Box::new(CrosstermBackend::new()), TerminalOptions::default())?;
+//! # Ok::<(), turbo_vision::core::error::TurboVisionError>(())
+//! ```
+
+use super::{ColorMode, CursorStyle};
+use crate::core::draw::Cell;
+use crate::core::palette::{Attr, StyleFlags, STYLE_BOLD, STYLE_DIM, STYLE_ITALIC, STYLE_REVERSE, STYLE_UNDERLINE};
+use crossterm::{
+    cursor, event::{self, Event as CTEvent}, queue, style,
+    terminal::{self},
+};
+use std::io::{self, stdout, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+use std::time::Duration;
+
+/// Terminal I/O used by [`Terminal`](super::Terminal)
+///
+/// Drawing calls may be buffered; nothing has to reach the device before
+/// [`flush()`](Backend::flush).
+pub trait Backend {
+    /// Current size of the output device in cells
+    fn size(&self) -> io::Result<(u16, u16)>;
+
+    /// Enter TUI mode (raw mode, alternate screen, hidden cursor, bracketed paste)
+    fn enter(&mut self, mouse: bool) -> io::Result<()>;
+
+    /// Leave TUI mode, undoing [`enter()`](Backend::enter)
+    fn leave(&mut self, mouse: bool) -> io::Result<()>;
+
+    /// Write a run of cells sharing `attr`, starting at physical position (x, y)
+    ///
+    /// Continuation cells (the right half of wide characters) are part of the
+    /// run so positions stay aligned; they produce no output of their own.
+    fn draw_run(&mut self, x: u16, y: u16, attr: Attr, cells: &[Cell]) -> io::Result<()>;
+
+    /// Clear the whole device screen
+    fn clear(&mut self) -> io::Result<()>;
+
+    fn show_cursor(&mut self, x: u16, y: u16) -> io::Result<()>;
+
+    fn hide_cursor(&mut self) -> io::Result<()>;
+
+    /// Change the cursor shape; backends without a visible cursor can ignore it
+    fn set_cursor_style(&mut self, _style: CursorStyle) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn set_mouse_capture(&mut self, enabled: bool) -> io::Result<()>;
+
+    /// Turn the kitty keyboard protocol on or off; returns whether it is on
+    ///
+    /// Only turned on when the terminal answers the support query. Backends
+    /// that are not a terminal keep the default, which never turns it on.
+    fn set_keyboard_enhancement(&mut self, _enabled: bool) -> io::Result<bool> {
+        Ok(false)
+    }
+
+    /// Ring the bell
+    fn beep(&mut self) -> io::Result<()>;
+
+    /// Send everything buffered since the last flush to the device
+    fn flush(&mut self) -> io::Result<()>;
+
+    /// Wait up to `timeout` for a raw input event
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<CTEvent>>;
+
+    /// Wait for a raw input event
+    fn read_event(&mut self) -> io::Result<CTEvent>;
+
+    /// Called when the terminal switches color mode; in [`ColorMode::Mono`] the
+    /// runs' colors should be ignored, their style already carries the emphasis
+    fn set_color_mode(&mut self, _mode: ColorMode) {}
+
+    /// Send an escape sequence the terminal itself interprets (e.g. OSC 52)
+    ///
+    /// Backends that are not a terminal can ignore it.
+    fn write_sequence(&mut self, _sequence: &[u8]) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Take the output captured so far, for backends that write to memory
+    fn take_output(&mut self) -> Vec<u8> {
+        Vec::new()
+    }
+}
+
+/// Encodes drawing calls as ANSI escape sequences
+#[derive(Default)]
+struct AnsiEncoder {
+    out: Vec<u8>,
+    /// Text style currently set on the device
+    style: StyleFlags,
+    /// Omit color escapes
+    mono: bool,
+}
+
+impl AnsiEncoder {
+    fn draw_run(&mut self, x: u16, y: u16, attr: Attr, cells: &[Cell]) -> io::Result<()> {
+        // A style reset also resets the colors, so the colors always come after it
+        queue!(self.out, cursor::MoveTo(x, y))?;
+        if attr.style != self.style {
+            queue_style(&mut self.out, self.style, attr.style)?;
+            self.style = attr.style;
+        }
+        if !self.mono {
+            queue!(
+                self.out,
+                style::SetForegroundColor(attr.fg.to_crossterm()),
+                style::SetBackgroundColor(attr.bg.to_crossterm())
+            )?;
+        }
+
+        // The terminal advances two columns for a wide character, so its
+        // continuation cell is skipped
+        for cell in cells {
+            if !cell.is_continuation() {
+                write!(self.out, "{}", cell.ch)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn show_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        queue!(self.out, cursor::MoveTo(x, y), cursor::Show)
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        queue!(self.out, cursor::Hide)
+    }
+
+    fn set_cursor_style(&mut self, style: CursorStyle) -> io::Result<()> {
+        let command = match style {
+            CursorStyle::Default => cursor::SetCursorStyle::DefaultUserShape,
+            CursorStyle::Block { blink: true } => cursor::SetCursorStyle::BlinkingBlock,
+            CursorStyle::Block { blink: false } => cursor::SetCursorStyle::SteadyBlock,
+            CursorStyle::Underline { blink: true } => cursor::SetCursorStyle::BlinkingUnderScore,
+            CursorStyle::Underline { blink: false } => cursor::SetCursorStyle::SteadyUnderScore,
+            CursorStyle::Bar { blink: true } => cursor::SetCursorStyle::BlinkingBar,
+            CursorStyle::Bar { blink: false } => cursor::SetCursorStyle::SteadyBar,
+        };
+        queue!(self.out, command)
+    }
+
+    fn set_mouse_capture(&mut self, enabled: bool) -> io::Result<()> {
+        if enabled {
+            queue!(self.out, event::EnableMouseCapture)
+        } else {
+            queue!(self.out, event::DisableMouseCapture)
+        }
+    }
+
+    fn beep(&mut self) {
+        self.out.push(0x07);
+    }
+
+    /// Take the encoded output; the text style is reset first, so each flush starts plain
+    fn take(&mut self) -> io::Result<Vec<u8>> {
+        if self.style != 0 {
+            queue!(self.out, style::SetAttribute(style::Attribute::Reset))?;
+            self.style = 0;
+        }
+        Ok(std::mem::take(&mut self.out))
+    }
+}
+
+/// The real terminal, through crossterm and stdout
+#[derive(Default)]
+pub struct CrosstermBackend {
+    encoder: AnsiEncoder,
+}
+
+impl CrosstermBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Backend for CrosstermBackend {
+    fn size(&self) -> io::Result<(u16, u16)> {
+        terminal::size()
+    }
+
+    fn enter(&mut self, mouse: bool) -> io::Result<()> {
+        terminal::enable_raw_mode()?;
+        let mut stdout = stdout();
+        write_startup_sequence(&mut stdout, mouse)?;
+        stdout.flush()
+    }
+
+    fn leave(&mut self, mouse: bool) -> io::Result<()> {
+        let mut stdout = stdout();
+        write_shutdown_sequence(&mut stdout, mouse)?;
+        stdout.flush()?;
+        terminal::disable_raw_mode()
+    }
+
+    fn draw_run(&mut self, x: u16, y: u16, attr: Attr, cells: &[Cell]) -> io::Result<()> {
+        self.encoder.draw_run(x, y, attr, cells)
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        queue!(self.encoder.out, terminal::Clear(terminal::ClearType::All))
+    }
+
+    fn show_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.encoder.show_cursor(x, y)
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.encoder.hide_cursor()
+    }
+
+    fn set_cursor_style(&mut self, style: CursorStyle) -> io::Result<()> {
+        self.encoder.set_cursor_style(style)
+    }
+
+    fn set_mouse_capture(&mut self, enabled: bool) -> io::Result<()> {
+        self.encoder.set_mouse_capture(enabled)
+    }
+
+    fn set_keyboard_enhancement(&mut self, enabled: bool) -> io::Result<bool> {
+        if !enabled {
+            pop_keyboard_enhancement()?;
+            return Ok(false);
+        }
+        if KEYBOARD_ENHANCED.load(Ordering::SeqCst) {
+            return Ok(true);
+        }
+        if !terminal::supports_keyboard_enhancement()? {
+            return Ok(false);
+        }
+        install_panic_restore();
+        let mut stdout = stdout();
+        write_keyboard_enhancement(&mut stdout, true)?;
+        stdout.flush()?;
+        KEYBOARD_ENHANCED.store(true, Ordering::SeqCst);
+        Ok(true)
+    }
+
+    fn set_color_mode(&mut self, mode: ColorMode) {
+        self.encoder.mono = mode == ColorMode::Mono;
+    }
+
+    fn beep(&mut self) -> io::Result<()> {
+        self.encoder.beep();
+        Ok(())
+    }
+
+    fn write_sequence(&mut self, sequence: &[u8]) -> io::Result<()> {
+        self.encoder.out.extend_from_slice(sequence);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let bytes = self.encoder.take()?;
+        let mut stdout = stdout();
+        stdout.write_all(&bytes)?;
+        stdout.flush()
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<CTEvent>> {
+        if event::poll(timeout)? {
+            event::read().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn read_event(&mut self) -> io::Result<CTEvent> {
+        event::read()
+    }
+}
+
+/// A fixed-size backend that never touches the TTY
+///
+/// Flushed output is captured in memory (see [`take_output()`](Backend::take_output))
+/// and there is no input; tests feed events with
+/// [`Terminal::push_input()`](super::Terminal::push_input) instead.
+pub struct HeadlessBackend {
+    size: (u16, u16),
+    encoder: AnsiEncoder,
+    captured: Vec<u8>,
+}
+
+impl HeadlessBackend {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            size: (width, height),
+            encoder: AnsiEncoder::default(),
+            captured: Vec::new(),
+        }
+    }
+}
+
+impl Backend for HeadlessBackend {
+    fn size(&self) -> io::Result<(u16, u16)> {
+        Ok(self.size)
+    }
+
+    fn enter(&mut self, _mouse: bool) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn leave(&mut self, _mouse: bool) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn draw_run(&mut self, x: u16, y: u16, attr: Attr, cells: &[Cell]) -> io::Result<()> {
+        self.encoder.draw_run(x, y, attr, cells)
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn show_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.encoder.show_cursor(x, y)
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.encoder.hide_cursor()
+    }
+
+    fn set_cursor_style(&mut self, style: CursorStyle) -> io::Result<()> {
+        self.encoder.set_cursor_style(style)
+    }
+
+    fn set_mouse_capture(&mut self, enabled: bool) -> io::Result<()> {
+        self.encoder.set_mouse_capture(enabled)
+    }
+
+    fn set_color_mode(&mut self, mode: ColorMode) {
+        self.encoder.mono = mode == ColorMode::Mono;
+    }
+
+    fn beep(&mut self) -> io::Result<()> {
+        self.encoder.beep();
+        Ok(())
+    }
+
+    fn write_sequence(&mut self, sequence: &[u8]) -> io::Result<()> {
+        self.encoder.out.extend_from_slice(sequence);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let bytes = self.encoder.take()?;
+        self.captured.extend_from_slice(&bytes);
+        Ok(())
+    }
+
+    fn poll_event(&mut self, _timeout: Duration) -> io::Result<Option<CTEvent>> {
+        Ok(None)
+    }
+
+    fn read_event(&mut self) -> io::Result<CTEvent> {
+        Err(io::Error::new(io::ErrorKind::UnexpectedEof, "headless backend has no input"))
+    }
+
+    fn take_output(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.captured)
+    }
+}
+
+/// What a [`RecordingBackend`] was asked to do, shared with the test that built it
+#[cfg(test)]
+#[derive(Default)]
+pub(crate) struct Recording {
+    /// Every run passed to `draw_run()`, as position and characters
+    pub runs: Vec<(u16, u16, String)>,
+    /// The character last drawn into each screen cell, by row
+    pub screen: Vec<Vec<char>>,
+    /// The timeout of every `poll_event()` call
+    pub poll_timeouts: Vec<Duration>,
+}
+
+#[cfg(test)]
+impl Recording {
+    /// Characters of screen row `y` as last drawn
+    pub fn screen_row(&self, y: usize) -> String {
+        self.screen[y].iter().collect()
+    }
+}
+
+/// A [`HeadlessBackend`] that records its draw and poll calls for tests
+///
+/// Polls answer with the scripted [`poll_replies()`](RecordingBackend::poll_replies)
+/// in order, then time out. A timeout waits the full poll interval, as a
+/// terminal without input would.
+#[cfg(test)]
+pub(crate) struct RecordingBackend {
+    inner: HeadlessBackend,
+    recording: std::rc::Rc<std::cell::RefCell<Recording>>,
+    replies: std::collections::VecDeque<Option<CTEvent>>,
+}
+
+#[cfg(test)]
+impl RecordingBackend {
+    pub fn new(width: u16, height: u16) -> Self {
+        let recording = Recording {
+            screen: vec![vec![' '; width as usize]; height as usize],
+            ..Recording::default()
+        };
+        Self {
+            inner: HeadlessBackend::new(width, height),
+            recording: std::rc::Rc::new(std::cell::RefCell::new(recording)),
+            replies: std::collections::VecDeque::new(),
+        }
+    }
+
+    /// Answers for the next polls, `None` being a timeout
+    #[must_use]
+    pub fn poll_replies(mut self, replies: impl IntoIterator<Item = Option<CTEvent>>) -> Self {
+        self.replies.extend(replies);
+        self
+    }
+
+    /// Handle on the recording, still readable once the backend is moved into a terminal
+    pub fn recording(&self) -> std::rc::Rc<std::cell::RefCell<Recording>> {
+        std::rc::Rc::clone(&self.recording)
+    }
+}
+
+#[cfg(test)]
+impl Backend for RecordingBackend {
+    fn size(&self) -> io::Result<(u16, u16)> {
+        self.inner.size()
+    }
+
+    fn enter(&mut self, mouse: bool) -> io::Result<()> {
+        self.inner.enter(mouse)
+    }
+
+    fn leave(&mut self, mouse: bool) -> io::Result<()> {
+        self.inner.leave(mouse)
+    }
+
+    fn draw_run(&mut self, x: u16, y: u16, attr: Attr, cells: &[Cell]) -> io::Result<()> {
+        let mut recording = self.recording.borrow_mut();
+        recording.runs.push((x, y, cells.iter().map(|cell| cell.ch).collect()));
+        for (i, cell) in cells.iter().enumerate() {
+            recording.screen[y as usize][x as usize + i] = cell.ch;
+        }
+        self.inner.draw_run(x, y, attr, cells)
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.inner.clear()
+    }
+
+    fn show_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.inner.show_cursor(x, y)
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.inner.hide_cursor()
+    }
+
+    fn set_mouse_capture(&mut self, enabled: bool) -> io::Result<()> {
+        self.inner.set_mouse_capture(enabled)
+    }
+
+    fn set_cursor_style(&mut self, style: CursorStyle) -> io::Result<()> {
+        self.inner.set_cursor_style(style)
+    }
+
+    fn set_color_mode(&mut self, mode: ColorMode) {
+        self.inner.set_color_mode(mode);
+    }
+
+    fn beep(&mut self) -> io::Result<()> {
+        self.inner.beep()
+    }
+
+    fn write_sequence(&mut self, sequence: &[u8]) -> io::Result<()> {
+        self.inner.write_sequence(sequence)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<CTEvent>> {
+        self.recording.borrow_mut().poll_timeouts.push(timeout);
+        let reply = self.replies.pop_front().flatten();
+        if reply.is_none() {
+            std::thread::sleep(timeout);
+        }
+        Ok(reply)
+    }
+
+    fn read_event(&mut self) -> io::Result<CTEvent> {
+        self.inner.read_event()
+    }
+
+    fn take_output(&mut self) -> Vec<u8> {
+        self.inner.take_output()
+    }
+}
+
+/// Switch the terminal's text style from `from` to `to` (STYLE_* bits)
+fn queue_style(out: &mut impl Write, from: StyleFlags, to: StyleFlags) -> io::Result<()> {
+    if from != 0 {
+        queue!(out, style::SetAttribute(style::Attribute::Reset))?;
+    }
+    let attributes = [
+        (STYLE_BOLD, style::Attribute::Bold),
+        (STYLE_DIM, style::Attribute::Dim),
+        (STYLE_UNDERLINE, style::Attribute::Underlined),
+        (STYLE_REVERSE, style::Attribute::Reverse),
+        (STYLE_ITALIC, style::Attribute::Italic),
+    ];
+    for (flag, attribute) in attributes {
+        if to & flag != 0 {
+            queue!(out, style::SetAttribute(attribute))?;
+        }
+    }
+    Ok(())
+}
+
+/// Write the sequence that enters TUI mode (alternate screen, hidden cursor, bracketed paste, mouse capture)
+fn write_startup_sequence(out: &mut impl Write, mouse: bool) -> io::Result<()> {
+    queue!(out, terminal::EnterAlternateScreen, cursor::Hide, event::EnableBracketedPaste)?;
+    if mouse {
+        queue!(out, event::EnableMouseCapture)?;
+    }
+    Ok(())
+}
+
+/// The kitty keyboard protocol flags are pushed on the terminal
+static KEYBOARD_ENHANCED: AtomicBool = AtomicBool::new(false);
+
+/// Write the sequence that pushes (or pops) the kitty keyboard protocol flags
+///
+/// Only escape codes are disambiguated: Esc, Alt+letter and Ctrl+Enter
+/// arrive as distinct keys, and text input is unchanged.
+fn write_keyboard_enhancement(out: &mut impl Write, enabled: bool) -> io::Result<()> {
+    if enabled {
+        queue!(out, event::PushKeyboardEnhancementFlags(event::KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES))
+    } else {
+        queue!(out, event::PopKeyboardEnhancementFlags)
+    }
+}
+
+/// Pop the kitty keyboard protocol flags if they are pushed; safe to call twice
+fn pop_keyboard_enhancement() -> io::Result<()> {
+    if KEYBOARD_ENHANCED.swap(false, Ordering::SeqCst) {
+        let mut stdout = stdout();
+        write_keyboard_enhancement(&mut stdout, false)?;
+        stdout.flush()?;
+    }
+    Ok(())
+}
+
+/// Pop the keyboard flags before a panic message is printed, so the shell
+/// the user returns to does not receive kitty-encoded keys
+fn install_panic_restore() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let _ = pop_keyboard_enhancement();
+            previous(info);
+        }));
+    });
+}
+
+/// Write the sequence that leaves TUI mode, undoing write_startup_sequence()
+fn write_shutdown_sequence(out: &mut impl Write, mouse: bool) -> io::Result<()> {
+    if mouse {
+        queue!(out, event::DisableMouseCapture)?;
+    }
+    queue!(out, event::DisableBracketedPaste, cursor::Show, terminal::LeaveAlternateScreen)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_startup_sequence_respects_mouse_option() {
+        const MOUSE_ON: &str = "\x1b[?1000h";
+        const MOUSE_OFF: &str = "\x1b[?1000l";
+
+        let mut out = Vec::new();
+        write_startup_sequence(&mut out, true).unwrap();
+        assert!(String::from_utf8_lossy(&out).contains(MOUSE_ON));
+
+        let mut out = Vec::new();
+        write_startup_sequence(&mut out, false).unwrap();
+        let text = String::from_utf8_lossy(&out).into_owned();
+        assert!(!text.contains(MOUSE_ON));
+        assert!(text.contains("\x1b[?1049h")); // Alternate screen is still entered
+        assert!(text.contains("\x1b[?2004h")); // Bracketed paste
+
+        let mut out = Vec::new();
+        write_shutdown_sequence(&mut out, false).unwrap();
+        let text = String::from_utf8_lossy(&out).into_owned();
+        assert!(!text.contains(MOUSE_OFF));
+        assert!(text.contains("\x1b[?2004l"));
+    }
+
+    #[test]
+    fn test_keyboard_enhancement_sequences() {
+        let mut out = Vec::new();
+        write_keyboard_enhancement(&mut out, true).unwrap();
+        assert_eq!(out, b"\x1b[>1u");
+
+        let mut out = Vec::new();
+        write_keyboard_enhancement(&mut out, false).unwrap();
+        assert_eq!(out, b"\x1b[<1u");
+    }
+}
@@ -0,0 +1,106 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Damage tracking - rectangles of cells changed by [`Terminal::flush()`](super::Terminal::flush).
+//!
+//! Lets an embedding program mirror the screen (e.g. to a web frontend) without
+//! diffing the buffer itself. Damage is computed even on a headless terminal.
+
+/// Rectangle of cells modified by a flush, in buffer coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DamageRect {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl DamageRect {
+    pub fn new(x: u16, y: u16, width: u16, height: u16) -> Self {
+        Self { x, y, width, height }
+    }
+
+    /// Returns true if the cell at (x, y) is inside this rectangle
+    pub fn contains(&self, x: u16, y: u16) -> bool {
+        x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.height
+    }
+
+    /// Smallest rectangle containing both rectangles
+    pub fn union(&self, other: &DamageRect) -> DamageRect {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        let right = (self.x + self.width).max(other.x + other.width);
+        let bottom = (self.y + self.height).max(other.y + other.height);
+        DamageRect::new(x, y, right - x, bottom - y)
+    }
+}
+
+/// Builds coalesced damage rectangles from changed spans, row by row.
+///
+/// Spans must be added in row order. A span extends the rectangle ending on
+/// the row above when both cover exactly the same columns.
+#[derive(Debug, Default)]
+pub(super) struct DamageBuilder {
+    rects: Vec<DamageRect>,
+    /// Indices into `rects` of rectangles ending on the previous row
+    open: Vec<usize>,
+    /// Indices of rectangles ending on the current row
+    current: Vec<usize>,
+    row: Option<u16>,
+}
+
+impl DamageBuilder {
+    /// Record the changed cells `start_x..end_x` on row `y`
+    pub(super) fn add_span(&mut self, y: u16, start_x: u16, end_x: u16) {
+        if self.row != Some(y) {
+            let continues = self.row.is_some_and(|row| row + 1 == y);
+            self.open = if continues { std::mem::take(&mut self.current) } else { Vec::new() };
+            self.current.clear();
+            self.row = Some(y);
+        }
+
+        let width = end_x - start_x;
+        let extend = self
+            .open
+            .iter()
+            .position(|&i| self.rects[i].x == start_x && self.rects[i].width == width);
+        match extend {
+            Some(pos) => {
+                let index = self.open.swap_remove(pos);
+                self.rects[index].height += 1;
+                self.current.push(index);
+            }
+            None => {
+                self.rects.push(DamageRect::new(start_x, y, width, 1));
+                self.current.push(self.rects.len() - 1);
+            }
+        }
+    }
+
+    pub(super) fn finish(self) -> Vec<DamageRect> {
+        self.rects
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_coalesces_matching_rows() {
+        let mut builder = DamageBuilder::default();
+        builder.add_span(2, 5, 10);
+        builder.add_span(3, 5, 10);
+        builder.add_span(3, 12, 13);
+        builder.add_span(4, 5, 10);
+        builder.add_span(6, 5, 10); // Gap on row 5 starts a new rectangle
+
+        assert_eq!(
+            builder.finish(),
+            vec![
+                DamageRect::new(5, 2, 5, 3),
+                DamageRect::new(12, 3, 1, 1),
+                DamageRect::new(5, 6, 5, 1),
+            ]
+        );
+    }
+}
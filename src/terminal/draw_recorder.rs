@@ -0,0 +1,108 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Draw recorder - in-memory log of draw calls made on a [`Terminal`](super::Terminal).
+//!
+//! Buffer snapshots only show the final screen. The recorder keeps the sequence of
+//! draw operations so tests can assert on exact draw behavior and ordering, e.g.
+//! that a `Group` draws its background before its children.
+//!
+//! Only available in tests or with the `test-util` feature.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use turbo_vision::Terminal;
+//! use turbo_vision::terminal::DrawOp;
+//!
+//! let mut terminal = Terminal::new_headless(80, 25);
+//! terminal.start_recording();
+//! window.draw(&mut terminal);
+//! let recorder = terminal.stop_recording().unwrap();
+//! assert!(matches!(recorder.ops()[0], DrawOp::WriteLine { x: 0, y: 0, .. }));
+//! ```
+
+use crate::core::draw::Cell;
+use crate::core::geometry::Rect;
+
+/// A single recorded draw operation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DrawOp {
+    /// `write_cell(x, y, cell)`
+    WriteCell { x: u16, y: u16, cell: Cell },
+    /// `write_line(x, y, cells)`
+    WriteLine { x: u16, y: u16, cells: Vec<Cell> },
+    /// `push_clip(rect)`
+    PushClip(Rect),
+    /// `pop_clip()`
+    PopClip,
+    /// `clear()`
+    Clear,
+    /// `flush()`
+    Flush,
+}
+
+impl DrawOp {
+    /// Text written by this operation (empty for non-writing operations)
+    pub fn text(&self) -> String {
+        match self {
+            DrawOp::WriteCell { cell, .. } => cell.ch.to_string(),
+            DrawOp::WriteLine { cells, .. } => cells.iter().filter(|c| !c.is_continuation()).map(|c| c.ch).collect(),
+            _ => String::new(),
+        }
+    }
+
+    /// Position written by this operation, if it writes cells
+    pub fn position(&self) -> Option<(u16, u16)> {
+        match self {
+            DrawOp::WriteCell { x, y, .. } | DrawOp::WriteLine { x, y, .. } => Some((*x, *y)),
+            _ => None,
+        }
+    }
+}
+
+/// Ordered log of draw operations
+#[derive(Debug, Clone, Default)]
+pub struct DrawRecorder {
+    ops: Vec<DrawOp>,
+}
+
+impl DrawRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append an operation to the log
+    pub fn record(&mut self, op: DrawOp) {
+        self.ops.push(op);
+    }
+
+    /// All recorded operations, in call order
+    pub fn ops(&self) -> &[DrawOp] {
+        &self.ops
+    }
+
+    /// Number of recorded operations
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Returns true if nothing was recorded
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Discard all recorded operations
+    pub fn clear(&mut self) {
+        self.ops.clear();
+    }
+
+    /// Index of the first operation matching the predicate
+    pub fn position(&self, predicate: impl Fn(&DrawOp) -> bool) -> Option<usize> {
+        self.ops.iter().position(predicate)
+    }
+
+    /// Index of the first write operation whose text contains `text`
+    pub fn position_of_text(&self, text: &str) -> Option<usize> {
+        self.position(|op| op.text().contains(text))
+    }
+}
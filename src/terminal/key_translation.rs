@@ -0,0 +1,367 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Key translation table - user-configurable fixups for terminal key quirks.
+//!
+//! Different terminals send different sequences for Home/End/Del and the keypad.
+//! When a raw crossterm key event does not map to the expected key code, a
+//! translation entry can rewrite it. Entries are applied before the built-in
+//! mapping, so they take precedence over it.
+//!
+//! Tables can be built in code or loaded from a text file, one entry per line:
+//!
+//! ```text
+//! # raw key pattern = key code (name or hex value)
+//! Shift+Home = Home
+//! Ctrl+End   = End
+//! F13        = 0x3B00
+//! ```
+//!
+//! [`Terminal::init()`](super::Terminal::init) adds the entries of the user's
+//! settings file, `turbo-vision/keys.conf` in the configuration directory
+//! (see [`KeyTranslationTable::settings_path()`]), to the built-in fixups.
+//!
+//! Run the `key_diagnostic` example to see the pattern for any key you press.
+
+use crate::core::error::{Result, TurboVisionError};
+use crate::core::event::*;
+use crossterm::event::{KeyCode as CKC, KeyEvent, KeyModifiers};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Settings file, relative to the configuration directory
+const SETTINGS_FILE: &str = "turbo-vision/keys.conf";
+
+/// A raw key event pattern: crossterm key code plus exact modifier set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyPattern {
+    pub code: CKC,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyPattern {
+    pub fn new(code: CKC, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    /// Pattern matching the given raw key event
+    pub fn from_event(key: &KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+
+    /// Returns true if the raw event has this code and exactly these modifiers
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        key.code == self.code && key.modifiers == self.modifiers
+    }
+
+    /// Parse a pattern such as `Home`, `Shift+End`, `Ctrl+Alt+x` or `F13`
+    pub fn parse(text: &str) -> Result<Self> {
+        let text = text.trim();
+        let mut modifiers = KeyModifiers::NONE;
+        let mut rest = text;
+
+        // Modifier prefixes; a lone "+" is a key, not a separator
+        while let Some((prefix, tail)) = rest.split_once('+') {
+            if tail.is_empty() {
+                break;
+            }
+            modifiers |= match prefix.to_ascii_lowercase().as_str() {
+                "shift" => KeyModifiers::SHIFT,
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                _ => return Err(TurboVisionError::parse(format!("unknown modifier '{prefix}' in '{text}'"))),
+            };
+            rest = tail;
+        }
+
+        let code = parse_crossterm_key(rest)
+            .ok_or_else(|| TurboVisionError::parse(format!("unknown key '{rest}' in '{text}'")))?;
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+impl fmt::Display for KeyPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "Shift+")?;
+        }
+        match self.code {
+            CKC::Char(' ') => write!(f, "Space"),
+            CKC::Char(c) => write!(f, "{c}"),
+            CKC::F(n) => write!(f, "F{n}"),
+            code => write!(f, "{code:?}"),
+        }
+    }
+}
+
+/// Ordered table of raw key pattern → key code translations
+#[derive(Debug, Clone, Default)]
+pub struct KeyTranslationTable {
+    entries: Vec<(KeyPattern, KeyCode)>,
+}
+
+impl KeyTranslationTable {
+    /// Creates an empty table
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a table holding the built-in fixups.
+    ///
+    /// rxvt/urxvt and the Linux console send Shift+F8..Shift+F10 as the
+    /// `ESC[32~`..`ESC[34~` sequences of F18..F20, which crossterm decodes as
+    /// F15..F17 and which would otherwise be dead keys. Shift+F3..Shift+F7
+    /// share the F13/F14 crossterm reports for them and cannot be told apart,
+    /// so they are left alone. Their Home/End (`ESC[7~`/`ESC[8~`) and xterm's
+    /// modified Home/End (`ESC[1;<mod>H`) need no entry: crossterm decodes
+    /// them already.
+    pub fn with_defaults() -> Self {
+        let mut table = Self::new();
+        table.add(KeyPattern::new(CKC::F(15), KeyModifiers::NONE), KB_SHIFT_F8);
+        table.add(KeyPattern::new(CKC::F(16), KeyModifiers::NONE), KB_SHIFT_F9);
+        table.add(KeyPattern::new(CKC::F(17), KeyModifiers::NONE), KB_SHIFT_F10);
+        table
+    }
+
+    /// Creates a table holding the built-in fixups and the entries of the
+    /// settings file, if there is one
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the settings file exists but cannot be read or
+    /// has an invalid line.
+    pub fn from_settings() -> Result<Self> {
+        let mut table = Self::with_defaults();
+        if let Some(path) = Self::settings_path().filter(|path| path.exists()) {
+            table.load(path)?;
+        }
+        Ok(table)
+    }
+
+    /// Where the settings file is looked for: `turbo-vision/keys.conf` in
+    /// `$XDG_CONFIG_HOME`, `$HOME/.config` or, on Windows, `%APPDATA%`
+    pub fn settings_path() -> Option<PathBuf> {
+        settings_path_from(|name| std::env::var_os(name).map(PathBuf::from))
+    }
+
+    /// Add a translation, replacing any existing entry for the same pattern
+    pub fn add(&mut self, pattern: KeyPattern, key_code: KeyCode) {
+        if let Some(entry) = self.entries.iter_mut().find(|(p, _)| *p == pattern) {
+            entry.1 = key_code;
+        } else {
+            self.entries.push((pattern, key_code));
+        }
+    }
+
+    /// Remove the translation for a pattern, returning its key code
+    pub fn remove(&mut self, pattern: &KeyPattern) -> Option<KeyCode> {
+        let index = self.entries.iter().position(|(p, _)| p == pattern)?;
+        Some(self.entries.remove(index).1)
+    }
+
+    /// Key code for a raw event, if a translation matches
+    pub fn translate(&self, key: &KeyEvent) -> Option<KeyCode> {
+        self.entries.iter().find(|(p, _)| p.matches(key)).map(|(_, code)| *code)
+    }
+
+    /// Number of entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the table has no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Add every entry from text in the `pattern = key code` format.
+    /// Blank lines and lines starting with `#` are ignored.
+    pub fn extend_from_str(&mut self, text: &str) -> Result<()> {
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (pattern, target) = line
+                .rsplit_once('=')
+                .ok_or_else(|| TurboVisionError::parse(format!("line {}: expected 'pattern = key'", index + 1)))?;
+            let pattern = KeyPattern::parse(pattern)
+                .map_err(|e| TurboVisionError::parse(format!("line {}: {e}", index + 1)))?;
+            let key_code = parse_key_code(target.trim())
+                .ok_or_else(|| TurboVisionError::parse(format!("line {}: unknown key code '{}'", index + 1, target.trim())))?;
+            self.add(pattern, key_code);
+        }
+        Ok(())
+    }
+
+    /// Add every entry from a translation file
+    pub fn load(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|e| TurboVisionError::file_operation(path, e))?;
+        self.extend_from_str(&text)
+            .map_err(|e| TurboVisionError::parse(format!("{}: {e}", path.display())))
+    }
+}
+
+/// Settings file location, reading environment variables through `var`
+fn settings_path_from(var: impl Fn(&str) -> Option<PathBuf>) -> Option<PathBuf> {
+    let config_dir = var("XDG_CONFIG_HOME")
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| var("HOME").map(|home| home.join(".config")))
+        .or_else(|| var("APPDATA"))?;
+    Some(config_dir.join(SETTINGS_FILE))
+}
+
+/// Parse a crossterm key name (`Home`, `PageUp`, `F5`, `Space`, single character)
+fn parse_crossterm_key(name: &str) -> Option<CKC> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(CKC::Char(c));
+    }
+
+    let code = match name.to_ascii_lowercase().as_str() {
+        "space" => CKC::Char(' '),
+        "enter" => CKC::Enter,
+        "tab" => CKC::Tab,
+        "backtab" => CKC::BackTab,
+        "backspace" => CKC::Backspace,
+        "esc" => CKC::Esc,
+        "up" => CKC::Up,
+        "down" => CKC::Down,
+        "left" => CKC::Left,
+        "right" => CKC::Right,
+        "home" => CKC::Home,
+        "end" => CKC::End,
+        "pageup" => CKC::PageUp,
+        "pagedown" => CKC::PageDown,
+        "insert" => CKC::Insert,
+        "delete" => CKC::Delete,
+        "keypadbegin" => CKC::KeypadBegin,
+        lower => {
+            let n = lower.strip_prefix('f')?.parse::<u8>().ok()?;
+            CKC::F(n)
+        }
+    };
+    Some(code)
+}
+
+/// Parse a target key code: a key name or a hex value such as `0x4700`
+fn parse_key_code(name: &str) -> Option<KeyCode> {
+    if let Some(hex) = name.strip_prefix("0x").or_else(|| name.strip_prefix("0X")) {
+        return KeyCode::from_str_radix(hex, 16).ok();
+    }
+
+    let code = match name.to_ascii_lowercase().as_str() {
+        "esc" => KB_ESC,
+        "enter" => KB_ENTER,
+        "backspace" => KB_BACKSPACE,
+        "tab" => KB_TAB,
+        "backtab" | "shift+tab" => KB_SHIFT_TAB,
+        "up" => KB_UP,
+        "down" => KB_DOWN,
+        "left" => KB_LEFT,
+        "right" => KB_RIGHT,
+        "home" => KB_HOME,
+        "end" => KB_END,
+        "shift+left" => KB_SHIFT_LEFT,
+        "shift+right" => KB_SHIFT_RIGHT,
+        "shift+home" => KB_SHIFT_HOME,
+        "shift+end" => KB_SHIFT_END,
+        "pageup" => KB_PGUP,
+        "pagedown" => KB_PGDN,
+        "insert" => KB_INS,
+        "delete" => KB_DEL,
+        "f1" => KB_F1,
+        "f2" => KB_F2,
+        "f3" => KB_F3,
+        "f4" => KB_F4,
+        "f5" => KB_F5,
+        "f6" => KB_F6,
+        "f7" => KB_F7,
+        "f8" => KB_F8,
+        "f9" => KB_F9,
+        "f10" => KB_F10,
+        "f11" => KB_F11,
+        "f12" => KB_F12,
+        _ => return None,
+    };
+    Some(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pattern_parse_and_display() {
+        let pattern = KeyPattern::parse("Ctrl+Shift+Home").unwrap();
+        assert_eq!(pattern.code, CKC::Home);
+        assert_eq!(pattern.modifiers, KeyModifiers::CONTROL | KeyModifiers::SHIFT);
+        assert_eq!(pattern.to_string(), "Ctrl+Shift+Home");
+
+        assert_eq!(KeyPattern::parse("Alt++").unwrap().code, CKC::Char('+'));
+        assert_eq!(KeyPattern::parse("F13").unwrap().code, CKC::F(13));
+        assert!(KeyPattern::parse("Hyper+Home").is_err());
+    }
+
+    #[test]
+    fn test_translate_requires_exact_modifiers() {
+        let mut table = KeyTranslationTable::new();
+        table.add(KeyPattern::new(CKC::F(13), KeyModifiers::NONE), KB_F1);
+
+        assert_eq!(table.translate(&KeyEvent::new(CKC::F(13), KeyModifiers::NONE)), Some(KB_F1));
+        assert_eq!(table.translate(&KeyEvent::new(CKC::F(13), KeyModifiers::SHIFT)), None);
+    }
+
+    #[test]
+    fn test_extend_from_str() {
+        let mut table = KeyTranslationTable::new();
+        table
+            .extend_from_str("# fixups\n\nShift+Home = Home\nF13 = 0x3B00\nShift+Home = End\n")
+            .unwrap();
+
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.translate(&KeyEvent::new(CKC::Home, KeyModifiers::SHIFT)), Some(KB_END));
+        assert_eq!(table.translate(&KeyEvent::new(CKC::F(13), KeyModifiers::NONE)), Some(KB_F1));
+
+        let err = table.extend_from_str("Home = Nowhere").unwrap_err();
+        assert!(err.is_parse());
+    }
+
+    #[test]
+    fn test_default_rxvt_shifted_function_keys() {
+        let table = KeyTranslationTable::with_defaults();
+        let key = |n| KeyEvent::new(CKC::F(n), KeyModifiers::NONE);
+        assert_eq!(crossterm_to_keycode(key(15)), 0, "a dead key without the fixup");
+        assert_eq!(table.translate(&key(15)), Some(KB_SHIFT_F8));
+        assert_eq!(table.translate(&key(17)), Some(KB_SHIFT_F10));
+        assert_eq!(table.translate(&key(13)), None, "ambiguous, left alone");
+    }
+
+    #[test]
+    fn test_settings_path_and_load() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| vars.iter().find(|(n, _)| *n == name).map(|(_, v)| PathBuf::from(v))
+        };
+        assert_eq!(
+            settings_path_from(env(&[("XDG_CONFIG_HOME", "/xdg"), ("HOME", "/home/ada")])),
+            Some(PathBuf::from("/xdg/turbo-vision/keys.conf"))
+        );
+        assert_eq!(
+            settings_path_from(env(&[("XDG_CONFIG_HOME", "relative"), ("HOME", "/home/ada")])),
+            Some(PathBuf::from("/home/ada/.config/turbo-vision/keys.conf"))
+        );
+        assert_eq!(settings_path_from(env(&[])), None);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("keys.conf");
+        std::fs::write(&path, "F13 = F1\nF14 = Nowhere\n").unwrap();
+        let err = KeyTranslationTable::new().load(&path).unwrap_err();
+        assert!(err.to_string().contains("keys.conf") && err.to_string().contains("line 2"), "{err}");
+    }
+}
@@ -1,6 +1,6 @@
 // (C) 2025 - Enzo Lombardi
 
-//! Terminal abstraction layer providing crossterm-based rendering.
+//! Terminal abstraction layer providing double-buffered rendering.
 //!
 //! This module provides the [`Terminal`] type which handles all interaction
 //! with the physical terminal including:
@@ -11,6 +11,9 @@
 //! - Clipping region management
 //! - ANSI dump support for debugging
 //!
+//! The I/O itself goes through a [`Backend`]: [`CrosstermBackend`] for a real
+//! terminal, [`HeadlessBackend`] for tests, or your own to embed the UI.
+//!
 //! # Examples
 //!
 //! Basic terminal usage:
@@ -29,24 +32,61 @@
 //! }
 //! ```
 
+mod backend;
+mod damage;
+mod key_translation;
+mod signals;
+pub use backend::{Backend, CrosstermBackend, HeadlessBackend};
+#[cfg(test)]
+pub(crate) use backend::{Recording, RecordingBackend};
+pub use damage::DamageRect;
+pub use key_translation::{KeyPattern, KeyTranslationTable};
+pub(crate) use signals::stop_process;
+use signals::ContinueWatch;
+
+#[cfg(any(test, feature = "test-util"))]
+mod draw_recorder;
+#[cfg(any(test, feature = "test-util"))]
+pub use draw_recorder::{DrawOp, DrawRecorder};
+#[cfg(any(test, feature = "test-util"))]
+mod test_terminal;
+#[cfg(any(test, feature = "test-util"))]
+pub use test_terminal::{TestTerminal, render_to_cells, row_text};
+
 use crate::core::draw::Cell;
-use crate::core::event::{Event, EventType, EscSequenceTracker, MB_LEFT_BUTTON, MB_MIDDLE_BUTTON, MB_RIGHT_BUTTON, KB_F12, KB_SHIFT_F12};
+use crate::core::event::{Event, EventType, EscSequenceTracker, KeyCode, crossterm_to_keycode, MB_LEFT_BUTTON, MB_MIDDLE_BUTTON, MB_RIGHT_BUTTON, KB_F12, KB_SHIFT_F12, WHEEL_SCROLL_LINES};
 use crate::core::geometry::Point;
 use crate::core::palette::Attr;
 use crate::core::ansi_dump;
+use crate::core::clipboard;
 use crate::core::error::Result;
-use crossterm::{
-    cursor, execute, queue, style,
-    terminal::{self},
-    event::{self, Event as CTEvent, KeyEventKind, MouseEventKind, MouseButton},
-};
-use std::io::{self, Write, stdout};
+use crossterm::event::{self, Event as CTEvent, KeyEvent, KeyEventKind, KeyModifiers, MouseEventKind, MouseButton};
+use std::collections::VecDeque;
+use std::io;
 use std::time::{Duration, Instant};
+use damage::DamageBuilder;
+
+/// Pending damage rectangles are merged into one bounding rectangle past this count
+const MAX_PENDING_DAMAGE: usize = 1024;
+
+/// Default maximum time between the two presses of a double-click
+pub const DEFAULT_DOUBLE_CLICK_TIME: Duration = Duration::from_millis(500);
+
+/// Starts a synchronized update: the terminal holds the screen until [`END_SYNCHRONIZED_UPDATE`]
+pub const BEGIN_SYNCHRONIZED_UPDATE: &[u8] = b"\x1b[?2026h";
+/// Ends a synchronized update and shows everything drawn since the begin sequence
+pub const END_SYNCHRONIZED_UPDATE: &[u8] = b"\x1b[?2026l";
+
+/// Callback receiving the damage of each flush
+pub type DamageListener = Box<dyn FnMut(&[DamageRect])>;
 
-/// Terminal abstraction for crossterm backend
+/// Double-buffered screen on top of a [`Backend`]
 pub struct Terminal {
+    backend: Box<dyn Backend>,
+    headless: bool,  // Created by new_headless(): simulated input only
     buffer: Vec<Vec<Cell>>,
     prev_buffer: Vec<Vec<Cell>>,
+    dirty_rows: Vec<bool>,  // Rows written since the last flush; only these are diffed
     width: u16,
     height: u16,
     esc_tracker: EscSequenceTracker,
@@ -54,9 +94,88 @@ pub struct Terminal {
     last_mouse_buttons: u8,
     last_click_time: Option<Instant>,
     last_click_pos: Point,
+    last_click_buttons: u8,
+    double_click_time: Duration,
+    wheel_lines: i16,  // Lines scrolled per wheel notch
     clip_stack: Vec<crate::core::geometry::Rect>,
     active_view_bounds: Option<crate::core::geometry::Rect>,
-    pending_event: Option<Event>,  // Event queue for putEvent() - matches Borland's TProgram::pending
+    pending_events: VecDeque<Event>,  // Events for poll_event(): put_event() ones first, then simulated input
+    posted: usize,  // Leading pending_events queued by put_event() - Borland's TProgram::pending
+    full_redraw: bool,  // Repaint every cell on flush, ignoring prev_buffer
+    viewport: Option<crate::core::geometry::Rect>,  // Physical sub-region the UI is confined to
+    screen_size: (u16, u16),  // Physical terminal size (differs from width/height with a viewport)
+    mouse_enabled: bool,  // Mouse capture is currently enabled
+    keyboard_enhanced: bool,  // Kitty keyboard protocol is on
+    color_mode: ColorMode,
+    synchronized_output: bool,  // Wrap each flush in synchronized-update sequences
+    cursor_style: CursorStyle,  // Style applied by the next show_cursor()
+    sent_cursor_style: CursorStyle,  // Style the device currently has
+    key_translations: KeyTranslationTable,  // Raw key fixups applied before the built-in mapping
+    last_raw_key: Option<KeyEvent>,  // Most recent raw key event (for key diagnostics)
+    damage: Vec<DamageRect>,  // Cells changed by flushes since the last take_damage()
+    damage_listener: Option<DamageListener>,  // Called with each flush's damage
+    save_under: Vec<SavedRegion>,  // Screen contents beneath open modal views, innermost last
+    continue_watch: Option<ContinueWatch>,  // SIGCONT notices; only for the real TTY
+    #[cfg(any(test, feature = "test-util"))]
+    recorder: Option<DrawRecorder>,  // Log of draw calls for golden tests
+}
+
+/// How colors are sent to the terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorMode {
+    /// Foreground and background colors as drawn
+    #[default]
+    Color,
+    /// No color escapes; emphasis is shown with reverse video, bold and dim
+    /// (see [`Attr::mono_style()`])
+    Mono,
+}
+
+/// Shape of the text cursor, with or without blinking
+///
+/// Editors usually show a bar while inserting and a block while overwriting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CursorStyle {
+    /// Whatever the user configured in their terminal
+    #[default]
+    Default,
+    Block { blink: bool },
+    Underline { blink: bool },
+    Bar { blink: bool },
+}
+
+/// Snapshot of the cells beneath a modal view
+struct SavedRegion {
+    rect: crate::core::geometry::Rect,
+    rows: Vec<Vec<Cell>>,
+}
+
+/// Options controlling terminal initialization
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use turbo_vision::terminal::{Terminal, TerminalOptions};
+///
+/// // Keep the terminal's own selection/scrollback working
+/// let terminal = Terminal::init_with_options(TerminalOptions { mouse: false, ..TerminalOptions::default() })?;
+/// # Ok::<(), turbo_vision::core::error::TurboVisionError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalOptions {
+    /// Enable mouse capture (default: true)
+    /// Disable for terminals where capture breaks copy/paste or scrollback.
+    pub mouse: bool,
+    /// Use the kitty keyboard protocol when the terminal supports it (default: false)
+    /// Esc, Alt+letter and Ctrl+Enter are then reported unambiguously, without
+    /// the ESC+letter Alt emulation.
+    pub kitty_keyboard: bool,
+}
+
+impl Default for TerminalOptions {
+    fn default() -> Self {
+        Self { mouse: true, kitty_keyboard: false }
+    }
 }
 
 impl Terminal {
@@ -100,24 +219,65 @@ impl Terminal {
     /// }
     /// ```
     pub fn init() -> Result<Self> {
-        terminal::enable_raw_mode()?;
-        let mut stdout = stdout();
-        execute!(
-            stdout,
-            terminal::EnterAlternateScreen,
-            cursor::Hide,
-            event::EnableMouseCapture  // Enable mouse support
-        )?;
+        Self::init_with_options(TerminalOptions::default())
+    }
+
+    /// Initializes a new terminal instance in raw mode with the given options.
+    ///
+    /// Same as [`init()`](Self::init), but mouse capture is only enabled when
+    /// `options.mouse` is true, and the kitty keyboard protocol is turned on
+    /// when `options.kitty_keyboard` is true and the terminal supports it.
+    /// The key translations of the user's settings file are added to the
+    /// built-in fixups (see [`KeyTranslationTable::from_settings()`]).
+    ///
+    /// # Errors
+    ///
+    /// See [`init()`](Self::init). Also fails if the key translation settings
+    /// file exists but cannot be read or parsed.
+    pub fn init_with_options(options: TerminalOptions) -> Result<Self> {
+        let key_translations = KeyTranslationTable::from_settings()?;
+        let mut terminal = Self::with_backend(Box::new(CrosstermBackend::new()), options)?;
+        terminal.key_translations = key_translations;
+        terminal.continue_watch = ContinueWatch::new();
+        Ok(terminal)
+    }
 
-        let (width, height) = terminal::size()?;
+    /// Initializes a terminal drawing through the given backend.
+    ///
+    /// The backend enters TUI mode, then the screen buffers are sized to it.
+    /// Starts in [`ColorMode::Mono`] when the `NO_COLOR` environment variable
+    /// is set to a non-empty value, and with synchronized output when the
+    /// terminal is known to support it (see
+    /// [`set_synchronized_output()`](Self::set_synchronized_output)).
+    ///
+    /// # Errors
+    ///
+    /// Returns the backend's error if it cannot enter TUI mode or report its size.
+    pub fn with_backend(mut backend: Box<dyn Backend>, options: TerminalOptions) -> Result<Self> {
+        backend.enter(options.mouse)?;
+        // A terminal that does not answer the support query just keeps the legacy encoding
+        let keyboard_enhanced = options.kitty_keyboard && backend.set_keyboard_enhancement(true).unwrap_or(false);
+        let (width, height) = backend.size()?;
+
+        let mut terminal = Self::with_size(width, height, backend);
+        terminal.mouse_enabled = options.mouse;
+        terminal.keyboard_enhanced = keyboard_enhanced;
+        if std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+            terminal.set_color_mode(ColorMode::Mono);
+        }
+        terminal.synchronized_output = supports_synchronized_output(|name| std::env::var(name).ok());
+        Ok(terminal)
+    }
 
+    /// Build the terminal state for the given size
+    fn with_size(width: u16, height: u16, backend: Box<dyn Backend>) -> Self {
         let empty_cell = Cell::new(' ', Attr::from_u8(0x07));
-        let buffer = vec![vec![empty_cell; width as usize]; height as usize];
-        let prev_buffer = vec![vec![empty_cell; width as usize]; height as usize];
-
-        Ok(Self {
-            buffer,
-            prev_buffer,
+        Self {
+            backend,
+            headless: false,
+            buffer: vec![vec![empty_cell; width as usize]; height as usize],
+            prev_buffer: vec![vec![empty_cell; width as usize]; height as usize],
+            dirty_rows: vec![true; height as usize],
             width,
             height,
             esc_tracker: EscSequenceTracker::new(),
@@ -125,10 +285,81 @@ impl Terminal {
             last_mouse_buttons: 0,
             last_click_time: None,
             last_click_pos: Point::zero(),
+            last_click_buttons: 0,
+            double_click_time: DEFAULT_DOUBLE_CLICK_TIME,
+            wheel_lines: WHEEL_SCROLL_LINES,
             clip_stack: Vec::new(),
             active_view_bounds: None,
-            pending_event: None,
-        })
+            pending_events: VecDeque::new(),
+            posted: 0,
+            full_redraw: false,
+            viewport: None,
+            screen_size: (width, height),
+            mouse_enabled: true,
+            keyboard_enhanced: false,
+            color_mode: ColorMode::Color,
+            synchronized_output: false,
+            cursor_style: CursorStyle::Default,
+            sent_cursor_style: CursorStyle::Default,
+            key_translations: KeyTranslationTable::with_defaults(),
+            last_raw_key: None,
+            damage: Vec::new(),
+            damage_listener: None,
+            save_under: Vec::new(),
+            continue_watch: None,
+            #[cfg(any(test, feature = "test-util"))]
+            recorder: None,
+        }
+    }
+
+    /// Creates a headless terminal of the given size that never touches the TTY.
+    ///
+    /// Output written by [`flush()`](Self::flush) is captured in memory by a
+    /// [`HeadlessBackend`] and can be retrieved with `take_output()`. Event polling
+    /// only returns events queued with [`put_event()`](Self::put_event) or
+    /// [`push_input()`](Self::push_input). Used for testing rendering code.
+    pub fn new_headless(width: u16, height: u16) -> Self {
+        let mut terminal = Self::with_size(width, height, Box::new(HeadlessBackend::new(width, height)));
+        terminal.headless = true;
+        terminal
+    }
+
+    /// Returns true if this terminal was created with `new_headless()`
+    fn is_headless(&self) -> bool {
+        self.headless
+    }
+
+    /// Takes the output captured by the backend since the last call
+    ///
+    /// Returns an empty vector for a real terminal.
+    pub fn take_output(&mut self) -> Vec<u8> {
+        self.backend.take_output()
+    }
+
+    /// Start recording draw calls into a fresh [`DrawRecorder`]
+    #[cfg(any(test, feature = "test-util"))]
+    pub fn start_recording(&mut self) {
+        self.recorder = Some(DrawRecorder::new());
+    }
+
+    /// Stop recording and return the recorded draw calls
+    #[cfg(any(test, feature = "test-util"))]
+    pub fn stop_recording(&mut self) -> Option<DrawRecorder> {
+        self.recorder.take()
+    }
+
+    /// Get the active recorder, if recording
+    #[cfg(any(test, feature = "test-util"))]
+    pub fn recorder(&self) -> Option<&DrawRecorder> {
+        self.recorder.as_ref()
+    }
+
+    /// Record a draw call if a recorder is attached
+    #[cfg(any(test, feature = "test-util"))]
+    fn record(&mut self, op: impl FnOnce() -> DrawOp) {
+        if let Some(recorder) = self.recorder.as_mut() {
+            recorder.record(op());
+        }
     }
 
     /// Shuts down the terminal and restores normal mode.
@@ -157,14 +388,11 @@ impl Terminal {
     /// # }
     /// ```
     pub fn shutdown(&mut self) -> Result<()> {
-        let mut stdout = stdout();
-        execute!(
-            stdout,
-            event::DisableMouseCapture,  // Disable mouse support
-            cursor::Show,
-            terminal::LeaveAlternateScreen
-        )?;
-        terminal::disable_raw_mode()?;
+        self.restore_cursor_style()?;
+        if self.keyboard_enhanced {
+            self.backend.set_keyboard_enhancement(false)?;
+        }
+        self.backend.leave(self.mouse_enabled)?;
         Ok(())
     }
 
@@ -173,14 +401,11 @@ impl Terminal {
     /// Leaves raw mode and restores cursor, but keeps the Terminal struct alive
     /// Call resume() to return to TUI mode
     pub fn suspend(&mut self) -> Result<()> {
-        let mut stdout = stdout();
-        execute!(
-            stdout,
-            event::DisableMouseCapture,
-            cursor::Show,
-            terminal::LeaveAlternateScreen
-        )?;
-        terminal::disable_raw_mode()?;
+        self.restore_cursor_style()?;
+        if self.keyboard_enhanced {
+            self.backend.set_keyboard_enhancement(false)?;
+        }
+        self.backend.leave(self.mouse_enabled)?;
         Ok(())
     }
 
@@ -188,38 +413,205 @@ impl Terminal {
     /// Matches Borland: TScreen::resume() - re-enters raw mode and redraws
     /// Re-initializes terminal state and forces full screen redraw
     pub fn resume(&mut self) -> Result<()> {
-        terminal::enable_raw_mode()?;
-        let mut stdout = stdout();
-        execute!(
-            stdout,
-            terminal::EnterAlternateScreen,
-            cursor::Hide,
-            event::EnableMouseCapture
-        )?;
+        self.reenter()?;
+        Ok(())
+    }
+
+    fn reenter(&mut self) -> io::Result<()> {
+        self.backend.enter(self.mouse_enabled)?;
+        if self.keyboard_enhanced {
+            self.backend.set_keyboard_enhancement(true)?;
+        }
+
+        // A SIGCONT that led here has been dealt with
+        if let Some(ref watch) = self.continue_watch {
+            watch.take();
+        }
 
         // Force full screen redraw by clearing prev_buffer
         // This ensures everything is redrawn after resume
-        let empty_cell = Cell::new(' ', Attr::from_u8(0x07));
-        for row in &mut self.prev_buffer {
-            for cell in row {
-                *cell = empty_cell;
-            }
-        }
+        self.force_full_redraw();
 
         Ok(())
     }
 
+    /// Returns true if [`Application::stop()`](crate::app::Application::stop) can stop the process
+    ///
+    /// Only a terminal from [`init()`](Self::init) on Unix has job control;
+    /// headless and custom-backend terminals never stop the process.
+    pub fn supports_job_control(&self) -> bool {
+        cfg!(unix) && self.continue_watch.is_some()
+    }
+
     /// Get terminal size
     pub fn size(&self) -> (i16, i16) {
         (self.width as i16, self.height as i16)
     }
 
+    /// Resize the screen buffers to the new terminal size
+    ///
+    /// Both buffers are reallocated, so the next flush() repaints the whole screen.
+    /// Called automatically when a resize event is received.
+    pub fn resize(&mut self, width: u16, height: u16) {
+        let empty_cell = Cell::new(' ', Attr::from_u8(0x07));
+        self.width = width;
+        self.height = height;
+        self.buffer = vec![vec![empty_cell; width as usize]; height as usize];
+        self.prev_buffer = vec![vec![empty_cell; width as usize]; height as usize];
+        self.dirty_rows = vec![true; height as usize];
+        if self.viewport.is_none() {
+            let _ = self.backend.clear().and_then(|()| self.backend.flush());
+        }
+    }
+
+    /// Confine rendering to a rectangle of the physical terminal
+    ///
+    /// Consumes and returns the terminal for chaining after init().
+    /// See [`set_viewport()`](Self::set_viewport).
+    #[must_use]
+    pub fn with_viewport(mut self, origin: Point, size: Point) -> Self {
+        self.set_viewport(origin, size);
+        self
+    }
+
+    /// Confine rendering to a rectangle of the physical terminal
+    ///
+    /// Views keep drawing in logical coordinates starting at (0, 0); the logical
+    /// size becomes `size` and flush()/cursor output is offset by `origin`.
+    /// Mouse positions are translated back into logical coordinates.
+    /// Used to embed the UI inside a larger layout managed elsewhere.
+    pub fn set_viewport(&mut self, origin: Point, size: Point) {
+        let physical = self.screen_size;
+        let viewport = crate::core::geometry::Rect::new(origin.x, origin.y, origin.x + size.x, origin.y + size.y)
+            .intersect(&crate::core::geometry::Rect::new(0, 0, physical.0 as i16, physical.1 as i16));
+        self.viewport = Some(viewport);
+        self.resize(viewport.width_clamped() as u16, viewport.height_clamped() as u16);
+    }
+
+    /// Remove the viewport so the UI covers the whole physical terminal again
+    pub fn clear_viewport(&mut self) {
+        if self.viewport.take().is_some() {
+            let (width, height) = self.screen_size;
+            self.resize(width, height);
+        }
+    }
+
+    /// Get the viewport rectangle in physical coordinates, if one is set
+    pub fn viewport(&self) -> Option<crate::core::geometry::Rect> {
+        self.viewport
+    }
+
+    /// Physical offset applied to all output
+    fn origin(&self) -> Point {
+        self.viewport.map_or(Point::zero(), |v| v.a)
+    }
+
+    /// Handle a physical terminal resize and return the new logical size
+    /// With a viewport, the viewport is clamped to the new physical size.
+    fn handle_terminal_resize(&mut self, width: u16, height: u16) -> (u16, u16) {
+        self.screen_size = (width, height);
+        if let Some(viewport) = self.viewport {
+            let clamped = viewport.intersect(&crate::core::geometry::Rect::new(0, 0, width as i16, height as i16));
+            self.viewport = Some(clamped);
+            self.resize(clamped.width_clamped() as u16, clamped.height_clamped() as u16);
+        } else {
+            self.resize(width, height);
+        }
+        (self.width, self.height)
+    }
+
+    /// Queue simulated input
+    ///
+    /// Queued events are returned by poll_event() in order, after any event
+    /// placed with put_event() and before the backend's input. Resize events resize the buffers as a real
+    /// terminal resize would.
+    pub fn push_input(&mut self, event: Event) {
+        self.pending_events.push_back(event);
+    }
+
     /// Set the ESC timeout in milliseconds
     /// This controls how long the terminal waits after ESC to detect ESC+letter sequences
     pub fn set_esc_timeout(&mut self, timeout_ms: u64) {
         self.esc_tracker.set_timeout(timeout_ms);
     }
 
+    /// Set the maximum time between two presses that still counts as a double-click
+    /// Matches Borland: TEventQueue::doubleDelay
+    pub fn set_double_click_time(&mut self, time: Duration) {
+        self.double_click_time = time;
+    }
+
+    /// Set how many lines one mouse wheel notch scrolls (default 3, at least 1)
+    pub fn set_wheel_lines(&mut self, lines: i16) {
+        self.wheel_lines = lines.max(1);
+    }
+
+    /// Enable or disable mouse capture at runtime
+    ///
+    /// Disabling capture gives mouse selection and scrollback back to the terminal.
+    pub fn set_mouse_enabled(&mut self, enabled: bool) -> io::Result<()> {
+        if enabled == self.mouse_enabled {
+            return Ok(());
+        }
+        self.mouse_enabled = enabled;
+        self.backend.set_mouse_capture(enabled)?;
+        self.backend.flush()
+    }
+
+    /// Returns true if mouse capture is enabled
+    pub fn mouse_enabled(&self) -> bool {
+        self.mouse_enabled
+    }
+
+    /// Returns true if the kitty keyboard protocol is on (see [`TerminalOptions::kitty_keyboard`])
+    pub fn keyboard_enhanced(&self) -> bool {
+        self.keyboard_enhanced
+    }
+
+    /// Add a raw key translation, applied before the built-in key mapping
+    ///
+    /// Use this to fix up keys a terminal sends in a form we don't recognize.
+    pub fn add_key_translation(&mut self, pattern: KeyPattern, key_code: KeyCode) {
+        self.key_translations.add(pattern, key_code);
+    }
+
+    /// Replace the whole key translation table (including the built-in fixups)
+    pub fn set_key_translations(&mut self, table: KeyTranslationTable) {
+        self.key_translations = table;
+    }
+
+    /// Current key translation table
+    pub fn key_translations(&self) -> &KeyTranslationTable {
+        &self.key_translations
+    }
+
+    /// Take the most recent raw key event received from the terminal, before translation
+    ///
+    /// Also set for keys that produce no event (dead keys), so diagnostics can show them.
+    pub fn take_raw_key(&mut self) -> Option<KeyEvent> {
+        self.last_raw_key.take()
+    }
+
+    /// Convert a raw key event to a key code: translation table first, then built-in mapping
+    /// Returns 0 while an ESC sequence is in progress.
+    ///
+    /// With the kitty keyboard protocol on, Alt+letter arrives as such, so only
+    /// Esc goes through the ESC tracker (Esc Esc still closes dialogs); a letter
+    /// typed after Esc is not turned into Alt+letter.
+    fn translate_key(&mut self, key: KeyEvent) -> (KeyCode, KeyModifiers) {
+        self.last_raw_key = Some(key);
+        if let Some(key_code) = self.key_translations.translate(&key) {
+            // A pending ESC must not turn a later letter into Alt+letter
+            self.esc_tracker.reset();
+            return (key_code, key.modifiers);
+        }
+        if self.keyboard_enhanced && key.code != crossterm::event::KeyCode::Esc {
+            self.esc_tracker.reset();
+            return (crossterm_to_keycode(key), key.modifiers);
+        }
+        self.esc_tracker.process_key_with_modifiers(key)
+    }
+
     /// Set the bounds of the currently active view (for F11 screen dumps)
     pub fn set_active_view_bounds(&mut self, bounds: crate::core::geometry::Rect) {
         self.active_view_bounds = Some(bounds);
@@ -242,15 +634,62 @@ impl Terminal {
                 *cell = empty_cell;
             }
         }
+        self.mark_all_dirty();
+    }
+
+    /// Enable or disable full redraw mode
+    ///
+    /// When enabled, every flush() repaints every cell instead of only the cells that
+    /// changed since the previous flush. This trades bandwidth for correctness on
+    /// terminals or multiplexers that mangle the incremental diff output.
+    pub fn set_full_redraw(&mut self, enabled: bool) {
+        self.full_redraw = enabled;
+    }
+
+    /// Returns true if full redraw mode is enabled
+    pub fn full_redraw(&self) -> bool {
+        self.full_redraw
+    }
+
+    /// Switch between color and monochrome output; the next flush repaints everything
+    pub fn set_color_mode(&mut self, mode: ColorMode) {
+        if mode != self.color_mode {
+            self.color_mode = mode;
+            self.backend.set_color_mode(mode);
+            self.force_full_redraw();
+        }
+    }
+
+    pub fn color_mode(&self) -> ColorMode {
+        self.color_mode
+    }
+
+    /// Wrap the output of each [`flush()`](Self::flush) in synchronized-update sequences
+    ///
+    /// The terminal then shows a frame all at once instead of painting it run
+    /// by run, which removes tearing while a window is dragged. Turned on at
+    /// init for terminals known to support it (kitty, WezTerm, iTerm2, foot,
+    /// Alacritty, Ghostty, Windows Terminal, Contour); terminals that do not
+    /// know the sequences ignore them. Pass `false` to force it off.
+    pub fn set_synchronized_output(&mut self, enabled: bool) {
+        self.synchronized_output = enabled;
+    }
+
+    pub fn synchronized_output(&self) -> bool {
+        self.synchronized_output
     }
 
     /// Push a clipping region onto the stack
     pub fn push_clip(&mut self, rect: crate::core::geometry::Rect) {
+        #[cfg(any(test, feature = "test-util"))]
+        self.record(|| DrawOp::PushClip(rect));
         self.clip_stack.push(rect);
     }
 
     /// Pop a clipping region from the stack
     pub fn pop_clip(&mut self) {
+        #[cfg(any(test, feature = "test-util"))]
+        self.record(|| DrawOp::PopClip);
         self.clip_stack.pop();
     }
 
@@ -278,6 +717,8 @@ impl Terminal {
 
     /// Write a cell at the given position
     pub fn write_cell(&mut self, x: u16, y: u16, cell: Cell) {
+        #[cfg(any(test, feature = "test-util"))]
+        self.record(|| DrawOp::WriteCell { x, y, cell });
         let x_i16 = x as i16;
         let y_i16 = y as i16;
 
@@ -292,10 +733,19 @@ impl Terminal {
         }
 
         self.buffer[y as usize][x as usize] = cell;
+        self.dirty_rows[y as usize] = true;
+        // A wide character also covers the next column
+        let next = x as usize + 1;
+        if cell.is_wide() && next < self.width as usize && !self.is_clipped(next as i16, y_i16) {
+            self.buffer[y as usize][next] = Cell::continuation(cell.attr);
+        }
+        self.fix_wide_pairs(y as usize, x as usize, next + 1);
     }
 
     /// Write a line from a draw buffer
     pub fn write_line(&mut self, x: u16, y: u16, cells: &[Cell]) {
+        #[cfg(any(test, feature = "test-util"))]
+        self.record(|| DrawOp::WriteLine { x, y, cells: cells.to_vec() });
         let y_i16 = y as i16;
 
         if (y as usize) >= self.height as usize {
@@ -304,6 +754,7 @@ impl Terminal {
 
         let max_width = (self.width as usize).saturating_sub(x as usize);
         let len = cells.len().min(max_width);
+        self.dirty_rows[y as usize] = true;
 
         for (i, cell) in cells.iter().enumerate().take(len) {
             let cell_x = (x as usize) + i;
@@ -314,6 +765,25 @@ impl Terminal {
                 self.buffer[y as usize][cell_x] = *cell;
             }
         }
+        self.fix_wide_pairs(y as usize, x as usize, x as usize + len);
+    }
+
+    /// Repair wide characters cut in half by a write to columns `from..to` of row `y`
+    ///
+    /// Overwriting either half of a wide character, or clipping a write
+    /// between the halves, leaves a lone half behind; it becomes a space so
+    /// the row keeps one character per column on screen.
+    fn fix_wide_pairs(&mut self, y: usize, from: usize, to: usize) {
+        let row = &mut self.buffer[y];
+        let end = (to + 1).min(row.len());
+        for x in from.saturating_sub(1)..end {
+            let cell = row[x];
+            let lone_right = cell.is_continuation() && (x == 0 || !row[x - 1].is_wide());
+            let lone_left = cell.is_wide() && row.get(x + 1).is_none_or(|next| !next.is_continuation());
+            if lone_right || lone_left {
+                row[x] = Cell::new(' ', cell.attr);
+            }
+        }
     }
 
     /// Read a cell from the buffer at the given position
@@ -327,209 +797,414 @@ impl Terminal {
 
     /// Clear the entire screen
     pub fn clear(&mut self) {
+        #[cfg(any(test, feature = "test-util"))]
+        self.record(|| DrawOp::Clear);
         let empty_cell = Cell::new(' ', Attr::from_u8(0x07));
         for row in &mut self.buffer {
             for cell in row {
                 *cell = empty_cell;
             }
         }
+        self.mark_all_dirty();
+    }
+
+    /// Make the next flush diff every row
+    fn mark_all_dirty(&mut self) {
+        self.dirty_rows.fill(true);
     }
 
     /// Flush changes to the terminal
+    ///
+    /// Only cells that differ from the previous flush are sent, unless full redraw
+    /// mode is enabled (see [`set_full_redraw()`](Self::set_full_redraw)). Each run
+    /// of changed cells with the same attribute goes to the backend in one call.
     pub fn flush(&mut self) -> io::Result<()> {
-        let mut stdout = stdout();
+        #[cfg(any(test, feature = "test-util"))]
+        self.record(|| DrawOp::Flush);
+        let origin = self.origin();
+        let mut damage = DamageBuilder::default();
+        let mut in_update = false;  // Begin sequence sent (only for frames that change something)
 
         for y in 0..self.height as usize {
+            // Rows nobody wrote to since the last flush cannot have changed
+            if !self.dirty_rows[y] && !self.full_redraw {
+                continue;
+            }
+            let mut span: Option<(usize, usize)> = None;  // Changed cells across attribute runs
             let mut x = 0;
             while x < self.width as usize {
                 // Find the start of a changed region
-                if self.buffer[y][x] == self.prev_buffer[y][x] {
+                if !self.cell_changed(x, y) {
                     x += 1;
                     continue;
                 }
 
-                // Find the end of the changed region
-                let start_x = x;
+                // Find the end of the changed region; a change to the right half
+                // of a wide character resends the whole character
+                let start_x = if self.buffer[y][x].is_continuation() && x > 0 { x - 1 } else { x };
                 let current_attr = self.buffer[y][x].attr;
 
                 while x < self.width as usize
-                    && self.buffer[y][x] != self.prev_buffer[y][x]
+                    && self.cell_changed(x, y)
                     && self.buffer[y][x].attr == current_attr
                 {
                     x += 1;
                 }
 
-                // Move cursor and set colors
-                queue!(
-                    stdout,
-                    cursor::MoveTo(start_x as u16, y as u16),
-                    style::SetForegroundColor(current_attr.fg.to_crossterm()),
-                    style::SetBackgroundColor(current_attr.bg.to_crossterm())
+                let attr = match self.color_mode {
+                    ColorMode::Color => current_attr,
+                    ColorMode::Mono => current_attr.with_style(current_attr.mono_style()),
+                };
+                if self.synchronized_output && !in_update {
+                    self.backend.write_sequence(BEGIN_SYNCHRONIZED_UPDATE)?;
+                    in_update = true;
+                }
+                self.backend.draw_run(
+                    start_x as u16 + origin.x as u16,
+                    y as u16 + origin.y as u16,
+                    attr,
+                    &self.buffer[y][start_x..x],
                 )?;
 
-                // Write the changed characters
-                for i in start_x..x {
-                    write!(stdout, "{}", self.buffer[y][i].ch)?;
-                }
+                span = match span {
+                    Some((span_start, span_end)) if span_end == start_x => Some((span_start, x)),
+                    Some((span_start, span_end)) => {
+                        damage.add_span(y as u16, span_start as u16, span_end as u16);
+                        Some((start_x, x))
+                    }
+                    None => Some((start_x, x)),
+                };
+            }
+
+            if let Some((span_start, span_end)) = span {
+                damage.add_span(y as u16, span_start as u16, span_end as u16);
             }
         }
 
-        stdout.flush()?;
+        // Host clipboard write queued by clipboard::set_clipboard()
+        if let Some(sequence) = clipboard::take_pending_osc52() {
+            self.backend.write_sequence(sequence.as_bytes())?;
+        }
 
-        // Copy current buffer to previous buffer
-        self.prev_buffer.clone_from(&self.buffer);
+        if in_update {
+            self.backend.write_sequence(END_SYNCHRONIZED_UPDATE)?;
+        }
+        self.backend.flush()?;
+        self.push_damage(damage.finish());
+
+        // Copy the rows that may have changed to the previous buffer
+        for (y, dirty) in self.dirty_rows.iter_mut().enumerate() {
+            if std::mem::take(dirty) {
+                self.prev_buffer[y].clone_from(&self.buffer[y]);
+            }
+        }
 
         Ok(())
     }
 
-    /// Show the cursor at the specified position
+    /// Take the rectangles of cells changed by flushes since the last call
+    ///
+    /// Rectangles are in buffer coordinates and coalesced row by row. They are
+    /// produced on headless terminals too, so custom frontends can mirror the screen.
+    pub fn take_damage(&mut self) -> Vec<DamageRect> {
+        std::mem::take(&mut self.damage)
+    }
+
+    /// Set a callback invoked after each flush that changed cells, with that flush's damage
+    pub fn set_damage_listener(&mut self, listener: DamageListener) {
+        self.damage_listener = Some(listener);
+    }
+
+    /// Remove the damage callback
+    pub fn clear_damage_listener(&mut self) {
+        self.damage_listener = None;
+    }
+
+    fn push_damage(&mut self, rects: Vec<DamageRect>) {
+        if rects.is_empty() {
+            return;
+        }
+        if let Some(ref mut listener) = self.damage_listener {
+            listener(&rects);
+        }
+
+        self.damage.extend(rects);
+        if self.damage.len() > MAX_PENDING_DAMAGE {
+            // Nobody is taking damage: keep one conservative bounding rectangle
+            let bounds = self.damage.iter().skip(1).fold(self.damage[0], |acc, r| acc.union(r));
+            self.damage = vec![bounds];
+        }
+    }
+
+    /// Snapshot the cells under `rect` before a modal view is drawn over them
+    ///
+    /// Snapshots form a stack: each [`restore_under()`](Self::restore_under) restores
+    /// the most recent one, so stacked modals are unwound in reverse order.
+    pub fn save_under(&mut self, rect: crate::core::geometry::Rect) {
+        let rect = self.clip_to_screen(rect);
+        let rows = (rect.a.y..rect.b.y)
+            .map(|y| self.buffer[y as usize][rect.a.x as usize..rect.b.x as usize].to_vec())
+            .collect();
+        self.save_under.push(SavedRegion { rect, rows });
+    }
+
+    /// Restore the cells saved by the matching [`save_under()`](Self::save_under) and flush them
+    ///
+    /// Works even if the application never redraws what was beneath the modal view.
+    /// Returns false if there was no saved region.
+    pub fn restore_under(&mut self) -> io::Result<bool> {
+        let Some(saved) = self.save_under.pop() else {
+            return Ok(false);
+        };
+
+        // Clamp again in case the terminal shrank while the modal view was open,
+        // possibly to the left of the region
+        let x = saved.rect.a.x as usize;
+        let rows = if x < self.width as usize { saved.rows.as_slice() } else { &[] };
+        for (row, y) in rows.iter().zip(saved.rect.a.y as usize..self.height as usize) {
+            let count = row.len().min(self.width as usize - x);
+            self.buffer[y][x..x + count].copy_from_slice(&row[..count]);
+            self.dirty_rows[y] = true;
+        }
+        self.flush()?;
+        Ok(true)
+    }
+
+    /// Number of regions saved by save_under() and not yet restored
+    pub fn save_under_depth(&self) -> usize {
+        self.save_under.len()
+    }
+
+    /// Returns true if the cell at (x, y) must be sent on the next flush
+    fn cell_changed(&self, x: usize, y: usize) -> bool {
+        self.full_redraw || self.buffer[y][x] != self.prev_buffer[y][x]
+    }
+
+    /// Show the cursor at the specified position, in the current [`CursorStyle`]
     pub fn show_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
-        let mut stdout = stdout();
-        execute!(
-            stdout,
-            cursor::MoveTo(x, y),
-            cursor::Show
-        )?;
+        if self.cursor_style != self.sent_cursor_style {
+            self.backend.set_cursor_style(self.cursor_style)?;
+            self.sent_cursor_style = self.cursor_style;
+        }
+        let origin = self.origin();
+        self.backend.show_cursor(x + origin.x as u16, y + origin.y as u16)?;
+        self.backend.flush()
+    }
+
+    /// Style of the cursor from the next [`show_cursor()`](Self::show_cursor) on
+    ///
+    /// Views call this from `update_cursor()` before showing the cursor; the
+    /// application resets it to [`CursorStyle::Default`] before each cursor
+    /// update, so views that don't ask for a style get the user's own. The
+    /// escape sequence is only sent when the style changes.
+    pub fn set_cursor_style(&mut self, style: CursorStyle) {
+        self.cursor_style = style;
+    }
+
+    pub fn cursor_style(&self) -> CursorStyle {
+        self.cursor_style
+    }
+
+    /// Give the terminal its own cursor style back before leaving TUI mode
+    fn restore_cursor_style(&mut self) -> io::Result<()> {
+        if self.sent_cursor_style != CursorStyle::Default {
+            self.backend.set_cursor_style(CursorStyle::Default)?;
+            self.backend.flush()?;
+            self.sent_cursor_style = CursorStyle::Default;
+        }
         Ok(())
     }
 
     /// Hide the cursor
     pub fn hide_cursor(&mut self) -> io::Result<()> {
-        let mut stdout = stdout();
-        execute!(stdout, cursor::Hide)?;
+        self.backend.hide_cursor()?;
+        self.backend.flush()
+    }
+
+    /// Copy `text` to the host terminal's clipboard with an OSC 52 sequence
+    ///
+    /// Works over SSH on terminals that support it; others ignore the
+    /// sequence. Text over [`clipboard::OSC52_MAX_PAYLOAD`] is not sent.
+    /// Unlike [`clipboard::enable_osc52()`], this does not touch the
+    /// application clipboard.
+    pub fn copy_to_system_clipboard(&mut self, text: &str) -> io::Result<()> {
+        if let Some(sequence) = clipboard::osc52_sequence(text) {
+            self.backend.write_sequence(sequence.as_bytes())?;
+            self.backend.flush()?;
+        }
         Ok(())
     }
 
     /// Put an event in the queue for next iteration
-    /// Matches Borland's TProgram::putEvent() - allows re-queuing events
+    ///
+    /// Queued events are returned in order by poll_event() and read_event()
+    /// before any input, so a command can post another command (or keys) to be
+    /// handled on the next loop iteration.
+    /// Matches Borland's TProgram::putEvent(), which holds a single event
     pub fn put_event(&mut self, event: Event) {
-        self.pending_event = Some(event);
+        // After the events already posted, ahead of simulated input
+        self.pending_events.insert(self.posted, event);
+        self.posted += 1;
     }
 
     /// Poll for an event with timeout
     pub fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
         // Check for pending event first (matches Borland's TProgram::getEvent)
-        if let Some(event) = self.pending_event.take() {
-            return Ok(Some(event));
+        if self.posted > 0 {
+            self.posted -= 1;
+            return Ok(self.pending_events.pop_front());
         }
 
-        if event::poll(timeout)? {
-            match event::read()? {
-                CTEvent::Key(key) => {
-                    // On Windows, crossterm sends both Press and Release events
-                    // Filter to only process Press events to avoid duplicates
-                    if key.kind != KeyEventKind::Press {
-                        return Ok(None);
-                    }
-
-                    let key_code = self.esc_tracker.process_key(key);
-                    if key_code == 0 {
-                        // ESC sequence in progress, don't generate event yet
-                        return Ok(None);
-                    }
-
-                    // Handle global screen dump shortcuts at the lowest level
-                    if key_code == KB_F12 {
-                        let _ = self.flash();
-                        let _ = self.dump_screen("screen-dump.txt");
-                        return Ok(None);  // Don't propagate event, it's been handled
-                    }
+        if self.is_headless() || !self.pending_events.is_empty() {
+            let event = self.pending_events.pop_front();
+            if let Some(ref ev) = event {
+                if ev.what == EventType::Resize {
+                    let (width, height) = self.handle_terminal_resize(ev.mouse.pos.x as u16, ev.mouse.pos.y as u16);
+                    return Ok(Some(Event::resize(width, height)));
+                }
+            }
+            return Ok(event);
+        }
 
-                    // Handle active view dump shortcut (Shift+F12)
-                    if key_code == KB_SHIFT_F12 {
-                        let _ = self.flash();
-                        if let Some(bounds) = self.active_view_bounds {
-                            let _ = self.dump_region(
-                                bounds.a.x as u16,
-                                bounds.a.y as u16,
-                                (bounds.b.x - bounds.a.x) as u16,
-                                (bounds.b.y - bounds.a.y) as u16,
-                                "active-view-dump.txt"
-                            );
-                        }
-                        return Ok(None);  // Don't propagate event, it's been handled
-                    }
+        // Continued after a stop from outside: take the screen back and lay it out again
+        if self.continue_watch.as_ref().is_some_and(ContinueWatch::take) {
+            self.reenter()?;
+            let (width, height) = self.backend.size()?;
+            let (width, height) = self.handle_terminal_resize(width, height);
+            return Ok(Some(Event::resize(width, height)));
+        }
 
-                    // Create event preserving modifiers from original crossterm event
-                    Ok(Some(Event {
-                        what: EventType::Keyboard,
-                        key_code,
-                        key_modifiers: key.modifiers,
-                        ..Event::nothing()
-                    }))
-                }
-                CTEvent::Mouse(mouse) => {
-                    Ok(self.convert_mouse_event(mouse))
-                }
-                _ => Ok(None),
+        if let Some(event) = self.backend.poll_event(timeout)? {
+            match self.convert_event(event) {
+                Some(wheel) if wheel.is_wheel() => Ok(Some(self.coalesce_wheel(wheel)?)),
+                other => Ok(other),
             }
+        } else if self.last_mouse_buttons != 0 {
+            // Button still held with no new input: repeat tick for auto-repeating views
+            // Matches Borland: TEventQueue::getMouseEvent() posting evMouseAuto
+            Ok(Some(Event::mouse(EventType::MouseAuto, self.last_mouse_pos, self.last_mouse_buttons, false)))
         } else {
             Ok(None)
         }
     }
 
-    /// Read an event (blocking)
-    pub fn read_event(&mut self) -> io::Result<Event> {
-        loop {
-            match event::read()? {
-                CTEvent::Key(key) => {
-                    // On Windows, crossterm sends both Press and Release events
-                    // Filter to only process Press events to avoid duplicates
-                    if key.kind != KeyEventKind::Press {
-                        continue;
-                    }
+    /// Translate a crossterm event; `None` for events that produce nothing
+    fn convert_event(&mut self, event: CTEvent) -> Option<Event> {
+        match event {
+            CTEvent::Key(key) => {
+                // On Windows, crossterm sends both Press and Release events
+                // Filter to only process Press events to avoid duplicates
+                if key.kind != KeyEventKind::Press {
+                    return None;
+                }
 
-                    let key_code = self.esc_tracker.process_key(key);
-                    if key_code == 0 {
-                        // ESC sequence in progress, wait for next key
-                        continue;
-                    }
+                let (key_code, key_modifiers) = self.translate_key(key);
+                if key_code == 0 {
+                    // ESC sequence in progress, don't generate event yet
+                    return None;
+                }
 
-                    // Handle global screen dump shortcuts at the lowest level
-                    if key_code == KB_F12 {
-                        let _ = self.flash();
-                        let _ = self.dump_screen("screen-dump.txt");
-                        continue;  // Don't return event, it's been handled - wait for next event
-                    }
+                // Handle global screen dump shortcuts at the lowest level
+                if key_code == KB_F12 {
+                    let _ = self.flash();
+                    let _ = self.dump_screen("screen-dump.txt");
+                    return None;  // Don't propagate event, it's been handled
+                }
 
-                    // Handle active view dump shortcut (Shift+F12)
-                    if key_code == KB_SHIFT_F12 {
-                        let _ = self.flash();
-                        if let Some(bounds) = self.active_view_bounds {
-                            let _ = self.dump_region(
-                                bounds.a.x as u16,
-                                bounds.a.y as u16,
-                                (bounds.b.x - bounds.a.x) as u16,
-                                (bounds.b.y - bounds.a.y) as u16,
-                                "active-view-dump.txt"
-                            );
-                        }
-                        continue;  // Don't return event, it's been handled - wait for next event
+                // Handle active view dump shortcut (Shift+F12)
+                if key_code == KB_SHIFT_F12 {
+                    let _ = self.flash();
+                    if let Some(bounds) = self.active_view_bounds {
+                        let _ = self.dump_region(
+                            bounds.a.x as u16,
+                            bounds.a.y as u16,
+                            (bounds.b.x - bounds.a.x) as u16,
+                            (bounds.b.y - bounds.a.y) as u16,
+                            "active-view-dump.txt"
+                        );
                     }
-
-                    return Ok(Event::keyboard(key_code));
+                    return None;  // Don't propagate event, it's been handled
                 }
-                CTEvent::Mouse(mouse) => {
-                    if let Some(event) = self.convert_mouse_event(mouse) {
-                        return Ok(event);
-                    }
+
+                // Create event preserving modifiers from original crossterm event
+                Some(Event::keyboard_with(key_code, key_modifiers))
+            }
+            CTEvent::Mouse(mouse) => {
+                self.convert_mouse_event(mouse)
+            }
+            CTEvent::Resize(width, height) => {
+                let (width, height) = self.handle_terminal_resize(width, height);
+                Some(Event::resize(width, height))
+            }
+            CTEvent::Paste(text) => Some(Event::paste(text)),
+            _ => None,
+        }
+    }
+
+    /// Merge wheel notches already waiting in the input into one event
+    /// The first event that is not a wheel turn in the same direction is kept for the next poll.
+    fn coalesce_wheel(&mut self, mut wheel: Event) -> io::Result<Event> {
+        while self.pending_events.is_empty() {
+            let Some(event) = self.backend.poll_event(Duration::ZERO)? else {
+                break;
+            };
+            match self.convert_event(event) {
+                Some(next) if next.what == wheel.what && next.mouse.pos == wheel.mouse.pos => {
+                    wheel.mouse.wheel_delta = wheel.mouse.wheel_delta.saturating_add(next.mouse.wheel_delta);
                 }
-                _ => continue,
+                // Already translated: keep it for the next poll like a posted event
+                Some(next) => self.put_event(next),
+                None => {}
+            }
+        }
+        Ok(wheel)
+    }
+
+    /// Read an event (blocking)
+    ///
+    /// Events queued with put_event() or push_input() come first.
+    pub fn read_event(&mut self) -> io::Result<Event> {
+        if !self.pending_events.is_empty() {
+            if let Some(event) = self.poll_event(Duration::ZERO)? {
+                return Ok(event);
+            }
+        }
+        // Same translation as poll_event(), modifiers included; wait past
+        // events that produce nothing (key releases, pending ESC sequences)
+        loop {
+            let event = self.backend.read_event()?;
+            if let Some(event) = self.convert_event(event) {
+                return Ok(event);
             }
         }
     }
 
     /// Convert crossterm mouse event to our Event type
     fn convert_mouse_event(&mut self, mouse: event::MouseEvent) -> Option<Event> {
-        let pos = Point::new(mouse.column as i16, mouse.row as i16);
+        let origin = self.origin();
+        let mut pos = Point::new(mouse.column as i16 - origin.x, mouse.row as i16 - origin.y);
+
+        // Outside the viewport, presses, wheel turns and plain moves belong to
+        // whatever surrounds the UI and are dropped. Drags and the release that
+        // ends them are clipped to the edge, so the view holding the mouse still
+        // follows it and sees the button go up.
+        let (width, height) = self.size();
+        if pos.x < 0 || pos.y < 0 || pos.x >= width || pos.y >= height {
+            let dragging = self.last_mouse_buttons != 0
+                && matches!(mouse.kind, MouseEventKind::Drag(_) | MouseEventKind::Up(_) | MouseEventKind::Moved);
+            if !dragging || width == 0 || height == 0 {
+                return None;
+            }
+            pos = Point::new(pos.x.clamp(0, width - 1), pos.y.clamp(0, height - 1));
+        }
 
         // Handle scroll wheel events separately
         match mouse.kind {
             MouseEventKind::ScrollUp => {
-                return Some(Event::mouse(EventType::MouseWheelUp, pos, 0, false));
+                return Some(Event::wheel(pos, -self.wheel_lines));
             }
             MouseEventKind::ScrollDown => {
-                return Some(Event::mouse(EventType::MouseWheelDown, pos, 0, false));
+                return Some(Event::wheel(pos, self.wheel_lines));
             }
             _ => {}
         }
@@ -547,17 +1222,7 @@ impl Terminal {
         // Determine event type and detect double-clicks
         let (event_type, is_double_click) = match mouse.kind {
             MouseEventKind::Down(_) => {
-                // Check for double-click: same position, within 500ms
-                let is_double = if let Some(last_time) = self.last_click_time {
-                    let elapsed = last_time.elapsed();
-                    elapsed.as_millis() <= 500 && pos == self.last_click_pos
-                } else {
-                    false
-                };
-
-                // Update click tracking
-                self.last_click_time = Some(Instant::now());
-                self.last_click_pos = pos;
+                let is_double = self.register_click(pos, buttons, Instant::now());
                 self.last_mouse_buttons = buttons;
                 self.last_mouse_pos = pos;
 
@@ -574,7 +1239,26 @@ impl Terminal {
             _ => return None,
         };
 
-        Some(Event::mouse(event_type, pos, buttons, is_double_click))
+        let mut event = Event::mouse(event_type, pos, buttons, is_double_click);
+        event.mouse.modifiers = mouse.modifiers;
+        Some(event)
+    }
+
+    /// Record a button press and tell whether it completes a double-click:
+    /// same button as the previous press, within the double-click time and
+    /// at most one cell away. The press after a double-click starts over.
+    fn register_click(&mut self, pos: Point, buttons: u8, now: Instant) -> bool {
+        let is_double = self.last_click_time.is_some_and(|last| {
+            now.saturating_duration_since(last) <= self.double_click_time
+                && buttons == self.last_click_buttons
+                && (pos.x - self.last_click_pos.x).abs() <= 1
+                && (pos.y - self.last_click_pos.y).abs() <= 1
+        });
+
+        self.last_click_time = if is_double { None } else { Some(now) };
+        self.last_click_pos = pos;
+        self.last_click_buttons = buttons;
+        is_double
     }
 
     /// Dump the entire screen buffer to an ANSI text file for debugging
@@ -582,6 +1266,15 @@ impl Terminal {
         ansi_dump::dump_buffer_to_file(&self.buffer, self.width as usize, self.height as usize, path)
     }
 
+    /// The part of `rect` that lies on screen; an empty rectangle at the origin if none
+    ///
+    /// Use it on [`View::shadow_bounds()`](crate::views::View::shadow_bounds), which
+    /// reaches past the screen for views at the right or bottom edge.
+    pub fn clip_to_screen(&self, rect: crate::core::geometry::Rect) -> crate::core::geometry::Rect {
+        let screen = crate::core::geometry::Rect::new(0, 0, self.width as i16, self.height as i16);
+        if rect.intersects(&screen) { rect.intersect(&screen) } else { crate::core::geometry::Rect::new(0, 0, 0, 0) }
+    }
+
     /// Dump a rectangular region of the screen to an ANSI text file
     pub fn dump_region(&self, x: u16, y: u16, width: u16, height: u16, path: &str) -> io::Result<()> {
         let mut file = std::fs::File::create(path)?;
@@ -608,6 +1301,7 @@ impl Terminal {
         let saved_buffer = self.buffer.clone();
 
         // Invert all colors
+        self.mark_all_dirty();
         for row in &mut self.buffer {
             for cell in row {
                 // Swap foreground and background colors
@@ -625,6 +1319,7 @@ impl Terminal {
 
         // Restore original buffer
         self.buffer = saved_buffer;
+        self.mark_all_dirty();
 
         // Flush restored screen
         self.flush()?;
@@ -636,10 +1331,8 @@ impl Terminal {
     /// Matches Borland: TScreen::makeBeep() which calls beep() + refresh()
     /// Outputs the terminal bell character and flushes immediately
     pub fn beep(&mut self) -> io::Result<()> {
-        let mut stdout = stdout();
-        write!(stdout, "\x07")?;  // Terminal bell character
-        stdout.flush()?;
-        Ok(())
+        self.backend.beep()?;
+        self.backend.flush()
     }
 }
 
@@ -648,3 +1341,598 @@ impl Drop for Terminal {
         let _ = self.shutdown();
     }
 }
+
+/// True when the environment names a terminal known to support synchronized updates
+///
+/// There is no reliable query for mode 2026 that works before raw mode, so
+/// this goes by the variables the terminals set themselves.
+fn supports_synchronized_output(var: impl Fn(&str) -> Option<String>) -> bool {
+    const PROGRAMS: [&str; 4] = ["iTerm.app", "WezTerm", "ghostty", "contour"];
+    const TERMS: [&str; 5] = ["kitty", "foot", "alacritty", "wezterm", "ghostty"];
+
+    let own_variable = ["KITTY_WINDOW_ID", "WEZTERM_EXECUTABLE", "WT_SESSION"]
+        .iter()
+        .any(|name| var(name).is_some_and(|value| !value.is_empty()));
+    let program = var("TERM_PROGRAM").is_some_and(|program| PROGRAMS.contains(&program.as_str()));
+    let term = var("TERM").is_some_and(|term| TERMS.iter().any(|name| term.contains(name)));
+    own_variable || program || term
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the set of rows that received a cursor move in the captured output
+    fn rows_written(output: &[u8], height: u16) -> Vec<u16> {
+        let text = String::from_utf8_lossy(output);
+        (0..height)
+            .filter(|y| text.contains(&format!("\x1b[{};1H", y + 1)))
+            .collect()
+    }
+
+    #[test]
+    fn test_flush_unchanged_buffer_writes_nothing() {
+        let mut terminal = Terminal::new_headless(10, 4);
+        terminal.write_cell(0, 0, Cell::new('A', Attr::from_u8(0x1F)));
+        terminal.flush().unwrap();
+        terminal.take_output();
+
+        terminal.flush().unwrap();
+        assert!(terminal.take_output().is_empty());
+    }
+
+    #[test]
+    fn test_flush_diffs_only_rows_written_since_last_flush() {
+        let mut terminal = Terminal::new_headless(10, 4);
+        terminal.flush().unwrap();
+        terminal.take_output();
+        assert!(terminal.dirty_rows.iter().all(|dirty| !dirty));
+
+        terminal.write_cell(0, 2, Cell::new('A', Attr::from_u8(0x1F)));
+        terminal.write_line(0, 3, &[Cell::new(' ', Attr::from_u8(0x07))]);
+        assert_eq!(terminal.dirty_rows, vec![false, false, true, true]);
+        terminal.flush().unwrap();
+        assert_eq!(rows_written(&terminal.take_output(), 4), vec![2], "row 3 was written but did not change");
+        assert!(terminal.dirty_rows.iter().all(|dirty| !dirty));
+
+        terminal.clear();
+        assert!(terminal.dirty_rows.iter().all(|dirty| *dirty));
+        terminal.flush().unwrap();
+        assert_eq!(rows_written(&terminal.take_output(), 4), vec![2]);
+
+        terminal.resize(6, 3);
+        assert_eq!(terminal.dirty_rows, vec![true; 3]);
+    }
+
+    #[test]
+    fn test_rgb_colors_are_emitted_exactly() {
+        let mut terminal = Terminal::new_headless(10, 1);
+        terminal.write_cell(0, 0, Cell::new('A', Attr::rgb((255, 128, 0), (30, 30, 30))));
+        terminal.flush().unwrap();
+        let output = String::from_utf8(terminal.take_output()).unwrap();
+        assert!(output.contains("\x1b[38;2;255;128;0m"), "{output:?}");
+        assert!(output.contains("\x1b[48;2;30;30;30m"), "{output:?}");
+
+        // Darkening (shadows) keeps true colors exact
+        assert_eq!(Attr::rgb((200, 100, 50), (0, 0, 0)).darken(0.5), Attr::rgb((100, 50, 25), (0, 0, 0)));
+    }
+
+    #[test]
+    fn test_style_changes_repaint_and_emit_attributes() {
+        use crate::core::palette::{TvColor, STYLE_BOLD, STYLE_UNDERLINE};
+
+        let plain = Attr::new(TvColor::White, TvColor::Blue);
+        let mut terminal = Terminal::new_headless(10, 1);
+        terminal.write_cell(0, 0, Cell::new('A', plain));
+        terminal.flush().unwrap();
+        terminal.take_output();
+
+        // Same character and colors, only the style differs
+        terminal.write_cell(0, 0, Cell::new('A', plain.with_style(STYLE_BOLD | STYLE_UNDERLINE)));
+        terminal.flush().unwrap();
+        let output = String::from_utf8(terminal.take_output()).unwrap();
+        assert!(output.contains("\x1b[1m") && output.contains("\x1b[4m"), "{output:?}");
+        assert!(output.ends_with("\x1b[0m"), "style is reset at the end of the flush: {output:?}");
+        assert_eq!(plain.with_style(STYLE_BOLD).to_u8(), plain.to_u8());
+    }
+
+    #[test]
+    fn test_wide_chars_flush_once_and_never_tear() {
+        use crate::core::draw::DrawBuffer;
+
+        let attr = Attr::from_u8(0x1F);
+        let mut terminal = Terminal::new_headless(10, 2);
+        let mut buf = DrawBuffer::new(8);
+        buf.move_str(0, "日本語|", attr);
+        terminal.write_line(1, 0, &buf.data);
+        terminal.flush().unwrap();
+        let output = String::from_utf8(terminal.take_output()).unwrap();
+        assert!(output.contains("日本語|"), "{output:?}");
+        assert!(!output.contains('\0'));
+
+        // Overwriting the right half of 本 blanks its left half; the last column can't hold 語
+        terminal.write_cell(4, 0, Cell::new('x', attr));
+        terminal.write_cell(9, 0, Cell::new('語', attr));
+        assert_eq!(row_chars(&terminal, 0), " 日\0 x語\0|  ");
+
+        // A clip between the halves of a wide character
+        terminal.push_clip(crate::core::geometry::Rect::new(0, 1, 2, 2));
+        terminal.write_line(1, 1, &buf.data);
+        terminal.pop_clip();
+        assert_eq!(row_chars(&terminal, 1), "          ");
+    }
+
+    #[test]
+    fn test_mono_mode_uses_reverse_video_instead_of_colors() {
+        use crate::core::palette::colors;
+
+        let mut terminal = Terminal::new_headless(10, 1);
+        terminal.set_color_mode(ColorMode::Mono);
+        terminal.write_cell(0, 0, Cell::new('n', colors::EDITOR_NORMAL));
+        terminal.write_cell(1, 0, Cell::new('s', colors::EDITOR_SELECTED));
+        terminal.flush().unwrap();
+        let output = String::from_utf8(terminal.take_output()).unwrap();
+        assert!(!output.contains("\x1b[38;"), "no foreground colors: {output:?}");
+        assert!(!output.contains("\x1b[48;"), "no background colors: {output:?}");
+        assert!(output.contains("\x1b[1mn"), "bright text is bold: {output:?}");
+        assert!(output.contains("\x1b[7ms"), "selection is reverse video: {output:?}");
+
+        // Switching back repaints in color
+        terminal.set_color_mode(ColorMode::Color);
+        terminal.flush().unwrap();
+        assert!(String::from_utf8(terminal.take_output()).unwrap().contains("\x1b[38;"));
+    }
+
+    #[test]
+    fn test_full_redraw_writes_every_row() {
+        let mut terminal = Terminal::new_headless(10, 4);
+        terminal.write_cell(0, 0, Cell::new('A', Attr::from_u8(0x1F)));
+        terminal.flush().unwrap();
+        terminal.take_output();
+
+        terminal.set_full_redraw(true);
+        assert!(terminal.full_redraw());
+        terminal.flush().unwrap();
+        let output = terminal.take_output();
+        assert_eq!(rows_written(&output, 4), vec![0, 1, 2, 3]);
+
+        // Still repaints on subsequent flushes with no changes
+        terminal.flush().unwrap();
+        let output = terminal.take_output();
+        assert_eq!(rows_written(&output, 4), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_clipboard_osc52_reaches_the_terminal() {
+        let mut terminal = Terminal::new_headless(10, 2);
+        terminal.copy_to_system_clipboard("hi").unwrap();
+        assert_eq!(terminal.take_output(), b"\x1b]52;c;aGk=\x07");
+
+        clipboard::enable_osc52(true);
+        clipboard::set_clipboard("hi");
+        terminal.flush().unwrap();
+        clipboard::enable_osc52(false);
+        assert!(String::from_utf8(terminal.take_output()).unwrap().contains("\x1b]52;c;aGk=\x07"));
+        terminal.flush().unwrap();
+        assert!(terminal.take_output().is_empty(), "sent once");
+    }
+
+    #[test]
+    fn test_synchronized_output_wraps_changed_frames_only() {
+        let mut terminal = Terminal::new_headless(10, 2);
+        assert!(!terminal.synchronized_output());
+        terminal.set_synchronized_output(true);
+
+        terminal.write_cell(3, 1, Cell::new('x', Attr::from_u8(0x1F)));
+        terminal.flush().unwrap();
+        let output = terminal.take_output();
+        assert!(output.starts_with(BEGIN_SYNCHRONIZED_UPDATE));
+        assert!(output.ends_with(END_SYNCHRONIZED_UPDATE));
+
+        terminal.flush().unwrap();
+        assert!(terminal.take_output().is_empty(), "nothing to show, nothing sent");
+
+        terminal.set_synchronized_output(false);
+        terminal.write_cell(3, 1, Cell::new('y', Attr::from_u8(0x1F)));
+        terminal.flush().unwrap();
+        let output = String::from_utf8(terminal.take_output()).unwrap();
+        assert!(output.contains('y') && !output.contains("2026"));
+    }
+
+    #[test]
+    fn test_cursor_style_sent_on_change_and_restored_on_shutdown() {
+        const BLINKING_BAR: &str = "\x1b[5 q";
+        const DEFAULT_SHAPE: &str = "\x1b[0 q";
+        let mut terminal = Terminal::new_headless(10, 2);
+        let output = |terminal: &mut Terminal| String::from_utf8(terminal.take_output()).unwrap();
+
+        terminal.show_cursor(1, 1).unwrap();
+        assert!(!output(&mut terminal).contains(" q"), "the default style is never sent unasked");
+
+        terminal.set_cursor_style(CursorStyle::Bar { blink: true });
+        terminal.show_cursor(2, 1).unwrap();
+        assert!(output(&mut terminal).contains(BLINKING_BAR));
+        terminal.show_cursor(3, 1).unwrap();
+        assert!(!output(&mut terminal).contains(BLINKING_BAR), "unchanged style is not resent");
+
+        terminal.shutdown().unwrap();
+        assert!(output(&mut terminal).contains(DEFAULT_SHAPE));
+    }
+
+    #[test]
+    fn test_synchronized_output_detection() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| vars.iter().find(|(n, _)| *n == name).map(|(_, v)| v.to_string())
+        };
+        assert!(supports_synchronized_output(env(&[("TERM", "xterm-kitty")])));
+        assert!(supports_synchronized_output(env(&[("TERM_PROGRAM", "iTerm.app"), ("TERM", "xterm-256color")])));
+        assert!(supports_synchronized_output(env(&[("WEZTERM_EXECUTABLE", "/usr/bin/wezterm-gui")])));
+        assert!(!supports_synchronized_output(env(&[("TERM", "xterm-256color")])));
+        assert!(!supports_synchronized_output(env(&[("TERM_PROGRAM", "Apple_Terminal"), ("KITTY_WINDOW_ID", "")])));
+    }
+
+    #[test]
+    fn test_custom_backend_receives_only_changed_runs() {
+        let backend = RecordingBackend::new(10, 3);
+        let recording = backend.recording();
+        let mut terminal = Terminal::with_backend(Box::new(backend), TerminalOptions::default()).unwrap();
+        assert_eq!(terminal.size(), (10, 3), "sized by the backend");
+
+        terminal.flush().unwrap();
+        recording.borrow_mut().runs.clear();
+
+        let attr = Attr::from_u8(0x1F);
+        terminal.write_line(2, 1, &[Cell::new('h', attr), Cell::new('i', attr)]);
+        terminal.write_cell(5, 1, Cell::new('!', Attr::from_u8(0x4E)));
+        terminal.flush().unwrap();
+        assert_eq!(recording.borrow().runs, vec![(2, 1, "hi".to_string()), (5, 1, "!".to_string())]);
+
+        recording.borrow_mut().runs.clear();
+        terminal.flush().unwrap();
+        assert!(recording.borrow().runs.is_empty(), "unchanged cells are not sent again");
+    }
+
+    #[test]
+    fn test_set_mouse_enabled_toggles_capture() {
+        let mut terminal = Terminal::new_headless(10, 4);
+        assert!(terminal.mouse_enabled());
+
+        terminal.set_mouse_enabled(false).unwrap();
+        assert!(!terminal.mouse_enabled());
+        assert!(String::from_utf8_lossy(&terminal.take_output()).contains("\x1b[?1000l"));
+
+        // No-op when already disabled
+        terminal.set_mouse_enabled(false).unwrap();
+        assert!(terminal.take_output().is_empty());
+
+        terminal.set_mouse_enabled(true).unwrap();
+        assert!(String::from_utf8_lossy(&terminal.take_output()).contains("\x1b[?1000h"));
+    }
+
+    #[test]
+    fn test_key_translation_applied_before_builtin_mapping() {
+        use crate::core::event::{KB_F1, KB_HOME};
+        use crossterm::event::{KeyCode as CKC, KeyModifiers};
+
+        let mut terminal = Terminal::new_headless(10, 4);
+        let f13 = KeyEvent::new(CKC::F(13), KeyModifiers::NONE);
+        assert_eq!(terminal.translate_key(f13).0, 0); // Dead key without a fixup
+
+        terminal.add_key_translation(KeyPattern::new(CKC::F(13), KeyModifiers::NONE), KB_F1);
+        assert_eq!(terminal.translate_key(f13).0, KB_F1);
+        assert_eq!(terminal.take_raw_key(), Some(f13));
+        assert_eq!(terminal.take_raw_key(), None);
+
+        // Translations override the built-in mapping
+        terminal.add_key_translation(KeyPattern::new(CKC::Char('h'), KeyModifiers::CONTROL), KB_HOME);
+        assert_eq!(terminal.translate_key(KeyEvent::new(CKC::Char('h'), KeyModifiers::CONTROL)).0, KB_HOME);
+
+        // An ESC before a translated key is forgotten, not paired with the next letter
+        assert_eq!(terminal.translate_key(KeyEvent::new(CKC::Esc, KeyModifiers::NONE)).0, 0);
+        assert_eq!(terminal.translate_key(f13).0, KB_F1);
+        assert_eq!(terminal.translate_key(KeyEvent::new(CKC::Char('f'), KeyModifiers::NONE)).0, 'f' as KeyCode);
+    }
+
+    #[test]
+    fn test_kitty_keyboard_bypasses_esc_letter_emulation() {
+        use crate::core::event::{KB_ALT_F, KB_ENTER, KB_ESC_ESC};
+        use crossterm::event::{KeyCode as CKC, KeyModifiers};
+
+        let key = |code, modifiers| KeyEvent::new(code, modifiers);
+        let esc = key(CKC::Esc, KeyModifiers::NONE);
+        let f = key(CKC::Char('f'), KeyModifiers::NONE);
+
+        // Legacy encoding: ESC then F is Alt+F
+        let mut terminal = Terminal::new_headless(10, 4);
+        assert!(!terminal.keyboard_enhanced(), "headless terminals never enable it");
+        assert_eq!(terminal.translate_key(esc).0, 0);
+        assert_eq!(terminal.translate_key(f), (KB_ALT_F, KeyModifiers::ALT));
+
+        terminal.keyboard_enhanced = true;
+        assert_eq!(terminal.translate_key(esc).0, 0);
+        assert_eq!(terminal.translate_key(f), ('f' as KeyCode, KeyModifiers::NONE));
+        assert_eq!(terminal.translate_key(key(CKC::Char('f'), KeyModifiers::ALT)), (KB_ALT_F, KeyModifiers::ALT));
+        assert_eq!(terminal.translate_key(key(CKC::Enter, KeyModifiers::CONTROL)), (KB_ENTER, KeyModifiers::CONTROL));
+        assert_eq!(terminal.translate_key(esc).0, 0);
+        assert_eq!(terminal.translate_key(esc).0, KB_ESC_ESC);
+    }
+
+    #[test]
+    fn test_damage_single_cell() {
+        let mut terminal = Terminal::new_headless(20, 10);
+        terminal.flush().unwrap();
+        terminal.take_damage();
+
+        terminal.write_cell(3, 4, Cell::new('x', Attr::from_u8(0x07)));
+        terminal.flush().unwrap();
+        assert_eq!(terminal.take_damage(), vec![DamageRect::new(3, 4, 1, 1)]);
+    }
+
+    #[test]
+    fn test_damage_empty_for_noop_frame() {
+        let mut terminal = Terminal::new_headless(20, 10);
+        terminal.write_cell(3, 4, Cell::new('x', Attr::from_u8(0x07)));
+        terminal.flush().unwrap();
+        terminal.take_damage();
+
+        let calls = std::rc::Rc::new(std::cell::Cell::new(0));
+        let counter = calls.clone();
+        terminal.set_damage_listener(Box::new(move |_| counter.set(counter.get() + 1)));
+
+        terminal.write_cell(3, 4, Cell::new('x', Attr::from_u8(0x07)));
+        terminal.flush().unwrap();
+        assert!(terminal.take_damage().is_empty());
+        assert_eq!(calls.get(), 0);
+    }
+
+    #[test]
+    fn test_damage_dialog_appearance() {
+        use crate::core::geometry::Rect;
+        use crate::views::dialog::Dialog;
+        use crate::views::View;
+
+        let mut terminal = Terminal::new_headless(40, 20);
+        terminal.flush().unwrap();
+        terminal.take_damage();
+
+        let frames = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let sink = frames.clone();
+        terminal.set_damage_listener(Box::new(move |rects| sink.borrow_mut().push(rects.to_vec())));
+
+        let bounds = Rect::new(5, 3, 25, 10);
+        let mut dialog = Dialog::new(bounds, "Damage");
+        dialog.draw(&mut terminal);
+        terminal.flush().unwrap();
+        let damage = terminal.take_damage();
+
+        assert_eq!(*frames.borrow(), vec![damage.clone()]);
+        // Every dialog cell is damaged; nothing outside the dialog and its shadow
+        for y in 0..20u16 {
+            for x in 0..40u16 {
+                let damaged = damage.iter().any(|r| r.contains(x, y));
+                let in_dialog = bounds.contains(crate::core::geometry::Point::new(x as i16, y as i16));
+                let in_shadow_area = x >= 5 && x < 27 && y >= 3 && y < 11;
+                if in_dialog {
+                    assert!(damaged, "dialog cell ({x}, {y}) not damaged");
+                } else if !in_shadow_area {
+                    assert!(!damaged, "cell ({x}, {y}) outside the dialog damaged");
+                }
+            }
+        }
+        // Rows of the dialog body coalesce into a few rectangles
+        assert!(damage.len() <= 4, "damage not coalesced: {damage:?}");
+    }
+
+    fn fill(terminal: &mut Terminal, ch: char) {
+        let (width, height) = terminal.size();
+        for y in 0..height as u16 {
+            for x in 0..width as u16 {
+                terminal.write_cell(x, y, Cell::new(ch, Attr::from_u8(0x07)));
+            }
+        }
+    }
+
+    /// Raw characters of row `y`, wide-character continuations included
+    fn row_chars(terminal: &Terminal, y: usize) -> String {
+        terminal.buffer()[y].iter().map(|c| c.ch).collect()
+    }
+
+    #[test]
+    fn test_save_under_restores_stacked_regions_in_reverse() {
+        use crate::core::geometry::Rect;
+
+        let mut terminal = Terminal::new_headless(10, 4);
+        fill(&mut terminal, '.');
+        terminal.flush().unwrap();
+
+        terminal.save_under(Rect::new(1, 0, 6, 3));
+        for x in 1..6 {
+            terminal.write_cell(x, 1, Cell::new('A', Attr::from_u8(0x07)));
+        }
+        terminal.save_under(Rect::new(4, 1, 9, 2));
+        for x in 4..9 {
+            terminal.write_cell(x, 1, Cell::new('B', Attr::from_u8(0x07)));
+        }
+        terminal.flush().unwrap();
+        terminal.take_output();
+        assert_eq!(row_chars(&terminal, 1), ".AAABBBBB.");
+        assert_eq!(terminal.save_under_depth(), 2);
+
+        assert!(terminal.restore_under().unwrap());
+        assert_eq!(row_chars(&terminal, 1), ".AAAAA....");
+        assert!(!terminal.take_output().is_empty(), "restore must be flushed");
+
+        assert!(terminal.restore_under().unwrap());
+        assert_eq!(row_chars(&terminal, 1), "..........");
+        assert!(!terminal.restore_under().unwrap());
+    }
+
+    #[test]
+    fn test_save_under_clamps_to_screen() {
+        use crate::core::geometry::Rect;
+
+        let mut terminal = Terminal::new_headless(10, 4);
+        fill(&mut terminal, '.');
+        terminal.save_under(Rect::new(7, 2, 14, 8));
+        fill(&mut terminal, '#');
+        terminal.restore_under().unwrap();
+        assert_eq!(row_chars(&terminal, 3), "#######...");
+        assert_eq!(row_chars(&terminal, 1), "##########");
+    }
+
+    #[test]
+    fn test_restore_under_after_shrinking_past_the_region() {
+        use crate::core::geometry::Rect;
+
+        let mut terminal = Terminal::new_headless(80, 25);
+        fill(&mut terminal, '.');
+        terminal.save_under(Rect::new(60, 5, 75, 10));
+        terminal.save_under(Rect::new(30, 5, 50, 10));
+        terminal.resize(40, 25);
+        fill(&mut terminal, '#');
+
+        // The region that now ends past the right edge is cut, the one beyond it skipped
+        assert!(terminal.restore_under().unwrap());
+        assert_eq!(row_chars(&terminal, 5), format!("{}{}", "#".repeat(30), ".".repeat(10)));
+        assert!(terminal.restore_under().unwrap());
+        assert_eq!(terminal.save_under_depth(), 0);
+    }
+
+    #[test]
+    fn test_viewport_translates_output() {
+        let mut terminal = Terminal::new_headless(80, 25).with_viewport(Point::new(10, 5), Point::new(20, 8));
+        assert_eq!(terminal.size(), (20, 8));
+
+        terminal.write_cell(0, 0, Cell::new('A', Attr::from_u8(0x1F)));
+        terminal.flush().unwrap();
+        let output = String::from_utf8_lossy(&terminal.take_output()).into_owned();
+
+        // Logical (0,0) lands at physical (10,5) - row 6, column 11 in 1-based ANSI
+        assert!(output.contains("\x1b[6;11H"));
+        assert!(!output.contains("\x1b[1;1H"));
+    }
+
+    #[test]
+    fn test_viewport_translates_mouse() {
+        use crossterm::event::{KeyModifiers, MouseEvent as CTMouseEvent};
+
+        let mut terminal = Terminal::new_headless(80, 25).with_viewport(Point::new(10, 5), Point::new(20, 8));
+        let event = terminal
+            .convert_mouse_event(CTMouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column: 12,
+                row: 6,
+                modifiers: KeyModifiers::CONTROL,
+            })
+            .unwrap();
+        assert_eq!(event.mouse.pos, Point::new(2, 1));
+        assert_eq!(event.mouse.modifiers, KeyModifiers::CONTROL, "modifiers are kept");
+    }
+
+    #[test]
+    fn test_mouse_outside_viewport_is_dropped_or_clipped() {
+        use crossterm::event::{KeyModifiers, MouseEvent as CTMouseEvent};
+
+        let mut terminal = Terminal::new_headless(80, 25).with_viewport(Point::new(10, 5), Point::new(20, 8));
+        let mut mouse = |kind, column, row| {
+            terminal.convert_mouse_event(CTMouseEvent { kind, column, row, modifiers: KeyModifiers::NONE })
+        };
+
+        // Left of and below the viewport: nothing for the UI
+        assert!(mouse(MouseEventKind::Down(MouseButton::Left), 3, 6).is_none());
+        assert!(mouse(MouseEventKind::ScrollDown, 12, 20).is_none());
+        assert!(mouse(MouseEventKind::Moved, 40, 6).is_none());
+        assert!(mouse(MouseEventKind::Up(MouseButton::Left), 3, 6).is_none());
+
+        // A drag started inside is clipped to the edge, up to its release
+        assert!(mouse(MouseEventKind::Down(MouseButton::Left), 12, 6).is_some());
+        let drag = mouse(MouseEventKind::Drag(MouseButton::Left), 50, 2).unwrap();
+        assert_eq!((drag.what, drag.mouse.pos), (EventType::MouseMove, Point::new(19, 0)));
+        let up = mouse(MouseEventKind::Up(MouseButton::Left), 0, 30).unwrap();
+        assert_eq!((up.what, up.mouse.pos), (EventType::MouseUp, Point::new(0, 7)));
+    }
+
+    #[test]
+    fn test_double_click_detection() {
+        let mut terminal = Terminal::new_headless(80, 25);
+        let start = Instant::now();
+        let ms = Duration::from_millis;
+
+        // Second press one cell away, within the time: double-click
+        assert!(!terminal.register_click(Point::new(5, 5), 1, start));
+        assert!(terminal.register_click(Point::new(6, 4), 1, start + ms(300)));
+        // A third press starts a new click sequence
+        assert!(!terminal.register_click(Point::new(6, 4), 1, start + ms(350)));
+
+        // Too slow, too far, or another button: single clicks
+        assert!(!terminal.register_click(Point::new(6, 4), 1, start + ms(900)));
+        assert!(!terminal.register_click(Point::new(8, 4), 1, start + ms(1000)));
+        assert!(!terminal.register_click(Point::new(8, 4), 2, start + ms(1100)));
+
+        terminal.set_double_click_time(ms(1000));
+        assert!(terminal.register_click(Point::new(8, 4), 2, start + ms(1900)));
+    }
+
+    #[test]
+    fn test_wheel_lines_per_notch() {
+        use crossterm::event::{KeyModifiers, MouseEvent as CTMouseEvent};
+
+        let mut terminal = Terminal::new_headless(80, 25);
+        let scroll = |terminal: &mut Terminal, kind| {
+            terminal
+                .convert_mouse_event(CTMouseEvent { kind, column: 3, row: 4, modifiers: KeyModifiers::NONE })
+                .unwrap()
+        };
+
+        let event = scroll(&mut terminal, MouseEventKind::ScrollDown);
+        assert_eq!((event.what, event.mouse.wheel_delta), (EventType::MouseWheelDown, 3));
+
+        terminal.set_wheel_lines(5);
+        let event = scroll(&mut terminal, MouseEventKind::ScrollUp);
+        assert_eq!((event.what, event.mouse.wheel_delta), (EventType::MouseWheelUp, -5));
+        assert_eq!(event.mouse.pos, Point::new(3, 4));
+    }
+
+    #[test]
+    fn test_viewport_clamped_on_resize() {
+        let mut terminal = Terminal::new_headless(80, 25).with_viewport(Point::new(10, 5), Point::new(20, 8));
+        terminal.push_input(Event::resize(25, 10));
+
+        let event = terminal.poll_event(Duration::ZERO).unwrap().unwrap();
+        assert_eq!(event.what, EventType::Resize);
+        assert_eq!(terminal.size(), (15, 5));
+        assert_eq!(event.mouse.pos, Point::new(15, 5));
+
+        terminal.clear_viewport();
+        assert_eq!(terminal.size(), (25, 10));
+    }
+
+    #[test]
+    fn test_put_event_queues_in_order_ahead_of_input() {
+        use crate::core::command::{CM_CLOSE, CM_OK};
+        use crate::core::event::{KB_ENTER, KB_ESC};
+
+        let mut terminal = Terminal::new_headless(80, 25);
+        terminal.push_input(Event::keyboard(KB_ESC));
+        terminal.put_event(Event::command(CM_OK));
+        terminal.put_event(Event::command(CM_CLOSE));
+        terminal.put_event(Event::keyboard(KB_ENTER));
+
+        let mut events = Vec::new();
+        while let Some(event) = terminal.poll_event(Duration::ZERO).unwrap() {
+            events.push((event.what, event.command, event.key_code));
+        }
+        assert_eq!(
+            events,
+            vec![
+                (EventType::Command, CM_OK, 0),
+                (EventType::Command, CM_CLOSE, 0),
+                (EventType::Keyboard, 0, KB_ENTER),
+                (EventType::Keyboard, 0, KB_ESC),
+            ]
+        );
+
+        terminal.put_event(Event::command(CM_OK));
+        assert_eq!(terminal.read_event().unwrap().command, CM_OK);
+    }
+}
@@ -0,0 +1,73 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Unix job control: stopping the process on Ctrl+Z and noticing when it continues.
+//!
+//! Raw mode turns off the terminal's own Ctrl+Z handling, so the application
+//! restores the screen and raises SIGTSTP itself. A SIGCONT after a stop from
+//! outside (`kill -TSTP`, `kill -STOP`) is recorded so the next poll can take
+//! the screen back. Windows has no job control: nothing is ever recorded and
+//! [`stop_process()`] returns at once.
+
+use std::io;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Records SIGCONT deliveries until dropped
+pub(super) struct ContinueWatch {
+    continued: Arc<AtomicBool>,
+    #[cfg(unix)]
+    id: signal_hook::SigId,
+}
+
+impl ContinueWatch {
+    /// Installs the SIGCONT handler; `None` if it cannot be installed
+    #[cfg(unix)]
+    pub(super) fn new() -> Option<Self> {
+        let continued = Arc::new(AtomicBool::new(false));
+        let id = signal_hook::flag::register(signal_hook::consts::SIGCONT, Arc::clone(&continued)).ok()?;
+        Some(Self { continued, id })
+    }
+
+    /// Without job control nothing ever continues the process
+    #[cfg(not(unix))]
+    pub(super) fn new() -> Option<Self> {
+        Some(Self { continued: Arc::new(AtomicBool::new(false)) })
+    }
+
+    /// True once for each SIGCONT received since the last call
+    pub(super) fn take(&self) -> bool {
+        self.continued.swap(false, Ordering::Relaxed)
+    }
+}
+
+#[cfg(unix)]
+impl Drop for ContinueWatch {
+    fn drop(&mut self) {
+        signal_hook::low_level::unregister(self.id);
+    }
+}
+
+/// Stops the process as the terminal's Ctrl+Z would; returns once it is continued
+///
+/// # Errors
+///
+/// Returns the OS error if the signal cannot be raised.
+pub(crate) fn stop_process() -> io::Result<()> {
+    #[cfg(unix)]
+    signal_hook::low_level::raise(signal_hook::consts::SIGTSTP)?;
+    Ok(())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_continue_watch_records_sigcont_once() {
+        let watch = ContinueWatch::new().unwrap();
+        assert!(!watch.take());
+        signal_hook::low_level::raise(signal_hook::consts::SIGCONT).unwrap();
+        assert!(watch.take());
+        assert!(!watch.take());
+    }
+}
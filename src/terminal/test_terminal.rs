@@ -0,0 +1,182 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Test terminal - a headless [`Terminal`] with assertion helpers for UI tests.
+//!
+//! [`TestTerminal`] renders into memory through a
+//! [`HeadlessBackend`](super::HeadlessBackend): no raw mode, no alternate
+//! screen, so tests run in CI. Views draw on it like on any terminal (it
+//! derefs to [`Terminal`]); tests then read back cells and text, and queue
+//! synthetic events that `poll_event()` returns in order.
+//!
+//! For a quick look at what a single view draws, [`render_to_cells()`] draws
+//! it once on a fresh headless terminal and returns the cells.
+//!
+//! Only available in tests or with the `test-util` feature.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use turbo_vision::terminal::TestTerminal;
+//! use turbo_vision::core::event::KB_TAB;
+//!
+//! let mut terminal = TestTerminal::new(80, 25);
+//! terminal.push_key(KB_TAB);
+//! terminal.dispatch_events(&mut dialog);
+//! let (x, y) = terminal.find_text("Cancel").unwrap();
+//! assert_eq!(terminal.cell_at(x, y).attr, focused_button_attr);
+//! ```
+
+use super::Terminal;
+use crate::core::draw::Cell;
+use crate::core::event::{Event, EventType, KeyCode};
+use crate::views::View;
+use std::ops::{Deref, DerefMut};
+use std::time::Duration;
+
+/// Headless terminal for automated UI tests
+pub struct TestTerminal {
+    terminal: Terminal,
+}
+
+impl TestTerminal {
+    /// Creates a headless terminal of the given size
+    pub fn new(width: u16, height: u16) -> Self {
+        Self { terminal: Terminal::new_headless(width, height) }
+    }
+
+    /// Cell at `(x, y)` in the screen buffer
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is off screen.
+    pub fn cell_at(&self, x: u16, y: u16) -> Cell {
+        self.terminal
+            .read_cell(x as i16, y as i16)
+            .unwrap_or_else(|| panic!("cell ({x}, {y}) is off screen"))
+    }
+
+    /// Text of row `y`, trailing blanks included (wide characters appear once)
+    pub fn line_text(&self, y: u16) -> String {
+        row_text(&self.terminal.buffer()[y as usize])
+    }
+
+    /// Whole screen as text, one line per row
+    pub fn screen_text(&self) -> String {
+        let (_, height) = self.terminal.size();
+        (0..height as u16).map(|y| self.line_text(y) + "\n").collect()
+    }
+
+    /// Position of the first occurrence of `text`, searching row by row
+    pub fn find_text(&self, text: &str) -> Option<(u16, u16)> {
+        let (_, height) = self.terminal.size();
+        (0..height as u16).find_map(|y| {
+            let line: Vec<char> = self.line_text(y).chars().collect();
+            let needle: Vec<char> = text.chars().collect();
+            let x = line.windows(needle.len()).position(|window| window == needle.as_slice())?;
+            // Columns, not characters: account for wide characters before the match
+            let column = self.terminal.buffer()[y as usize]
+                .iter()
+                .enumerate()
+                .filter(|(_, cell)| !cell.is_continuation())
+                .nth(x)
+                .map(|(column, _)| column)?;
+            Some((column as u16, y))
+        })
+    }
+
+    /// Queue a synthetic event for `poll_event()`
+    pub fn push_event(&mut self, event: Event) {
+        self.terminal.push_input(event);
+    }
+
+    /// Queue a key press for `poll_event()`
+    pub fn push_key(&mut self, key_code: KeyCode) {
+        self.push_event(Event::keyboard(key_code));
+    }
+
+    /// Deliver every queued event to `view`, then redraw it
+    ///
+    /// Returns the events the view left unhandled, in order.
+    pub fn dispatch_events(&mut self, view: &mut dyn View) -> Vec<Event> {
+        let mut unhandled = Vec::new();
+        while let Ok(Some(mut event)) = self.terminal.poll_event(Duration::ZERO) {
+            view.handle_event(&mut event);
+            if event.what != EventType::Nothing {
+                unhandled.push(event);
+            }
+        }
+        view.draw(&mut self.terminal);
+        unhandled
+    }
+
+    /// The wrapped terminal, e.g. to hand it to an `Application`
+    pub fn into_inner(self) -> Terminal {
+        self.terminal
+    }
+}
+
+/// Draw `view` once on a `width` x `height` headless terminal and return the cells
+///
+/// The view keeps its own bounds (absolute screen coordinates), so
+/// `cells[y][x]` is the cell at screen position `(x, y)`.
+pub fn render_to_cells(view: &mut dyn View, width: u16, height: u16) -> Vec<Vec<Cell>> {
+    let mut terminal = Terminal::new_headless(width, height);
+    view.draw(&mut terminal);
+    terminal.buffer().to_vec()
+}
+
+/// Text of a row of cells (wide characters appear once)
+pub fn row_text(row: &[Cell]) -> String {
+    row.iter().filter(|cell| !cell.is_continuation()).map(|cell| cell.ch).collect()
+}
+
+impl Deref for TestTerminal {
+    type Target = Terminal;
+
+    fn deref(&self) -> &Terminal {
+        &self.terminal
+    }
+}
+
+impl DerefMut for TestTerminal {
+    fn deref_mut(&mut self) -> &mut Terminal {
+        &mut self.terminal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::command::CM_CANCEL;
+    use crate::core::event::{KB_ENTER, KB_TAB};
+    use crate::core::geometry::Rect;
+    use crate::core::state::SF_MODAL;
+    use crate::views::dialog::{ButtonSet, Dialog, standard_buttons};
+
+    #[test]
+    fn test_tab_moves_focus_between_dialog_buttons() {
+        let mut dialog = Dialog::new(Rect::new(0, 0, 40, 10), "Confirm");
+        standard_buttons(&mut dialog, ButtonSet::OkCancel);
+        dialog.set_initial_focus();
+        dialog.set_state(dialog.state() | SF_MODAL);
+
+        let mut terminal = TestTerminal::new(40, 10);
+        dialog.draw(&mut terminal);
+        assert!(terminal.line_text(0).contains("Confirm"));
+        let (ok_x, ok_y) = terminal.find_text("OK").unwrap();
+        // Past the highlighted shortcut letter
+        let (cancel_x, cancel_y) = terminal.find_text("ancel").unwrap();
+        let focused = terminal.cell_at(ok_x, ok_y).attr;
+        let unfocused = terminal.cell_at(cancel_x, cancel_y).attr;
+        assert_ne!(focused, unfocused);
+
+        terminal.push_key(KB_TAB);
+        assert!(terminal.dispatch_events(&mut dialog).is_empty());
+        assert_eq!(terminal.cell_at(cancel_x, cancel_y).attr, focused);
+        assert_ne!(terminal.cell_at(ok_x, ok_y).attr, focused);
+
+        terminal.push_key(KB_ENTER);
+        terminal.dispatch_events(&mut dialog);
+        assert_eq!(dialog.get_end_state(), CM_CANCEL);
+    }
+}
@@ -5,6 +5,9 @@
 //! This module provides mock implementations and testing helpers that are only
 //! available when the `test-util` feature is enabled.
 //!
+//! To render real views, use [`TestTerminal`](crate::terminal::TestTerminal)
+//! instead: it is a headless [`Terminal`](crate::terminal::Terminal) views can draw on.
+//!
 //! # Examples
 //!
 //! Enable the feature in your `Cargo.toml`:
@@ -0,0 +1,271 @@
+// (C) 2025 - Enzo Lombardi
+
+//! ActivityIndicator view - animated spinner for operations of unknown length.
+//!
+//! Shows a one-cell animation (`|/-\` or braille dots) followed by an
+//! optional label. It only animates while started; when stopped it draws
+//! nothing, so whatever lies beneath it (usually the status line) shows
+//! through from the next full redraw on.
+//!
+//! The indicator is an [`IdleView`]: add it with
+//! [`Application::add_overlay_widget()`](crate::app::Application::add_overlay_widget)
+//! to place it anywhere on screen (a status line slot, a window frame
+//! corner) and it is animated by the application's idle timer, even during
+//! modal dialogs. Inside a dialog it also advances every time it is drawn.
+//! Keep an [`ActivityHandle`] to start and stop it after it has been boxed.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use turbo_vision::app::Application;
+//! use turbo_vision::core::geometry::Rect;
+//! use turbo_vision::views::activity_indicator::ActivityIndicator;
+//!
+//! let mut app = Application::new()?;
+//! let (width, height) = app.terminal.size();
+//! let mut spinner = ActivityIndicator::new(Rect::new(width - 12, height - 1, width, height));
+//! spinner.set_label("Scanning");
+//! let activity = spinner.handle();
+//! app.add_overlay_widget(Box::new(spinner));
+//!
+//! activity.start();
+//! // ... slow work ...
+//! activity.stop();
+//! # Ok::<(), turbo_vision::core::error::TurboVisionError>(())
+//! ```
+
+use super::view::{write_line_to_terminal, IdleView, View};
+use crate::core::draw::DrawBuffer;
+use crate::core::event::Event;
+use crate::core::geometry::Rect;
+use crate::core::palette::{colors, Attr};
+use crate::core::state::StateFlags;
+use crate::terminal::Terminal;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// Default time between animation frames
+const DEFAULT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Animation frames
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpinnerStyle {
+    /// `| / - \`, works on any terminal
+    #[default]
+    Ascii,
+    /// Rotating braille dots
+    Braille,
+}
+
+impl SpinnerStyle {
+    fn frames(self) -> &'static [char] {
+        match self {
+            SpinnerStyle::Ascii => &['|', '/', '-', '\\'],
+            SpinnerStyle::Braille => &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'],
+        }
+    }
+}
+
+/// Starts and stops an [`ActivityIndicator`] from outside the view tree
+#[derive(Debug, Clone, Default)]
+pub struct ActivityHandle {
+    active: Rc<Cell<bool>>,
+}
+
+impl ActivityHandle {
+    pub fn start(&self) {
+        self.active.set(true);
+    }
+
+    pub fn stop(&self) {
+        self.active.set(false);
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active.get()
+    }
+}
+
+/// Indeterminate activity indicator (spinner)
+pub struct ActivityIndicator {
+    bounds: Rect,
+    label: String,
+    style: SpinnerStyle,
+    interval: Duration,
+    attr: Attr,
+    activity: ActivityHandle,
+    frame: usize,
+    last_frame: Instant,
+    state: StateFlags,
+}
+
+impl ActivityIndicator {
+    /// Creates a stopped indicator
+    pub fn new(bounds: Rect) -> Self {
+        Self {
+            bounds,
+            label: String::new(),
+            style: SpinnerStyle::default(),
+            interval: DEFAULT_INTERVAL,
+            attr: colors::STATUS_NORMAL,
+            activity: ActivityHandle::default(),
+            frame: 0,
+            last_frame: Instant::now(),
+            state: 0,
+        }
+    }
+
+    /// Text drawn after the spinner while it is active
+    pub fn set_label(&mut self, label: &str) {
+        self.label = label.to_string();
+    }
+
+    pub fn set_style(&mut self, style: SpinnerStyle) {
+        self.style = style;
+        self.frame = 0;
+    }
+
+    /// Time between animation frames (default 100 ms)
+    pub fn set_interval(&mut self, interval: Duration) {
+        self.interval = interval;
+    }
+
+    /// Colors used for the spinner and label (default: status line colors)
+    pub fn set_attr(&mut self, attr: Attr) {
+        self.attr = attr;
+    }
+
+    /// A handle that starts and stops this indicator
+    pub fn handle(&self) -> ActivityHandle {
+        self.activity.clone()
+    }
+
+    pub fn start(&mut self) {
+        self.activity.start();
+    }
+
+    pub fn stop(&mut self) {
+        self.activity.stop();
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.activity.is_active()
+    }
+
+    /// The animation frame currently shown
+    pub fn current_frame(&self) -> char {
+        let frames = self.style.frames();
+        frames[self.frame % frames.len()]
+    }
+
+    /// Advance to the next frame if active and the interval has elapsed
+    fn tick(&mut self) {
+        if self.is_active() && self.last_frame.elapsed() >= self.interval {
+            self.frame = (self.frame + 1) % self.style.frames().len();
+            self.last_frame = Instant::now();
+        }
+    }
+}
+
+impl View for ActivityIndicator {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        let width = self.bounds.width_clamped() as usize;
+        if width == 0 || !self.is_active() {
+            return;
+        }
+
+        self.tick();
+        let mut buf = DrawBuffer::new(width);
+        buf.move_char(0, ' ', self.attr, width);
+        buf.put_char(0, self.current_frame(), self.attr);
+        if !self.label.is_empty() {
+            buf.move_str(2, &self.label, self.attr);
+        }
+        write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y, &buf);
+    }
+
+    fn handle_event(&mut self, _event: &mut Event) {}
+
+    fn state(&self) -> StateFlags {
+        self.state
+    }
+
+    fn set_state(&mut self, state: StateFlags) {
+        self.state = state;
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        None
+    }
+}
+
+impl IdleView for ActivityIndicator {
+    fn idle(&mut self) {
+        self.tick();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_animates_only_while_active() {
+        let mut spinner = ActivityIndicator::new(Rect::new(0, 0, 10, 1));
+        spinner.set_interval(Duration::ZERO);
+
+        spinner.idle();
+        assert_eq!(spinner.current_frame(), '|');
+
+        let handle = spinner.handle();
+        handle.start();
+        spinner.idle();
+        assert_eq!(spinner.current_frame(), '/');
+        spinner.idle();
+        spinner.idle();
+        spinner.idle();
+        assert_eq!(spinner.current_frame(), '|', "frames wrap around");
+
+        handle.stop();
+        spinner.idle();
+        assert_eq!(spinner.current_frame(), '|');
+    }
+
+    #[test]
+    fn test_draw_shows_label_and_leaves_the_screen_alone_when_stopped() {
+        let mut terminal = Terminal::new_headless(20, 2);
+        let mut spinner = ActivityIndicator::new(Rect::new(5, 1, 15, 2));
+        spinner.set_label("Reading");
+
+        let status_line = |terminal: &mut Terminal| {
+            let mut buf = DrawBuffer::new(10);
+            buf.move_str(0, "F1 Help", colors::STATUS_NORMAL);
+            write_line_to_terminal(terminal, 5, 1, &buf);
+        };
+
+        // Stopped, the status line text beneath is kept
+        status_line(&mut terminal);
+        spinner.draw(&mut terminal);
+        assert_eq!(terminal.read_cell(5, 1).unwrap().ch, 'F');
+
+        spinner.start();
+        spinner.draw(&mut terminal);
+        assert_eq!(terminal.read_cell(5, 1).unwrap().ch, '|');
+        assert_eq!(terminal.read_cell(7, 1).unwrap().ch, 'R');
+
+        spinner.handle().stop();
+        assert!(!spinner.is_active());
+        status_line(&mut terminal);
+        spinner.draw(&mut terminal);
+        assert_eq!(terminal.read_cell(8, 1).unwrap().ch, 'H');
+    }
+}
@@ -4,17 +4,29 @@
 
 use crate::core::geometry::Rect;
 use crate::core::event::Event;
-use crate::core::draw::DrawBuffer;
+use crate::core::draw::{Cell, DrawBuffer};
 use crate::core::palette::Attr;
 use crate::terminal::Terminal;
 use super::view::{View, write_line_to_terminal};
 
+/// How a wallpaper is placed on the background
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WallpaperMode {
+    /// Centered once; clipped if larger, surrounded by the pattern if smaller
+    #[default]
+    Centered,
+    /// Repeated from the top-left corner to fill the background
+    Tiled,
+}
+
 /// Background view - fills its bounds with a pattern character
 /// Matches Borland's TBackground (tbackgro.cc)
 pub struct Background {
     bounds: Rect,
     pattern: char,
     attr: Attr,
+    wallpaper: Option<Vec<Vec<Cell>>>,
+    wallpaper_mode: WallpaperMode,
     owner: Option<*const dyn View>,
 }
 
@@ -24,9 +36,55 @@ impl Background {
             bounds,
             pattern,
             attr,
+            wallpaper: None,
+            wallpaper_mode: WallpaperMode::default(),
             owner: None,
         }
     }
+
+    /// Draw these cells (rows of cells) instead of the pattern; `None` restores the pattern
+    pub fn set_wallpaper(&mut self, wallpaper: Option<Vec<Vec<Cell>>>) {
+        self.wallpaper = wallpaper.filter(|rows| rows.iter().any(|row| !row.is_empty()));
+    }
+
+    pub fn wallpaper(&self) -> Option<&[Vec<Cell>]> {
+        self.wallpaper.as_deref()
+    }
+
+    pub fn set_wallpaper_mode(&mut self, mode: WallpaperMode) {
+        self.wallpaper_mode = mode;
+    }
+
+    pub fn wallpaper_mode(&self) -> WallpaperMode {
+        self.wallpaper_mode
+    }
+
+    /// Wallpaper cell at background-relative (x, y), if the wallpaper covers it
+    fn wallpaper_cell(&self, wallpaper: &[Vec<Cell>], x: i16, y: i16) -> Option<Cell> {
+        let height = wallpaper.len() as i16;
+        let (x, y) = match self.wallpaper_mode {
+            WallpaperMode::Centered => {
+                let width = wallpaper.iter().map(Vec::len).max().unwrap_or(0) as i16;
+                (x - (self.bounds.width() - width) / 2, y - (self.bounds.height() - height) / 2)
+            }
+            WallpaperMode::Tiled => (x, y.rem_euclid(height)),
+        };
+        let row = wallpaper.get(usize::try_from(y).ok()?)?;
+        let x = match self.wallpaper_mode {
+            WallpaperMode::Centered => usize::try_from(x).ok()?,
+            WallpaperMode::Tiled => x.rem_euclid(row.len().max(1) as i16) as usize,
+        };
+        row.get(x).copied()
+    }
+
+    /// Change the fill character
+    pub fn set_pattern(&mut self, pattern: char) {
+        self.pattern = pattern;
+    }
+
+    pub fn pattern(&self) -> char {
+        self.pattern
+    }
 }
 
 impl View for Background {
@@ -41,10 +99,17 @@ impl View for Background {
     fn draw(&mut self, terminal: &mut Terminal) {
         let width = self.bounds.width_clamped() as usize;
         let mut buf = DrawBuffer::new(width);
-        buf.move_char(0, self.pattern, self.attr, width);
 
-        // Draw every row
+        // Draw every row: the pattern, with the wallpaper (if any) on top
         for y in self.bounds.a.y..self.bounds.b.y {
+            buf.move_char(0, self.pattern, self.attr, width);
+            if let Some(wallpaper) = &self.wallpaper {
+                for x in 0..width {
+                    if let Some(cell) = self.wallpaper_cell(wallpaper, x as i16, y - self.bounds.a.y) {
+                        buf.put_char(x, cell.ch, cell.attr);
+                    }
+                }
+            }
             write_line_to_terminal(terminal, self.bounds.a.x, y, &buf);
         }
     }
@@ -65,6 +130,14 @@ impl View for Background {
         use crate::core::palette::{palettes, Palette};
         Some(Palette::from_slice(palettes::CP_BACKGROUND))
     }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
 }
 
 /// Builder for creating backgrounds with a fluent API.
@@ -2,15 +2,15 @@
 
 //! Button view - clickable button with keyboard shortcuts and command dispatch.
 
-use super::view::{write_line_to_terminal, View};
+use super::view::{shadow_face, write_line_to_terminal, View};
 use crate::core::command::CommandId;
-use crate::core::draw::DrawBuffer;
+use crate::core::draw::{shortcut_hotkey, str_width, strip_shortcut_markers, DrawBuffer};
 use crate::core::event::{Event, EventType, KB_ENTER, MB_LEFT_BUTTON};
-use crate::core::geometry::Rect;
+use crate::core::geometry::{Point, Rect};
 use crate::core::palette::{
     BUTTON_DEFAULT, BUTTON_DISABLED, BUTTON_NORMAL, BUTTON_SELECTED, BUTTON_SHADOW, BUTTON_SHORTCUT,
 };
-use crate::core::state::{StateFlags, SF_DISABLED, SHADOW_BOTTOM, SHADOW_SOLID, SHADOW_TOP};
+use crate::core::state::{StateFlags, SF_DISABLED, SHADOW_BOTTOM, SHADOW_OFFSET, SHADOW_SOLID, SHADOW_TOP};
 use crate::terminal::Terminal;
 
 pub struct Button {
@@ -19,10 +19,16 @@ pub struct Button {
     command: CommandId,
     is_default: bool,
     is_broadcast: bool,
+    /// Dialog falls back to this button on Enter when the default is disabled
+    ok_on_enter: bool,
     state: StateFlags,
     options: u16,
     owner: Option<*const dyn View>,
     owner_type: super::view::OwnerType,
+    /// Left button went down on this button and is still held
+    tracking: bool,
+    /// Mouse is over the button while tracking (drawn pressed)
+    pressed: bool,
 }
 
 impl Button {
@@ -43,10 +49,32 @@ impl Button {
             command,
             is_default,
             is_broadcast: false,
+            ok_on_enter: false,
             state,
             options: OF_POST_PROCESS, // Buttons process in post-process phase
             owner: None,
             owner_type: super::view::OwnerType::Dialog, // Buttons default to Dialog context
+            tracking: false,
+            pressed: false,
+        }
+    }
+
+    /// Returns true while the button is drawn pressed (mouse held down over it)
+    pub fn is_pressed(&self) -> bool {
+        self.pressed
+    }
+
+    /// True if `pos` is on the button face (the shadow line is excluded)
+    fn hit(&self, pos: Point) -> bool {
+        pos.x >= self.bounds.a.x && pos.x < self.bounds.b.x && pos.y >= self.bounds.a.y && pos.y < shadow_face(self.bounds).b.y
+    }
+
+    /// Turn the event into this button's command (or broadcast)
+    fn press(&self, event: &mut Event) {
+        if self.is_broadcast {
+            *event = Event::broadcast(self.command);
+        } else {
+            *event = Event::command(self.command);
         }
     }
 
@@ -64,6 +92,13 @@ impl Button {
         self.is_broadcast = broadcast;
     }
 
+    /// Let Enter press this button when the dialog's default button is disabled
+    ///
+    /// The dialog picks the first enabled button marked this way.
+    pub fn set_ok_on_enter(&mut self, ok_on_enter: bool) {
+        self.ok_on_enter = ok_on_enter;
+    }
+
     /// Set whether this button is selectable (can receive focus)
     /// Matches Borland: ofSelectable flag
     pub fn set_selectable(&mut self, selectable: bool) {
@@ -76,18 +111,9 @@ impl Button {
     }
 
     /// Extract the hotkey character from the button title
-    /// Returns the uppercase character following the first '~', or None if no hotkey
+    /// Returns the first highlighted character in uppercase, or None if no hotkey
     fn get_hotkey(&self) -> Option<char> {
-        let mut chars = self.title.chars();
-        while let Some(ch) = chars.next() {
-            if ch == '~' {
-                // Next character is the hotkey
-                if let Some(hotkey) = chars.next() {
-                    return Some(hotkey.to_uppercase().next().unwrap_or(hotkey));
-                }
-            }
-        }
-        None
+        shortcut_hotkey(&self.title).map(|hotkey| hotkey.to_uppercase().next().unwrap_or(hotkey))
     }
 }
 
@@ -111,6 +137,11 @@ impl View for Button {
             return;
         }
 
+        // The shadow is drawn inside the bounds; the face is what is left
+        let face = shadow_face(self.bounds);
+        let face_width = face.width_clamped() as usize;
+        let face_height = face.height_clamped() as usize;
+
         let is_disabled = self.is_disabled();
         let is_focused = self.is_focused();
 
@@ -165,7 +196,7 @@ impl View for Button {
             let bg_attr = Attr::from_u8(bg_color);
 
             // Shadow is White on owner's background color
-            shadow_attr = Attr::new(crate::core::palette::TvColor::White, bg_attr.bg);
+            shadow_attr = Attr::with_colors(crate::core::palette::TvColor::White.into(), bg_attr.bg);
         }
 
         let shadow_attr = shadow_attr.swap();
@@ -177,8 +208,30 @@ impl View for Button {
             self.map_color(BUTTON_SHORTCUT) // Shortcut color
         };
 
+        // Pressed: the face moves one cell right over its shadow, which is
+        // replaced by the owner's background
+        // Matches Borland: TButton::drawState(True)
+        if self.pressed {
+            let background_attr = shadow_attr.swap();
+            for y in 0..face_height {
+                let mut buf = DrawBuffer::new(width);
+                buf.put_char(0, ' ', background_attr);
+                buf.move_char(1, ' ', button_attr, face_width);
+                if y == face_height / 2 {
+                    let display_len = str_width(&strip_shortcut_markers(&self.title));
+                    let start = face_width.saturating_sub(display_len) / 2;
+                    buf.move_str_with_shortcut(start + 1, &self.title, button_attr, shortcut_attr);
+                }
+                write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y + y as i16, &buf);
+            }
+            let mut bottom_buf = DrawBuffer::new(width);
+            bottom_buf.move_char(0, ' ', background_attr, width);
+            write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y + face_height as i16, &bottom_buf);
+            return;
+        }
+
         // Draw all lines except the last (which is the bottom shadow)
-        for y in 0..(height - 1) {
+        for y in 0..face_height {
             let mut buf = DrawBuffer::new(width);
 
             // Fill entire line with button color
@@ -186,13 +239,13 @@ impl View for Button {
 
             // Right edge gets shadow character and attribute (last column)
             let shadow_char = if y == 0 { SHADOW_TOP } else { SHADOW_SOLID };
-            buf.put_char(width - 1, shadow_char, shadow_attr);
+            buf.put_char(face_width, shadow_char, shadow_attr);
 
             // Draw the label on the middle line
-            if y == (height - 1) / 2 {
+            if y == face_height / 2 {
                 // Calculate display length without tildes
-                let display_len = self.title.chars().filter(|&c| c != '~').count();
-                let content_width = width - 1; // Exclude right shadow column
+                let display_len = str_width(&strip_shortcut_markers(&self.title));
+                let content_width = face_width; // Exclude right shadow column
                 let start = (content_width.saturating_sub(display_len)) / 2;
                 buf.move_str_with_shortcut(start, &self.title, button_attr, shortcut_attr);
             }
@@ -200,14 +253,14 @@ impl View for Button {
             write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y + y as i16, &buf);
         }
 
-        // Draw bottom shadow line (1 char shorter, offset 1 to the right)
-        let mut bottom_buf = DrawBuffer::new(width - 1);
-        // Bottom shadow character across width-1
-        bottom_buf.move_char(0, SHADOW_BOTTOM, shadow_attr, width - 1);
+        // Draw bottom shadow line (shifted right by the shadow offset)
+        let bottom_width = width - SHADOW_OFFSET.0 as usize;
+        let mut bottom_buf = DrawBuffer::new(bottom_width);
+        bottom_buf.move_char(0, SHADOW_BOTTOM, shadow_attr, bottom_width);
         write_line_to_terminal(
             terminal,
-            self.bounds.a.x + 1,
-            self.bounds.a.y + (height - 1) as i16,
+            self.bounds.a.x + SHADOW_OFFSET.0,
+            self.bounds.a.y + face_height as i16,
             &bottom_buf,
         );
     }
@@ -266,11 +319,7 @@ impl View for Button {
 
                     if key_char_upper == hotkey {
                         // Hotkey matched! Activate button
-                        if self.is_broadcast {
-                            *event = Event::broadcast(self.command);
-                        } else {
-                            *event = Event::command(self.command);
-                        }
+                        self.press(event);
                         return;
                     }
                 }
@@ -280,29 +329,28 @@ impl View for Button {
                     return;
                 }
                 if event.key_code == KB_ENTER || event.key_code == ' ' as u16 {
-                    if self.is_broadcast {
-                        *event = Event::broadcast(self.command);
-                    } else {
-                        *event = Event::command(self.command);
-                    }
+                    self.press(event);
                 }
             }
-            EventType::MouseDown => {
-                // Check if click is within button bounds
-                let mouse_pos = event.mouse.pos;
-                if event.mouse.buttons & MB_LEFT_BUTTON != 0
-                    && mouse_pos.x >= self.bounds.a.x
-                    && mouse_pos.x < self.bounds.b.x
-                    && mouse_pos.y >= self.bounds.a.y
-                    && mouse_pos.y < self.bounds.b.y - 1
-                // Exclude shadow line
-                {
-                    // Button clicked - generate command or broadcast
-                    if self.is_broadcast {
-                        *event = Event::broadcast(self.command);
-                    } else {
-                        *event = Event::command(self.command);
-                    }
+            // Mouse: press on MouseDown, fire on MouseUp over the button.
+            // Dragging off the button before releasing cancels the click.
+            // Matches Borland: TButton::handleEvent() mouse tracking loop (tbutton.cc:199-214)
+            EventType::MouseDown if event.mouse.buttons & MB_LEFT_BUTTON != 0 && self.hit(event.mouse.pos) => {
+                self.tracking = true;
+                self.pressed = true;
+                event.clear();
+            }
+            EventType::MouseMove if self.tracking => {
+                self.pressed = self.hit(event.mouse.pos);
+                event.clear();
+            }
+            EventType::MouseUp if self.tracking => {
+                self.tracking = false;
+                self.pressed = false;
+                if self.hit(event.mouse.pos) {
+                    self.press(event);
+                } else {
+                    event.clear();
                 }
             }
             _ => {}
@@ -313,6 +361,10 @@ impl View for Button {
         !self.is_disabled()
     }
 
+    fn has_mouse_capture(&self) -> bool {
+        self.tracking
+    }
+
     // set_focus() now uses default implementation from View trait
     // which sets/clears SF_FOCUSED flag
 
@@ -336,6 +388,10 @@ impl View for Button {
         self.is_default
     }
 
+    fn is_ok_on_enter_button(&self) -> bool {
+        self.ok_on_enter
+    }
+
     fn button_command(&self) -> Option<u16> {
         Some(self.command)
     }
@@ -383,6 +439,7 @@ pub struct ButtonBuilder {
     title: Option<String>,
     command: Option<CommandId>,
     is_default: bool,
+    ok_on_enter: bool,
 }
 
 impl ButtonBuilder {
@@ -393,6 +450,7 @@ impl ButtonBuilder {
             title: None,
             command: None,
             is_default: false,
+            ok_on_enter: false,
         }
     }
 
@@ -427,6 +485,13 @@ impl ButtonBuilder {
         self
     }
 
+    /// Sets whether Enter falls back to this button when the default is disabled (optional, defaults to false).
+    #[must_use]
+    pub fn ok_on_enter(mut self, ok_on_enter: bool) -> Self {
+        self.ok_on_enter = ok_on_enter;
+        self
+    }
+
     /// Builds the Button.
     ///
     /// # Panics
@@ -437,7 +502,9 @@ impl ButtonBuilder {
         let title = self.title.expect("Button title must be set");
         let command = self.command.expect("Button command must be set");
 
-        Button::new(bounds, &title, command, self.is_default)
+        let mut button = Button::new(bounds, &title, command, self.is_default);
+        button.set_ok_on_enter(self.ok_on_enter);
+        button
     }
 }
 
@@ -452,7 +519,6 @@ mod tests {
     use super::*;
     use crate::core::command::CM_COMMAND_SET_CHANGED;
     use crate::core::command_set;
-    use crate::core::geometry::Point;
 
     #[test]
     fn test_button_creation_with_disabled_command() {
@@ -589,6 +655,74 @@ mod tests {
         );
     }
 
+    fn left_mouse(what: EventType, x: i16, y: i16) -> Event {
+        Event::mouse(what, Point::new(x, y), crate::core::event::MB_LEFT_BUTTON, false)
+    }
+
+    #[test]
+    fn test_mouse_release_inside_fires() {
+        const TEST_CMD: u16 = 520;
+        command_set::enable_command(TEST_CMD);
+        let mut button = Button::new(Rect::new(0, 0, 10, 2), "Test", TEST_CMD, false);
+
+        // MouseDown only presses the button
+        let mut event = left_mouse(EventType::MouseDown, 5, 0);
+        button.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Nothing);
+        assert!(button.is_pressed());
+        assert!(button.has_mouse_capture());
+
+        let mut event = left_mouse(EventType::MouseUp, 6, 0);
+        button.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Command);
+        assert_eq!(event.command, TEST_CMD);
+        assert!(!button.is_pressed());
+        assert!(!button.has_mouse_capture());
+    }
+
+    #[test]
+    fn test_mouse_release_outside_cancels() {
+        const TEST_CMD: u16 = 521;
+        command_set::enable_command(TEST_CMD);
+        let mut button = Button::new(Rect::new(0, 0, 10, 2), "Test", TEST_CMD, false);
+
+        button.handle_event(&mut left_mouse(EventType::MouseDown, 5, 0));
+        // Dragging off releases the pressed look, dragging back restores it
+        button.handle_event(&mut left_mouse(EventType::MouseMove, 20, 0));
+        assert!(!button.is_pressed());
+        button.handle_event(&mut left_mouse(EventType::MouseMove, 2, 0));
+        assert!(button.is_pressed());
+
+        // Releasing on the shadow line counts as outside
+        let mut event = left_mouse(EventType::MouseUp, 5, 1);
+        button.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Nothing);
+        assert!(!button.is_pressed());
+    }
+
+    #[test]
+    fn test_group_routes_release_outside_to_pressed_button() {
+        use crate::views::group::Group;
+
+        const TEST_CMD: u16 = 522;
+        command_set::enable_command(TEST_CMD);
+        let mut group = Group::new(Rect::new(0, 0, 40, 10));
+        group.add(Box::new(Button::new(Rect::new(2, 2, 12, 4), "Test", TEST_CMD, false)));
+
+        group.handle_event(&mut left_mouse(EventType::MouseDown, 5, 2));
+        assert!(group.has_mouse_capture());
+        let mut event = left_mouse(EventType::MouseUp, 30, 8);
+        group.handle_event(&mut event);
+        assert_ne!(event.what, EventType::Command);
+        assert!(!group.has_mouse_capture());
+
+        group.handle_event(&mut left_mouse(EventType::MouseDown, 5, 2));
+        let mut event = left_mouse(EventType::MouseUp, 5, 2);
+        group.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Command);
+        assert_eq!(event.command, TEST_CMD);
+    }
+
     #[test]
     fn test_broadcast_does_not_clear_event() {
         // Test that CM_COMMAND_SET_CHANGED broadcast is not cleared
@@ -707,4 +841,25 @@ mod tests {
             assert!(bounds.height_clamped() >= 0);
         }
     }
+
+    #[test]
+    fn test_draw_shadow_and_shortcut() {
+        use crate::terminal::{render_to_cells, row_text};
+
+        const TEST_CMD: u16 = 510;
+        command_set::enable_command(TEST_CMD);
+        let mut button = Button::new(Rect::new(0, 0, 12, 3), "~O~K", TEST_CMD, false);
+        let cells = render_to_cells(&mut button, 12, 3);
+
+        // Label centered in the 11 face columns, on the middle face row
+        assert_eq!(row_text(&cells[1]), "    OK     \u{2588}");
+        assert_eq!(cells[1][4].attr, button.map_color(BUTTON_SHORTCUT));
+        assert_eq!(cells[1][5].attr, button.map_color(BUTTON_NORMAL));
+        // Right shadow: half block on the first row, full block below
+        assert_eq!(cells[0][11].ch, SHADOW_TOP);
+        assert_eq!(cells[1][11].ch, SHADOW_SOLID);
+        // Bottom shadow is offset one column to the right
+        assert_eq!(cells[2][0].ch, ' ');
+        assert!(cells[2][1..].iter().all(|cell| cell.ch == SHADOW_BOTTOM));
+    }
 }
@@ -24,7 +24,7 @@ use crate::core::event::Event;
 use crate::core::geometry::Rect;
 use crate::core::state::StateFlags;
 use crate::terminal::Terminal;
-use super::view::View;
+use super::view::{DataValue, View};
 use super::cluster::{Cluster, ClusterState};
 
 /// CheckBox - A boolean selection control with a label
@@ -93,6 +93,16 @@ impl View for CheckBox {
         true
     }
 
+    fn get_data(&self) -> Option<DataValue> {
+        Some(DataValue::Value(self.cluster_state.value))
+    }
+
+    fn set_data(&mut self, value: &DataValue) {
+        if let DataValue::Value(value) = value {
+            self.cluster_state.set_value(*value);
+        }
+    }
+
     fn state(&self) -> StateFlags {
         self.state
     }
@@ -121,6 +131,14 @@ impl View for CheckBox {
     fn set_owner_type(&mut self, owner_type: super::view::OwnerType) {
         self.owner_type = owner_type;
     }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
 }
 
 // Implement Cluster trait
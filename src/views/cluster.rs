@@ -184,7 +184,7 @@ pub trait Cluster: View {
     ///
     /// Provides common drawing logic for all cluster controls
     fn draw_cluster(&self, terminal: &mut crate::terminal::Terminal) {
-        use crate::core::draw::DrawBuffer;
+        use crate::core::draw::{str_width, DrawBuffer};
         use crate::views::view::write_line_to_terminal;
 
         let bounds = self.bounds();
@@ -199,7 +199,7 @@ pub trait Cluster: View {
 
         // Draw label with hotkey support
         let label = self.get_label();
-        buffer.move_str_with_shortcut(marker.len(), label, color, hotkey_color);
+        buffer.move_str_with_shortcut(str_width(marker), label, color, hotkey_color);
 
         write_line_to_terminal(terminal, bounds.a.x, bounds.a.y, &buffer);
     }
@@ -0,0 +1,450 @@
+// (C) 2025 - Enzo Lombardi
+
+//! ComboBox view - input line with a drop-down list of choices.
+//!
+//! Renders like an [`InputLine`] with a `▼` button in the rightmost column.
+//! Clicking the button or pressing Alt+Down opens a [`ListBox`] of choices
+//! below the input line, drawn with a shadow like the menu bar drop-downs.
+//! While it is open, the arrow keys, PgUp/PgDn and Home/End move through the
+//! choices; Enter or a click copies the selected choice into the shared
+//! text, and ESC closes the list without changing it. The user can also
+//! type any value into the input line, so the choices act as presets.
+//!
+//! The list is drawn inside the owner's clip region: leave room for it below
+//! the combo box in the dialog.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use turbo_vision::core::geometry::Rect;
+//! use turbo_vision::views::combobox::ComboBoxBuilder;
+//! use turbo_vision::views::dialog::Dialog;
+//! use std::cell::RefCell;
+//! use std::rc::Rc;
+//!
+//! let encoding = Rc::new(RefCell::new(String::from("UTF-8")));
+//! let mut dialog = Dialog::new(Rect::new(10, 5, 50, 18), "Settings");
+//! dialog.add(
+//!     ComboBoxBuilder::new()
+//!         .bounds(Rect::new(2, 2, 30, 3))
+//!         .data(Rc::clone(&encoding))
+//!         .choices(vec!["UTF-8".into(), "Latin-1".into(), "UTF-16".into()])
+//!         .build_boxed(),
+//! );
+//! ```
+
+use super::input_line::InputLine;
+use super::listbox::ListBox;
+use super::view::{draw_shadow_bounds, write_line_to_terminal, DataValue, View};
+use crate::core::draw::DrawBuffer;
+use crate::core::event::{Event, EventType, KB_ALT_DOWN, KB_ALT_UP, KB_DOWN, KB_END, KB_ENTER, KB_ESC, KB_HOME, KB_PGDN, KB_PGUP, KB_UP, MB_LEFT_BUTTON};
+use crate::core::geometry::Rect;
+use crate::core::state::{StateFlags, SF_FOCUSED};
+use crate::terminal::Terminal;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Rows the drop-down list shows at most before it scrolls
+pub const DEFAULT_DROP_DOWN_ROWS: usize = 8;
+
+// ComboBox palette indices (CP_HISTORY)
+const COMBO_BUTTON: u8 = 1;
+const COMBO_ARROW: u8 = 2;
+
+/// Input line with a drop-down list of choices
+pub struct ComboBox {
+    bounds: Rect,
+    input: InputLine,
+    list: ListBox,
+    choices: Vec<String>,
+    drop_down_rows: usize,
+    open: bool,
+    state: StateFlags,
+    owner: Option<*const dyn View>,
+    owner_type: super::view::OwnerType,
+}
+
+impl ComboBox {
+    /// Creates a combo box editing `data`; the last column of `bounds` holds the `▼` button
+    pub fn new(bounds: Rect, max_length: usize, data: Rc<RefCell<String>>, choices: Vec<String>) -> Self {
+        let input = InputLine::new(Self::input_bounds(bounds), max_length, data);
+        Self {
+            bounds,
+            input,
+            list: ListBox::new(Rect::new(0, 0, 0, 0), 0),
+            choices,
+            drop_down_rows: DEFAULT_DROP_DOWN_ROWS,
+            open: false,
+            state: 0,
+            owner: None,
+            owner_type: super::view::OwnerType::Dialog,
+        }
+    }
+
+    fn input_bounds(bounds: Rect) -> Rect {
+        Rect::new(bounds.a.x, bounds.a.y, bounds.b.x - 1, bounds.a.y + 1)
+    }
+
+    /// Replace the choices; closes the list if it is open
+    pub fn set_choices(&mut self, choices: Vec<String>) {
+        self.choices = choices;
+        self.close();
+    }
+
+    pub fn choices(&self) -> &[String] {
+        &self.choices
+    }
+
+    /// Rows shown at most before the list scrolls (default [`DEFAULT_DROP_DOWN_ROWS`])
+    pub fn set_drop_down_rows(&mut self, rows: usize) {
+        self.drop_down_rows = rows.max(1);
+    }
+
+    pub fn set_text(&mut self, text: String) {
+        self.input.set_text(text);
+    }
+
+    pub fn get_text(&self) -> String {
+        self.input.get_text()
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Open the list below the input line, with the current text's choice selected
+    ///
+    /// Does nothing without choices.
+    pub fn open(&mut self) {
+        if self.open || self.choices.is_empty() {
+            return;
+        }
+        let rows = self.choices.len().min(self.drop_down_rows) as i16;
+        let top = self.bounds.a.y + 1;
+        self.list = ListBox::new(Rect::new(self.bounds.a.x, top, self.bounds.b.x, top + rows), 0);
+        self.list.set_items(self.choices.clone());
+        self.list.show_scrollbar(self.choices.len() > self.drop_down_rows);
+        self.list.set_state(SF_FOCUSED);
+        if let Some(owner) = self.owner {
+            self.list.set_owner(owner);
+        }
+        self.list.set_owner_type(self.owner_type);
+        let text = self.input.get_text();
+        self.list.set_selection(self.choices.iter().position(|choice| *choice == text).unwrap_or(0));
+        self.open = true;
+    }
+
+    /// Close the list without changing the text
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    /// Copy the selected choice into the text and close the list
+    fn pick(&mut self) {
+        if let Some(choice) = self.list.selected_item_text() {
+            let choice = choice.into_owned();
+            self.input.set_text(choice);
+        }
+        self.close();
+    }
+
+    fn on_button(&self, event: &Event) -> bool {
+        event.mouse.pos.x == self.bounds.b.x - 1 && event.mouse.pos.y == self.bounds.a.y
+    }
+
+    /// Events while the list is open: it keeps the keyboard and the mouse until closed
+    fn handle_open_event(&mut self, event: &mut Event) {
+        match event.what {
+            EventType::Keyboard => match event.key_code {
+                KB_ENTER => self.pick(),
+                KB_ESC | KB_ALT_UP => self.close(),
+                KB_UP | KB_DOWN | KB_PGUP | KB_PGDN | KB_HOME | KB_END => {
+                    self.list.handle_event(event);
+                }
+                // Anything else (Tab, typing) closes the list and goes to the input line
+                _ => {
+                    self.close();
+                    return self.input.handle_event(event);
+                }
+            },
+            EventType::MouseDown if event.mouse.buttons & MB_LEFT_BUTTON != 0 => {
+                let list_bounds = self.list.bounds();
+                let on_scrollbar = self.list.has_scrollbar() && event.mouse.pos.x == list_bounds.b.x - 1;
+                if list_bounds.contains(event.mouse.pos) && !on_scrollbar {
+                    let row = (event.mouse.pos.y - list_bounds.a.y) as usize;
+                    self.list.set_selection(self.list.top_item() + row);
+                    self.pick();
+                } else if list_bounds.contains(event.mouse.pos) {
+                    self.list.handle_event(event);
+                } else {
+                    // A click anywhere else, the button included, just closes the list
+                    self.close();
+                }
+            }
+            EventType::MouseMove | EventType::MouseAuto | EventType::MouseUp
+            | EventType::MouseWheelUp | EventType::MouseWheelDown => {
+                self.list.handle_event(event);
+            }
+            _ => return,
+        }
+        event.clear();
+    }
+}
+
+impl View for ComboBox {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+        self.input.set_bounds(Self::input_bounds(bounds));
+        self.close();
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        if self.bounds.width_clamped() < 2 {
+            return;
+        }
+        self.input.draw(terminal);
+
+        let mut buf = DrawBuffer::new(1);
+        buf.move_str(0, "▼", self.map_color(if self.is_focused() { COMBO_ARROW } else { COMBO_BUTTON }));
+        write_line_to_terminal(terminal, self.bounds.b.x - 1, self.bounds.a.y, &buf);
+
+        // Drop-down list below, with a shadow (same pattern as the menu bar drop-downs)
+        if self.open {
+            self.list.draw(terminal);
+            draw_shadow_bounds(terminal, self.list.bounds());
+        }
+    }
+
+    fn handle_event(&mut self, event: &mut Event) {
+        if self.open {
+            self.handle_open_event(event);
+            return;
+        }
+        match event.what {
+            EventType::MouseDown if self.on_button(event) && event.mouse.buttons & MB_LEFT_BUTTON != 0 => {
+                self.open();
+                event.clear();
+            }
+            EventType::Keyboard if event.key_code == KB_ALT_DOWN && self.is_focused() => {
+                self.open();
+                event.clear();
+            }
+            _ => self.input.handle_event(event),
+        }
+    }
+
+    fn can_focus(&self) -> bool {
+        true
+    }
+
+    /// The open list takes every mouse event, so clicks outside it close it
+    fn has_mouse_capture(&self) -> bool {
+        self.open || self.input.has_mouse_capture()
+    }
+
+    fn state(&self) -> StateFlags {
+        self.state
+    }
+
+    fn set_state(&mut self, state: StateFlags) {
+        self.state = state;
+        self.input.set_state(state);
+        if state & SF_FOCUSED == 0 {
+            self.close();
+        }
+    }
+
+    fn update_cursor(&self, terminal: &mut Terminal) {
+        if !self.open {
+            self.input.update_cursor(terminal);
+        }
+    }
+
+    fn get_data(&self) -> Option<DataValue> {
+        self.input.get_data()
+    }
+
+    fn set_data(&mut self, value: &DataValue) {
+        self.input.set_data(value);
+    }
+
+    fn set_owner(&mut self, owner: *const dyn View) {
+        self.owner = Some(owner);
+        self.input.set_owner(owner);
+        self.list.set_owner(owner);
+    }
+
+    fn get_owner(&self) -> Option<*const dyn View> {
+        self.owner
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        use crate::core::palette::{palettes, Palette};
+        Some(Palette::from_slice(palettes::CP_HISTORY))
+    }
+
+    fn get_owner_type(&self) -> super::view::OwnerType {
+        self.owner_type
+    }
+
+    fn set_owner_type(&mut self, owner_type: super::view::OwnerType) {
+        self.owner_type = owner_type;
+        self.input.set_owner_type(owner_type);
+        self.list.set_owner_type(owner_type);
+    }
+}
+
+/// Builder for creating combo boxes with a fluent API.
+pub struct ComboBoxBuilder {
+    bounds: Option<Rect>,
+    data: Option<Rc<RefCell<String>>>,
+    max_length: usize,
+    choices: Vec<String>,
+    drop_down_rows: usize,
+}
+
+impl ComboBoxBuilder {
+    pub fn new() -> Self {
+        Self {
+            bounds: None,
+            data: None,
+            max_length: 255,
+            choices: Vec::new(),
+            drop_down_rows: DEFAULT_DROP_DOWN_ROWS,
+        }
+    }
+
+    #[must_use]
+    pub fn bounds(mut self, bounds: Rect) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    #[must_use]
+    pub fn data(mut self, data: Rc<RefCell<String>>) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    #[must_use]
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    #[must_use]
+    pub fn choices(mut self, choices: Vec<String>) -> Self {
+        self.choices = choices;
+        self
+    }
+
+    #[must_use]
+    pub fn drop_down_rows(mut self, rows: usize) -> Self {
+        self.drop_down_rows = rows;
+        self
+    }
+
+    pub fn build(self) -> ComboBox {
+        let bounds = self.bounds.expect("ComboBox bounds must be set");
+        let data = self.data.unwrap_or_else(|| Rc::new(RefCell::new(String::new())));
+        let mut combo = ComboBox::new(bounds, self.max_length, data, self.choices);
+        combo.set_drop_down_rows(self.drop_down_rows);
+        combo
+    }
+
+    pub fn build_boxed(self) -> Box<ComboBox> {
+        Box::new(self.build())
+    }
+}
+
+impl Default for ComboBoxBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::command::CM_OK;
+    use crate::core::geometry::Point;
+    use crate::terminal::TestTerminal;
+    use crate::views::button::Button;
+    use crate::views::dialog::Dialog;
+
+    fn presets() -> Vec<String> {
+        ["Small", "Medium", "Large"].iter().map(|s| s.to_string()).collect()
+    }
+
+    fn click(x: i16, y: i16) -> Event {
+        Event::mouse(EventType::MouseDown, Point::new(x, y), MB_LEFT_BUTTON, false)
+    }
+
+    #[test]
+    fn test_keyboard_opens_navigates_and_picks() {
+        let data = Rc::new(RefCell::new(String::from("Medium")));
+        let mut combo = ComboBox::new(Rect::new(2, 2, 22, 3), 20, Rc::clone(&data), presets());
+        combo.set_focus(true);
+
+        let mut key = Event::keyboard(KB_ALT_DOWN);
+        combo.handle_event(&mut key);
+        assert!(combo.is_open());
+        assert_eq!(combo.list.get_selection(), Some(1), "opens on the current value");
+
+        let mut key = Event::keyboard(KB_DOWN);
+        combo.handle_event(&mut key);
+        let mut key = Event::keyboard(KB_ENTER);
+        combo.handle_event(&mut key);
+        assert_eq!(key.what, EventType::Nothing, "Enter must not reach the dialog");
+        assert!(!combo.is_open());
+        assert_eq!(*data.borrow(), "Large");
+
+        combo.handle_event(&mut Event::keyboard(KB_ALT_DOWN));
+        combo.handle_event(&mut Event::keyboard(KB_UP));
+        let mut key = Event::keyboard(KB_ESC);
+        combo.handle_event(&mut key);
+        assert_eq!(key.what, EventType::Nothing);
+        assert!(!combo.is_open());
+        assert_eq!(*data.borrow(), "Large", "ESC keeps the text");
+    }
+
+    #[test]
+    fn test_mouse_opens_picks_and_closes_outside() {
+        let data = Rc::new(RefCell::new(String::new()));
+        let mut combo = ComboBox::new(Rect::new(2, 2, 22, 3), 20, Rc::clone(&data), presets());
+        combo.set_focus(true);
+
+        combo.handle_event(&mut click(21, 2));
+        assert!(combo.is_open());
+        assert!(combo.has_mouse_capture());
+        combo.handle_event(&mut click(5, 5));
+        assert!(!combo.is_open());
+        assert_eq!(*data.borrow(), "Large");
+
+        combo.handle_event(&mut click(21, 2));
+        combo.handle_event(&mut click(40, 10));
+        assert!(!combo.is_open());
+        assert_eq!(*data.borrow(), "Large");
+    }
+
+    #[test]
+    fn test_open_list_draws_over_later_siblings() {
+        let data = Rc::new(RefCell::new(String::from("Small")));
+        let mut dialog = Dialog::new(Rect::new(0, 0, 30, 10), "Size");
+        dialog.add(Box::new(ComboBox::new(Rect::new(1, 1, 21, 2), 20, data, presets())));
+        dialog.add(Box::new(Button::new(Rect::new(1, 3, 11, 5), "~O~K", CM_OK, true)));
+        dialog.set_initial_focus();
+
+        let mut terminal = TestTerminal::new(30, 10);
+        terminal.push_key(KB_ALT_DOWN);
+        terminal.dispatch_events(&mut dialog);
+        assert!(terminal.line_text(2).starts_with("║ Small              ▼"));
+        assert!(terminal.line_text(3).contains("Small"));
+        assert!(terminal.line_text(4).contains("Medium"), "list covers the button");
+        assert!(terminal.line_text(5).contains("Large"));
+    }
+}
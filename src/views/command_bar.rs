@@ -0,0 +1,297 @@
+// (C) 2025 - Enzo Lombardi
+
+//! CommandBar view - vi-style command line on the bottom row.
+//!
+//! When the user presses `:` (and no view consumed it), the application
+//! activates the command bar. It captures one line of input with history
+//! (Up/Down), and on Enter passes the text to a parse callback. A parsed
+//! command is emitted as a Command event; a parse error is shown inline and
+//! the bar stays open. Esc closes the bar.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use turbo_vision::app::Application;
+//! use turbo_vision::core::command::{CM_QUIT, CM_SAVE};
+//! use turbo_vision::views::command_bar::CommandBar;
+//!
+//! let mut app = Application::new()?;
+//! app.set_command_bar(CommandBar::new(':', |text| match text {
+//!     "q" | "quit" => Ok(CM_QUIT),
+//!     "w" | "write" => Ok(CM_SAVE),
+//!     other => Err(format!("Unknown command: {other}")),
+//! }));
+//! # Ok::<(), turbo_vision::core::error::TurboVisionError>(())
+//! ```
+
+use super::input_line::InputLine;
+use super::view::{write_line_to_terminal, View};
+use crate::core::command::CommandId;
+use crate::core::draw::DrawBuffer;
+use crate::core::event::{Event, EventType, KB_DOWN, KB_ENTER, KB_ESC, KB_ESC_ESC, KB_UP};
+use crate::core::geometry::Rect;
+use crate::core::history::HistoryManager;
+use crate::core::palette::colors;
+use crate::terminal::Terminal;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// History list used for command bar entries (see [`HistoryManager`])
+pub const COMMAND_BAR_HISTORY_ID: u16 = 0xC0DE;
+
+/// Maximum length of a command line
+const MAX_COMMAND_LENGTH: usize = 255;
+
+/// Parse callback: command text to a command, or an error message shown inline
+pub type CommandParser = Box<dyn Fn(&str) -> Result<CommandId, String>>;
+
+/// Single-line command input shown on the bottom row while active
+pub struct CommandBar {
+    bounds: Rect,
+    prompt: char,
+    input: InputLine,
+    data: Rc<RefCell<String>>,
+    parser: CommandParser,
+    error: Option<String>,
+    active: bool,
+    history_id: u16,
+    /// Position while browsing history (None = editing a new line)
+    history_index: Option<usize>,
+    owner: Option<*const dyn View>,
+}
+
+impl CommandBar {
+    /// Creates an inactive command bar; the application places it on the bottom row
+    pub fn new(prompt: char, parser: impl Fn(&str) -> Result<CommandId, String> + 'static) -> Self {
+        let data = Rc::new(RefCell::new(String::new()));
+        Self {
+            bounds: Rect::new(0, 0, 1, 1),
+            prompt,
+            input: InputLine::new(Rect::new(1, 0, 1, 1), MAX_COMMAND_LENGTH, Rc::clone(&data)),
+            data,
+            parser: Box::new(parser),
+            error: None,
+            active: false,
+            history_id: COMMAND_BAR_HISTORY_ID,
+            history_index: None,
+            owner: None,
+        }
+    }
+
+    /// Open the bar with an empty line
+    pub fn activate(&mut self) {
+        self.active = true;
+        self.error = None;
+        self.history_index = None;
+        self.input.set_text(String::new());
+        self.input.set_focus(true);
+    }
+
+    /// Close the bar, discarding the current line
+    pub fn deactivate(&mut self) {
+        self.active = false;
+        self.error = None;
+        self.input.set_focus(false);
+    }
+
+    /// Use a different history list (default [`COMMAND_BAR_HISTORY_ID`])
+    pub fn set_history_id(&mut self, history_id: u16) {
+        self.history_id = history_id;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn prompt(&self) -> char {
+        self.prompt
+    }
+
+    /// Current command line text
+    pub fn text(&self) -> String {
+        self.data.borrow().clone()
+    }
+
+    /// Error message from the last parse, if any
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Parse the current line; emit its command or show the error
+    fn submit(&mut self, event: &mut Event) {
+        let text = self.text();
+        let line = text.trim();
+        if line.is_empty() {
+            self.deactivate();
+            event.clear();
+            return;
+        }
+
+        HistoryManager::add(self.history_id, line.to_string());
+        match (self.parser)(line) {
+            Ok(command) => {
+                self.deactivate();
+                *event = Event::command(command);
+            }
+            Err(message) => {
+                self.error = Some(message);
+                event.clear();
+            }
+        }
+    }
+
+    /// Step through history: Up goes to older entries, Down to newer ones
+    fn browse_history(&mut self, older: bool) {
+        let items = HistoryManager::get_list(self.history_id);
+        if items.is_empty() {
+            return;
+        }
+        // HistoryManager keeps the most recent entry first
+        let index = match (self.history_index, older) {
+            (None, true) => Some(0),
+            (None | Some(0), false) => None,
+            (Some(i), true) => Some((i + 1).min(items.len() - 1)),
+            (Some(i), false) => Some(i - 1),
+        };
+        self.history_index = index;
+        self.input.set_text(index.map(|i| items[i].clone()).unwrap_or_default());
+    }
+}
+
+impl View for CommandBar {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+        self.input.set_bounds(Rect::new(bounds.a.x + 1, bounds.a.y, bounds.b.x, bounds.a.y + 1));
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        if !self.active {
+            return;
+        }
+
+        let width = self.bounds.width_clamped() as usize;
+        let mut buf = DrawBuffer::new(width);
+        buf.move_char(0, ' ', colors::STATUS_NORMAL, width);
+        buf.put_char(0, self.prompt, colors::STATUS_NORMAL);
+        write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y, &buf);
+
+        self.input.draw(terminal);
+
+        // Parse errors are shown inline, right-aligned over the input
+        if let Some(error) = &self.error {
+            let message = format!(" {error} ");
+            let len = message.chars().count().min(width.saturating_sub(2));
+            let mut buf = DrawBuffer::new(len);
+            buf.move_str(0, &message, colors::STATUS_SHORTCUT);
+            let x = self.bounds.b.x - len as i16;
+            write_line_to_terminal(terminal, x, self.bounds.a.y, &buf);
+        }
+    }
+
+    fn handle_event(&mut self, event: &mut Event) {
+        if !self.active || event.what != EventType::Keyboard {
+            return;
+        }
+
+        match event.key_code {
+            KB_ENTER => self.submit(event),
+            KB_ESC | KB_ESC_ESC => {
+                self.deactivate();
+                event.clear();
+            }
+            KB_UP | KB_DOWN => {
+                self.browse_history(event.key_code == KB_UP);
+                event.clear();
+            }
+            _ => {
+                self.error = None;
+                self.input.handle_event(event);
+                // The bar is modal while open: keys never reach the desktop
+                event.clear();
+            }
+        }
+    }
+
+    fn update_cursor(&self, terminal: &mut Terminal) {
+        if self.active {
+            self.input.update_cursor(terminal);
+        }
+    }
+
+    fn set_owner(&mut self, owner: *const dyn View) {
+        self.owner = Some(owner);
+    }
+
+    fn get_owner(&self) -> Option<*const dyn View> {
+        self.owner
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::command::{CM_QUIT, CM_SAVE};
+
+    fn command_bar() -> CommandBar {
+        let mut bar = CommandBar::new(':', |text| match text {
+            "q" => Ok(CM_QUIT),
+            "w" => Ok(CM_SAVE),
+            other => Err(format!("Unknown: {other}")),
+        });
+        bar.set_bounds(Rect::new(0, 24, 80, 25));
+        bar
+    }
+
+    fn type_text(bar: &mut CommandBar, text: &str) {
+        for ch in text.chars() {
+            bar.handle_event(&mut Event::keyboard(ch as u16));
+        }
+    }
+
+    #[test]
+    fn test_unknown_command_shows_error_and_stays_open() {
+        let mut bar = command_bar();
+        bar.activate();
+        type_text(&mut bar, "zz");
+
+        let mut event = Event::keyboard(KB_ENTER);
+        bar.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Nothing);
+        assert!(bar.is_active());
+        assert_eq!(bar.error(), Some("Unknown: zz"));
+
+        // Typing clears the error; Esc closes the bar
+        type_text(&mut bar, "x");
+        assert_eq!(bar.error(), None);
+        bar.handle_event(&mut Event::keyboard(KB_ESC));
+        assert!(!bar.is_active());
+    }
+
+    #[test]
+    fn test_history_recalls_previous_commands() {
+        let mut bar = command_bar();
+        bar.set_history_id(0xC0DF);
+        for command in ["w", "q"] {
+            bar.activate();
+            type_text(&mut bar, command);
+            bar.handle_event(&mut Event::keyboard(KB_ENTER));
+        }
+
+        bar.activate();
+        bar.handle_event(&mut Event::keyboard(KB_UP));
+        assert_eq!(bar.text(), "q");
+        bar.handle_event(&mut Event::keyboard(KB_UP));
+        assert_eq!(bar.text(), "w");
+        bar.handle_event(&mut Event::keyboard(KB_DOWN));
+        bar.handle_event(&mut Event::keyboard(KB_DOWN));
+        assert_eq!(bar.text(), "");
+    }
+}
@@ -7,12 +7,20 @@ use crate::core::event::Event;
 use crate::terminal::Terminal;
 use super::view::{View, ViewId};
 use super::group::Group;
-use super::background::Background;
+use super::file_editor::FileEditor;
+use crate::app::Application;
+use crate::core::command::CM_CLOSE;
+use super::background::{Background, WallpaperMode};
+use crate::core::draw::Cell;
+use crate::core::error::{Result, TurboVisionError};
+use std::path::Path;
 
 pub struct Desktop {
     bounds: Rect,
     children: Group,
     owner: Option<*const dyn View>,
+    /// Window last told it is active, see [`View::on_activate()`]
+    active: Option<ViewId>,
 }
 
 impl Desktop {
@@ -28,10 +36,14 @@ impl Desktop {
         let background = Box::new(Background::new(background_bounds, '░', crate::core::palette::colors::DESKTOP));
         children.add(background);
 
+        // Windows completely covered by others are not drawn
+        children.set_occlusion_culling(true);
+
         Self {
             bounds,
             children,
             owner: None,
+            active: None,
         }
     }
 
@@ -44,6 +56,50 @@ impl Desktop {
         // NOTE: We don't set owner pointer to avoid unsafe casting
     }
 
+    /// Change the character used to fill the desktop background
+    /// Matches Borland: TDeskTop::defaultBkgrnd / TBackground::pattern
+    pub fn set_background_pattern(&mut self, pattern: char) {
+        if let Some(background) = self.background_mut() {
+            background.set_pattern(pattern);
+        }
+    }
+
+    /// Show a wallpaper (rows of cells) beneath the windows instead of the pattern
+    /// Smaller wallpapers are centered on the pattern, larger ones are clipped
+    /// (see [`set_wallpaper_mode()`](Self::set_wallpaper_mode) for tiling).
+    pub fn set_wallpaper(&mut self, cells: Vec<Vec<Cell>>) {
+        if let Some(background) = self.background_mut() {
+            background.set_wallpaper(Some(cells));
+        }
+    }
+
+    /// Load an ANSI-art file (see [`ansi_dump::load_ansi_file()`](crate::core::ansi_dump::load_ansi_file))
+    /// and show it as the wallpaper
+    pub fn set_wallpaper_from_ansi(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let cells = crate::core::ansi_dump::load_ansi_file(path, crate::core::palette::colors::DESKTOP)
+            .map_err(|e| TurboVisionError::file_operation(path, e))?;
+        self.set_wallpaper(cells);
+        Ok(())
+    }
+
+    /// Remove the wallpaper and go back to the background pattern
+    pub fn clear_wallpaper(&mut self) {
+        if let Some(background) = self.background_mut() {
+            background.set_wallpaper(None);
+        }
+    }
+
+    pub fn set_wallpaper_mode(&mut self, mode: WallpaperMode) {
+        if let Some(background) = self.background_mut() {
+            background.set_wallpaper_mode(mode);
+        }
+    }
+
+    fn background_mut(&mut self) -> Option<&mut Background> {
+        self.children.child_at_mut(0).as_any_mut().downcast_mut::<Background>()
+    }
+
     pub fn add(&mut self, mut view: Box<dyn View>) -> ViewId {
         use crate::core::state::{OF_CENTERED, OF_CENTER_X, OF_CENTER_Y};
 
@@ -84,6 +140,7 @@ impl Desktop {
                 self.children.set_focus_to(last_idx);
             }
         }
+        self.update_active();
         view_id
     }
 
@@ -118,6 +175,17 @@ impl Desktop {
         view.set_bounds(new_bounds);
     }
 
+    /// Skip drawing windows completely covered by windows in front of them (on by default)
+    pub fn set_occlusion_culling(&mut self, enable: bool) {
+        self.children.set_occlusion_culling(enable);
+    }
+
+    /// Check if the window at `index` (shadow included) is completely covered by
+    /// windows in front of it, so culling skips it
+    pub fn is_obscured(&self, index: usize) -> bool {
+        self.children.is_obscured(index + 1)  // +1 to skip background
+    }
+
     /// Get the number of child views (windows) on the desktop
     /// Note: Subtracts 1 because the background is also a child
     pub fn child_count(&self) -> usize {
@@ -141,6 +209,7 @@ impl Desktop {
     /// Used by Application::exec_view() to remove modal dialogs after they close
     pub fn remove_child(&mut self, index: usize) {
         self.children.remove(index + 1);  // +1 to skip background
+        self.update_active();
     }
 
     /// Draw views in the affected rectangle (Borland's drawUnderRect pattern)
@@ -304,9 +373,11 @@ impl Desktop {
         // Calculate grid dimensions (most square layout)
         let (cols, rows) = Self::calculate_grid_layout(count);
 
-        let tile_bounds = rect;
-        let cell_width = tile_bounds.width() / cols as i16;
-        let cell_height = tile_bounds.height() / rows as i16;
+        // Too small to give every window a cell (Borland: tileError)
+        let (width, height) = (rect.width_clamped() as usize, rect.height_clamped() as usize);
+        if width < cols || height < rows {
+            return;
+        }
 
         // Position windows in grid
         let mut tile_index = 0;
@@ -314,22 +385,31 @@ impl Desktop {
             let child = self.children.child_at(i);
             let options = child.options();
             if (options & OF_TILEABLE) != 0 {
-                let col = tile_index % cols;
-                let row = tile_index / cols;
-
-                let new_bounds = Rect::new(
-                    tile_bounds.a.x + (col as i16 * cell_width),
-                    tile_bounds.a.y + (row as i16 * cell_height),
-                    tile_bounds.a.x + ((col + 1) as i16 * cell_width),
-                    tile_bounds.a.y + ((row + 1) as i16 * cell_height),
-                );
-
+                let new_bounds = Self::tile_rect(rect, tile_index, count, cols, rows);
                 self.children.child_at_mut(i).set_bounds(new_bounds);
                 tile_index += 1;
             }
         }
     }
 
+    /// Cell `index` of a `cols` x `rows` grid over `rect` holding `count` windows
+    ///
+    /// Cell edges are spread proportionally, so the cells cover `rect` exactly
+    /// whatever its size; the windows of a short last row share its full width.
+    fn tile_rect(rect: Rect, index: usize, count: usize, cols: usize, rows: usize) -> Rect {
+        let row = index / cols;
+        let row_cols = if row == rows - 1 { count - row * cols } else { cols };
+        let col = index % cols;
+        let edge = |start: i16, length: i16, i: usize, n: usize| start + (length as i32 * i as i32 / n as i32) as i16;
+        let (width, height) = (rect.width_clamped(), rect.height_clamped());
+        Rect::new(
+            edge(rect.a.x, width, col, row_cols),
+            edge(rect.a.y, height, row, rows),
+            edge(rect.a.x, width, col + 1, row_cols),
+            edge(rect.a.y, height, row + 1, rows),
+        )
+    }
+
     /// Calculate grid layout (rows x cols) that's most square
     /// Matches Borland: mostEqualDivisors()
     fn calculate_grid_layout(count: usize) -> (usize, usize) {
@@ -383,6 +463,8 @@ impl Desktop {
             // Move top window behind all others (after background)
             // This is equivalent to Borland's: current->putInFrontOf(background)
             self.children.send_to_back(top_window_idx);
+            self.focus_top_window();
+            self.update_active();
         }
     }
 
@@ -409,9 +491,77 @@ impl Desktop {
         if has_top_select {
             // Bring bottom window to front
             self.children.bring_to_front(bottom_window_idx);
+            self.focus_top_window();
+            self.update_active();
+        }
+    }
+
+    /// Give the focus to the front window, clearing it everywhere else
+    /// Keeps the group's focused index in step with the SF_FOCUSED flags after a reorder
+    fn focus_top_window(&mut self) {
+        if self.children.len() > 1 {
+            self.children.set_focus_to(self.children.len() - 1);
         }
     }
 
+    /// Get a window by the ViewId returned from [`add()`](Self::add)
+    pub fn window_by_id(&self, view_id: ViewId) -> Option<&dyn View> {
+        self.children.child_by_id(view_id)
+    }
+
+    /// Get a mutable window by the ViewId returned from [`add()`](Self::add)
+    pub fn window_by_id_mut(&mut self, view_id: ViewId) -> Option<&mut (dyn View + '_)> {
+        self.children.child_by_id_mut(view_id)
+    }
+
+    /// ViewId of the front window, if any
+    pub fn top_window_id(&self) -> Option<ViewId> {
+        (self.children.len() > 1).then(|| self.children.view_id_at(self.children.len() - 1))
+    }
+
+    /// Bring a window to the front and focus it
+    /// Matches Borland: TView::select() on a desktop window
+    /// Returns false if no window has this ViewId
+    pub fn select_window(&mut self, view_id: ViewId) -> bool {
+        let Some(index) = self.children.index_of(view_id) else {
+            return false;
+        };
+        let index = self.children.bring_to_front(index);
+        self.children.set_focus_to(index);
+        self.update_active();
+        true
+    }
+
+    /// Tell the windows about a change of active (topmost focused) window
+    ///
+    /// Called after anything that can reorder, add or remove windows. The old
+    /// window gets `on_activate(false)` unless it was removed, in which case
+    /// it only got `on_remove()`. When the focused window was removed, the
+    /// front one takes the focus, as Borland's TGroup::resetCurrent() does.
+    fn update_active(&mut self) {
+        use crate::core::state::SF_FOCUSED;
+
+        let focused = |desktop: &Self| {
+            (1..desktop.children.len())
+                .rev()
+                .find(|&i| desktop.children.child_at(i).state() & SF_FOCUSED != 0)
+        };
+        if focused(self).is_none() {
+            self.focus_top_window();
+        }
+        let top = focused(self).map(|i| self.children.view_id_at(i));
+        if top == self.active {
+            return;
+        }
+        if let Some(window) = self.active.and_then(|id| self.children.child_by_id_mut(id)) {
+            window.on_activate(false);
+        }
+        if let Some(window) = top.and_then(|id| self.children.child_by_id_mut(id)) {
+            window.on_activate(true);
+        }
+        self.active = top;
+    }
+
     /// Get a mutable reference to a window by index (for movement tracking)
     /// Returns None if index is out of bounds
     /// Index 0 refers to first window (background is at internal index 0)
@@ -443,6 +593,60 @@ impl Desktop {
         self.children.child_at_mut(top_window_idx).zoom(desktop_bounds);
     }
 
+    /// Ask every window whether it may close, then close them all
+    ///
+    /// Windows are asked front to back with `valid(CM_CLOSE)`. A modified
+    /// [`FileEditor`] is brought to the front and asks to save its changes.
+    /// If any window refuses (Cancel), nothing is closed and false is
+    /// returned. Called when the application quits.
+    /// Matches Borland: TGroup::valid(cmQuit) over the desktop's windows
+    pub fn close_all_with_prompt(app: &mut Application) -> bool {
+        let ids: Vec<ViewId> = (1..app.desktop.children.len()).rev().map(|index| app.desktop.children.view_id_at(index)).collect();
+
+        for &id in &ids {
+            let Some(window) = app.desktop.window_by_id_mut(id) else {
+                continue;
+            };
+            let question = match window.as_any().downcast_ref::<FileEditor>() {
+                Some(editor) => editor.close_question(CM_CLOSE),
+                None => {
+                    if !window.valid(CM_CLOSE) {
+                        return false;
+                    }
+                    None
+                }
+            };
+            let Some(message) = question else {
+                continue;
+            };
+
+            // Show which window is asking
+            app.desktop.select_window(id);
+            let answer = FileEditor::ask_close(app, &message);
+            let untitled = app
+                .desktop
+                .window_by_id(id)
+                .and_then(|window| window.as_any().downcast_ref::<FileEditor>())
+                .is_some_and(|editor| editor.filename().is_none());
+            // Asked before borrowing the editor: the save dialog needs the application
+            let save_path = FileEditor::ask_save_path(app, answer, untitled);
+            let allowed = app
+                .desktop
+                .window_by_id_mut(id)
+                .and_then(|window| window.as_any_mut().downcast_mut::<FileEditor>())
+                .is_none_or(|editor| editor.resolve_close(answer, || save_path));
+            if !allowed {
+                return false;
+            }
+        }
+
+        for id in ids {
+            app.desktop.children.remove_by_id(id);
+        }
+        app.desktop.update_active();
+        true
+    }
+
     /// Remove closed windows (those with SF_CLOSED flag)
     /// In Borland, views call CLY_destroy() which removes them from the owner
     /// In Rust, views set SF_CLOSED flag and the parent removes them
@@ -464,6 +668,7 @@ impl Desktop {
             }
         }
 
+        self.update_active();
         had_removals
     }
 
@@ -474,9 +679,30 @@ impl View for Desktop {
         self.bounds
     }
 
+    /// Only the background is stretched to the new bounds. Windows keep their
+    /// size and are moved back on screen if they would fall off it; zoomed
+    /// windows (covering the whole desktop) follow the new size.
     fn set_bounds(&mut self, bounds: Rect) {
+        let old_bounds = self.bounds;
+        let windows: Vec<Rect> = (1..self.children.len()).map(|i| self.children.child_at(i).bounds()).collect();
+
         self.bounds = bounds;
         self.children.set_bounds(bounds);
+
+        let (dx, dy) = (bounds.a.x - old_bounds.a.x, bounds.a.y - old_bounds.a.y);
+        for (i, mut window) in windows.into_iter().enumerate() {
+            let child = self.children.child_at_mut(i + 1);
+            if window == old_bounds {
+                window = bounds;
+            } else {
+                window.move_by(dx, dy);
+                let shadow = if child.has_shadow() { 1 } else { 0 };
+                let x = window.a.x.min(bounds.b.x - window.width()).max(bounds.a.x);
+                let y = window.a.y.min(bounds.b.y - window.height() - shadow).max(bounds.a.y);
+                window.move_by(x - window.a.x, y - window.a.y);
+            }
+            child.set_bounds(window);
+        }
     }
 
     fn draw(&mut self, terminal: &mut Terminal) {
@@ -535,48 +761,19 @@ impl View for Desktop {
         // Matches Borland: TDesktop::handleEvent (tdesktop.cc:103-133)
         if event.what == EventType::Command {
             use crate::core::command::{CM_NEXT, CM_PREV};
-            use crate::core::state::SF_FOCUSED;
 
             match event.command {
                 CM_NEXT => {
                     // Cycle to next window (send top window to back)
                     // Matches Borland: cmNext command calls selectNext(False)
-                    if self.children.len() > 2 {
-                        // Clear focus from current top window
-                        let old_top_idx = self.children.len() - 1;
-                        let old_state = self.children.child_at(old_top_idx).state();
-                        if (old_state & SF_FOCUSED) != 0 {
-                            self.children.child_at_mut(old_top_idx).set_focus(false);
-                        }
-
-                        // Move top window to back
-                        self.children.send_to_back(old_top_idx);
-
-                        // Focus the new top window
-                        let new_top_idx = self.children.len() - 1;
-                        self.children.child_at_mut(new_top_idx).set_focus(true);
-                    }
+                    self.select_next();
                     event.clear();
                     return;
                 }
                 CM_PREV => {
                     // Cycle to previous window (bring bottom window to front)
                     // Matches Borland: cmPrev calls current->putInFrontOf(background)
-                    if self.children.len() > 2 {
-                        // Clear focus from current top window
-                        let old_top_idx = self.children.len() - 1;
-                        let old_state = self.children.child_at(old_top_idx).state();
-                        if (old_state & SF_FOCUSED) != 0 {
-                            self.children.child_at_mut(old_top_idx).set_focus(false);
-                        }
-
-                        // Bring bottom window (after background) to front
-                        self.children.bring_to_front(1);
-
-                        // Focus the new top window
-                        let new_top_idx = self.children.len() - 1;
-                        self.children.child_at_mut(new_top_idx).set_focus(true);
-                    }
+                    self.select_prev();
                     event.clear();
                     return;
                 }
@@ -592,6 +789,9 @@ impl View for Desktop {
         } else {
             self.children.handle_event(event);
         }
+
+        // A click may have brought another window to the front
+        self.update_active();
     }
 
     fn set_owner(&mut self, owner: *const dyn View) {
@@ -602,6 +802,14 @@ impl View for Desktop {
         self.owner
     }
 
+    fn on_modal_enter(&mut self) {
+        self.children.on_modal_enter();
+    }
+
+    fn on_modal_leave(&mut self) {
+        self.children.on_modal_leave();
+    }
+
     fn get_palette(&self) -> Option<crate::core::palette::Palette> {
         use crate::core::palette::{Palette, palettes};
         // Desktop uses the application palette directly (no remapping)
@@ -641,3 +849,259 @@ impl Default for DesktopBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::palette::Attr;
+    use crate::views::window::Window;
+
+    #[test]
+    fn test_wallpaper_visible_where_no_window_covers_it() {
+        let mut terminal = Terminal::new_headless(40, 12);
+        let mut desktop = Desktop::new(Rect::new(0, 0, 40, 12));
+        let attr = Attr::from_u8(0x1E);
+        desktop.set_wallpaper(vec![vec![Cell::new('W', attr); 10]; 4]);
+        // Covers the left half of the centered wallpaper (columns 15..25, rows 4..8)
+        desktop.add(Box::new(Window::new(Rect::new(0, 0, 20, 12), "Left")));
+        desktop.draw(&mut terminal);
+
+        let cell = |x, y| terminal.read_cell(x, y).unwrap();
+        assert_eq!(cell(22, 5), Cell::new('W', attr));
+        assert_ne!(cell(16, 5).ch, 'W');
+        // Outside the wallpaper the pattern shows
+        assert_eq!(cell(30, 1).ch, '░');
+
+        // Tiled wallpaper fills the whole background; clearing restores the pattern
+        desktop.set_wallpaper_mode(WallpaperMode::Tiled);
+        desktop.draw(&mut terminal);
+        assert_eq!(terminal.read_cell(30, 1).unwrap().ch, 'W');
+        desktop.clear_wallpaper();
+        desktop.draw(&mut terminal);
+        assert_eq!(terminal.read_cell(30, 1).unwrap().ch, '░');
+    }
+
+    #[test]
+    fn test_uncovered_window_is_repainted() {
+        let mut terminal = Terminal::new_headless(40, 12);
+        let mut desktop = Desktop::new(Rect::new(0, 0, 40, 12));
+        desktop.add(Box::new(Window::new(Rect::new(2, 2, 20, 8), "Bottom")));
+        desktop.add(Box::new(Window::new(Rect::new(0, 0, 30, 11), "Top")));
+        let row = |terminal: &Terminal, y| (0..40).map(|x| terminal.read_cell(x, y).unwrap().ch).collect::<String>();
+
+        // Internal index 1 is the bottom window (0 is the background)
+        assert!(desktop.children.is_obscured(1));
+        desktop.draw(&mut terminal);
+        assert!(!row(&terminal, 2).contains("Bottom"));
+
+        // Move the top window aside: the bottom one is drawn again
+        desktop.child_at_mut(1).set_bounds(Rect::new(22, 0, 38, 11));
+        assert!(!desktop.children.is_obscured(1));
+        desktop.draw(&mut terminal);
+        assert!(row(&terminal, 2).contains("Bottom"));
+    }
+
+    #[test]
+    fn test_tile_covers_desktop_without_overlap() {
+        use crate::core::geometry::Point;
+
+        // Odd sizes: the cells must still meet edge to edge
+        let bounds = Rect::new(0, 1, 81, 24);
+        for count in [4, 3, 5] {
+            let mut desktop = Desktop::new(bounds);
+            for _ in 0..count {
+                desktop.add(Box::new(Window::new(Rect::new(5, 5, 30, 15), "W")));
+            }
+            desktop.tile();
+
+            let rects: Vec<Rect> = (1..desktop.children.len()).map(|i| desktop.children.child_at(i).bounds()).collect();
+            for y in bounds.a.y..bounds.b.y {
+                for x in bounds.a.x..bounds.b.x {
+                    let covering = rects.iter().filter(|r| r.contains(Point::new(x, y))).count();
+                    assert_eq!(covering, 1, "{count} windows: ({x}, {y}) covered {covering} times");
+                }
+            }
+        }
+    }
+
+    /// ViewId of the focused window, checking that no other window has SF_FOCUSED
+    fn focused_window(desktop: &Desktop) -> Option<ViewId> {
+        use crate::core::state::SF_FOCUSED;
+        let focused: Vec<usize> = (1..desktop.children.len())
+            .filter(|&i| desktop.children.child_at(i).state() & SF_FOCUSED != 0)
+            .collect();
+        assert!(focused.len() <= 1, "several windows focused: {focused:?}");
+        let index = focused.first().copied();
+        // The group routes keys to the same window the flags say is focused
+        let bounds = |i: usize| desktop.children.child_at(i).bounds();
+        assert_eq!(desktop.children.focused_child().map(|v| v.bounds()), index.map(bounds));
+        index.map(|i| desktop.children.view_id_at(i))
+    }
+
+    #[test]
+    fn test_click_brings_background_window_to_front_with_focus() {
+        use crate::core::event::{EventType, MB_LEFT_BUTTON};
+        use crate::core::geometry::Point;
+
+        let mut desktop = Desktop::new(Rect::new(0, 0, 40, 12));
+        let back = desktop.add(Box::new(Window::new(Rect::new(0, 0, 20, 10), "Back")));
+        let front = desktop.add(Box::new(Window::new(Rect::new(10, 2, 30, 12), "Front")));
+        assert_eq!(focused_window(&desktop), Some(front));
+
+        // Visible corner of the back window
+        let mut click = Event::mouse(EventType::MouseDown, Point::new(2, 1), MB_LEFT_BUTTON, false);
+        desktop.handle_event(&mut click);
+        assert_eq!(desktop.top_window_id(), Some(back));
+        assert_eq!(focused_window(&desktop), Some(back));
+    }
+
+    #[test]
+    fn test_cm_next_cycles_z_order_and_focus() {
+        use crate::core::command::{CM_NEXT, CM_PREV};
+
+        let mut desktop = Desktop::new(Rect::new(0, 0, 40, 12));
+        let ids: Vec<ViewId> = (0..3)
+            .map(|i| desktop.add(Box::new(Window::new(Rect::new(i, i, 20 + i, 8 + i), "W"))))
+            .collect();
+        assert_eq!(focused_window(&desktop), Some(ids[2]));
+
+        for expected in [ids[1], ids[0], ids[2]] {
+            desktop.handle_event(&mut Event::command(CM_NEXT));
+            assert_eq!(desktop.top_window_id(), Some(expected));
+            assert_eq!(focused_window(&desktop), Some(expected));
+        }
+
+        desktop.handle_event(&mut Event::command(CM_PREV));
+        assert_eq!(desktop.top_window_id(), Some(ids[0]));
+        assert_eq!(focused_window(&desktop), Some(ids[0]));
+    }
+
+    #[test]
+    fn test_cancel_on_any_editor_keeps_every_window_open() {
+        use crate::core::command::CM_QUIT;
+        use crate::core::event::{KB_ENTER, KB_ESC_ESC, KB_TAB};
+
+        let mut app = Application::with_terminal(Terminal::new_headless(80, 25));
+        let modified_editor = |app: &mut Application, title| {
+            let mut editor = FileEditor::new(Rect::new(0, 0, 40, 12), title);
+            editor.handle_event(&mut Event::keyboard('x' as u16));
+            assert!(editor.is_modified());
+            app.desktop.add(Box::new(editor))
+        };
+        let first = modified_editor(&mut app, "First");
+        let second = modified_editor(&mut app, "Second");
+        app.desktop.add(Box::new(Window::new(Rect::new(5, 5, 20, 10), "Plain")));
+
+        // The front editor (Second) is asked first: No; then First: Cancel
+        for key in [KB_TAB, KB_ENTER, KB_ESC_ESC] {
+            app.terminal.push_input(Event::keyboard(key));
+        }
+        assert!(!Desktop::close_all_with_prompt(&mut app));
+        assert_eq!(app.desktop.child_count(), 3);
+        assert_eq!(app.desktop.top_window_id(), Some(first));
+
+        // Quitting asks again; answering No to both closes everything
+        app.running = true;
+        for key in [KB_TAB, KB_ENTER, KB_TAB, KB_ENTER] {
+            app.terminal.push_input(Event::keyboard(key));
+        }
+        app.handle_event(&mut Event::command(CM_QUIT));
+        assert!(!app.running);
+        assert_eq!(app.desktop.child_count(), 0);
+        assert!(app.desktop.window_by_id(second).is_none());
+    }
+
+    #[test]
+    fn test_yes_on_untitled_editor_asks_for_a_name_before_quitting() {
+        use crate::core::event::{KB_ENTER, KB_ESC};
+
+        let mut app = Application::with_terminal(Terminal::new_headless(80, 25));
+        let mut editor = FileEditor::new(Rect::new(0, 0, 40, 12), "");
+        editor.handle_event(&mut Event::keyboard('x' as u16));
+        let id = app.desktop.add(Box::new(editor));
+
+        // Yes opens the save dialog; leaving it without a name cancels the quit
+        app.terminal.push_input(Event::keyboard(KB_ENTER));
+        app.terminal.push_input(Event::keyboard(KB_ESC));
+        assert!(!Desktop::close_all_with_prompt(&mut app));
+        let editor = app.desktop.window_by_id(id).and_then(|window| window.as_any().downcast_ref::<FileEditor>());
+        assert!(editor.is_some_and(FileEditor::is_modified), "the text is kept");
+    }
+
+    /// Records the lifecycle calls it receives as "<name> <hook>"
+    struct LifecycleRecorder {
+        name: &'static str,
+        bounds: Rect,
+        state: crate::core::state::StateFlags,
+        calls: std::rc::Rc<std::cell::RefCell<Vec<String>>>,
+    }
+
+    impl LifecycleRecorder {
+        fn boxed(name: &'static str, bounds: Rect, calls: &std::rc::Rc<std::cell::RefCell<Vec<String>>>) -> Box<Self> {
+            Box::new(Self { name, bounds, state: 0, calls: std::rc::Rc::clone(calls) })
+        }
+
+        fn log(&self, hook: &str) {
+            self.calls.borrow_mut().push(format!("{} {hook}", self.name));
+        }
+    }
+
+    impl View for LifecycleRecorder {
+        fn bounds(&self) -> Rect {
+            self.bounds
+        }
+        fn set_bounds(&mut self, bounds: Rect) {
+            self.bounds = bounds;
+        }
+        fn draw(&mut self, _terminal: &mut Terminal) {}
+        fn handle_event(&mut self, _event: &mut Event) {}
+        fn can_focus(&self) -> bool {
+            true
+        }
+        fn state(&self) -> crate::core::state::StateFlags {
+            self.state
+        }
+        fn set_state(&mut self, state: crate::core::state::StateFlags) {
+            self.state = state;
+        }
+        fn on_insert(&mut self, _owner: *const dyn View) {
+            self.log("insert");
+        }
+        fn on_remove(&mut self) {
+            self.log("remove");
+        }
+        fn on_activate(&mut self, active: bool) {
+            self.log(if active { "active" } else { "inactive" });
+        }
+        fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_lifecycle_hooks_order() {
+        let calls = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let take = || std::mem::take(&mut *calls.borrow_mut());
+        let mut desktop = Desktop::new(Rect::new(0, 0, 60, 20));
+
+        let a = desktop.add(LifecycleRecorder::boxed("A", Rect::new(0, 0, 20, 10), &calls));
+        assert_eq!(take(), ["A insert", "A active"]);
+
+        // B sits inside a window: activation reaches it through the window's group
+        let mut window = Window::new(Rect::new(20, 0, 50, 10), "B");
+        window.add(LifecycleRecorder::boxed("B", Rect::new(1, 1, 10, 2), &calls));
+        assert_eq!(take(), ["B insert"]);
+        desktop.add(Box::new(window));
+        assert_eq!(take(), ["A inactive", "B active"]);
+
+        desktop.select_window(a);
+        assert_eq!(take(), ["B inactive", "A active"]);
+        desktop.select_window(a);
+        assert!(take().is_empty(), "already active");
+
+        // A is in front; removing it activates the window behind
+        desktop.remove_child(1);
+        assert_eq!(take(), ["A remove", "B active"]);
+        assert_eq!(desktop.window_by_id(a).map(|w| w.bounds()), None);
+    }
+}
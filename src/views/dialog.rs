@@ -6,13 +6,16 @@ use crate::core::geometry::Rect;
 use crate::core::event::{Event, EventType, KB_ESC_ESC, KB_ENTER};
 use crate::core::command::{CommandId, CM_CANCEL};
 use crate::terminal::Terminal;
-use super::view::{View, ViewId};
+use super::view::{DataValue, View, ViewId};
 use super::window::Window;
-use std::time::Duration;
 
 pub struct Dialog {
     window: Window,
     result: CommandId,
+    /// Child values recorded by capture_initial_state(), by child index
+    initial_state: Vec<Option<DataValue>>,
+    /// Enter found no enabled button to press; ring the bell on the next draw
+    beep_pending: bool,
 }
 
 impl Dialog {
@@ -20,6 +23,8 @@ impl Dialog {
         Self {
             window: Window::new_for_dialog(bounds, title),
             result: CM_CANCEL,
+            initial_state: Vec::new(),
+            beep_pending: false,
         }
     }
 
@@ -41,12 +46,60 @@ impl Dialog {
         self.window.set_initial_focus();
     }
 
+    /// Set the space kept free between the frame and the controls
+    /// See [`Window::set_content_padding()`](super::window::Window::set_content_padding).
+    pub fn set_content_padding(&mut self, left: i16, top: i16, right: i16, bottom: i16) {
+        self.window.set_content_padding(left, top, right, bottom);
+    }
+
+    /// Area available to controls, in the coordinates passed to add()
+    pub fn content_rect(&self) -> Rect {
+        self.window.content_rect()
+    }
+
     /// Set focus to a specific child by index
     /// Matches Borland: owner->setCurrent(this, normalSelect)
     pub fn set_focus_to_child(&mut self, index: usize) {
         self.window.set_focus_to_child(index);
     }
 
+    /// Reposition the dialog after the terminal was resized
+    ///
+    /// Dialogs flagged with OF_CENTERED (or OF_CENTER_X/OF_CENTER_Y) are re-centered
+    /// within `limits`; all dialogs are then clamped so they stay fully on screen.
+    /// Matches Borland: TView::locate() constrains position to owner bounds
+    pub fn reposition_after_resize(&mut self, limits: Rect) {
+        use crate::core::state::{OF_CENTER_X, OF_CENTER_Y};
+
+        let options = self.options();
+        let bounds = self.bounds();
+        let width = bounds.width();
+        let height = bounds.height();
+        let mut x = bounds.a.x;
+        let mut y = bounds.a.y;
+
+        if (options & OF_CENTER_X) != 0 {
+            x = limits.a.x + (limits.width() - width) / 2;
+        }
+        if (options & OF_CENTER_Y) != 0 {
+            y = limits.a.y + (limits.height() - height) / 2;
+        }
+        if x != bounds.a.x || y != bounds.a.y {
+            self.set_bounds(Rect::new(x, y, x + width, y + height));
+        }
+
+        self.window.set_drag_limits(limits);
+        self.window.constrain_to_limits();
+
+        // A dialog larger than the screen keeps its top-left corner visible
+        let bounds = self.bounds();
+        if bounds.a.x < limits.a.x || bounds.a.y < limits.a.y {
+            let x = bounds.a.x.max(limits.a.x);
+            let y = bounds.a.y.max(limits.a.y);
+            self.set_bounds(Rect::new(x, y, x + width, y + height));
+        }
+    }
+
     /// Get the number of child views
     pub fn child_count(&self) -> usize {
         self.window.child_count()
@@ -92,6 +145,40 @@ impl Dialog {
         self.window.get_end_state()
     }
 
+    /// Remember the current value of every data control (see [`View::get_data()`])
+    ///
+    /// Call once all controls are added and filled in; [`is_dirty()`](Self::is_dirty)
+    /// and [`reset_to_initial()`](Self::reset_to_initial) compare against this snapshot.
+    pub fn capture_initial_state(&mut self) {
+        self.initial_state = (0..self.child_count()).map(|i| self.child_at(i).get_data()).collect();
+    }
+
+    /// True if any control's value differs from the captured snapshot
+    ///
+    /// Always false before [`capture_initial_state()`](Self::capture_initial_state).
+    /// Enable an Apply button with this after each event.
+    pub fn is_dirty(&self) -> bool {
+        self.initial_state
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i < self.child_count())
+            .any(|(i, initial)| self.child_at(i).get_data() != *initial)
+    }
+
+    /// Restore every control to its captured value
+    ///
+    /// Also done by the dialog itself on [`CM_RESET`](crate::core::command::CM_RESET),
+    /// so a [`StandardButton::Reset`] button works without extra code.
+    pub fn reset_to_initial(&mut self) {
+        let initial_state = std::mem::take(&mut self.initial_state);
+        for (i, initial) in initial_state.iter().enumerate().take(self.child_count()) {
+            if let Some(value) = initial {
+                self.child_at_mut(i).set_data(value);
+            }
+        }
+        self.initial_state = initial_state;
+    }
+
     /// Execute the dialog with its own event loop (self-contained pattern)
     ///
     /// **Two execution patterns supported:**
@@ -133,6 +220,13 @@ impl Dialog {
         // Matches Borland: TView::locate() constrains position to owner bounds
         self.window.constrain_to_limits();
 
+        // Save what's beneath the dialog so closing it restores the screen
+        // even when the caller doesn't redraw
+        app.terminal.save_under(self.shadow_bounds());
+
+        // The desktop's windows are inactive while this dialog runs
+        app.desktop.on_modal_enter();
+
         // Event loop matching Borland's TGroup::execute() (tgroup.cc:182-195)
         // IMPORTANT: We can't just delegate to window.execute() because that would
         // call Group::handle_event(), but we need Dialog::handle_event() to be called
@@ -169,9 +263,15 @@ impl Dialog {
             self.update_cursor(&mut app.terminal);
             let _ = app.terminal.flush();
 
-            // Poll for event with 20ms timeout (matches magiblot's eventTimeoutMs)
+            // Poll for event with the application's poll interval
             // This blocks until an event arrives or timeout occurs
-            match app.terminal.poll_event(Duration::from_millis(20)).ok().flatten() {
+            match app.terminal.poll_event(app.poll_interval()).ok().flatten() {
+                Some(event) if event.what == EventType::Resize => {
+                    // Terminal was resized - re-lay out the application and keep the
+                    // dialog on screen before the next redraw
+                    app.handle_resize();
+                    self.reposition_after_resize(app.desktop.get_bounds());
+                }
                 Some(mut event) => {
                     // Event received - handle it immediately without calling idle()
                     // Matches magiblot: idle() is NOT called when events are present
@@ -200,10 +300,146 @@ impl Dialog {
             }
         }
 
+        app.desktop.on_modal_leave();
+        let _ = app.terminal.restore_under();
         self.result
     }
 }
 
+/// Buttons available in a standard dialog button row
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StandardButton {
+    Ok,
+    Cancel,
+    Yes,
+    No,
+    Help,
+    /// Restores the values captured by [`Dialog::capture_initial_state()`]
+    Reset,
+}
+
+impl StandardButton {
+    /// Button label with shortcut markers
+    /// Matches Borland's titles in msgbox.cc and tvtext1.cc, except that Cancel
+    /// keeps the ~C~ shortcut the message boxes here always had
+    pub fn label(self) -> &'static str {
+        match self {
+            StandardButton::Ok => "O~K~",
+            StandardButton::Cancel => "~C~ancel",
+            StandardButton::Yes => "~Y~es",
+            StandardButton::No => "~N~o",
+            StandardButton::Help => "~H~elp",
+            StandardButton::Reset => "~R~eset",
+        }
+    }
+
+    /// Command emitted when the button is pressed
+    pub fn command(self) -> CommandId {
+        use crate::core::command::{CM_HELP, CM_NO, CM_OK, CM_RESET, CM_YES};
+        match self {
+            StandardButton::Ok => CM_OK,
+            StandardButton::Cancel => CM_CANCEL,
+            StandardButton::Yes => CM_YES,
+            StandardButton::No => CM_NO,
+            StandardButton::Help => CM_HELP,
+            StandardButton::Reset => CM_RESET,
+        }
+    }
+}
+
+/// Standard button combinations for [`standard_buttons()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ButtonSet {
+    Ok,
+    OkCancel,
+    OkCancelHelp,
+    YesNo,
+    YesNoCancel,
+    Cancel,
+}
+
+impl ButtonSet {
+    /// Buttons in this set, in left-to-right order
+    pub fn buttons(self) -> &'static [StandardButton] {
+        use StandardButton::{Cancel, Help, No, Ok, Yes};
+        match self {
+            ButtonSet::Ok => &[Ok],
+            ButtonSet::OkCancel => &[Ok, Cancel],
+            ButtonSet::OkCancelHelp => &[Ok, Cancel, Help],
+            ButtonSet::YesNo => &[Yes, No],
+            ButtonSet::YesNoCancel => &[Yes, No, Cancel],
+            ButtonSet::Cancel => &[Cancel],
+        }
+    }
+}
+
+/// Standard button size (matches Borland: 10x2 dialog buttons)
+const STANDARD_BUTTON_WIDTH: i16 = 10;
+const STANDARD_BUTTON_HEIGHT: i16 = 2;
+const STANDARD_BUTTON_SPACING: i16 = 2;
+
+/// Add a standard button row (OK/Cancel/Help, Yes/No/Cancel, ...) to a dialog
+///
+/// Buttons are 10x2, spaced by 2 columns, centered on the bottom row of the dialog content
+/// ([`Dialog::content_rect()`], so the content padding is respected).
+/// OK, or a lone button, is marked as the default; Yes/No rows have none.
+/// Returns the ids of the created buttons in left-to-right order.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use turbo_vision::views::dialog::{standard_buttons, ButtonSet, Dialog};
+///
+/// let mut dialog = Dialog::new(Rect::new(0, 0, 50, 10), "Options");
+/// let ids = standard_buttons(&mut dialog, ButtonSet::OkCancelHelp);
+/// ```
+pub fn standard_buttons(dialog: &mut Dialog, set: ButtonSet) -> Vec<ViewId> {
+    standard_button_row(dialog, set.buttons())
+}
+
+/// Add an arbitrary row of standard buttons to a dialog
+///
+/// Same layout rules as [`standard_buttons()`], for combinations not covered by [`ButtonSet`].
+/// OK, or a button on its own, is the default; otherwise there is none and
+/// Enter presses the focused button.
+pub fn standard_button_row(dialog: &mut Dialog, buttons: &[StandardButton]) -> Vec<ViewId> {
+    let default = match buttons {
+        [only] => *only,
+        _ => StandardButton::Ok,
+    };
+    standard_button_row_with_default(dialog, buttons, default)
+}
+
+/// Add a row of standard buttons with an explicit default button
+///
+/// Use for destructive prompts where Enter should pick the safe answer (e.g. No).
+/// If `default` is not in the row, no button is the default.
+pub fn standard_button_row_with_default(dialog: &mut Dialog, buttons: &[StandardButton], default: StandardButton) -> Vec<ViewId> {
+    use super::button::Button;
+
+    if buttons.is_empty() {
+        return Vec::new();
+    }
+
+    let default = buttons.iter().position(|b| *b == default);
+
+    // Button coordinates are relative to the dialog content (inside frame and padding)
+    let content = dialog.content_rect();
+    let count = buttons.len() as i16;
+    let total_width = count * STANDARD_BUTTON_WIDTH + (count - 1) * STANDARD_BUTTON_SPACING;
+
+    let mut x = content.a.x + ((content.width() - total_width) / 2).max(0);
+    let y = content.b.y - STANDARD_BUTTON_HEIGHT;
+
+    let mut ids = Vec::with_capacity(buttons.len());
+    for (i, button) in buttons.iter().enumerate() {
+        let button_bounds = Rect::new(x, y, x + STANDARD_BUTTON_WIDTH, y + STANDARD_BUTTON_HEIGHT);
+        ids.push(dialog.add(Box::new(Button::new(button_bounds, button.label(), button.command(), Some(i) == default))));
+        x += STANDARD_BUTTON_WIDTH + STANDARD_BUTTON_SPACING;
+    }
+    ids
+}
+
 impl View for Dialog {
     fn bounds(&self) -> Rect {
         self.window.bounds()
@@ -215,6 +451,9 @@ impl View for Dialog {
 
     fn draw(&mut self, terminal: &mut Terminal) {
         self.window.draw(terminal);
+        if std::mem::take(&mut self.beep_pending) {
+            let _ = terminal.beep();
+        }
     }
 
     fn handle_event(&mut self, event: &mut Event) {
@@ -252,6 +491,11 @@ impl View for Dialog {
                         *event = Event::command(default_command);
                         // Re-process as command (will be handled below)
                         self.handle_event(event);
+                    } else if self.has_default_button() {
+                        // The default button is disabled and nothing else may take
+                        // Enter: beep instead of ignoring the key silently
+                        self.beep_pending = true;
+                        event.clear();
                     }
                     return;
                 }
@@ -266,13 +510,24 @@ impl View for Dialog {
         // IMPORTANT: Only intercept commands when dialog is actually modal!
         // Non-modal dialogs (added to desktop) should let commands pass through
         // Matches Borland: TDialog::handleEvent() checks for these commands
+        if event.what == EventType::Command && event.command == crate::core::command::CM_RESET {
+            // Reset button - restore the captured values and keep the dialog open
+            self.reset_to_initial();
+            event.clear();
+            return;
+        }
+
         if event.what == EventType::Command {
-            use crate::core::command::{CM_CANCEL, CM_OK, CM_YES, CM_NO};
+            use crate::core::command::{CM_CANCEL, CM_HELP, CM_OK, CM_YES, CM_NO};
             use crate::core::state::SF_MODAL;
 
             // Only intercept commands if this dialog is modal
             if self.state() & SF_MODAL != 0 {
                 match event.command {
+                    CM_HELP => {
+                        // Help button - keep the dialog open and let the
+                        // application or help system handle the command
+                    }
                     CM_CANCEL => {
                         // Cancel button or Esc-Esc pressed
                         // End the modal loop with CM_CANCEL
@@ -310,6 +565,10 @@ impl View for Dialog {
         }
     }
 
+    fn is_opaque(&self) -> bool {
+        self.window.is_opaque()
+    }
+
     fn state(&self) -> crate::core::state::StateFlags {
         self.window.state()
     }
@@ -318,6 +577,22 @@ impl View for Dialog {
         self.window.set_state(state);
     }
 
+    fn has_mouse_capture(&self) -> bool {
+        self.window.has_mouse_capture()
+    }
+
+    fn on_modal_enter(&mut self) {
+        self.window.on_modal_enter();
+    }
+
+    fn on_modal_leave(&mut self) {
+        self.window.on_modal_leave();
+    }
+
+    fn on_activate(&mut self, active: bool) {
+        self.window.on_activate(active);
+    }
+
     fn options(&self) -> u16 {
         self.window.options()
     }
@@ -379,30 +654,45 @@ impl View for Dialog {
         self.window.get_end_state()
     }
 
+    fn set_end_state(&mut self, command: crate::core::command::CommandId) {
+        self.window.set_end_state(command);
+    }
+
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
         self
     }
 }
 
 impl Dialog {
-    /// Find the default button and return its command if it's enabled
-    /// Returns None if no default button found or if it's disabled
+    /// Find the button Enter should press and return its command
+    /// Returns None if no enabled button may take Enter
     /// Matches Borland's TButton::handleEvent() cmDefault broadcast handling (tbutton.cc lines 238-244)
+    ///
+    /// When the default button is disabled, Enter falls back to the focused
+    /// button, then to the first enabled button marked ok-on-enter.
     fn find_default_button_command(&self) -> Option<CommandId> {
-        for i in 0..self.child_count() {
+        // A button that can receive focus (i.e., is not disabled)
+        // Borland checks: amDefault && !(state & sfDisabled)
+        let enabled_command = |i: usize| {
             let child = self.child_at(i);
-            if child.is_default_button() {
-                // Check if the button can receive focus (i.e., not disabled)
-                // Borland checks: amDefault && !(state & sfDisabled)
-                if child.can_focus() {
-                    return child.button_command();
-                } else {
-                    // Default button is disabled
-                    return None;
-                }
+            if child.can_focus() {
+                child.button_command()
+            } else {
+                None
             }
-        }
-        None
+        };
+        let children = 0..self.child_count();
+
+        children
+            .clone()
+            .find(|&i| self.child_at(i).is_default_button())
+            .and_then(enabled_command)
+            .or_else(|| children.clone().filter(|&i| self.child_at(i).is_focused()).find_map(enabled_command))
+            .or_else(|| children.filter(|&i| self.child_at(i).is_ok_on_enter_button()).find_map(enabled_command))
+    }
+
+    fn has_default_button(&self) -> bool {
+        (0..self.child_count()).any(|i| self.child_at(i).is_default_button())
     }
 }
 
@@ -507,6 +797,7 @@ impl Default for DialogBuilder {
 mod tests {
     use super::*;
     use crate::core::state::SF_MODAL;
+    use crate::views::EnterBehavior;
 
     /// Regression test for FileDialog folder navigation bug (issue #73 follow-up)
     ///
@@ -670,4 +961,244 @@ mod tests {
             "Non-modal dialog should not set end_state for internal commands"
         );
     }
+
+    #[test]
+    fn test_reposition_after_resize_recenters_centered_dialog() {
+        use crate::core::state::OF_CENTERED;
+
+        let mut dialog = Dialog::new(Rect::new(30, 10, 70, 20), "Test");
+        dialog.set_options(dialog.options() | OF_CENTERED);
+
+        dialog.reposition_after_resize(Rect::new(0, 1, 60, 17));
+        assert_eq!(dialog.bounds(), Rect::new(10, 4, 50, 14));
+    }
+
+    #[test]
+    fn test_reposition_after_resize_clamps_uncentered_dialog() {
+        let mut dialog = Dialog::new(Rect::new(30, 10, 70, 20), "Test");
+
+        dialog.reposition_after_resize(Rect::new(0, 1, 60, 17));
+        let bounds = dialog.bounds();
+        assert_eq!(bounds.width(), 40);
+        assert_eq!(bounds.height(), 10);
+        assert!(bounds.b.x <= 60);
+        // Shadow row must also fit
+        assert!(bounds.b.y < 17);
+    }
+
+    #[test]
+    fn test_standard_buttons_layout() {
+        let mut dialog = Dialog::new(Rect::new(0, 0, 50, 10), "Test");
+        let ids = standard_buttons(&mut dialog, ButtonSet::OkCancelHelp);
+        assert_eq!(ids.len(), 3);
+
+        let bounds: Vec<Rect> = ids.iter().map(|id| dialog.child_by_id(*id).unwrap().bounds()).collect();
+        // Centered in the 48-column interior, on the bottom interior rows
+        assert_eq!(bounds[0], Rect::new(8, 7, 18, 9));
+        assert_eq!(bounds[1], Rect::new(20, 7, 30, 9));
+        assert_eq!(bounds[2], Rect::new(32, 7, 42, 9));
+
+        let commands: Vec<CommandId> = ids.iter().map(|id| dialog.child_by_id(*id).unwrap().button_command().unwrap()).collect();
+        assert_eq!(commands, vec![crate::core::command::CM_OK, CM_CANCEL, crate::core::command::CM_HELP]);
+        assert!(dialog.child_by_id(ids[0]).unwrap().is_default_button());
+        assert!(!dialog.child_by_id(ids[1]).unwrap().is_default_button());
+    }
+
+    #[test]
+    fn test_standard_buttons_respect_content_padding() {
+        let mut dialog = Dialog::new(Rect::new(0, 0, 50, 10), "Test");
+        dialog.set_content_padding(2, 1, 2, 1);
+        assert_eq!(dialog.content_rect(), Rect::new(0, 0, 44, 6));
+
+        let ids = standard_buttons(&mut dialog, ButtonSet::OkCancel);
+        // Centered in the 44-column content, on its bottom rows (absolute coordinates)
+        assert_eq!(dialog.child_by_id(ids[0]).unwrap().bounds(), Rect::new(14, 6, 24, 8));
+
+        // The padding is kept when the dialog is resized
+        dialog.set_bounds(Rect::new(0, 0, 60, 12));
+        assert_eq!(dialog.content_rect(), Rect::new(0, 0, 54, 8));
+    }
+
+    /// Modal dialog holding `field` (focused) and OK (default) / Cancel
+    fn modal_dialog_with(field: Box<dyn View>) -> Dialog {
+        let mut dialog = Dialog::new(Rect::new(0, 0, 40, 12), "Enter");
+        dialog.add(field);
+        standard_buttons(&mut dialog, ButtonSet::OkCancel);
+        dialog.set_initial_focus();
+        let state = dialog.state();
+        dialog.set_state(state | SF_MODAL);
+        dialog
+    }
+
+    #[test]
+    fn test_enter_in_input_line_presses_default_button() {
+        use super::super::input_line::InputLine;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let data = Rc::new(RefCell::new(String::from("text")));
+        let mut dialog = modal_dialog_with(Box::new(InputLine::new(Rect::new(1, 1, 30, 2), 50, Rc::clone(&data))));
+        dialog.handle_event(&mut Event::keyboard(KB_ENTER));
+        assert_eq!(dialog.get_end_state(), crate::core::command::CM_OK);
+
+        let mut input = InputLine::new(Rect::new(1, 1, 30, 2), 50, data);
+        input.set_enter_behavior(EnterBehavior::Consume);
+        let mut dialog = modal_dialog_with(Box::new(input));
+        dialog.handle_event(&mut Event::keyboard(KB_ENTER));
+        assert_eq!(dialog.get_end_state(), 0);
+    }
+
+    #[test]
+    fn test_enter_in_memo_inserts_line_break() {
+        use super::super::memo::Memo;
+
+        let mut dialog = modal_dialog_with(Box::new(Memo::new(Rect::new(1, 1, 30, 6))));
+        dialog.handle_event(&mut Event::keyboard('a' as u16));
+        dialog.handle_event(&mut Event::keyboard(KB_ENTER));
+        dialog.handle_event(&mut Event::keyboard('b' as u16));
+        assert_eq!(dialog.get_end_state(), 0);
+        let memo = dialog.child_at(0).as_any().downcast_ref::<Memo>().unwrap();
+        assert_eq!(memo.get_text(), "a\nb");
+
+        let mut memo = Memo::new(Rect::new(1, 1, 30, 6));
+        memo.set_enter_behavior(EnterBehavior::Submit);
+        let mut dialog = modal_dialog_with(Box::new(memo));
+        dialog.handle_event(&mut Event::keyboard(KB_ENTER));
+        assert_eq!(dialog.get_end_state(), crate::core::command::CM_OK);
+    }
+
+    #[test]
+    fn test_memo_writes_bound_data_and_tab_leaves_it() {
+        use super::super::memo::MemoBuilder;
+        use crate::core::event::{KB_END, KB_TAB};
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let notes = Rc::new(RefCell::new(String::from("first")));
+        let memo = MemoBuilder::new().bounds(Rect::new(1, 1, 30, 6)).data(Rc::clone(&notes)).build_boxed();
+        let mut dialog = modal_dialog_with(memo);
+        for key in [KB_END, KB_ENTER, 'b' as u16, KB_TAB] {
+            dialog.handle_event(&mut Event::keyboard(key));
+        }
+
+        assert_eq!(*notes.borrow(), "first\nb");
+        assert!(!dialog.child_at(0).is_focused(), "Tab moves on to the buttons");
+    }
+
+    #[test]
+    fn test_editing_marks_dirty_and_reset_restores() {
+        use super::super::checkbox::CheckBox;
+        use super::super::input_line::InputLine;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let data = Rc::new(RefCell::new(String::from("80")));
+        let mut dialog = modal_dialog_with(Box::new(InputLine::new(Rect::new(1, 1, 30, 2), 50, Rc::clone(&data))));
+        dialog.add(Box::new(CheckBox::new(Rect::new(1, 3, 30, 4), "Wrap")));
+        standard_button_row(&mut dialog, &[StandardButton::Reset]);
+        dialog.capture_initial_state();
+        assert!(!dialog.is_dirty());
+
+        dialog.handle_event(&mut Event::keyboard('0' as u16));
+        assert_eq!(*data.borrow(), "800");
+        assert!(dialog.is_dirty());
+
+        dialog.reset_to_initial();
+        assert_eq!(*data.borrow(), "80");
+        assert!(!dialog.is_dirty());
+
+        // The Reset button does the same and keeps the dialog open
+        let checkbox = dialog.child_count() - 2;
+        dialog.child_at_mut(checkbox).set_data(&DataValue::Value(1));
+        assert!(dialog.is_dirty());
+        let mut event = Event::command(crate::core::command::CM_RESET);
+        dialog.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Nothing);
+        assert_eq!(dialog.get_end_state(), 0);
+        assert!(!dialog.is_dirty());
+    }
+
+    #[test]
+    fn test_enter_follows_command_set_of_default_button() {
+        use super::super::button::ButtonBuilder;
+        use super::super::input_line::InputLine;
+        use crate::core::command::{CM_COMMAND_SET_CHANGED, CM_OK};
+        use crate::core::command_set;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        command_set::enable_command(CM_OK);
+        let data = Rc::new(RefCell::new(String::new()));
+        let mut dialog = modal_dialog_with(Box::new(InputLine::new(Rect::new(1, 1, 30, 2), 50, data)));
+        let ok = (0..dialog.child_count()).find(|&i| dialog.child_at(i).is_default_button()).unwrap();
+        let ok_cell = {
+            let b = dialog.child_at(ok).bounds();
+            (b.a.x + 1, b.a.y)
+        };
+        let mut terminal = Terminal::new_headless(40, 12);
+        let set_ok_enabled = |dialog: &mut Dialog, enabled: bool| {
+            if enabled {
+                command_set::enable_command(CM_OK);
+            } else {
+                command_set::disable_command(CM_OK);
+            }
+            dialog.handle_event(&mut Event::broadcast(CM_COMMAND_SET_CHANGED));
+        };
+        let press_enter = |dialog: &mut Dialog| {
+            dialog.set_end_state(0);
+            let mut event = Event::keyboard(KB_ENTER);
+            dialog.handle_event(&mut event);
+            assert_eq!(event.what, EventType::Nothing, "Enter is never a dead key");
+            dialog.get_end_state()
+        };
+
+        // Enabled: Enter presses OK
+        dialog.draw(&mut terminal);
+        let default_attr = terminal.read_cell(ok_cell.0, ok_cell.1).unwrap().attr;
+        assert_eq!(press_enter(&mut dialog), CM_OK);
+
+        // Disabled and nothing to fall back to: the bell rings on the next draw
+        set_ok_enabled(&mut dialog, false);
+        assert_eq!(press_enter(&mut dialog), 0);
+        terminal.take_output();
+        dialog.draw(&mut terminal);
+        terminal.flush().unwrap();
+        assert!(terminal.take_output().contains(&0x07));
+
+        // Disabled with an ok-on-enter button: Enter falls back to it
+        const CM_APPLY: CommandId = 20;
+        dialog.add(Box::new(ButtonBuilder::new().bounds(Rect::new(1, 4, 11, 6)).title("Apply").command(CM_APPLY).ok_on_enter(true).build()));
+        assert_eq!(press_enter(&mut dialog), CM_APPLY);
+
+        // Enabled again: the default highlight is back and focus has not moved
+        set_ok_enabled(&mut dialog, true);
+        assert!(dialog.child_at(0).is_focused());
+        dialog.draw(&mut terminal);
+        assert_eq!(terminal.read_cell(ok_cell.0, ok_cell.1).unwrap().attr, default_attr);
+        assert_eq!(press_enter(&mut dialog), CM_OK);
+    }
+
+    #[test]
+    fn test_standard_buttons_yes_no_cancel_has_no_default() {
+        let mut dialog = Dialog::new(Rect::new(0, 0, 50, 10), "Test");
+        let ids = standard_buttons(&mut dialog, ButtonSet::YesNoCancel);
+        let defaults: Vec<bool> = ids.iter().map(|id| dialog.child_by_id(*id).unwrap().is_default_button()).collect();
+        assert_eq!(defaults, vec![false, false, false]);
+
+        let mut dialog = Dialog::new(Rect::new(0, 0, 50, 10), "Test");
+        let ids = standard_button_row(&mut dialog, &[StandardButton::Cancel]);
+        assert!(dialog.child_by_id(ids[0]).unwrap().is_default_button());
+    }
+
+    #[test]
+    fn test_help_command_does_not_close_dialog() {
+        let mut dialog = Dialog::new(Rect::new(0, 0, 40, 10), "Test");
+        let current_state = dialog.state();
+        dialog.set_state(current_state | SF_MODAL);
+
+        let mut event = Event::command(crate::core::command::CM_HELP);
+        dialog.handle_event(&mut event);
+        assert_eq!(dialog.get_end_state(), 0);
+        assert_eq!(event.what, EventType::Command);
+    }
 }
@@ -14,6 +14,8 @@
 // - Navigate up and down the directory tree
 // - Expand/collapse directories
 // - Current path tracking
+// - Incremental reading of subdirectories, ESC cancels a slow listing
+// - Ctrl+C copies the focused directory's full path to the clipboard
 //
 // Display format:
 //   C:\
@@ -23,11 +25,13 @@
 //   └─ Program Files
 
 use crate::core::geometry::Rect;
-use crate::core::event::{Event, EventType, KB_ENTER};
+use crate::core::clipboard;
+use crate::core::event::{Event, EventType, KB_CTRL_C, KB_ENTER, KB_ESC};
 use crate::core::state::StateFlags;
 use crate::terminal::Terminal;
 use super::view::View;
 use super::list_viewer::{ListViewer, ListViewerState};
+use super::dir_reader::DirReader;
 use std::path::{Path, PathBuf};
 use std::fs;
 
@@ -82,6 +86,10 @@ pub struct DirListBox {
     entries: Vec<DirEntry>,
     current_path: PathBuf,
     root_path: PathBuf,
+    /// Subdirectory listing still in progress
+    reader: Option<DirReader>,
+    /// Index of the first subdirectory entry of the current directory
+    subdirs_start: usize,
     owner: Option<*const dyn View>,
     owner_type: super::view::OwnerType,
 }
@@ -96,6 +104,8 @@ impl DirListBox {
             entries: Vec::new(),
             current_path: path.to_path_buf(),
             root_path: Self::find_root(path),
+            reader: None,
+            subdirs_start: 0,
             owner: None,
             owner_type: super::view::OwnerType::None,
         };
@@ -128,6 +138,12 @@ impl DirListBox {
         self.entries.get(idx)
     }
 
+    /// Absolute path of the focused directory
+    pub fn selected_path(&self) -> Option<PathBuf> {
+        let path = &self.get_focused_entry()?.path;
+        Some(std::path::absolute(path).unwrap_or_else(|_| path.clone()))
+    }
+
     /// Navigate to a different directory
     pub fn change_dir(&mut self, path: &Path) -> std::io::Result<()> {
         if path.is_dir() {
@@ -186,33 +202,10 @@ impl DirListBox {
             });
         }
 
-        // Add subdirectories of current directory
-        if let Ok(entries) = fs::read_dir(&self.current_path) {
-            let mut subdirs: Vec<_> = entries
-                .filter_map(|e| e.ok())
-                .filter_map(|e| {
-                    let path = e.path();
-                    if path.is_dir() {
-                        Some((e.file_name().to_string_lossy().to_string(), path))
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-
-            subdirs.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
-
-            let current_level = path_components.len() + 1;
-            for (i, (name, path)) in subdirs.iter().enumerate() {
-                let is_last = i == subdirs.len() - 1;
-                self.entries.push(DirEntry {
-                    name: name.clone(),
-                    path: path.clone(),
-                    level: current_level,
-                    is_last,
-                });
-            }
-        }
+        // Start reading the subdirectories of the current directory
+        self.subdirs_start = self.entries.len();
+        self.reader = DirReader::open(&self.current_path).ok();
+        self.poll_directory();
 
         // Update list state
         self.list_state.set_range(self.entries.len());
@@ -225,6 +218,52 @@ impl DirListBox {
         }
     }
 
+    /// Read the next chunk of subdirectories into the tree
+    ///
+    /// Returns true while the listing is still in progress. `draw()` calls
+    /// this once per frame.
+    pub fn poll_directory(&mut self) -> bool {
+        let Some(reader) = self.reader.as_mut() else {
+            return false;
+        };
+        let chunk = reader.read_chunk();
+        let done = reader.is_done();
+
+        // Root plus one entry per path component come first, so this is also the nesting level
+        let level = self.subdirs_start;
+        let before = self.entries.len();
+        self.entries.extend(chunk.into_iter().filter(|e| e.is_dir).map(|e| DirEntry {
+            name: e.name,
+            path: e.path,
+            level,
+            is_last: false,
+        }));
+
+        if self.entries.len() > before {
+            let subdirs = &mut self.entries[self.subdirs_start..];
+            subdirs.sort_by_key(|e| e.name.to_lowercase());
+            let last = subdirs.len() - 1;
+            for (i, entry) in subdirs.iter_mut().enumerate() {
+                entry.is_last = i == last;
+            }
+            self.list_state.set_range(self.entries.len());
+        }
+        if done {
+            self.reader = None;
+        }
+        !done
+    }
+
+    /// True while subdirectories are still being read
+    pub fn is_reading(&self) -> bool {
+        self.reader.is_some()
+    }
+
+    /// Stop reading subdirectories, keeping the ones read so far
+    pub fn cancel_reading(&mut self) {
+        self.reader = None;
+    }
+
     /// Enter the focused directory
     pub fn enter_focused_dir(&mut self) -> std::io::Result<()> {
         if let Some(entry) = self.get_focused_entry() {
@@ -299,6 +338,7 @@ impl View for DirListBox {
         let width = self.bounds.width_clamped() as usize;
         let height = self.bounds.height_clamped() as usize;
 
+        self.poll_directory();
         self.list_state.set_range(self.entries.len());
 
         // Use direct Attr colors (matching FileList behavior)
@@ -339,26 +379,47 @@ impl View for DirListBox {
                 );
             }
         }
+
+        if let Some(ref reader) = self.reader {
+            reader.draw_status(terminal, self.bounds, color_normal);
+        }
     }
 
     fn handle_event(&mut self, event: &mut Event) {
-        // Handle double-click BEFORE focus check (to allow clicking to focus AND navigate)
-        if event.what == EventType::MouseDown {
-            use crate::core::event::MB_LEFT_BUTTON;
-            if self.bounds.contains(event.mouse.pos) && event.mouse.buttons & MB_LEFT_BUTTON != 0 {
-                if event.mouse.double_click && self.is_focused() {
-                    // Double-click navigates into directory (only when already focused)
-                    let _ = self.enter_focused_dir();
-                    event.clear();
-                    return;
-                }
-            }
+        // The wheel scrolls the tree under the pointer, focused or not
+        if event.is_wheel() {
+            self.handle_list_event(event);
+            return;
         }
 
         if !self.is_focused() {
             return;
         }
 
+        // Double-click navigates into the clicked directory
+        if event.what == EventType::MouseDown && event.mouse.double_click {
+            if self.handle_list_event(event) {
+                let _ = self.enter_focused_dir();
+            }
+            return;
+        }
+
+        // ESC aborts a listing that is still being read
+        if self.is_reading() && event.what == EventType::Keyboard && event.key_code == KB_ESC {
+            self.cancel_reading();
+            event.clear();
+            return;
+        }
+
+        // Ctrl+C copies the focused directory's full path
+        if event.what == EventType::Keyboard && event.key_code == KB_CTRL_C {
+            if let Some(path) = self.selected_path() {
+                clipboard::set_clipboard(&path.to_string_lossy());
+            }
+            event.clear();
+            return;
+        }
+
         // Use default ListViewer navigation
         self.handle_list_event(event);
 
@@ -442,6 +503,32 @@ mod tests {
         assert!(text.contains("subdir"));
     }
 
+    #[test]
+    fn test_mouse_selects_and_enters_directories() {
+        use crate::core::event::MB_LEFT_BUTTON;
+        use crate::core::geometry::Point;
+
+        let dir = tempfile::tempdir().unwrap();
+        let root = fs::canonicalize(dir.path()).unwrap();
+        fs::create_dir(root.join("one")).unwrap();
+        fs::create_dir(root.join("two")).unwrap();
+        let mut dlb = DirListBox::new(Rect::new(0, 2, 40, 40), &root);
+        while dlb.poll_directory() {}
+        dlb.set_focus(true);
+        let row_of = |dlb: &DirListBox, name: &str| 2 + dlb.entries.iter().position(|e| e.name == name).unwrap() as i16;
+        let click = |y, double| Event::mouse(EventType::MouseDown, Point::new(3, y), MB_LEFT_BUTTON, double);
+
+        let mut event = click(row_of(&dlb, "two"), false);
+        dlb.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Nothing);
+        assert_eq!(dlb.get_focused_entry().unwrap().name, "two");
+        assert_eq!(dlb.current_path(), root.as_path(), "a single click only selects");
+
+        let mut event = click(row_of(&dlb, "one"), true);
+        dlb.handle_event(&mut event);
+        assert_eq!(dlb.current_path(), root.join("one").as_path());
+    }
+
     #[test]
     fn test_parent_navigation() {
         let path = env::current_dir().unwrap();
@@ -0,0 +1,227 @@
+// (C) 2025 - Enzo Lombardi
+
+//! DirReader - incremental, cancelable directory listing.
+//!
+//! Reading a directory with `fs::read_dir` in one go freezes the UI on slow
+//! filesystems (network mounts) or huge directories. [`DirReader`] hands out
+//! the entries in chunks instead, so a view can read one chunk per frame,
+//! show what it has so far and stay responsive to ESC/Cancel, which call
+//! [`DirReader::cancel()`].
+//!
+//! Used by [`FileList`](super::file_list::FileList),
+//! [`DirListBox`](super::dir_listbox::DirListBox) and
+//! [`FileDialog`](super::file_dialog::FileDialog).
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use turbo_vision::views::dir_reader::DirReader;
+//!
+//! let mut reader = DirReader::open(std::path::Path::new("/mnt/share"))?;
+//! while !reader.is_done() {
+//!     let chunk = reader.read_chunk();
+//!     // ... add `chunk` to the list, draw, poll events ...
+//! #   drop(chunk);
+//! }
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use super::file_list::FileEntry;
+use super::view::write_line_to_terminal;
+use crate::core::draw::DrawBuffer;
+use crate::core::geometry::Rect;
+use crate::core::palette::Attr;
+use crate::terminal::Terminal;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Entries read per chunk by default
+pub const DEFAULT_CHUNK_SIZE: usize = 500;
+
+/// Source of directory entries
+pub type DirEntries = Box<dyn Iterator<Item = io::Result<FileEntry>>>;
+
+/// Reads a directory a chunk at a time
+pub struct DirReader {
+    /// `None` once the listing is complete or canceled
+    source: Option<DirEntries>,
+    chunk_size: usize,
+    entries_read: usize,
+    canceled: bool,
+}
+
+impl DirReader {
+    /// Start listing `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from `fs::read_dir` if the directory cannot be opened.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let entries = fs::read_dir(path)?.map(|entry| entry.and_then(|e| FileEntry::from_dir_entry(&e)));
+        Ok(Self::from_entries(entries))
+    }
+
+    /// List entries from any source (e.g. a remote listing, or a test double)
+    pub fn from_entries<I>(entries: I) -> Self
+    where
+        I: Iterator<Item = io::Result<FileEntry>> + 'static,
+    {
+        Self {
+            source: Some(Box::new(entries)),
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            entries_read: 0,
+            canceled: false,
+        }
+    }
+
+    /// Maximum number of entries returned by one `read_chunk()` call
+    pub fn set_chunk_size(&mut self, chunk_size: usize) {
+        self.chunk_size = chunk_size.max(1);
+    }
+
+    /// Read up to one chunk of entries; entries that cannot be read are skipped
+    pub fn read_chunk(&mut self) -> Vec<FileEntry> {
+        let Some(source) = self.source.as_mut() else {
+            return Vec::new();
+        };
+
+        let mut chunk = Vec::new();
+        let mut pulled = 0;
+        while pulled < self.chunk_size {
+            match source.next() {
+                Some(Ok(entry)) => chunk.push(entry),
+                Some(Err(_)) => {}
+                None => {
+                    self.source = None;
+                    break;
+                }
+            }
+            pulled += 1;
+        }
+        self.entries_read += chunk.len();
+        chunk
+    }
+
+    /// Read everything that is left (blocking)
+    pub fn read_all(&mut self) -> Vec<FileEntry> {
+        let mut all = Vec::new();
+        while !self.is_done() {
+            all.extend(self.read_chunk());
+        }
+        all
+    }
+
+    /// Stop listing; entries already returned stay valid
+    pub fn cancel(&mut self) {
+        if self.source.take().is_some() {
+            self.canceled = true;
+        }
+    }
+
+    /// True when the listing is complete or was canceled
+    pub fn is_done(&self) -> bool {
+        self.source.is_none()
+    }
+
+    pub fn is_canceled(&self) -> bool {
+        self.canceled
+    }
+
+    /// Number of entries read so far
+    pub fn entries_read(&self) -> usize {
+        self.entries_read
+    }
+
+    /// Progress text shown while reading, e.g. "(reading… 1500 entries)"
+    pub fn status_text(&self) -> String {
+        format!("(reading… {} entries)", self.entries_read)
+    }
+
+    /// Draw the progress text right-aligned on the bottom row of `bounds`
+    pub(crate) fn draw_status(&self, terminal: &mut Terminal, bounds: Rect, attr: Attr) {
+        let width = bounds.width_clamped() as usize;
+        if width == 0 || bounds.height_clamped() == 0 {
+            return;
+        }
+        let text = self.status_text();
+        let len = text.chars().count().min(width);
+        let mut buf = DrawBuffer::new(len);
+        buf.move_str_clipped(0, &text, attr, len);
+        write_line_to_terminal(terminal, bounds.b.x - len as i16, bounds.b.y - 1, &buf);
+    }
+}
+
+/// Sort entries for display: directories first, then files, both by name ignoring case
+pub fn sort_entries(entries: &mut [FileEntry]) {
+    entries.sort_by(|a, b| match (a.is_dir, b.is_dir) {
+        (true, false) => std::cmp::Ordering::Less,
+        (false, true) => std::cmp::Ordering::Greater,
+        _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::path::PathBuf;
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    fn entry(i: usize) -> io::Result<FileEntry> {
+        Ok(FileEntry {
+            name: format!("file{i:05}"),
+            path: PathBuf::from(format!("file{i:05}")),
+            is_dir: false,
+            size: 0,
+            modified: None,
+        })
+    }
+
+    /// A listing that takes a while per entry and counts how far it got
+    fn slow_listing(total: usize, pulled: Rc<Cell<usize>>) -> impl Iterator<Item = io::Result<FileEntry>> {
+        (0..total).map(move |i| {
+            std::thread::sleep(Duration::from_micros(50));
+            pulled.set(pulled.get() + 1);
+            entry(i)
+        })
+    }
+
+    #[test]
+    fn test_reads_in_chunks() {
+        let pulled = Rc::new(Cell::new(0));
+        let mut reader = DirReader::from_entries(slow_listing(250, Rc::clone(&pulled)));
+        reader.set_chunk_size(100);
+
+        assert_eq!(reader.read_chunk().len(), 100);
+        assert_eq!(pulled.get(), 100, "nothing is read ahead");
+        assert_eq!(reader.status_text(), "(reading… 100 entries)");
+        assert_eq!(reader.read_all().len(), 150);
+        assert!(reader.is_done());
+        assert!(!reader.is_canceled());
+    }
+
+    #[test]
+    fn test_cancel_stops_reading() {
+        let pulled = Rc::new(Cell::new(0));
+        let mut reader = DirReader::from_entries(slow_listing(100_000, Rc::clone(&pulled)));
+        reader.set_chunk_size(50);
+
+        reader.read_chunk();
+        reader.cancel();
+        assert!(reader.is_done());
+        assert!(reader.is_canceled());
+        assert!(reader.read_chunk().is_empty());
+        assert_eq!(pulled.get(), 50);
+        assert_eq!(reader.entries_read(), 50);
+    }
+
+    #[test]
+    fn test_unreadable_entries_are_skipped() {
+        let listing = vec![entry(0), Err(io::Error::from(io::ErrorKind::PermissionDenied)), entry(2)];
+        let mut reader = DirReader::from_entries(listing.into_iter());
+        let names: Vec<_> = reader.read_all().into_iter().map(|e| e.name).collect();
+        assert_eq!(names, vec!["file00000", "file00002"]);
+    }
+}
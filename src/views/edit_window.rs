@@ -126,6 +126,10 @@ impl View for SharedEditor {
         self.0.borrow_mut().set_options(options);
     }
 
+    fn is_opaque(&self) -> bool {
+        self.0.borrow().is_opaque()
+    }
+
     fn state(&self) -> StateFlags {
         self.0.borrow().state()
     }
@@ -162,7 +166,6 @@ pub struct EditWindow {
     h_scrollbar: Rc<RefCell<ScrollBar>>,
     #[allow(dead_code)] // Used for initialization, stored for lifetime management
     v_scrollbar: Rc<RefCell<ScrollBar>>,
-    #[allow(dead_code)] // Used for initialization, stored for lifetime management
     indicator: Rc<RefCell<Indicator>>,
     // Indices in window.frame_children for direct updates
     h_scrollbar_idx: usize,
@@ -239,6 +242,14 @@ impl EditWindow {
         edit_window
     }
 
+    /// Set the text format of the line:col indicator
+    ///
+    /// See [`Indicator::set_format()`]: `{line}`, `{col}` and `{modified}`
+    /// are substituted, e.g. `" Ln {line}, Col {col} "`.
+    pub fn set_indicator_format(&mut self, format: &str) {
+        self.indicator.borrow_mut().set_format(format);
+    }
+
     /// Load a file into the editor
     pub fn load_file(&mut self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
         self.editor.borrow_mut().load_file(path)
@@ -328,34 +339,8 @@ impl View for EditWindow {
         // Window handles updating all children (including scrollbars, indicator, and editor)
         self.window.set_bounds(bounds);
 
-        // Recalculate scrollbar positions based on NEW window size
-        let window_width = bounds.width();
-        let window_height = bounds.height();
-
-        // Update scrollbar bounds to new window edges
-        let h_bounds = Rect::new(
-            bounds.a.x + 18,
-            bounds.a.y + window_height - 1,
-            bounds.a.x + window_width - 2,
-            bounds.a.y + window_height,
-        );
-        self.window.update_frame_child(self.h_scrollbar_idx, h_bounds);
-
-        let v_bounds = Rect::new(
-            bounds.a.x + window_width - 1,
-            bounds.a.y + 1,
-            bounds.a.x + window_width,
-            bounds.a.y + window_height - 2,
-        );
-        self.window.update_frame_child(self.v_scrollbar_idx, v_bounds);
-
-        let ind_bounds = Rect::new(
-            bounds.a.x + 2,
-            bounds.a.y + window_height - 1,
-            bounds.a.x + 16,
-            bounds.a.y + window_height,
-        );
-        self.window.update_frame_child(self.indicator_idx, ind_bounds);
+        // Move scrollbars and indicator to the new window edges
+        self.sync_frame_children_positions();
     }
 
     fn draw(&mut self, terminal: &mut Terminal) {
@@ -464,8 +449,9 @@ impl View for EditWindow {
                 self.editor.borrow_mut().set_bounds(editor_bounds);
             }
 
-            // Note: Frame children (scrollbars, indicator) will be synced in draw()
-            // No need to update them here - this prevents duplicate work
+            // Lay out the scrollbars and indicator now, so their values and
+            // positions are right even before the next draw
+            self.sync_frame_children_positions();
         }
     }
 
@@ -481,6 +467,10 @@ impl View for EditWindow {
         self.window.set_options(options);
     }
 
+    fn is_opaque(&self) -> bool {
+        self.window.is_opaque()
+    }
+
     fn state(&self) -> StateFlags {
         self.window.state()
     }
@@ -493,6 +483,18 @@ impl View for EditWindow {
         self.window.get_palette()
     }
 
+    fn on_modal_enter(&mut self) {
+        self.window.on_modal_enter();
+    }
+
+    fn on_modal_leave(&mut self) {
+        self.window.on_modal_leave();
+    }
+
+    fn on_activate(&mut self, active: bool) {
+        self.window.on_activate(active);
+    }
+
     fn get_end_state(&self) -> crate::core::command::CommandId {
         self.window.get_end_state()
     }
@@ -546,6 +548,59 @@ mod tests {
         assert_eq!(window.get_filename(), Some(path2.to_string()));
     }
 
+    #[test]
+    fn test_zoom_and_unzoom_keeps_scroll_and_relayouts_scrollbars() {
+        let mut window = EditWindow::new(Rect::new(0, 0, 82, 22), "Test Editor");
+        let editor = window.editor_rc();
+        let text: String = (0..100).map(|i| format!("line {i}\n")).collect();
+        editor.borrow_mut().set_text(&text);
+        for _ in 0..50 {
+            window.handle_event(&mut Event::keyboard(crate::core::event::KB_DOWN));
+        }
+        let top = editor.borrow().scroll_offset().y;
+        assert!(top > 0);
+
+        window.set_bounds(Rect::new(0, 0, 42, 12));
+        let v_bar = window.v_scrollbar.borrow().bounds();
+        assert_eq!(v_bar, Rect::new(41, 1, 42, 10));
+        let (cursor, delta, rows) = {
+            let editor = editor.borrow();
+            (editor.cursor_position(), editor.scroll_offset(), editor.bounds().height())
+        };
+        assert!(cursor.y >= delta.y && cursor.y < delta.y + rows);
+
+        window.set_bounds(Rect::new(0, 0, 82, 22));
+        assert_eq!(editor.borrow().scroll_offset().y, top);
+        assert_eq!(window.v_scrollbar.borrow().bounds(), Rect::new(81, 1, 82, 20));
+    }
+
+    #[test]
+    fn test_indicator_follows_cursor_and_modified_state() {
+        use crate::core::event::{KB_DOWN, KB_END};
+        use crate::terminal::{render_to_cells, row_text};
+
+        let mut window = EditWindow::new(Rect::new(0, 0, 40, 10), "Test Editor");
+        window.editor_rc().borrow_mut().set_text("first line\nsecond line\n");
+        let bottom_row = |window: &mut EditWindow| row_text(&render_to_cells(window, 40, 10)[9]);
+        assert!(bottom_row(&mut window).contains(" 1:1 "));
+
+        window.handle_event(&mut Event::keyboard(KB_DOWN));
+        window.handle_event(&mut Event::keyboard(KB_END));
+        let row = bottom_row(&mut window);
+        // The indicator starts at column 2 of the frame; its colon sits at column 8
+        assert_eq!(row.chars().position(|c| c == ':'), Some(10));
+        assert!(row.contains(" 2:12 "));
+        assert_eq!(row.chars().nth(2), Some(' '));
+
+        window.handle_event(&mut Event::keyboard('!' as u16));
+        let row = bottom_row(&mut window);
+        assert!(row.contains(" 2:13 "));
+        assert_eq!(row.chars().nth(2), Some('*'));
+
+        window.set_indicator_format("Ln {line}, Col {col}");
+        assert!(bottom_row(&mut window).contains("Ln 2, Col 13"));
+    }
+
     #[test]
     fn test_edit_window_editor_access() {
         let bounds = Rect::new(0, 0, 80, 25);
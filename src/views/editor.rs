@@ -1,14 +1,24 @@
 // (C) 2025 - Enzo Lombardi
 
 //! Editor view - advanced multi-line text editor with syntax highlighting support.
+//!
+//! With [`Editor::set_word_wrap()`] on, long lines wrap at word boundaries to
+//! the view width instead of scrolling horizontally. The cursor keeps its
+//! logical (line, column) position; Up/Down, paging, the mouse and scrolling
+//! work in visual rows, and `delta.y` counts visual rows.
+//!
+//! Alt+drag (or [`Editor::set_block_selection()`], bound to `CM_BLOCK_SELECT`)
+//! selects a rectangle of columns instead of a run of text. Typing, Backspace
+//! and Del then act on every line of the rectangle, which shrinks to an empty
+//! column so that further typing goes on all the lines.
 
 use crate::core::geometry::{Point, Rect};
 use crate::core::event::{Event, EventType, KB_UP, KB_DOWN, KB_LEFT, KB_RIGHT, KB_PGUP, KB_PGDN, KB_HOME, KB_END, KB_ENTER, KB_BACKSPACE, KB_DEL, KB_TAB, MB_LEFT_BUTTON};
 use crate::core::draw::DrawBuffer;
 use crate::core::clipboard;
 use crate::core::state::StateFlags;
-use crate::terminal::Terminal;
-use super::view::{View, write_line_to_terminal};
+use crate::terminal::{CursorStyle, Terminal};
+use super::view::{EnterBehavior, View, write_line_to_terminal};
 use super::scrollbar::ScrollBar;
 use super::indicator::Indicator;
 use super::syntax::SyntaxHighlighter;
@@ -64,6 +74,8 @@ enum EditAction {
     DeleteText { pos: Point, text: String },
     InsertLine { line: usize, text: String },
     DeleteLine { line: usize, text: String },
+    /// Several actions undone and redone as one step (a block edit)
+    Group(Vec<EditAction>),
 }
 
 impl EditAction {
@@ -76,6 +88,7 @@ impl EditAction {
             EditAction::DeleteText { pos, text } => EditAction::InsertText { pos: *pos, text: text.clone() },
             EditAction::InsertLine { line, text } => EditAction::DeleteLine { line: *line, text: text.clone() },
             EditAction::DeleteLine { line, text } => EditAction::InsertLine { line: *line, text: text.clone() },
+            EditAction::Group(actions) => EditAction::Group(actions.iter().rev().map(EditAction::inverse).collect()),
         }
     }
 }
@@ -88,7 +101,11 @@ pub struct Editor {
     lines: Vec<String>,
     cursor: Point,
     delta: Point,
+    /// Scroll position before the last resize and the one the resize chose,
+    /// so the old position comes back with the old size (zoom / unzoom)
+    resize_anchor: Option<(Point, Point)>,
     selection_start: Option<Point>,
+    block_selection: bool, // The selection is a rectangle of columns
     state: StateFlags,
     v_scrollbar: Option<Rc<RefCell<ScrollBar>>>,
     h_scrollbar: Option<Rc<RefCell<ScrollBar>>>,
@@ -109,6 +126,8 @@ pub struct Editor {
     highlighter: Option<Box<dyn SyntaxHighlighter>>,
     owner: Option<*const dyn View>,
     owner_type: super::view::OwnerType,
+    enter_behavior: EnterBehavior,
+    word_wrap: bool,
 }
 
 impl Editor {
@@ -119,7 +138,9 @@ impl Editor {
             lines: vec![String::new()],
             cursor: Point::zero(),
             delta: Point::zero(),
+            resize_anchor: None,
             selection_start: None,
+            block_selection: false,
             state: 0,
             v_scrollbar: None,
             h_scrollbar: None,
@@ -137,6 +158,8 @@ impl Editor {
             highlighter: None,
             owner: None,
             owner_type: super::view::OwnerType::None,
+            enter_behavior: EnterBehavior::Consume,
+            word_wrap: false,
         }
     }
 
@@ -172,6 +195,40 @@ impl Editor {
         self.auto_indent = auto_indent;
     }
 
+    /// Choose whether Enter inserts a line break (default) or presses the
+    /// dialog's default button
+    pub fn set_enter_behavior(&mut self, behavior: EnterBehavior) {
+        self.enter_behavior = behavior;
+    }
+
+    pub fn enter_behavior(&self) -> EnterBehavior {
+        self.enter_behavior
+    }
+
+    /// Wrap long lines to the view width instead of scrolling horizontally
+    pub fn set_word_wrap(&mut self, word_wrap: bool) {
+        self.word_wrap = word_wrap;
+        self.delta = Point::new(0, 0);
+        self.resize_anchor = None;
+        self.ensure_cursor_visible();
+    }
+
+    pub fn word_wrap(&self) -> bool {
+        self.word_wrap
+    }
+
+    /// Make the selection a rectangle of columns (true) or a run of text (false)
+    ///
+    /// Applies to the current selection and the ones made with Shift+keys
+    /// afterwards; a mouse click sets it again, on with Alt held.
+    pub fn set_block_selection(&mut self, block: bool) {
+        self.block_selection = block;
+    }
+
+    pub fn block_selection(&self) -> bool {
+        self.block_selection
+    }
+
     /// Set syntax highlighter
     pub fn set_highlighter(&mut self, highlighter: Box<dyn SyntaxHighlighter>) {
         self.highlighter = Some(highlighter);
@@ -206,6 +263,7 @@ impl Editor {
         }
         self.cursor = Point::zero();
         self.delta = Point::zero();
+        self.resize_anchor = None;
         self.selection_start = None;
         self.modified = false;
         self.undo_stack.clear();
@@ -225,11 +283,27 @@ impl Editor {
         self.update_indicator();
     }
 
+    /// Mark the text as modified, e.g. after restoring it from a backup
+    pub fn mark_modified(&mut self) {
+        self.modified = true;
+        self.update_indicator();
+    }
+
     /// Get current line count
     pub fn line_count(&self) -> usize {
         self.lines.len()
     }
 
+    /// Cursor position (column, line), zero-based
+    pub fn cursor_position(&self) -> Point {
+        self.cursor
+    }
+
+    /// Scroll offset: first visible column and line
+    pub fn scroll_offset(&self) -> Point {
+        self.delta
+    }
+
     /// Get the maximum line width (length of the longest line)
     pub fn max_line_width(&self) -> usize {
         self.lines.iter().map(|line| line.len()).max().unwrap_or(0)
@@ -340,6 +414,8 @@ impl Editor {
 
     /// Find text starting from current cursor position
     fn find_from_cursor(&mut self, text: &str, options: SearchOptions) -> Option<Point> {
+        // A match is selected as a run of text
+        self.block_selection = false;
         let search_text = if options.case_sensitive {
             text.to_string()
         } else {
@@ -492,6 +568,98 @@ impl Editor {
         self.bounds
     }
 
+    /// Start column of each visual row of `line` in word-wrap mode
+    fn wrap_starts(&self, line: &str) -> Vec<usize> {
+        wrap_starts(line, self.get_content_area().width().max(1) as usize)
+    }
+
+    /// Position of `pos` in word-wrap mode: (visual row in the document, column in that row)
+    fn visual_pos(&self, pos: Point) -> (i16, i16) {
+        let line_idx = (pos.y.max(0) as usize).min(self.lines.len() - 1);
+        let rows_before: usize = self.lines[..line_idx].iter().map(|line| self.wrap_starts(line).len()).sum();
+        let starts = self.wrap_starts(&self.lines[line_idx]);
+        let col = pos.x.max(0) as usize;
+        // A column on a break belongs to the row it starts
+        let row = starts.iter().rposition(|&start| start <= col).unwrap_or(0);
+        ((rows_before + row) as i16, (col - starts[row]) as i16)
+    }
+
+    /// Cursor position for `col` in visual row `row` in word-wrap mode,
+    /// clamped to the text of that row
+    fn logical_pos(&self, row: i16, col: i16) -> Point {
+        let mut row = row.max(0) as usize;
+        for (line_idx, line) in self.lines.iter().enumerate() {
+            let starts = self.wrap_starts(line);
+            if row < starts.len() {
+                let len = line.chars().count();
+                // Past the end of a wrapped row is the last column of that row, not the next row
+                let end = starts.get(row + 1).map_or(len, |&next| next - 1);
+                let x = (starts[row] + col.max(0) as usize).min(end);
+                return Point::new(x as i16, line_idx as i16);
+            }
+            row -= starts.len();
+        }
+        let last = self.lines.len() - 1;
+        Point::new(self.lines[last].chars().count() as i16, last as i16)
+    }
+
+    /// Number of rows to scroll through: visual rows in word-wrap mode, lines otherwise
+    fn row_count(&self) -> i16 {
+        if self.word_wrap {
+            self.lines.iter().map(|line| self.wrap_starts(line).len() as i16).sum()
+        } else {
+            self.lines.len() as i16
+        }
+    }
+
+    /// Columns to scroll through horizontally (none in word-wrap mode)
+    fn scroll_width(&self) -> i16 {
+        if self.word_wrap { 0 } else { self.max_line_length() }
+    }
+
+    /// What each of the `height` screen rows shows: (line, first column, column limit)
+    ///
+    /// Columns at or past the limit belong to another row (word-wrap mode) or
+    /// are off screen.
+    fn screen_rows(&self, width: usize, height: usize) -> Vec<Option<(usize, usize, usize)>> {
+        if !self.word_wrap {
+            let start = self.delta.x.max(0) as usize;
+            return (0..height)
+                .map(|y| {
+                    let line_idx = (self.delta.y + y as i16) as usize;
+                    (line_idx < self.lines.len()).then_some((line_idx, start, start + width))
+                })
+                .collect();
+        }
+        let mut rows = Vec::with_capacity(height);
+        let mut skip = self.delta.y.max(0) as usize;
+        for (line_idx, line) in self.lines.iter().enumerate() {
+            let starts = self.wrap_starts(line);
+            for (i, &start) in starts.iter().enumerate() {
+                if skip > 0 {
+                    skip -= 1;
+                    continue;
+                }
+                if rows.len() == height {
+                    return rows;
+                }
+                rows.push(Some((line_idx, start, starts.get(i + 1).copied().unwrap_or(start + width))));
+            }
+        }
+        rows.resize(height, None);
+        rows
+    }
+
+    /// Cursor position relative to the top-left of the content area
+    fn cursor_screen_offset(&self) -> (i16, i16) {
+        if self.word_wrap {
+            let (row, col) = self.visual_pos(self.cursor);
+            (col, row - self.delta.y)
+        } else {
+            (self.cursor.x - self.delta.x, self.cursor.y - self.delta.y)
+        }
+    }
+
     /// Convert mouse position to cursor position (line, column)
     /// Matches Borland: TEditor::getMousePtr() (teditor.cc:426-433)
     fn mouse_pos_to_cursor(&self, mouse_pos: Point) -> Point {
@@ -505,6 +673,11 @@ impl Editor {
         relative_x = relative_x.max(0).min(content_area.width() - 1);
         relative_y = relative_y.max(0).min(content_area.height() - 1);
 
+        if self.word_wrap {
+            let row = (relative_y + self.delta.y).min(self.row_count() - 1);
+            return self.logical_pos(row, relative_x);
+        }
+
         // Add scroll offset to get document position
         let doc_y = (relative_y + self.delta.y) as usize;
         let doc_x = (relative_x + self.delta.x) as usize;
@@ -550,8 +723,8 @@ impl Editor {
 
     fn update_scrollbars(&mut self) {
         let content_area = self.get_content_area();
-        let max_x = self.max_line_length();
-        let max_y = self.lines.len() as i16;
+        let max_x = self.scroll_width();
+        let max_y = self.row_count();
 
         if let Some(ref h_bar) = self.h_scrollbar {
             h_bar.borrow_mut().set_params(
@@ -604,6 +777,19 @@ impl Editor {
         let width = content_area.width();
         let height = content_area.height();
 
+        if self.word_wrap {
+            let (row, _) = self.visual_pos(self.cursor);
+            if row < self.delta.y {
+                self.delta.y = row;
+            } else if row >= self.delta.y + height {
+                self.delta.y = row - height + 1;
+            }
+            self.delta.x = 0;
+            self.update_scrollbars();
+            self.update_indicator();
+            return;
+        }
+
         if self.cursor.y < self.delta.y {
             self.delta.y = self.cursor.y;
         } else if self.cursor.y >= self.delta.y + height {
@@ -620,6 +806,41 @@ impl Editor {
         self.update_indicator();
     }
 
+    /// Scroll after a size change
+    ///
+    /// The scroll position is kept if the cursor is still visible; otherwise
+    /// the cursor keeps its row (and column) in the viewport, shortened to
+    /// fit. The position from before the resize is remembered, so returning
+    /// to the old size restores it unless the user scrolled in between.
+    fn restore_scroll_after_resize(&mut self) {
+        // Rows re-wrap to the new width, so old positions mean nothing
+        if self.word_wrap {
+            self.ensure_cursor_visible();
+            return;
+        }
+        let anchor = match self.resize_anchor {
+            Some((anchor, after_resize)) if after_resize == self.delta => anchor,
+            _ => self.delta,
+        };
+        let content_area = self.get_content_area();
+        let fit = |anchor: i16, cursor: i16, current: i16, size: i16| -> i16 {
+            let size = size.max(1);
+            if cursor >= anchor && cursor < anchor + size {
+                anchor
+            } else {
+                cursor - (cursor - current).clamp(0, size - 1)
+            }
+        };
+
+        self.delta = Point::new(
+            fit(anchor.x, self.cursor.x, self.delta.x, content_area.width()),
+            fit(anchor.y, self.cursor.y, self.delta.y, content_area.height()),
+        );
+        self.resize_anchor = Some((anchor, self.delta));
+        self.update_scrollbars();
+        self.update_indicator();
+    }
+
     fn clamp_cursor(&mut self) {
         if self.cursor.y < 0 {
             self.cursor.y = 0;
@@ -684,9 +905,20 @@ impl Editor {
             EditAction::DeleteText { pos, text } => {
                 self.cursor = *pos;
                 self.selection_start = Some(*pos);
-                self.cursor.x += text.chars().count() as i16;
+                match text.rsplit_once('\n') {
+                    Some((_, last)) => {
+                        self.cursor.y += text.matches('\n').count() as i16;
+                        self.cursor.x = last.chars().count() as i16;
+                    }
+                    None => self.cursor.x += text.chars().count() as i16,
+                }
                 self.delete_selection_internal();
             }
+            EditAction::Group(actions) => {
+                for action in actions {
+                    self.apply_action(action);
+                }
+            }
             _ => {}
         }
         self.ensure_cursor_visible();
@@ -701,6 +933,10 @@ impl Editor {
         if self.read_only {
             return;
         }
+        if let Some(block) = self.block_rect() {
+            self.edit_block(block.a.x as usize, block.b.x as usize, &ch.to_string());
+            return;
+        }
 
         let line_idx = self.cursor.y as usize;
         let col = self.cursor.x as usize;
@@ -841,12 +1077,33 @@ impl Editor {
             self.selection_start = Some(self.cursor);
         }
 
+        // Up and Down move by visual row, keeping the column within the row
+        if self.word_wrap && dy != 0 {
+            let (row, col) = self.visual_pos(self.cursor);
+            let row = (row + dy).clamp(0, self.row_count() - 1);
+            self.cursor = self.logical_pos(row, col + dx);
+            self.ensure_cursor_visible();
+            return;
+        }
+
         self.cursor.x += dx;
         self.cursor.y += dy;
         self.clamp_cursor();
         self.ensure_cursor_visible();
     }
 
+    /// Scroll one page up (direction < 0) or down (direction > 0)
+    /// Pages by height - 1 lines so one line of context stays visible; the view
+    /// scrolls with the cursor so it keeps its row on screen where possible.
+    /// Matches Borland: TEditor cmPageUp/cmPageDown scroll by size.y - 1
+    fn move_cursor_page(&mut self, direction: i16, extend_selection: bool) {
+        let height = self.get_content_area().height();
+        let step = (height - 1).max(1) * direction.signum();
+        let max_delta = (self.row_count() - height).max(0);
+        self.delta.y = (self.delta.y + step).clamp(0, max_delta);
+        self.move_cursor(0, step, extend_selection);
+    }
+
     /// Move cursor left (previous character), wrapping to previous line if at start
     fn move_cursor_left(&mut self, extend_selection: bool) {
         if !extend_selection {
@@ -896,8 +1153,72 @@ impl Editor {
         self.selection_start.is_some()
     }
 
+    /// The block selection: lines `a.y..b.y` and columns `a.x..b.x`
+    ///
+    /// None unless the selection is a block; an empty column still spans its lines.
+    fn block_rect(&self) -> Option<Rect> {
+        let start = self.selection_start.filter(|_| self.block_selection)?;
+        let end = self.cursor;
+        Some(Rect::new(start.x.min(end.x), start.y.min(end.y), start.x.max(end.x), start.y.max(end.y) + 1))
+    }
+
+    /// Replace columns `left..right` on every line of the block with `text`, as one undo step
+    ///
+    /// Lines shorter than `left` are padded with spaces before inserting. The
+    /// block then becomes the empty column after the inserted text.
+    fn edit_block(&mut self, left: usize, right: usize, text: &str) {
+        let (Some(block), Some(anchor)) = (self.block_rect(), self.selection_start) else {
+            return;
+        };
+        if self.read_only {
+            return;
+        }
+
+        let mut actions = Vec::new();
+        for y in block.a.y..block.b.y {
+            let line_len = self.lines[y as usize].chars().count();
+            if !text.is_empty() && line_len < left {
+                let padding = " ".repeat(left - line_len);
+                actions.push(EditAction::InsertText { pos: Point::new(line_len as i16, y), text: padding });
+            }
+            if left < line_len.min(right) {
+                let span = self.lines[y as usize].chars().skip(left).take(right.min(line_len) - left).collect();
+                actions.push(EditAction::DeleteText { pos: Point::new(left as i16, y), text: span });
+            }
+            if !text.is_empty() {
+                actions.push(EditAction::InsertText { pos: Point::new(left as i16, y), text: text.to_string() });
+            }
+        }
+        let action = EditAction::Group(actions);
+        self.apply_action(&action);
+        self.push_undo(action);
+
+        let column = (left + text.chars().count()) as i16;
+        self.selection_start = Some(Point::new(column, anchor.y));
+        self.cursor = Point::new(column, self.cursor.y);
+        self.ensure_cursor_visible();
+    }
+
+    /// Backspace or Del on a block: an empty column deletes the character
+    /// before (after) it on every line, otherwise the block's columns go
+    fn delete_block(&mut self, forward: bool) {
+        let Some(block) = self.block_rect() else {
+            return;
+        };
+        let (left, right) = (block.a.x as usize, block.b.x as usize);
+        match (left == right, forward) {
+            (true, true) => self.edit_block(left, left + 1, ""),
+            (true, false) if left > 0 => self.edit_block(left - 1, left, ""),
+            (true, false) => {}
+            (false, _) => self.edit_block(left, right, ""),
+        }
+    }
+
     /// Check if a position (line, column) is within the current selection
     fn is_position_selected(&self, line: i16, col: i16) -> bool {
+        if self.block_selection {
+            return self.block_rect().is_some_and(|block| block.contains(Point::new(col, line)));
+        }
         if let Some(start) = self.selection_start {
             let end = self.cursor;
 
@@ -931,6 +1252,16 @@ impl Editor {
     }
 
     fn get_selection(&self) -> Option<String> {
+        if let Some(block) = self.block_rect() {
+            if block.a.x == block.b.x {
+                return None;
+            }
+            let (left, width) = (block.a.x as usize, (block.b.x - block.a.x) as usize);
+            let rows: Vec<String> = (block.a.y..block.b.y)
+                .map(|y| self.lines[y as usize].chars().skip(left).take(width).collect())
+                .collect();
+            return Some(rows.join("\n"));
+        }
         let start = self.selection_start?;
         let end = self.cursor;
 
@@ -981,6 +1312,7 @@ impl Editor {
     }
 
     fn select_all(&mut self) {
+        self.block_selection = false;
         self.selection_start = Some(Point::zero());
         self.cursor = Point::new(
             self.lines.last().map(|l| l.chars().count()).unwrap_or(0) as i16,
@@ -1038,6 +1370,12 @@ impl Editor {
         if !self.has_selection() {
             return;
         }
+        if let Some(block) = self.block_rect() {
+            if block.a.x < block.b.x {
+                self.edit_block(block.a.x as usize, block.b.x as usize, "");
+            }
+            return;
+        }
 
         if let Some(text) = self.get_selection() {
             let action = EditAction::DeleteText { pos: self.selection_start.unwrap(), text };
@@ -1098,10 +1436,11 @@ impl Editor {
             return;
         }
 
-        let lines_to_insert: Vec<&str> = text.lines().collect();
-        if lines_to_insert.is_empty() {
+        if text.is_empty() {
             return;
         }
+        // split() rather than lines(): a trailing newline starts a new line
+        let lines_to_insert: Vec<&str> = text.split('\n').map(|line| line.strip_suffix('\r').unwrap_or(line)).collect();
 
         let line_idx = self.cursor.y as usize;
         let col_char = self.cursor.x as usize;
@@ -1145,17 +1484,41 @@ impl Editor {
     }
 }
 
+/// Start column of each row of `line` wrapped to `width` columns
+///
+/// Rows break after the last space that fits; a word longer than the width
+/// is split. Always at least one row, starting at 0.
+fn wrap_starts(line: &str, width: usize) -> Vec<usize> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut starts = vec![0];
+    let mut start = 0;
+    while chars.len() - start > width {
+        let end = start + width;
+        start = match chars[start..end].iter().rposition(|&ch| ch == ' ') {
+            Some(space) => start + space + 1,
+            None => end,
+        };
+        starts.push(start);
+    }
+    starts
+}
+
 impl View for Editor {
     fn bounds(&self) -> Rect {
         self.bounds
     }
 
     fn set_bounds(&mut self, bounds: Rect) {
+        let resized = bounds.width() != self.bounds.width() || bounds.height() != self.bounds.height();
         self.bounds = bounds;
         // Note: Scrollbars and indicator are now children of the Window, not the Editor
         // The Window's interior Group automatically handles their positioning
         // We only need to update our internal state
-        self.update_scrollbars();
+        if resized {
+            self.restore_scroll_after_resize();
+        } else {
+            self.update_scrollbars();
+        }
     }
 
     fn draw(&mut self, terminal: &mut Terminal) {
@@ -1169,20 +1532,18 @@ impl View for Editor {
         let selected_color = self.map_color(EDITOR_SELECTED);
         let cursor_color = self.map_color(EDITOR_CURSOR);
 
-        for y in 0..height {
-            let line_idx = (self.delta.y + y as i16) as usize;
+        for (y, row) in self.screen_rows(width, height).into_iter().enumerate() {
             let mut buf = DrawBuffer::new(width);
 
             buf.move_char(0, ' ', default_color, width);
 
-            if line_idx < self.lines.len() {
+            if let Some((line_idx, start_col, limit)) = row {
                 let line = &self.lines[line_idx];
-                let start_col = self.delta.x as usize;
                 let line_char_count = line.chars().count();
 
                 if start_col < line_char_count {
                     // Calculate visible portion in CHARACTER positions
-                    let end_col_char = min(start_col + width, line_char_count);
+                    let end_col_char = min(limit, line_char_count);
 
                     // Convert to string slice using character-based iteration
                     let visible_text: String = line
@@ -1244,12 +1605,11 @@ impl View for Editor {
 
             // Apply selection highlighting
             // Check each character position in this line to see if it's selected
-            if self.has_selection() {
-                let line_y = (self.delta.y + y as i16) as i16;
-                let start_col = self.delta.x;
+            if let (true, Some((line_idx, start_col, limit))) = (self.has_selection(), row) {
+                let line_y = line_idx as i16;
 
-                for x in 0..width {
-                    let col = (start_col + x as i16) as i16;
+                for x in 0..width.min(limit - start_col) {
+                    let col = (start_col + x) as i16;
                     if self.is_position_selected(line_y, col) {
                         // Highlight this character as selected
                         if x < buf.data.len() {
@@ -1269,8 +1629,9 @@ impl View for Editor {
 
         // Draw cursor if focused
         if self.is_focused() {
-            let cursor_screen_x = content_area.a.x + (self.cursor.x - self.delta.x);
-            let cursor_screen_y = content_area.a.y + (self.cursor.y - self.delta.y);
+            let (offset_x, offset_y) = self.cursor_screen_offset();
+            let cursor_screen_x = content_area.a.x + offset_x;
+            let cursor_screen_y = content_area.a.y + offset_y;
 
             if cursor_screen_x >= content_area.a.x && cursor_screen_x < content_area.b.x
                 && cursor_screen_y >= content_area.a.y && cursor_screen_y < content_area.b.y
@@ -1297,6 +1658,17 @@ impl View for Editor {
     }
 
     fn handle_event(&mut self, event: &mut Event) {
+        // Bracketed paste: insert the whole text as one undoable edit
+        if event.what == EventType::Paste {
+            if !self.is_focused() || self.read_only {
+                return;
+            }
+            let text = event.paste_text().unwrap_or_default().replace("\r\n", "\n").replace('\r', "\n");
+            self.insert_text(&text);
+            event.clear();
+            return;
+        }
+
         // Handle mouse events (matching Borland TEditor::handleEvent - teditor.cc:454-493)
         if event.what == EventType::MouseDown {
             // Only handle mouse events if focused
@@ -1319,6 +1691,9 @@ impl View for Editor {
             // Matches Borland: do { ... } while( mouseEvent(event, evMouseMove + evMouseAuto) )
             let extend_selection = false;
 
+            // Alt+drag selects a block of columns
+            self.block_selection = event.mouse.modifiers.contains(crossterm::event::KeyModifiers::ALT);
+
             // First click sets cursor position
             self.set_cursor_with_selection(cursor_pos, extend_selection);
 
@@ -1344,6 +1719,19 @@ impl View for Editor {
             return;
         }
 
+        // Mouse wheel scrolls the view without moving the cursor
+        if event.is_wheel() {
+            let content_area = self.get_content_area();
+            if !content_area.contains(event.mouse.pos) {
+                return;
+            }
+            let max_y = (self.row_count() - content_area.height()).max(0);
+            self.delta.y = (self.delta.y + event.wheel_lines()).clamp(0, max_y);
+            self.update_scrollbars();
+            event.clear();
+            return;
+        }
+
         // Handle mouse move for drag selection
         if event.what == EventType::MouseMove {
             // Only track drags if focused and left button is held
@@ -1377,10 +1765,10 @@ impl View for Editor {
 
             if needs_scroll {
                 // Clamp scroll position
-                let max_x = self.max_line_length().saturating_sub(content_area.width());
-                let max_y = (self.lines.len() as i16).saturating_sub(content_area.height());
-                scroll_delta.x = scroll_delta.x.max(0).min(max_x);
-                scroll_delta.y = scroll_delta.y.max(0).min(max_y);
+                let max_x = self.scroll_width().saturating_sub(content_area.width());
+                let max_y = self.row_count().saturating_sub(content_area.height());
+                scroll_delta.x = scroll_delta.x.min(max_x).max(0);
+                scroll_delta.y = scroll_delta.y.min(max_y).max(0);
 
                 self.delta = scroll_delta;
                 self.update_scrollbars();
@@ -1394,6 +1782,33 @@ impl View for Editor {
             return;
         }
 
+        // Edit menu commands act on the focused editor
+        // Matches Borland: TEditor::handleEvent() evCommand (cmCut, cmCopy, cmPaste, cmUndo, ...)
+        if event.what == EventType::Command && self.is_focused() {
+            use crate::core::command::{CM_BLOCK_SELECT, CM_COPY, CM_CUT, CM_PASTE, CM_REDO, CM_SEARCH_AGAIN, CM_SELECT_ALL, CM_UNDO};
+            match event.command {
+                CM_UNDO => self.undo(),
+                CM_REDO => self.redo(),
+                CM_CUT => {
+                    self.clip_cut();
+                }
+                CM_COPY => {
+                    self.clip_copy();
+                }
+                CM_PASTE => {
+                    self.clip_paste();
+                }
+                CM_SELECT_ALL => self.select_all(),
+                CM_BLOCK_SELECT => self.block_selection = !self.block_selection,
+                CM_SEARCH_AGAIN => {
+                    self.find_next();
+                }
+                _ => return,
+            }
+            event.clear();
+            return;
+        }
+
         if event.what == EventType::Keyboard {
             // Only handle keyboard events if focused
             if !self.is_focused() {
@@ -1452,21 +1867,23 @@ impl View for Editor {
                     event.clear();
                 }
                 KB_PGUP => {
-                    let height = self.get_content_area().height();
-                    self.move_cursor(0, -height, shift_pressed);
+                    self.move_cursor_page(-1, shift_pressed);
                     event.clear();
                 }
                 KB_PGDN => {
-                    let height = self.get_content_area().height();
-                    self.move_cursor(0, height, shift_pressed);
+                    self.move_cursor_page(1, shift_pressed);
                     event.clear();
                 }
                 KB_ENTER => {
-                    self.insert_newline();
-                    event.clear();
+                    if self.enter_behavior == EnterBehavior::Consume {
+                        self.insert_newline();
+                        event.clear();
+                    }
                 }
                 KB_BACKSPACE => {
-                    if self.has_selection() {
+                    if self.block_rect().is_some() {
+                        self.delete_block(false);
+                    } else if self.has_selection() {
                         self.delete_selection();
                     } else {
                         self.backspace();
@@ -1474,7 +1891,9 @@ impl View for Editor {
                     event.clear();
                 }
                 KB_DEL => {
-                    if self.has_selection() {
+                    if self.block_rect().is_some() {
+                        self.delete_block(true);
+                    } else if self.has_selection() {
                         self.delete_selection();
                     } else {
                         self.delete_char();
@@ -1511,13 +1930,9 @@ impl View for Editor {
                 }
                 key_code => {
                     // Accept all printable characters including Unicode (è, à, etc.)
-                    // Key codes represent Unicode codepoints, so convert directly to char
-                    if let Some(ch) = char::from_u32(key_code as u32) {
-                        // Only insert if it's a printable character (not control characters)
-                        if !ch.is_control() {
-                            self.insert_char(ch);
-                            event.clear();
-                        }
+                    if let Some(ch) = crate::core::event::key_char(key_code) {
+                        self.insert_char(ch);
+                        event.clear();
                     }
                 }
             }
@@ -1544,10 +1959,17 @@ impl View for Editor {
             // Calculate cursor position on screen using content area (not bounds)
             // to account for indicator and scrollbars
             let content_area = self.get_content_area();
-            let cursor_x = content_area.a.x + (self.cursor.x - self.delta.x);
-            let cursor_y = content_area.a.y + (self.cursor.y - self.delta.y);
+            let (offset_x, offset_y) = self.cursor_screen_offset();
+            let cursor_x = content_area.a.x + offset_x;
+            let cursor_y = content_area.a.y + offset_y;
 
-            // Show cursor at the position
+            // Bar while inserting, block while overwriting the character under it
+            let style = if self.insert_mode {
+                CursorStyle::Bar { blink: true }
+            } else {
+                CursorStyle::Block { blink: true }
+            };
+            terminal.set_cursor_style(style);
             let _ = terminal.show_cursor(cursor_x as u16, cursor_y as u16);
         }
     }
@@ -1582,6 +2004,32 @@ mod tests {
     use std::io::Write;
     use tempfile::NamedTempFile;
 
+    #[test]
+    fn test_resize_keeps_cursor_visible_and_restores_scroll() {
+        let mut editor = Editor::new(Rect::new(0, 0, 80, 20));
+        let text: String = (0..100).map(|i| format!("{i:03}{}\n", "x".repeat(100))).collect();
+        editor.set_text(&text);
+        editor.cursor = Point::new(60, 50);
+        editor.delta = Point::new(10, 40);
+
+        editor.set_bounds(Rect::new(0, 0, 40, 10));
+        let visible = |e: &Editor| {
+            e.cursor.x >= e.delta.x && e.cursor.x < e.delta.x + e.bounds.width()
+                && e.cursor.y >= e.delta.y && e.cursor.y < e.delta.y + e.bounds.height()
+        };
+        assert!(visible(&editor), "delta {:?}", editor.delta);
+        // The cursor row was 10 in the old viewport; it now sits on the last row
+        assert_eq!(editor.delta.y, 41);
+
+        // Moving the window does not count as a resize
+        editor.set_bounds(Rect::new(5, 5, 45, 15));
+        assert_eq!(editor.delta.y, 41);
+
+        editor.set_bounds(Rect::new(0, 0, 80, 20));
+        assert_eq!(editor.delta, Point::new(10, 40), "old scroll position restored");
+        assert!(visible(&editor));
+    }
+
     #[test]
     fn test_editor_load_file() {
         let mut file = NamedTempFile::new().unwrap();
@@ -1675,4 +2123,167 @@ mod tests {
         assert_eq!(editor.get_text(), "");
         assert!(!editor.is_modified());
     }
+
+    #[test]
+    fn test_editor_page_keeps_one_line_of_context() {
+        use crate::core::event::{KB_PGDN, KB_PGUP};
+
+        let mut editor = Editor::new(Rect::new(0, 0, 40, 10));
+        editor.set_focus(true);
+        let text: Vec<String> = (0..35).map(|i| format!("Line {i}")).collect();
+        editor.set_text(&text.join("\n"));
+        editor.cursor = Point::new(0, 0);
+        editor.delta = Point::new(0, 0);
+
+        let mut event = Event::keyboard(KB_PGDN);
+        editor.handle_event(&mut event);
+        assert_eq!((editor.delta.y, editor.cursor.y), (9, 9));
+
+        let mut event = Event::keyboard(KB_PGDN);
+        editor.handle_event(&mut event);
+        let mut event = Event::keyboard(KB_PGDN);
+        editor.handle_event(&mut event);
+        assert_eq!((editor.delta.y, editor.cursor.y), (25, 27));
+
+        let mut event = Event::keyboard(KB_PGUP);
+        editor.handle_event(&mut event);
+        assert_eq!((editor.delta.y, editor.cursor.y), (16, 18));
+    }
+
+    #[test]
+    fn test_wheel_scrolls_without_moving_cursor() {
+        let mut editor = Editor::new(Rect::new(0, 0, 40, 10));
+        let text: Vec<String> = (0..35).map(|i| format!("Line {i}")).collect();
+        editor.set_text(&text.join("\n"));
+        editor.cursor = Point::new(2, 1);
+        editor.delta = Point::new(0, 0);
+
+        let mut event = Event::wheel(Point::new(5, 5), 6);
+        editor.handle_event(&mut event);
+        assert_eq!(editor.delta.y, 6);
+        assert_eq!(editor.cursor, Point::new(2, 1));
+        assert_eq!(event.what, EventType::Nothing);
+
+        let mut event = Event::wheel(Point::new(5, 5), -10);
+        editor.handle_event(&mut event);
+        assert_eq!(editor.delta.y, 0);
+    }
+
+    #[test]
+    fn test_bracketed_paste_inserts_lines_as_one_edit() {
+        let mut editor = Editor::new(Rect::new(0, 0, 40, 10));
+        editor.set_focus(true);
+        editor.set_text("<>");
+        editor.cursor = Point::new(1, 0);
+
+        let mut event = Event::paste("one\r\ntwo\rthree\n");
+        editor.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Nothing);
+        assert_eq!(editor.get_text(), "<one\ntwo\nthree\n>");
+        assert_eq!(editor.cursor, Point::new(0, 3));
+
+        editor.undo();
+        assert_eq!(editor.get_text(), "<>");
+        assert_eq!(editor.cursor, Point::new(1, 0));
+    }
+
+    #[test]
+    fn test_word_wrap_long_line_takes_several_rows() {
+        let mut terminal = Terminal::new_headless(20, 5);
+        let mut editor = Editor::new(Rect::new(0, 0, 12, 5));
+        editor.set_text("the quick brown fox jumps\nend");
+        editor.set_word_wrap(true);
+        editor.draw(&mut terminal);
+
+        let row = |y| (0..12).map(|x| terminal.read_cell(x, y).unwrap().ch).collect::<String>();
+        assert_eq!(row(0), "the quick   ");
+        assert_eq!(row(1), "brown fox   ");
+        assert_eq!(row(2), "jumps       ");
+        assert_eq!(row(3), "end         ");
+        assert_eq!(wrap_starts("abcdefghij", 4), [0, 4, 8], "long words are split");
+    }
+
+    #[test]
+    fn test_word_wrap_down_moves_by_visual_row() {
+        let mut editor = Editor::new(Rect::new(0, 0, 12, 2));
+        editor.set_focus(true);
+        editor.set_text("the quick brown fox jumps\nend");
+        editor.set_word_wrap(true);
+        editor.cursor = Point::new(2, 0);
+
+        let mut down = || {
+            editor.handle_event(&mut Event::keyboard(KB_DOWN));
+            (editor.cursor, editor.delta.y)
+        };
+        assert_eq!(down(), (Point::new(12, 0), 0)); // "brown fox", column 2
+        assert_eq!(down(), (Point::new(22, 0), 1)); // "jumps", scrolled by one row
+        assert_eq!(down(), (Point::new(2, 1), 2));
+        assert_eq!(down(), (Point::new(2, 1), 2), "stays on the last row");
+
+        // Clicking the second screen row puts the cursor in the wrapped part of line 0
+        editor.handle_event(&mut Event::keyboard(KB_UP));
+        editor.handle_event(&mut Event::keyboard(KB_UP));
+        assert_eq!(editor.mouse_pos_to_cursor(Point::new(30, 1)), Point::new(25, 0));
+    }
+
+    fn block_editor() -> Editor {
+        let mut editor = Editor::new(Rect::new(0, 0, 20, 6));
+        editor.set_text("abcdefgh\nab\nabcdefgh\nabcd");
+        editor.set_focus(true);
+        editor
+    }
+
+    #[test]
+    fn test_alt_drag_selects_block_rectangle() {
+        use crossterm::event::KeyModifiers;
+
+        let mut editor = block_editor();
+        editor.handle_event(&mut Event::mouse_with(EventType::MouseDown, Point::new(5, 0), MB_LEFT_BUTTON, KeyModifiers::ALT));
+        editor.handle_event(&mut Event::mouse(EventType::MouseMove, Point::new(2, 2), MB_LEFT_BUTTON, false));
+        assert!(editor.block_selection());
+        assert_eq!(editor.block_rect(), Some(Rect::new(2, 0, 5, 3)));
+
+        // Only the columns of the rectangle are selected, also past a short line's end
+        assert!(editor.is_position_selected(1, 3) && editor.is_position_selected(2, 4));
+        assert!(!editor.is_position_selected(0, 5) && !editor.is_position_selected(3, 2));
+        assert_eq!(editor.get_selection().as_deref(), Some("cde\n\ncde"));
+
+        // A plain click goes back to runs of text
+        editor.handle_event(&mut Event::mouse(EventType::MouseDown, Point::new(1, 0), MB_LEFT_BUTTON, false));
+        editor.handle_event(&mut Event::mouse(EventType::MouseMove, Point::new(1, 2), MB_LEFT_BUTTON, false));
+        assert_eq!(editor.block_rect(), None);
+        assert!(editor.is_position_selected(1, 0), "the stream selection covers whole middle lines");
+    }
+
+    #[test]
+    fn test_block_delete_and_typing_edit_every_line() {
+        let mut editor = block_editor();
+        editor.set_block_selection(true);
+        editor.selection_start = Some(Point::new(2, 0));
+        editor.cursor = Point::new(5, 3);
+        assert_eq!(editor.block_rect(), Some(Rect::new(2, 0, 5, 4)));
+
+        // Del removes columns 2..5 of each line, as far as the line reaches
+        editor.handle_event(&mut Event::keyboard(KB_DEL));
+        assert_eq!(editor.get_text(), "abfgh\nab\nabfgh\nab");
+        assert_eq!(editor.block_rect(), Some(Rect::new(2, 0, 2, 4)), "an empty column is left");
+
+        // Typing goes on every line, padding the short ones
+        editor.handle_event(&mut Event::keyboard('|' as u16));
+        assert_eq!(editor.get_text(), "ab|fgh\nab|\nab|fgh\nab|");
+        editor.handle_event(&mut Event::keyboard(KB_BACKSPACE));
+        editor.handle_event(&mut Event::keyboard(KB_BACKSPACE));
+        assert_eq!(editor.get_text(), "afgh\na\nafgh\na");
+
+        // Each block edit is one undo step
+        editor.undo();
+        assert_eq!(editor.get_text(), "abfgh\nab\nabfgh\nab");
+        editor.undo();
+        assert_eq!(editor.get_text(), "ab|fgh\nab|\nab|fgh\nab|");
+        editor.undo();
+        editor.undo();
+        assert_eq!(editor.get_text(), "abcdefgh\nab\nabcdefgh\nabcd");
+        editor.redo();
+        assert_eq!(editor.get_text(), "abfgh\nab\nabfgh\nab");
+    }
 }
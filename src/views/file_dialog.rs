@@ -11,6 +11,11 @@
 //! - Directory navigation (double-click directories or select and press Enter)
 //! - Wildcard filtering (e.g., "*.rs" shows only Rust files)
 //! - Parent directory navigation via ".."
+//! - Incremental directory reading: large or slow directories fill the list
+//!   in batches with a "(reading… N entries)" indicator; ESC closes the
+//!   dialog, which stops the scan
+//! - [`FileDialogMode::Save`]: the typed name may be a new file, and
+//!   choosing an existing one asks before overwriting it
 //!
 //! ## Usage
 //!
@@ -20,6 +25,9 @@
 //! 3. **Use arrow keys** to navigate the list, press Enter to select
 //! 4. **Type a filename** directly in the input box
 //!
+//! With the file list focused, Ctrl+C copies the highlighted entry's full
+//! path to the clipboard (in the input box it copies the selected text).
+//!
 //! Directory navigation:
 //! - Press Enter on a folder (`[dirname]`) to navigate into it
 //! - Press Enter on `..` to go to parent directory
@@ -59,12 +67,15 @@
 /// ## Usage
 ///
 /// ```rust,ignore
-/// // Open dialog (default)
-/// let mut dialog = FileDialog::new(bounds, "Open File", "*.rs", None).build();
+/// // Open dialog
+/// let mut dialog = FileDialog::new(bounds, "Open File", "*.rs", None, FileDialogMode::Open).build();
+///
+/// // Save dialog: accepts new names, confirms overwriting existing files
+/// let mut dialog = FileDialog::new(bounds, "Save File", "*", None, FileDialogMode::Save).build();
 ///
-/// // Save dialog with custom button label
-/// let mut dialog = FileDialog::new(bounds, "Save File", "*", None)
-///     .with_button_label("~S~ave")
+/// // Export dialog with a custom button label
+/// let mut dialog = FileDialog::new(bounds, "Export", "*", None, FileDialogMode::Save)
+///     .with_button_label("~E~xport")
 ///     .build();
 ///
 /// // Execute and get selected file path
@@ -81,6 +92,10 @@
 /// 2. **File is selected and Enter/OK is pressed** → Dialog closes, returns file path
 /// 3. **User cancels** (close button, Cancel button, or double ESC) → Dialog closes, returns None
 ///
+/// In save mode the returned file need not exist. If it does, a confirmation
+/// box asks first: Yes returns it, No goes back to the dialog, Cancel closes
+/// the dialog and returns None.
+///
 /// ## Folder Navigation
 ///
 /// When a folder is selected and opened (double-click or Enter while focused on folder):
@@ -94,8 +109,7 @@
 /// ## Button Labels
 ///
 /// Customize the button label using `with_button_label()`:
-/// - Default: `"~O~pen"` (Open button)
-/// - For save: `"~S~ave"`
+/// - Default: `"~O~pen"`, or `"~S~ave"` in save mode
 /// - For export: `"~E~xport"`
 /// - Any other text: `"~C~ustom"`
 ///
@@ -104,15 +118,17 @@
 use super::View;
 use super::button::Button;
 use super::dialog::Dialog;
+use super::dir_reader::DirReader;
+use super::file_list::FileEntry;
 use super::input_line::InputLine;
 use super::label::Label;
 use super::listbox::ListBox;
-use crate::core::command::{CM_CANCEL, CM_FILE_FOCUSED, CM_OK, CommandId};
-use crate::core::event::{Event, EventType};
+use crate::core::command::{CM_CANCEL, CM_FILE_FOCUSED, CM_OK, CM_YES, CommandId};
+use crate::core::clipboard;
+use crate::core::event::{Event, EventType, KB_CTRL_C};
 use crate::core::geometry::Rect;
 use crate::terminal::Terminal;
 use std::cell::RefCell;
-use std::fs;
 use std::path::PathBuf;
 use std::rc::Rc;
 
@@ -122,20 +138,35 @@ const CMD_FILE_SELECTED: u16 = 1000;
 const CHILD_LISTBOX: usize = 4; // ListBox
 const CHILD_OK_BUTTON: usize = 5; // Open button
 
+/// Whether the dialog picks a file to read or a name to write
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FileDialogMode {
+    /// Pick a file to open
+    #[default]
+    Open,
+    /// Pick a name to save to, new or existing (overwriting is confirmed)
+    Save,
+}
+
 pub struct FileDialog {
     dialog: Dialog,
     current_path: PathBuf,
     wildcard: String,
     file_name_data: Rc<RefCell<String>>,
     files: Vec<String>,
+    dir_count: usize, // Folders listed after "..", ahead of the files
+    reader: Option<DirReader>, // Directory listing still in progress
     selected_file_index: usize, // Track ListBox selection
     title: String, // Store title for rebuilds
     button_label: String, // "Open", "Save", etc.
+    mode: FileDialogMode,
 }
 
 impl FileDialog {
-    pub fn new(bounds: Rect, title: &str, wildcard: &str, initial_dir: Option<PathBuf>) -> Self {
-        let dialog = Dialog::new(bounds, title);
+    pub fn new(bounds: Rect, title: &str, wildcard: &str, initial_dir: Option<PathBuf>, mode: FileDialogMode) -> Self {
+        let mut dialog = Dialog::new(bounds, title);
+        // Re-center on terminal resize
+        dialog.set_options(dialog.options() | crate::core::state::OF_CENTERED);
 
         let current_path = initial_dir
             .unwrap_or_else(|| std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")));
@@ -148,12 +179,22 @@ impl FileDialog {
             wildcard: wildcard.to_string(),
             file_name_data,
             files: Vec::new(),
+            dir_count: 0,
+            reader: None,
             selected_file_index: 0,
             title: title.to_string(),
-            button_label: "~O~pen".to_string(), // Default to "Open"
+            button_label: match mode {
+                FileDialogMode::Open => "~O~pen".to_string(),
+                FileDialogMode::Save => "~S~ave".to_string(),
+            },
+            mode,
         }
     }
 
+    pub fn mode(&self) -> FileDialogMode {
+        self.mode
+    }
+
     /// Set the button label (e.g., "~S~ave" for save dialogs)
     pub fn with_button_label(mut self, label: &str) -> Self {
         self.button_label = label.to_string();
@@ -241,7 +282,19 @@ impl FileDialog {
         let old_state = self.dialog.state();
         self.dialog.set_state(old_state | SF_MODAL);
 
+        // Save what's beneath the dialog so closing it restores the screen
+        app.terminal.save_under(self.dialog.shadow_bounds());
+        let result = self.run_modal(app);
+        let _ = app.terminal.restore_under();
+        result
+    }
+
+    /// Modal event loop of execute()
+    fn run_modal(&mut self, app: &mut crate::app::Application) -> Option<PathBuf> {
         loop {
+            // Add the next batch of a directory that is still being read
+            self.poll_directory();
+
             // Update OK button state based on input field
             self.update_ok_button_state();
 
@@ -259,6 +312,10 @@ impl FileDialog {
 
             // Draw the file dialog on top of desktop/menu/status
             self.dialog.draw(&mut app.terminal);
+            if let Some(ref reader) = self.reader {
+                let list_bounds = self.dialog.child_at(CHILD_LISTBOX).bounds();
+                reader.draw_status(&mut app.terminal, list_bounds, crate::core::palette::colors::LISTBOX_NORMAL);
+            }
 
             // Draw overlay widgets on top of everything (animations, etc.)
             // These continue to animate even during modal dialogs
@@ -270,25 +327,31 @@ impl FileDialog {
             self.dialog.update_cursor(&mut app.terminal);
             let _ = app.terminal.flush();
 
-            // Get event with 20ms timeout (matches magiblot's eventTimeoutMs)
-            match app
-                .terminal
-                .poll_event(std::time::Duration::from_millis(20))
-                .ok()
-                .flatten()
-            {
+            // Get event with the application's poll interval
+            let interval = app.poll_interval();
+            match app.terminal.poll_event(interval).ok().flatten() {
+                Some(event) if event.what == EventType::Resize => {
+                    // Terminal was resized - re-lay out and re-center the dialog
+                    app.handle_resize();
+                    self.dialog.reposition_after_resize(app.desktop.get_bounds());
+                }
                 Some(mut event) => {
                     // Event received - handle it immediately without calling idle()
                     // Matches magiblot: idle() is NOT called when events are present
 
-                    // Handle double ESC to close (Cancel operation)
+                    // Handle ESC and double ESC to close (Cancel operation)
+                    // Closing also stops a directory that is still being read
                     if event.what == EventType::Keyboard
-                        && event.key_code == crate::core::event::KB_ESC_ESC
+                        && matches!(event.key_code, crate::core::event::KB_ESC | crate::core::event::KB_ESC_ESC)
                     {
+                        if let Some(reader) = self.reader.as_mut() {
+                            reader.cancel();
+                        }
                         return None;
                     }
 
                     // Let the dialog (and its children) handle the event first
+                    self.copy_path_on_ctrl_c(&mut event);
                     self.dialog.handle_event(&mut event);
 
                     // Check if dialog wants to close (e.g., close button clicked)
@@ -311,6 +374,12 @@ impl FileDialog {
                     // We read the ListBox selection after it has processed navigation events
                     self.sync_inputline_with_listbox();
 
+                    // A modal Dialog consumes CM_OK (OK button or Enter) after end_modal(),
+                    // so recover the command from the end state
+                    if end_state == CM_OK && event.what == EventType::Nothing {
+                        event = Event::command(CM_OK);
+                    }
+
                     // Check if dialog should close based on command
                     if event.what == EventType::Command {
                         match event.command {
@@ -362,7 +431,11 @@ impl FileDialog {
                                         self.handle_selection(&file_name, &mut app.terminal)
                                     {
                                         // CLOSE CONDITION 2: File selected and OK pressed
-                                        return Some(path);
+                                        match self.confirm_overwrite(app, &path) {
+                                            CM_YES => return Some(path),
+                                            CM_CANCEL => return None,
+                                            _ => {}
+                                        }
                                     }
                                     // Directory/folder selected - navigate into it (stay open)
                                     // CRITICAL: Clear the end_state so the loop continues
@@ -392,7 +465,11 @@ impl FileDialog {
                                         self.handle_selection(&file_name, &mut app.terminal)
                                     {
                                         // CLOSE CONDITION 1: File double-clicked or Enter pressed on file
-                                        return Some(path);
+                                        match self.confirm_overwrite(app, &path) {
+                                            CM_YES => return Some(path),
+                                            CM_CANCEL => return None,
+                                            _ => {}
+                                        }
                                     }
                                     // Folder/directory selected - navigate into it (stay open)
                                     // Loop continues with new directory contents
@@ -501,6 +578,11 @@ impl FileDialog {
             self.current_path.push(dir_name);
             self.rebuild_and_redraw(terminal);
             None // Stay open after navigating
+        } else if self.current_path.join(file_name).is_dir() {
+            // A typed directory name - navigate into it
+            self.current_path.push(file_name);
+            self.rebuild_and_redraw(terminal);
+            None
         } else {
             // Regular file selected - close dialog with path
             *self.file_name_data.borrow_mut() = file_name.to_string();
@@ -508,6 +590,21 @@ impl FileDialog {
         }
     }
 
+    /// In save mode, ask before returning a file that already exists
+    ///
+    /// Returns CM_YES to return `path`, CM_NO to stay open, or CM_CANCEL to close.
+    fn confirm_overwrite(&mut self, app: &mut crate::app::Application, path: &std::path::Path) -> CommandId {
+        if self.mode == FileDialogMode::Open || !path.exists() {
+            return CM_YES;
+        }
+        let name = path.file_name().map_or_else(|| path.display().to_string(), |name| name.to_string_lossy().into_owned());
+        let answer = super::msgbox::confirmation_box(app, &format!("{name} already exists. Overwrite it?"));
+        // Dialog called end_modal(CM_OK); stay open unless the answer closes it
+        self.dialog.set_end_state(0);
+        app.terminal.force_full_redraw();
+        answer
+    }
+
     fn update_ok_button_state(&mut self) {
         use crate::core::state::SF_DISABLED;
 
@@ -539,6 +636,7 @@ impl FileDialog {
             &old_title,
             &self.wildcard.clone(),
             Some(self.current_path.clone()),
+            self.mode,
         )
         .with_button_label(&old_button_label)
         .build();
@@ -605,37 +703,77 @@ impl FileDialog {
         }
     }
 
+    /// Start reading the current directory; the first batch is read right away
+    /// Matches Borland: TFileList::readDirectory()
     fn read_directory(&mut self) {
         self.files.clear();
+        self.dir_count = 0;
 
         // Add parent directory entry
         if self.current_path.parent().is_some() {
             self.files.push("..".to_string());
         }
 
-        // Read directory contents
-        if let Ok(entries) = fs::read_dir(&self.current_path) {
-            let mut dirs = Vec::new();
-            let mut regular_files = Vec::new();
+        self.reader = DirReader::open(&self.current_path).ok();
+        if let Some(chunk) = self.reader.as_mut().map(DirReader::read_chunk) {
+            self.add_entries(chunk);
+        }
+    }
 
-            for entry in entries.flatten() {
-                if let Ok(metadata) = entry.metadata() {
-                    let name = entry.file_name().to_string_lossy().to_string();
+    /// Read the next batch of the directory into the ListBox
+    /// Returns true while the listing is still in progress
+    fn poll_directory(&mut self) -> bool {
+        let Some(reader) = self.reader.as_mut() else {
+            return false;
+        };
+        let chunk = reader.read_chunk();
+        let done = reader.is_done();
+        if done {
+            self.reader = None;
+        }
 
-                    if metadata.is_dir() {
-                        dirs.push(format!("[{}]", name));
-                    } else if self.matches_wildcard(&name) {
-                        regular_files.push(name);
-                    }
+        let selected = self.files.get(self.selected_file_index).cloned();
+        if self.add_entries(chunk) && CHILD_LISTBOX < self.dialog.child_count() {
+            let view = self.dialog.child_at_mut(CHILD_LISTBOX);
+            if let Some(listbox) = view.as_any_mut().downcast_mut::<ListBox>() {
+                listbox.set_items(self.files.clone());
+                // Keep the selection on the same entry while batches arrive
+                if let Some(index) = selected.and_then(|name| self.files.iter().position(|f| *f == name)) {
+                    listbox.set_list_selection(index);
+                    self.selected_file_index = index;
                 }
             }
+        }
+        !done
+    }
 
-            // Sort and combine: directories first, then files
-            dirs.sort();
-            regular_files.sort();
-            self.files.extend(dirs);
-            self.files.extend(regular_files);
+    /// Merge a batch of entries into the sorted list; returns true if any was added
+    ///
+    /// Directories come first, then files, each sorted. Only the batch is
+    /// sorted; it is then merged into the folders and files already listed.
+    fn add_entries(&mut self, entries: Vec<FileEntry>) -> bool {
+        let mut dirs = Vec::new();
+        let mut files = Vec::new();
+        for entry in entries {
+            if entry.is_dir {
+                dirs.push(format!("[{}]", entry.name));
+            } else if self.matches_wildcard(&entry.name) {
+                files.push(entry.name);
+            }
+        }
+        if dirs.is_empty() && files.is_empty() {
+            return false;
         }
+        dirs.sort();
+        files.sort();
+
+        let first = usize::from(self.files.first().is_some_and(|f| f == ".."));
+        let listed_files = self.files.split_off(first + self.dir_count);
+        let listed_dirs = self.files.split_off(first);
+        self.dir_count += dirs.len();
+        self.files.extend(merge_sorted(listed_dirs, dirs));
+        self.files.extend(merge_sorted(listed_files, files));
+        true
     }
 
     fn contains_wildcards(&self, name: &str) -> bool {
@@ -670,6 +808,36 @@ impl FileDialog {
         }
     }
 
+    /// Absolute path of the entry highlighted in the file list (`..`, a directory or a file)
+    pub fn selected_path(&self) -> Option<PathBuf> {
+        if CHILD_LISTBOX >= self.dialog.child_count() {
+            return None;
+        }
+        let name = self.files.get(self.dialog.child_at(CHILD_LISTBOX).get_list_selection())?;
+        let path = if name == ".." {
+            self.current_path.parent()?.to_path_buf()
+        } else if let Some(dir) = name.strip_prefix('[').and_then(|n| n.strip_suffix(']')) {
+            self.current_path.join(dir)
+        } else {
+            self.current_path.join(name)
+        };
+        Some(std::path::absolute(&path).unwrap_or(path))
+    }
+
+    /// Ctrl+C in the file list copies the highlighted entry's path
+    /// (the input line keeps Ctrl+C for its own text)
+    fn copy_path_on_ctrl_c(&mut self, event: &mut Event) {
+        if event.what != EventType::Keyboard || event.key_code != KB_CTRL_C {
+            return;
+        }
+        if CHILD_LISTBOX < self.dialog.child_count() && self.dialog.child_at(CHILD_LISTBOX).is_focused() {
+            if let Some(path) = self.selected_path() {
+                clipboard::set_clipboard(&path.to_string_lossy());
+            }
+            event.clear();
+        }
+    }
+
     /// Get the current directory being browsed
     /// Useful for ChDirDialog to get the selected directory
     pub fn get_current_directory(&self) -> PathBuf {
@@ -682,6 +850,20 @@ impl FileDialog {
     }
 }
 
+/// Merge two sorted lists into one sorted list
+fn merge_sorted(a: Vec<String>, b: Vec<String>) -> Vec<String> {
+    let mut merged = Vec::with_capacity(a.len() + b.len());
+    let mut b = b.into_iter().peekable();
+    for item in a {
+        while let Some(next) = b.next_if(|next| *next < item) {
+            merged.push(next);
+        }
+        merged.push(item);
+    }
+    merged.extend(b);
+    merged
+}
+
 impl View for FileDialog {
     fn bounds(&self) -> Rect {
         self.dialog.bounds()
@@ -696,6 +878,7 @@ impl View for FileDialog {
     }
 
     fn handle_event(&mut self, event: &mut Event) {
+        self.copy_path_on_ctrl_c(event);
         self.dialog.handle_event(event);
     }
 
@@ -733,7 +916,8 @@ pub struct FileDialogBuilder {
     title: Option<String>,
     wildcard: String,
     initial_dir: Option<PathBuf>,
-    button_label: String,
+    button_label: Option<String>,
+    mode: FileDialogMode,
 }
 
 impl FileDialogBuilder {
@@ -744,7 +928,8 @@ impl FileDialogBuilder {
             title: None,
             wildcard: "*".to_string(),
             initial_dir: None,
-            button_label: "~O~pen".to_string(),
+            button_label: None,
+            mode: FileDialogMode::Open,
         }
     }
 
@@ -778,12 +963,19 @@ impl FileDialogBuilder {
         self
     }
 
-    /// Sets the button label (optional, default: "~O~pen").
-    /// Examples: "~S~ave", "~E~xport", "~C~hoose"
+    /// Sets the button label (optional, default: "~O~pen", or "~S~ave" in save mode).
+    /// Examples: "~E~xport", "~C~hoose"
     /// The ~ character indicates the hotkey underline.
     #[must_use]
     pub fn button_label(mut self, label: impl Into<String>) -> Self {
-        self.button_label = label.into();
+        self.button_label = Some(label.into());
+        self
+    }
+
+    /// Sets open or save mode (default: open).
+    #[must_use]
+    pub fn mode(mut self, mode: FileDialogMode) -> Self {
+        self.mode = mode;
         self
     }
 
@@ -795,9 +987,11 @@ impl FileDialogBuilder {
     pub fn build(self) -> FileDialog {
         let bounds = self.bounds.expect("FileDialog bounds must be set");
         let title = self.title.expect("FileDialog title must be set");
-        FileDialog::new(bounds, &title, &self.wildcard, self.initial_dir)
-            .with_button_label(&self.button_label)
-            .build()
+        let mut dialog = FileDialog::new(bounds, &title, &self.wildcard, self.initial_dir, self.mode);
+        if let Some(label) = self.button_label {
+            dialog = dialog.with_button_label(&label);
+        }
+        dialog.build()
     }
 
     /// Builds the FileDialog as a Box.
@@ -811,3 +1005,115 @@ impl Default for FileDialogBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::event::{KB_DOWN, KB_TAB};
+
+    #[test]
+    fn test_ctrl_c_in_file_list_copies_highlighted_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = std::fs::canonicalize(dir.path()).unwrap();
+        std::fs::create_dir(root.join("docs")).unwrap();
+        std::fs::write(root.join("readme.txt"), "r").unwrap();
+        let mut dialog = FileDialog::new(Rect::new(0, 0, 60, 16), "Open", "*", Some(root.clone()), FileDialogMode::Open).build();
+        while dialog.poll_directory() {}
+
+        clipboard::enable_osc52(true);
+        // The input line has the focus: Ctrl+C is its own (nothing selected, nothing copied)
+        dialog.handle_event(&mut Event::keyboard(KB_CTRL_C));
+        assert_eq!(clipboard::take_pending_osc52(), None);
+
+        // Rows: "..", "[docs]", "readme.txt"
+        dialog.handle_event(&mut Event::keyboard(KB_TAB));
+        dialog.handle_event(&mut Event::keyboard(KB_DOWN));
+        assert_eq!(dialog.selected_path(), Some(root.join("docs")));
+        dialog.handle_event(&mut Event::keyboard(KB_DOWN));
+        let mut event = Event::keyboard(KB_CTRL_C);
+        dialog.handle_event(&mut event);
+        let copied = clipboard::take_pending_osc52();
+        clipboard::enable_osc52(false);
+
+        assert_eq!(event.what, EventType::Nothing);
+        assert_eq!(copied, clipboard::osc52_sequence(&root.join("readme.txt").to_string_lossy()));
+    }
+
+    #[test]
+    fn test_save_mode_accepts_new_names_and_confirms_overwrite() {
+        use crate::app::Application;
+        use crate::core::event::{KB_ENTER, KB_ESC_ESC};
+
+        let dir = tempfile::tempdir().unwrap();
+        let root = std::fs::canonicalize(dir.path()).unwrap();
+        std::fs::write(root.join("readme.txt"), "r").unwrap();
+        let mut app = Application::with_terminal(Terminal::new_headless(80, 25));
+        let save_dialog = || FileDialog::new(Rect::new(0, 0, 60, 16), "Save", "*", Some(root.clone()), FileDialogMode::Save).build();
+        let type_name = |app: &mut Application, name: &str| {
+            for ch in name.chars() {
+                app.terminal.push_input(Event::keyboard(ch as u16));
+            }
+            app.terminal.push_input(Event::keyboard(KB_ENTER));
+        };
+
+        // A new name is returned although no such file exists
+        type_name(&mut app, "notes.md");
+        assert_eq!(save_dialog().execute(&mut app), Some(root.join("notes.md")));
+
+        // An existing one asks first: No goes back to the dialog, Yes returns it
+        type_name(&mut app, "readme.txt");
+        app.terminal.push_input(Event::keyboard(KB_TAB));
+        app.terminal.push_input(Event::keyboard(KB_ENTER));
+        app.terminal.push_input(Event::keyboard(KB_ESC_ESC));
+        assert_eq!(save_dialog().execute(&mut app), None);
+
+        type_name(&mut app, "readme.txt");
+        app.terminal.push_input(Event::keyboard(KB_ENTER));
+        assert_eq!(save_dialog().execute(&mut app), Some(root.join("readme.txt")));
+    }
+
+    fn entry(name: &str, is_dir: bool) -> FileEntry {
+        FileEntry { name: name.to_string(), path: PathBuf::from(name), is_dir, size: 0, modified: None }
+    }
+
+    #[test]
+    fn test_batches_are_merged_and_sorted_by_kind() {
+        let mut dialog = FileDialog::new(Rect::new(0, 0, 60, 16), "Open", "*", None, FileDialogMode::Open);
+        dialog.files.push("..".to_string());
+
+        // A file whose name looks like a folder still sorts with the files
+        assert!(dialog.add_entries(vec![entry("b.txt", false), entry("zeta", true), entry("[x]", false)]));
+        assert!(dialog.add_entries(vec![entry("docs", true), entry("c.txt", false), entry("a.txt", false)]));
+        assert!(!dialog.add_entries(Vec::new()));
+
+        assert_eq!(dialog.files, ["..", "[docs]", "[zeta]", "[x]", "a.txt", "b.txt", "c.txt"]);
+        assert_eq!(dialog.dir_count, 2);
+    }
+
+    #[test]
+    fn test_esc_closes_the_dialog_while_reading() {
+        use crate::app::Application;
+        use crate::core::event::KB_ESC;
+
+        let mut app = Application::with_terminal(Terminal::new_headless(80, 25));
+        let mut dialog = FileDialog::new(Rect::new(0, 0, 60, 16), "Open", "*", None, FileDialogMode::Open).build();
+        // A listing that never ends
+        dialog.reader = Some(DirReader::from_entries((0..).map(|i| Ok(entry(&format!("file{i}"), false)))));
+
+        app.terminal.push_input(Event::keyboard(KB_ESC));
+        assert_eq!(dialog.execute(&mut app), None);
+        assert!(dialog.reader.as_ref().is_some_and(DirReader::is_canceled));
+
+        // Without a listing in progress ESC closes it as well
+        app.terminal.push_input(Event::keyboard(KB_ESC));
+        assert_eq!(FileDialog::new(Rect::new(0, 0, 60, 16), "Open", "*", None, FileDialogMode::Open).build().execute(&mut app), None);
+    }
+
+    #[test]
+    fn test_mode_sets_default_button_label() {
+        let dialog = FileDialogBuilder::new().bounds(Rect::new(0, 0, 60, 16)).title("Save").mode(FileDialogMode::Save).build();
+        assert_eq!((dialog.mode(), dialog.button_label.as_str()), (FileDialogMode::Save, "~S~ave"));
+        let dialog = FileDialog::new(Rect::new(0, 0, 60, 16), "Open", "*", None, FileDialogMode::Open);
+        assert_eq!(dialog.button_label, "~O~pen");
+    }
+}
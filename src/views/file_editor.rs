@@ -13,8 +13,20 @@
 //
 // Architecture:
 // Editor (core editing) -> EditWindow (adds frame/scrollbars) -> FileEditor (adds file I/O)
+//
+// Autosave: with set_autosave(interval), a modified buffer is written to a
+// backup next to the file ("notes.txt" -> "notes.txt~") at most once per
+// interval, from idle(). A successful save removes the backup. When a file
+// is opened while a newer backup exists, Application::open_file offers to
+// recover it.
+//
+// Changes on disk: the file's modification time is remembered when it is
+// loaded or saved. When the window becomes the active one (on_activate), a
+// newer time on disk sets changed_on_disk() for the application to act on.
 
-use std::path::PathBuf;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
 use crate::core::geometry::Rect;
 use crate::core::event::Event;
 use crate::core::command::{CommandId, CM_YES, CM_NO};
@@ -22,8 +34,16 @@ use crate::core::state::StateFlags;
 use crate::terminal::Terminal;
 use crate::app::Application;
 use super::edit_window::EditWindow;
-use super::view::View;
-use super::msgbox::confirmation_box;
+use super::view::{IdleView, View};
+use super::file_dialog::FileDialogMode;
+use super::msgbox::{ConfirmOptions, confirmation_box_with};
+
+/// Backup file for `path`: the same name with `~` appended
+pub fn backup_path_for(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push("~");
+    PathBuf::from(name)
+}
 
 /// FileEditor - EditWindow with file management
 ///
@@ -31,6 +51,14 @@ use super::msgbox::confirmation_box;
 pub struct FileEditor {
     edit_window: EditWindow,
     filename: Option<PathBuf>,
+    /// Autosave interval; None disables autosave
+    autosave: Option<Duration>,
+    /// When the next backup is due, armed once the buffer is modified
+    next_backup: Option<Instant>,
+    /// Modification time of the file when it was last loaded or saved
+    disk_time: Option<SystemTime>,
+    /// Another program wrote the file since it was loaded or saved
+    changed_on_disk: bool,
 }
 
 impl FileEditor {
@@ -41,15 +69,108 @@ impl FileEditor {
         Self {
             edit_window: EditWindow::new(bounds, title),
             filename: None,
+            autosave: None,
+            next_backup: None,
+            disk_time: None,
+            changed_on_disk: false,
+        }
+    }
+
+    /// Write a backup of the modified buffer every `interval`
+    ///
+    /// `Duration::ZERO` turns autosave off. Untitled buffers have no backup.
+    pub fn set_autosave(&mut self, interval: Duration) {
+        self.autosave = (!interval.is_zero()).then_some(interval);
+        self.next_backup = None;
+    }
+
+    pub fn autosave(&self) -> Option<Duration> {
+        self.autosave
+    }
+
+    /// Backup file of the current file, None while untitled
+    pub fn backup_path(&self) -> Option<PathBuf> {
+        self.filename.as_deref().map(backup_path_for)
+    }
+
+    /// Write the buffer to the backup file if it is modified
+    ///
+    /// Returns true if a backup was written.
+    pub fn write_backup(&mut self) -> io::Result<bool> {
+        match self.backup_path() {
+            Some(backup) if self.is_modified() => {
+                std::fs::write(backup, self.edit_window.editor_rc().borrow().get_text())?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Write a backup if autosave is on and one is due at `now`
+    ///
+    /// The interval starts at the first call that finds the buffer modified.
+    /// Returns true if a backup was written.
+    pub fn autosave_tick(&mut self, now: Instant) -> io::Result<bool> {
+        let Some(interval) = self.autosave else {
+            return Ok(false);
+        };
+        if !self.is_modified() {
+            self.next_backup = None;
+            return Ok(false);
+        }
+        match self.next_backup {
+            Some(due) if now >= due => {
+                self.next_backup = Some(now + interval);
+                self.write_backup()
+            }
+            Some(_) => Ok(false),
+            None => {
+                self.next_backup = Some(now + interval);
+                Ok(false)
+            }
         }
     }
 
+    /// Remove the backup file, if any
+    pub fn remove_backup(&mut self) -> io::Result<()> {
+        self.next_backup = None;
+        match self.backup_path() {
+            Some(backup) => match std::fs::remove_file(backup) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+                _ => Ok(()),
+            },
+            None => Ok(()),
+        }
+    }
+
+    /// The backup file, if it was written after the file was last saved
+    pub fn recoverable_backup(&self) -> Option<PathBuf> {
+        let modified = |path: &Path| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+        let file_time = self.filename.as_deref().and_then(modified).unwrap_or(SystemTime::UNIX_EPOCH);
+        let backup = self.backup_path()?;
+        (modified(&backup)? > file_time).then_some(backup)
+    }
+
+    /// Replace the buffer with the backup's contents, leaving it modified
+    ///
+    /// The backup file stays until the buffer is saved.
+    pub fn recover_backup(&mut self) -> io::Result<()> {
+        let backup = self.backup_path().ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "untitled buffer has no backup"))?;
+        let text = std::fs::read_to_string(backup)?;
+        let editor = self.edit_window.editor_rc();
+        let mut editor = editor.borrow_mut();
+        editor.set_text(&text);
+        editor.mark_modified();
+        Ok(())
+    }
+
     /// Load a file
     ///
     /// Matches Borland: TFileEditor::loadFile()
     pub fn load_file(&mut self, path: PathBuf) -> std::io::Result<()> {
         self.edit_window.load_file(&path)?;
         self.filename = Some(path);
+        self.record_disk_time();
         Ok(())
     }
 
@@ -59,6 +180,8 @@ impl FileEditor {
     pub fn save(&mut self) -> std::io::Result<bool> {
         if self.filename.is_some() {
             self.edit_window.save_file()?;
+            self.record_disk_time();
+            self.remove_backup()?;
             Ok(true)
         } else {
             Ok(false) // Need to call save_as
@@ -70,8 +193,43 @@ impl FileEditor {
     /// Matches Borland: TFileEditor::saveAs()
     pub fn save_as(&mut self, path: PathBuf) -> std::io::Result<()> {
         self.edit_window.save_as(&path)?;
+        // The old name's backup is stale too
+        self.remove_backup()?;
         self.filename = Some(path);
-        Ok(())
+        self.record_disk_time();
+        self.remove_backup()
+    }
+
+    /// Modification time of the file on disk, if it can be read
+    fn file_time(&self) -> Option<SystemTime> {
+        let path = self.filename.as_deref()?;
+        std::fs::metadata(path).and_then(|m| m.modified()).ok()
+    }
+
+    fn record_disk_time(&mut self) {
+        self.disk_time = self.file_time();
+        self.changed_on_disk = false;
+    }
+
+    /// Check whether another program wrote the file since it was loaded or saved
+    ///
+    /// Done automatically when the window becomes active. Returns true (and
+    /// sets [`changed_on_disk()`](Self::changed_on_disk)) once per change.
+    pub fn check_disk_changes(&mut self) -> bool {
+        let newer = match (self.file_time(), self.disk_time) {
+            (Some(now), Some(then)) => now > then,
+            _ => false,
+        };
+        if newer {
+            self.disk_time = self.file_time();
+            self.changed_on_disk = true;
+        }
+        newer
+    }
+
+    /// The file changed on disk since it was loaded or saved; cleared by the next load or save
+    pub fn changed_on_disk(&self) -> bool {
+        self.changed_on_disk
     }
 
     /// Get the filename
@@ -115,32 +273,62 @@ impl FileEditor {
     /// Matches Borland: TFileEditor::valid(command)
     /// Returns true if close is allowed, false if cancelled
     pub fn valid(&mut self, app: &mut Application, command: CommandId) -> bool {
-        // Only prompt for cmClose when modified
-        if command == crate::core::command::CM_CLOSE && self.is_modified() {
-            let message = format!("Save changes to {}?", self.get_title());
-            match confirmation_box(app, &message) {
-                cmd if cmd == CM_YES => {
-                    // Try to save
-                    if let Some(_) = &self.filename {
-                        self.save().is_ok()
-                    } else {
-                        // TODO: Need to show save_as dialog
-                        // For now, just allow close
-                        true
-                    }
-                }
-                cmd if cmd == CM_NO => {
-                    // Don't save, allow close
-                    true
-                }
-                _ => {
-                    // Cancel
-                    false
-                }
+        match self.close_question(command) {
+            Some(message) => {
+                let answer = Self::ask_close(app, &message);
+                let untitled = self.filename.is_none();
+                self.resolve_close(answer, || Self::ask_save_path(app, answer, untitled))
             }
-        } else {
             // Not modified or not closing, allow
-            true
+            None => true,
+        }
+    }
+
+    /// The question valid() asks before closing, if there are unsaved changes
+    ///
+    /// Only cmClose prompts, and only when modified.
+    pub(crate) fn close_question(&self, command: CommandId) -> Option<String> {
+        (command == crate::core::command::CM_CLOSE && self.is_modified())
+            .then(|| format!("Save changes to {}?", self.get_title()))
+    }
+
+    /// Show the "Save changes" box and return the command chosen
+    pub(crate) fn ask_close(app: &mut Application, message: &str) -> CommandId {
+        // Saving is the safe answer, so Yes stays the default
+        let options = ConfirmOptions::new().title("Save Changes");
+        confirmation_box_with(app, message, &options).command
+    }
+
+    /// Ask where to save an untitled buffer, if Yes was the answer to the "Save changes" box
+    pub(crate) fn ask_save_path(app: &mut Application, answer: CommandId, untitled: bool) -> Option<PathBuf> {
+        (answer == CM_YES && untitled)
+            .then(|| app.ask_file_name("Save File As", FileDialogMode::Save))
+            .flatten()
+    }
+
+    /// Act on the answer to the "Save changes" box
+    /// An untitled buffer is saved to the path `save_path` returns (see
+    /// [`ask_save_path()`](Self::ask_save_path)); without one the close is cancelled.
+    /// Returns true if close is allowed, false if cancelled
+    pub(crate) fn resolve_close(&mut self, answer: CommandId, save_path: impl FnOnce() -> Option<PathBuf>) -> bool {
+        match answer {
+            cmd if cmd == CM_YES => {
+                // Try to save
+                if self.filename.is_some() {
+                    self.save().is_ok()
+                } else {
+                    save_path().is_some_and(|path| self.save_as(path).is_ok())
+                }
+            }
+            cmd if cmd == CM_NO => {
+                // Don't save, allow close; the changes are discarded on purpose
+                let _ = self.remove_backup();
+                true
+            }
+            _ => {
+                // Cancel
+                false
+            }
         }
     }
 
@@ -184,6 +372,10 @@ impl View for FileEditor {
         self.edit_window.set_options(options);
     }
 
+    fn is_opaque(&self) -> bool {
+        self.edit_window.is_opaque()
+    }
+
     fn state(&self) -> StateFlags {
         self.edit_window.state()
     }
@@ -199,6 +391,28 @@ impl View for FileEditor {
     fn set_owner(&mut self, owner: *const dyn View) {
         self.edit_window.set_owner(owner);
     }
+
+    fn on_activate(&mut self, active: bool) {
+        if active {
+            self.check_disk_changes();
+        }
+        self.edit_window.on_activate(active);
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+impl IdleView for FileEditor {
+    /// Autosave; a failed backup write is retried at the next interval
+    fn idle(&mut self) {
+        let _ = self.autosave_tick(Instant::now());
+    }
 }
 
 /// Builder for creating file editors with a fluent API.
@@ -242,3 +456,133 @@ impl Default for FileEditorBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::event::KB_ENTER;
+    use std::fs::File;
+
+    fn editor_for(path: &Path) -> FileEditor {
+        let mut editor = FileEditor::new(Rect::new(0, 0, 40, 12), "");
+        editor.load_file(path.to_path_buf()).unwrap();
+        editor
+    }
+
+    fn type_text(editor: &mut FileEditor, text: &str) {
+        for ch in text.chars() {
+            let key = if ch == '\n' { KB_ENTER } else { ch as u16 };
+            editor.handle_event(&mut Event::keyboard(key));
+        }
+    }
+
+    #[test]
+    fn test_yes_saves_untitled_buffer_to_the_chosen_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("untitled.txt");
+        let mut editor = FileEditor::new(Rect::new(0, 0, 40, 12), "");
+        type_text(&mut editor, "keep me");
+
+        // No file name chosen: the window stays open
+        assert!(!editor.resolve_close(CM_YES, || None));
+        assert!(editor.is_modified());
+
+        assert!(editor.resolve_close(CM_YES, || Some(path.clone())));
+        assert_eq!(std::fs::read_to_string(&path).unwrap().trim_end(), "keep me");
+    }
+
+    #[test]
+    fn test_autosave_writes_backup_and_save_removes_it() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.txt");
+        std::fs::write(&path, "first\n").unwrap();
+        let backup = dir.path().join("notes.txt~");
+
+        let mut editor = editor_for(&path);
+        editor.set_autosave(Duration::from_secs(30));
+        assert_eq!(editor.backup_path(), Some(backup.clone()));
+
+        // Nothing to back up while unmodified
+        let start = Instant::now();
+        assert!(!editor.autosave_tick(start + Duration::from_secs(60)).unwrap());
+
+        type_text(&mut editor, "new ");
+        assert!(!editor.autosave_tick(start).unwrap(), "interval starts at the first modified tick");
+        assert!(!editor.autosave_tick(start + Duration::from_secs(10)).unwrap());
+        assert!(editor.autosave_tick(start + Duration::from_secs(30)).unwrap());
+        assert_eq!(std::fs::read_to_string(&backup).unwrap(), "new first");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "first\n", "the file itself is untouched");
+
+        assert!(editor.save().unwrap());
+        assert!(!backup.exists());
+        assert!(!editor.autosave_tick(start + Duration::from_secs(120)).unwrap());
+    }
+
+    #[test]
+    fn test_newer_backup_is_recoverable() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.txt");
+        let backup = backup_path_for(&path);
+        std::fs::write(&path, "saved").unwrap();
+        std::fs::write(&backup, "unsaved edits").unwrap();
+        let set_mtime = |path: &Path, secs: u64| {
+            let time = SystemTime::UNIX_EPOCH + Duration::from_secs(secs);
+            File::options().write(true).open(path).unwrap().set_modified(time).unwrap();
+        };
+
+        // A backup older than the file is stale
+        set_mtime(&path, 2_000_000);
+        set_mtime(&backup, 1_000_000);
+        assert_eq!(editor_for(&path).recoverable_backup(), None);
+
+        set_mtime(&backup, 3_000_000);
+        let mut editor = editor_for(&path);
+        assert_eq!(editor.recoverable_backup(), Some(backup.clone()));
+        editor.recover_backup().unwrap();
+        assert!(editor.is_modified());
+        assert_eq!(editor.edit_window().editor_rc().borrow().get_text(), "unsaved edits");
+
+        editor.save().unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "unsaved edits");
+        assert!(!backup.exists());
+    }
+
+    #[test]
+    fn test_untitled_buffer_has_no_backup() {
+        let mut editor = FileEditor::new(Rect::new(0, 0, 40, 12), "Untitled");
+        editor.set_autosave(Duration::from_secs(1));
+        type_text(&mut editor, "text");
+        assert_eq!(editor.backup_path(), None);
+        assert!(!editor.write_backup().unwrap());
+        assert!(editor.recover_backup().is_err());
+    }
+
+    #[test]
+    fn test_activation_checks_for_changes_on_disk() {
+        use std::time::Duration;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.txt");
+        std::fs::write(&path, "one\n").unwrap();
+        let set_mtime = |secs: u64| {
+            let time = SystemTime::UNIX_EPOCH + Duration::from_secs(secs);
+            File::options().write(true).open(&path).unwrap().set_modified(time).unwrap();
+        };
+        set_mtime(1_000_000);
+        let mut editor = editor_for(&path);
+
+        editor.on_activate(true);
+        assert!(!editor.changed_on_disk());
+
+        // Another program writes the file while the window is in the background
+        set_mtime(2_000_000);
+        editor.on_activate(false);
+        assert!(!editor.changed_on_disk(), "only activation checks");
+        editor.on_activate(true);
+        assert!(editor.changed_on_disk());
+        assert!(!editor.check_disk_changes(), "reported once per change");
+
+        editor.save().unwrap();
+        assert!(!editor.changed_on_disk());
+    }
+}
@@ -14,13 +14,19 @@
 // - Parent directory (..) navigation
 // - File info display (size, date, attributes)
 // - Integrates with ListViewer trait for consistent navigation
+// - Incremental reading (one chunk per frame), ESC cancels a slow listing
+// - Ctrl+C copies the focused entry's full path to the clipboard
+// - Optional multi-select: Ctrl+click and Shift+click mark entries
 
 use crate::core::geometry::Rect;
-use crate::core::event::{Event, EventType};
+use crate::core::command::CM_FILE_DOUBLE_CLICKED;
+use crate::core::clipboard;
+use crate::core::event::{Event, EventType, KB_CTRL_C};
 use crate::core::state::StateFlags;
 use crate::terminal::Terminal;
 use super::view::View;
 use super::list_viewer::{ListViewer, ListViewerState};
+use super::dir_reader::{sort_entries, DirReader};
 use std::path::{Path, PathBuf};
 use std::fs;
 use std::time::SystemTime;
@@ -90,6 +96,8 @@ pub struct FileList {
     current_path: PathBuf,
     wildcard: String,
     show_hidden: bool,
+    /// Listing still in progress
+    reader: Option<DirReader>,
     owner: Option<*const dyn View>,
     owner_type: super::view::OwnerType,
 }
@@ -105,6 +113,7 @@ impl FileList {
             current_path: path.to_path_buf(),
             wildcard: "*".to_string(),
             show_hidden: false,
+            reader: None,
             owner: None,
             owner_type: super::view::OwnerType::None,
         }
@@ -136,8 +145,13 @@ impl FileList {
     }
 
     /// Refresh the file list
+    ///
+    /// Reads the first chunk of the directory right away; the rest is read
+    /// by [`poll_directory()`](Self::poll_directory), which `draw()` calls
+    /// once per frame.
     pub fn refresh(&mut self) {
         self.files.clear();
+        self.list_state.clear_marks();
 
         // Add parent directory entry if not at root
         if self.current_path.parent().is_some() {
@@ -150,36 +164,9 @@ impl FileList {
             });
         }
 
-        // Read directory entries
-        if let Ok(entries) = fs::read_dir(&self.current_path) {
-            let mut file_entries: Vec<FileEntry> = entries
-                .filter_map(|e| e.ok())
-                .filter_map(|e| FileEntry::from_dir_entry(&e).ok())
-                .filter(|entry| {
-                    // Filter hidden files
-                    if !self.show_hidden && entry.name.starts_with('.') {
-                        return false;
-                    }
-                    // Always show directories
-                    if entry.is_dir {
-                        return true;
-                    }
-                    // Filter files by wildcard
-                    self.matches_wildcard(&entry.name)
-                })
-                .collect();
-
-            // Sort: directories first, then files, both alphabetically
-            file_entries.sort_by(|a, b| {
-                match (a.is_dir, b.is_dir) {
-                    (true, false) => std::cmp::Ordering::Less,
-                    (false, true) => std::cmp::Ordering::Greater,
-                    _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
-                }
-            });
-
-            self.files.extend(file_entries);
-        }
+        // Start reading directory entries
+        self.reader = DirReader::open(&self.current_path).ok();
+        self.poll_directory();
 
         // Update list state
         self.list_state.set_range(self.files.len());
@@ -190,6 +177,65 @@ impl FileList {
         }
     }
 
+    /// Read the next chunk of the directory into the list
+    ///
+    /// Returns true while the listing is still in progress.
+    pub fn poll_directory(&mut self) -> bool {
+        let Some(reader) = self.reader.as_mut() else {
+            return false;
+        };
+        let chunk = reader.read_chunk();
+        let done = reader.is_done();
+
+        let focused_path = self.get_focused_entry().map(|entry| entry.path.clone());
+        let path_of = |index: usize| self.files[index].path.clone();
+        let marked_paths: Vec<PathBuf> = self.list_state.marked.iter().map(|&index| path_of(index)).collect();
+        let anchor_path = self.list_state.anchor.map(path_of);
+        let first = usize::from(self.files.first().is_some_and(|entry| entry.name == ".."));
+        let mut added = false;
+        for entry in chunk {
+            // Filter hidden files; always show directories, filter files by wildcard
+            let hidden = !self.show_hidden && entry.name.starts_with('.');
+            if !hidden && (entry.is_dir || self.matches_wildcard(&entry.name)) {
+                self.files.push(entry);
+                added = true;
+            }
+        }
+
+        if added {
+            // Sort: directories first, then files, both alphabetically (".." stays on top)
+            sort_entries(&mut self.files[first..]);
+            self.list_state.set_range(self.files.len());
+            // Keep the focus on the same entry while batches arrive
+            if let Some(path) = focused_path {
+                self.list_state.focused = self.files.iter().position(|entry| entry.path == path);
+            }
+            // The marks follow their entries too
+            let index_of = |path: &PathBuf| self.files.iter().position(|entry| entry.path == *path);
+            self.list_state.marked = marked_paths.iter().filter_map(index_of).collect();
+            self.list_state.anchor = anchor_path.as_ref().and_then(index_of);
+        }
+        if done {
+            self.reader = None;
+        }
+        !done
+    }
+
+    /// True while the directory is still being read
+    pub fn is_reading(&self) -> bool {
+        self.reader.is_some()
+    }
+
+    /// Stop reading the directory, keeping the entries read so far
+    pub fn cancel_reading(&mut self) {
+        self.reader = None;
+    }
+
+    /// Read the rest of the directory (blocking)
+    pub fn finish_reading(&mut self) {
+        while self.poll_directory() {}
+    }
+
     /// Check if filename matches wildcard pattern
     fn matches_wildcard(&self, filename: &str) -> bool {
         if self.wildcard == "*" {
@@ -221,6 +267,12 @@ impl FileList {
         }
     }
 
+    /// Absolute path of the focused entry, file or directory
+    pub fn selected_path(&self) -> Option<PathBuf> {
+        let path = &self.get_focused_entry()?.path;
+        Some(std::path::absolute(path).unwrap_or_else(|_| path.clone()))
+    }
+
     /// Navigate into the focused directory
     pub fn enter_focused_dir(&mut self) -> std::io::Result<bool> {
         let path = if let Some(entry) = self.get_focused_entry() {
@@ -245,6 +297,41 @@ impl FileList {
     pub fn file_count(&self) -> usize {
         self.files.len()
     }
+
+    /// Let Ctrl+click and Shift+click mark several entries
+    ///
+    /// Turning it off clears the marks.
+    pub fn set_multi_select(&mut self, multi_select: bool) {
+        self.list_state.multi_select = multi_select;
+        if !multi_select {
+            self.list_state.clear_marks();
+        }
+    }
+
+    pub fn is_multi_select(&self) -> bool {
+        self.list_state.multi_select
+    }
+
+    /// The marked entries, in list order, for operations such as copy or delete
+    ///
+    /// The ".." entry is never included.
+    pub fn marked_entries(&self) -> Vec<&FileEntry> {
+        self.list_state
+            .marked
+            .iter()
+            .filter_map(|&index| self.files.get(index))
+            .filter(|entry| entry.name != "..")
+            .collect()
+    }
+
+    /// Paths of the marked entries
+    pub fn marked_paths(&self) -> Vec<PathBuf> {
+        self.marked_entries().into_iter().map(|entry| entry.path.clone()).collect()
+    }
+
+    pub fn clear_marks(&mut self) {
+        self.list_state.clear_marks();
+    }
 }
 
 impl ListViewer for FileList {
@@ -277,6 +364,7 @@ impl View for FileList {
         let width = self.bounds.width_clamped() as usize;
         let height = self.bounds.height_clamped() as usize;
 
+        self.poll_directory();
         self.list_state.set_range(self.files.len());
 
         for y in 0..height {
@@ -285,10 +373,12 @@ impl View for FileList {
             let (text, color) = if item_idx < self.files.len() {
                 let text = self.get_text(item_idx, width);
                 let is_focused = self.is_focused() && Some(item_idx) == self.list_state.focused;
-                let color = if is_focused {
-                    crate::core::palette::colors::LISTBOX_FOCUSED
-                } else {
-                    crate::core::palette::colors::LISTBOX_NORMAL
+                let is_marked = self.list_state.marked.contains(&item_idx);
+                let color = match (is_focused, is_marked) {
+                    (true, true) => crate::core::palette::colors::LISTBOX_SELECTED_FOCUSED,
+                    (true, false) => crate::core::palette::colors::LISTBOX_FOCUSED,
+                    (false, true) => crate::core::palette::colors::LISTBOX_SELECTED,
+                    (false, false) => crate::core::palette::colors::LISTBOX_NORMAL,
                 };
                 (text, color)
             } else {
@@ -305,13 +395,52 @@ impl View for FileList {
                 );
             }
         }
+
+        if let Some(ref reader) = self.reader {
+            reader.draw_status(terminal, self.bounds, crate::core::palette::colors::LISTBOX_NORMAL);
+        }
     }
 
     fn handle_event(&mut self, event: &mut Event) {
+        // The wheel scrolls the list under the pointer, focused or not
+        if event.is_wheel() {
+            self.handle_list_event(event);
+            return;
+        }
+
         if !self.is_focused() {
             return;
         }
 
+        // Double-click enters the clicked directory, or tells the owner a file was picked
+        // Matches Borland: TFileList broadcasts cmFileDoubleClicked
+        if event.what == EventType::MouseDown && event.mouse.double_click {
+            if self.handle_list_event(event) {
+                if self.get_selected_file().is_some() {
+                    *event = Event::broadcast(CM_FILE_DOUBLE_CLICKED);
+                } else {
+                    let _ = self.enter_focused_dir();
+                }
+            }
+            return;
+        }
+
+        // ESC aborts a listing that is still being read
+        if self.is_reading() && event.what == EventType::Keyboard && event.key_code == crate::core::event::KB_ESC {
+            self.cancel_reading();
+            event.clear();
+            return;
+        }
+
+        // Ctrl+C copies the focused entry's full path
+        if event.what == EventType::Keyboard && event.key_code == KB_CTRL_C {
+            if let Some(path) = self.selected_path() {
+                clipboard::set_clipboard(&path.to_string_lossy());
+            }
+            event.clear();
+            return;
+        }
+
         // Use default ListViewer navigation
         self.handle_list_event(event);
 
@@ -389,6 +518,102 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_mouse_selects_scrolls_and_opens() {
+        use crate::core::event::MB_LEFT_BUTTON;
+        use crate::core::geometry::Point;
+
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("alpha")).unwrap();
+        fs::write(dir.path().join("b.txt"), "b").unwrap();
+        fs::write(dir.path().join("c.txt"), "c").unwrap();
+        let mut list = FileList::new(Rect::new(0, 5, 30, 8), dir.path());
+        list.refresh();
+        list.finish_reading();
+        list.set_focus(true);
+        let click = |y, double| Event::mouse(EventType::MouseDown, Point::new(3, y), MB_LEFT_BUTTON, double);
+
+        // Rows: "..", "alpha", "b.txt"
+        let mut event = click(7, false);
+        list.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Nothing);
+        assert_eq!(list.get_focused_entry().unwrap().name, "b.txt");
+
+        let mut event = Event::wheel(Point::new(3, 6), 1);
+        list.handle_event(&mut event);
+        assert_eq!(list.top_item(), 1);
+        assert_eq!(list.get_focused_entry().unwrap().name, "b.txt");
+
+        // Rows: "alpha", "b.txt", "c.txt"
+        let mut event = click(7, true);
+        list.handle_event(&mut event);
+        assert_eq!((event.what, event.command), (EventType::Broadcast, CM_FILE_DOUBLE_CLICKED));
+        assert_eq!(list.get_selected_file(), Some(dir.path().join("c.txt")));
+
+        let mut event = click(5, true);
+        list.handle_event(&mut event);
+        assert_eq!(list.current_path(), fs::canonicalize(dir.path().join("alpha")).unwrap());
+    }
+
+    #[test]
+    fn test_modifier_clicks_mark_entries() {
+        use crate::core::event::MB_LEFT_BUTTON;
+        use crate::core::geometry::Point;
+        use crossterm::event::KeyModifiers;
+
+        let dir = tempfile::tempdir().unwrap();
+        let root = fs::canonicalize(dir.path()).unwrap();
+        for i in 0..20 {
+            fs::write(root.join(format!("f{i:02}.txt")), "x").unwrap();
+        }
+        let mut list = FileList::new(Rect::new(0, 0, 30, 21), &root);
+        list.refresh();
+        list.finish_reading();
+        list.set_focus(true);
+        list.set_multi_select(true);
+        let click = |list: &mut FileList, y, modifiers| {
+            list.handle_event(&mut Event::mouse_with(EventType::MouseDown, Point::new(3, y), MB_LEFT_BUTTON, modifiers));
+        };
+        let names = |list: &FileList| list.marked_entries().iter().map(|entry| entry.name.clone()).collect::<Vec<_>>();
+
+        // Row 0 is "..", row n is f(n-1)
+        click(&mut list, 2, KeyModifiers::empty());
+        click(&mut list, 5, KeyModifiers::CONTROL);
+        assert_eq!(names(&list), ["f01.txt", "f04.txt"]);
+        click(&mut list, 0, KeyModifiers::SHIFT);
+        assert_eq!(names(&list), ["f00.txt", "f01.txt"], "\"..\" is never part of the marked set");
+        click(&mut list, 4, KeyModifiers::SHIFT);
+        assert_eq!(list.marked_paths(), (1..4).map(|i| root.join(format!("f{i:02}.txt"))).collect::<Vec<_>>());
+
+        list.refresh();
+        assert!(list.marked_entries().is_empty());
+    }
+
+    #[test]
+    fn test_ctrl_c_copies_selected_path() {
+        use crate::core::event::KB_DOWN;
+
+        let dir = tempfile::tempdir().unwrap();
+        let root = fs::canonicalize(dir.path()).unwrap();
+        fs::write(root.join("notes.txt"), "n").unwrap();
+        let mut list = FileList::new(Rect::new(0, 0, 30, 5), &root);
+        list.refresh();
+        list.finish_reading();
+        list.set_focus(true);
+        list.handle_event(&mut Event::keyboard(KB_DOWN));
+        let expected = root.join("notes.txt");
+        assert_eq!(list.selected_path(), Some(expected.clone()));
+
+        // The OSC 52 queue is per thread, so other tests using the clipboard don't interfere
+        clipboard::enable_osc52(true);
+        let mut event = Event::keyboard(KB_CTRL_C);
+        list.handle_event(&mut event);
+        let copied = clipboard::take_pending_osc52();
+        clipboard::enable_osc52(false);
+        assert_eq!(event.what, EventType::Nothing);
+        assert_eq!(copied, clipboard::osc52_sequence(&expected.to_string_lossy()));
+    }
+
     #[test]
     fn test_file_entry_display() {
         let entry = FileEntry {
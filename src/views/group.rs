@@ -3,11 +3,38 @@
 //! Group view - container for managing multiple child views with focus handling.
 
 use crate::core::geometry::Rect;
+use crate::core::command::{CM_FOCUS_LINK, CM_RADIO_SELECTED};
 use crate::core::event::{Event, EventType, KB_TAB, KB_SHIFT_TAB};
 use crate::core::draw::DrawBuffer;
-use crate::core::palette::Attr;
+use crate::core::palette::{Attr, FRAME_ACTIVE_BORDER};
+use crate::core::state::{StateFlags, SF_FOCUSED};
 use crate::terminal::Terminal;
-use super::view::{View, ViewId, write_line_to_terminal};
+use super::cluster::Cluster;
+use super::view::{OwnerType, View, ViewId, write_line_to_terminal};
+
+/// Line style of a group frame (see [`Group::set_frame()`])
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameStyle {
+    Single,
+    Double,
+}
+
+impl FrameStyle {
+    /// Corners and edges: top-left, top-right, bottom-left, bottom-right, horizontal, vertical
+    fn chars(self) -> [char; 6] {
+        match self {
+            FrameStyle::Single => ['┌', '┐', '└', '┘', '─', '│'],
+            FrameStyle::Double => ['╔', '╗', '╚', '╝', '═', '║'],
+        }
+    }
+}
+
+/// Box drawn on a group's border cells, with an optional title in the top edge
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct GroupFrame {
+    style: FrameStyle,
+    title: Option<String>,
+}
 
 /// Group - a container for child views
 /// Matches Borland: TGroup (tgroup.h/tgroup.cc)
@@ -19,6 +46,10 @@ pub struct Group {
     background: Option<Attr>,
     end_state: crate::core::command::CommandId,  // For execute() event loop (Borland: endState)
     owner: Option<*const dyn View>,  // Borland: TView::owner field
+    owner_type: OwnerType,  // Palette context passed on to children
+    cull_obscured: bool,  // Skip drawing children hidden behind opaque ones
+    frame: Option<GroupFrame>,  // Lightweight frame, children are inset by 1 (see set_frame())
+    state: StateFlags,  // SF_FOCUSED while the group is the focused child of another group
 }
 
 impl Group {
@@ -31,6 +62,10 @@ impl Group {
             background: None,
             end_state: 0,
             owner: None,
+            owner_type: OwnerType::None,
+            cull_obscured: false,
+            frame: None,
+            state: 0,
         }
     }
 
@@ -43,25 +78,140 @@ impl Group {
             background: Some(background),
             end_state: 0,
             owner: None,
+            owner_type: OwnerType::None,
+            cull_obscured: false,
+            frame: None,
+            state: 0,
+        }
+    }
+
+    /// Skip drawing children that are completely hidden behind opaque children above them
+    /// Partially covered children are still drawn; clipping keeps the result correct
+    pub fn set_occlusion_culling(&mut self, enable: bool) {
+        self.cull_obscured = enable;
+    }
+
+    /// Check if the child at `index` (shadow included) is completely covered by
+    /// opaque children later in the z-order
+    pub fn is_obscured(&self, index: usize) -> bool {
+        let covers: Vec<Rect> = self.children[index + 1..]
+            .iter()
+            .filter(|child| child.is_opaque())
+            .map(|child| child.bounds())
+            .collect();
+        !covers.is_empty() && self.children[index].shadow_bounds().is_covered_by(&covers)
+    }
+
+    /// Per-child flags for `draw()`: true for children hidden behind opaque ones
+    fn obscured_children(&self) -> Vec<bool> {
+        let mut obscured = vec![false; self.children.len()];
+        if !self.cull_obscured {
+            return obscured;
+        }
+        // Walk from the top-most child down, collecting the opaque area above each one
+        let mut covers = Vec::new();
+        for (i, child) in self.children.iter().enumerate().rev() {
+            obscured[i] = !covers.is_empty() && child.shadow_bounds().is_covered_by(&covers);
+            if child.is_opaque() {
+                covers.push(child.bounds());
+            }
+        }
+        obscured
+    }
+
+    /// Draw a box on the group's border cells, with `title` embedded in the top edge
+    ///
+    /// Children are laid out inside the box: [`content_rect()`](Self::content_rect)
+    /// shrinks by one cell on each side, and children already added move in by one.
+    /// The colors are the owner's frame entries (the DIALOG palette inside a dialog).
+    pub fn set_frame(&mut self, style: FrameStyle, title: Option<&str>) {
+        let was_framed = self.frame.is_some();
+        self.frame = Some(GroupFrame { style, title: title.map(str::to_string) });
+        if !was_framed {
+            self.offset_children(1);
         }
     }
 
+    /// Remove the frame; children move back out by one cell
+    pub fn clear_frame(&mut self) {
+        if self.frame.take().is_some() {
+            self.offset_children(-1);
+        }
+    }
+
+    pub fn has_frame(&self) -> bool {
+        self.frame.is_some()
+    }
+
+    /// Cells between the group's bounds and the area children are laid out in
+    fn inset(&self) -> i16 {
+        i16::from(self.frame.is_some())
+    }
+
+    fn offset_children(&mut self, delta: i16) {
+        for child in &mut self.children {
+            let b = child.bounds();
+            child.set_bounds(Rect::new(b.a.x + delta, b.a.y + delta, b.b.x + delta, b.b.y + delta));
+        }
+    }
+
+    fn draw_frame(&self, terminal: &mut Terminal, frame: &GroupFrame) {
+        let width = self.bounds.width_clamped() as usize;
+        let height = self.bounds.height_clamped();
+        if width < 2 || height < 2 {
+            return;
+        }
+        let attr = self.map_color(FRAME_ACTIVE_BORDER);
+        let [top_left, top_right, bottom_left, bottom_right, horizontal, vertical] = frame.style.chars();
+
+        let mut buf = DrawBuffer::new(width);
+        buf.move_char(0, horizontal, attr, width);
+        buf.put_char(0, top_left, attr);
+        buf.put_char(width - 1, top_right, attr);
+        if let Some(ref title) = frame.title {
+            // "┌─ Title ───┐", clipped before the corner
+            buf.move_str_clipped(2, &format!(" {title} "), attr, width.saturating_sub(4));
+        }
+        write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y, &buf);
+
+        let mut side = DrawBuffer::new(1);
+        side.put_char(0, vertical, attr);
+        for y in 1..height - 1 {
+            write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y + y, &side);
+            write_line_to_terminal(terminal, self.bounds.b.x - 1, self.bounds.a.y + y, &side);
+        }
+
+        buf.move_char(0, horizontal, attr, width);
+        buf.put_char(0, bottom_left, attr);
+        buf.put_char(width - 1, bottom_right, attr);
+        write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.b.y - 1, &buf);
+    }
+
     pub fn add(&mut self, mut view: Box<dyn View>) -> ViewId {
         // Set owner pointer for palette chain resolution
         // Child views need to know their parent to traverse the palette chain
         view.set_owner(self as *const _ as *const dyn View);
 
+        // Pass on the palette context (Window vs Dialog) so controls pick the
+        // right colors. Plain groups keep the child's own default.
+        if self.owner_type != OwnerType::None {
+            view.set_owner_type(self.owner_type);
+        }
+
         // Convert child's bounds from relative to absolute coordinates
-        // Child bounds are specified relative to this Group's interior
+        // Child bounds are specified relative to this Group's interior (inside the frame, if any)
         let child_bounds = view.bounds();
+        let inset = self.inset();
         let absolute_bounds = Rect::new(
-            self.bounds.a.x + child_bounds.a.x,
-            self.bounds.a.y + child_bounds.a.y,
-            self.bounds.a.x + child_bounds.b.x,
-            self.bounds.a.y + child_bounds.b.y,
+            self.bounds.a.x + inset + child_bounds.a.x,
+            self.bounds.a.y + inset + child_bounds.a.y,
+            self.bounds.a.x + inset + child_bounds.b.x,
+            self.bounds.a.y + inset + child_bounds.b.y,
         );
         view.set_bounds(absolute_bounds);
 
+        view.on_insert(self as *const _ as *const dyn View);
+
         let view_id = ViewId::new();
         self.children.push(view);
         self.view_ids.push(view_id);
@@ -89,6 +239,13 @@ impl Group {
         }
     }
 
+    /// Area available to children, in the coordinates passed to add()
+    /// Layout helpers use this instead of computing sizes from the bounds.
+    pub fn content_rect(&self) -> Rect {
+        let inset = self.inset();
+        Rect::new(0, 0, self.bounds.width() - 2 * inset, self.bounds.height() - 2 * inset)
+    }
+
     pub fn len(&self) -> usize {
         self.children.len()
     }
@@ -127,6 +284,30 @@ impl Group {
         false
     }
 
+    /// Deselect the radio buttons of `group_id`, except the child at `except_index`
+    pub fn deselect_radio_group(&mut self, group_id: u16, except_index: usize) {
+        for (i, child) in self.children.iter_mut().enumerate() {
+            if i == except_index {
+                continue;
+            }
+            if let Some(radio) = child.as_radio_button_mut() {
+                if radio.group_id() == group_id {
+                    radio.deselect();
+                }
+            }
+        }
+    }
+
+    /// A child radio button reported its selection: deselect the rest of its group
+    fn handle_radio_selected(&mut self, index: usize, event: &mut Event) {
+        if event.what == EventType::Broadcast && event.command == CM_RADIO_SELECTED {
+            if let Some(group_id) = self.children[index].as_radio_button().map(Cluster::group_id) {
+                self.deselect_radio_group(group_id, index);
+            }
+            event.clear();
+        }
+    }
+
     /// Bring a child view to the front (top of z-order)
     /// Matches Borland: TGroup::selectView() which reorders views
     /// Returns the new index of the moved child
@@ -136,11 +317,13 @@ impl Group {
             return index;
         }
 
-        // Remove the view from its current position
+        // Remove the view from its current position (keeping its ViewId with it)
         let view = self.children.remove(index);
+        let view_id = self.view_ids.remove(index);
 
         // Add it to the end (front of z-order)
         self.children.push(view);
+        self.view_ids.push(view_id);
 
         // Update focused index if necessary
         let new_index = self.children.len() - 1;
@@ -163,11 +346,13 @@ impl Group {
             return index;
         }
 
-        // Remove the view from its current position
+        // Remove the view from its current position (keeping its ViewId with it)
         let view = self.children.remove(index);
+        let view_id = self.view_ids.remove(index);
 
         // Insert it at position 1 (right after element 0, which is typically background)
         self.children.insert(1, view);
+        self.view_ids.insert(1, view_id);
 
         // Update focused index if necessary
         if self.focused == index {
@@ -184,7 +369,8 @@ impl Group {
     /// Matches Borland: TGroup::remove(TView *p) or TGroup::shutDown()
     pub fn remove(&mut self, index: usize) {
         if index < self.children.len() {
-            self.children.remove(index);
+            self.children.remove(index).on_remove();
+            self.view_ids.remove(index);
 
             // Update focused index if needed
             if self.focused >= index && self.focused > 0 {
@@ -198,6 +384,16 @@ impl Group {
         }
     }
 
+    /// Get the ViewId of the child at `index`
+    pub fn view_id_at(&self, index: usize) -> ViewId {
+        self.view_ids[index]
+    }
+
+    /// Find the current index of a child by its ViewId
+    pub fn index_of(&self, view_id: ViewId) -> Option<usize> {
+        self.view_ids.iter().position(|&id| id == view_id)
+    }
+
     /// Get an immutable reference to a child by its ViewId
     /// Returns None if the ViewId is not found
     pub fn child_by_id(&self, view_id: ViewId) -> Option<&dyn View> {
@@ -220,7 +416,6 @@ impl Group {
     pub fn remove_by_id(&mut self, view_id: ViewId) -> bool {
         if let Some(index) = self.view_ids.iter().position(|&id| id == view_id) {
             self.remove(index);
-            self.view_ids.remove(index);
             true
         } else {
             false
@@ -353,6 +548,16 @@ impl Group {
         }
     }
 
+    /// True if no focusable child follows the focused one (precedes it, going backwards)
+    fn at_focus_edge(&self, forward: bool) -> bool {
+        let focusable = |i: &usize| self.children[*i].can_focus();
+        if forward {
+            !(self.focused + 1..self.children.len()).any(|i| focusable(&i))
+        } else {
+            !(0..self.focused.min(self.children.len())).any(|i| focusable(&i))
+        }
+    }
+
     pub fn select_next(&mut self) {
         if self.children.is_empty() {
             return;
@@ -456,6 +661,10 @@ impl View for Group {
             }
         }
 
+        if let Some(ref frame) = self.frame {
+            self.draw_frame(terminal, frame);
+        }
+
         // Push clipping region for this group's bounds
         // Expand by 1 on all sides to allow children (like scrollbars) to overlap with parent's frame
         let mut clip_bounds = self.bounds;
@@ -464,13 +673,20 @@ impl View for Group {
 
         // Only draw children that intersect with this group's bounds
         // The clipping region ensures children can't render outside parent boundaries
-        for child in &mut self.children {
+        let obscured = self.obscured_children();
+        for (child, hidden) in self.children.iter_mut().zip(obscured) {
             let child_bounds = child.bounds();
-            if self.bounds.intersects(&child_bounds) {
+            if self.bounds.intersects(&child_bounds) && !hidden {
                 child.draw(terminal);
             }
         }
 
+        // A focused child tracking the mouse may have a popup open (combo box list):
+        // draw it again so the popup stays above the children after it
+        if self.focused + 1 < self.children.len() && self.children[self.focused].has_mouse_capture() {
+            self.children[self.focused].draw(terminal);
+        }
+
         // Pop clipping region
         terminal.pop_clip();
     }
@@ -481,37 +697,46 @@ impl View for Group {
         // Mouse events: positional events (no three-phase processing)
         // Search in REVERSE order (top-most child first) - matches Borland's z-order
         // Matches Borland: TGroup::handleEvent() processes mouse events from front to back
-        if event.what == EventType::MouseDown || event.what == EventType::MouseMove || event.what == EventType::MouseUp {
+        // The wheel goes to the view under the pointer, focused or not
+        if matches!(
+            event.what,
+            EventType::MouseDown
+                | EventType::MouseMove
+                | EventType::MouseUp
+                | EventType::MouseAuto
+                | EventType::MouseWheelUp
+                | EventType::MouseWheelDown
+        ) {
             let mouse_pos = event.mouse.pos;
 
-            // For MouseMove and MouseUp, check if the focused child is dragging or resizing
-            // If so, send the event to it even if mouse is outside its bounds
-            // This allows dragging and resizing beyond window boundaries (matches Borland behavior)
-            if (event.what == EventType::MouseMove || event.what == EventType::MouseUp) && self.focused < self.children.len() {
-                // Check if focused child is in dragging or resizing state
-                let child_state = self.children[self.focused].state();
-                if (child_state & (crate::core::state::SF_DRAGGING | crate::core::state::SF_RESIZING)) != 0 {
-                    self.children[self.focused].handle_event(event);
+            // Check if the focused child has captured the mouse (dragging, resizing, pressed
+            // button, held scroll arrow, open drop-down list). If so, send the event to it even
+            // if the mouse is outside its bounds (matches Borland behavior)
+            let mut captured = false;
+            if self.focused < self.children.len()
+                && self.children[self.focused].has_mouse_capture()
+            {
+                self.children[self.focused].handle_event(event);
+                // A command (e.g. button released) goes through command processing below
+                if event.what != EventType::Command {
                     return;
                 }
+                captured = true;
             }
 
             // First pass: find which child contains the mouse (search in reverse z-order)
-            let mut clicked_child_index: Option<usize> = None;
-            for i in (0..self.children.len()).rev() {
-                let child_bounds = self.children[i].bounds();
-                if child_bounds.contains(mouse_pos) {
-                    clicked_child_index = Some(i);
-                    break;
-                }
-            }
+            let clicked_child_index = if captured {
+                None
+            } else {
+                (0..self.children.len()).rev().find(|&i| self.children[i].bounds().contains(mouse_pos))
+            };
 
             // If a child was clicked, handle focus and events
             if let Some(i) = clicked_child_index {
                 if event.what == EventType::MouseDown {
                     // Check if this is a label with a link (Borland: TLabel::focusLink)
                     // If so, focus the linked control instead of the label
-                    if let Some(link_id) = self.children[i].label_link() {
+                    if let Some(link_id) = self.children[i].link_id() {
                         // Find the child with the matching ViewId
                         if let Some(link_index) = self.view_ids.iter().position(|&id| id == link_id) {
                             if self.children[link_index].can_focus() {
@@ -532,6 +757,7 @@ impl View for Group {
 
                 // Second pass: handle the event
                 self.children[i].handle_event(event);
+                self.handle_radio_selected(i, event);
 
                 // IMPORTANT: If the child converted the event to Broadcast (e.g., calculator buttons),
                 // we need to handle that broadcast now (matches Borland's putEvent behavior)
@@ -575,17 +801,26 @@ impl View for Group {
             // Give focused view a chance if event wasn't handled
             if event.what != EventType::Nothing && self.focused < self.children.len() {
                 self.children[self.focused].handle_event(event);
+                self.handle_radio_selected(self.focused, event);
             }
 
             // Phase 3: PostProcess
             // Views with OF_POST_PROCESS get last chance (e.g., status line, buttons)
             if event.what != EventType::Nothing {
-                for child in &mut self.children {
+                for i in 0..self.children.len() {
                     if event.what == EventType::Nothing {
                         break; // Event was handled
                     }
-                    if (child.options() & OF_POST_PROCESS) != 0 {
-                        child.handle_event(event);
+                    if (self.children[i].options() & OF_POST_PROCESS) != 0 {
+                        self.children[i].handle_event(event);
+
+                        // A label matched its hotkey: focus its linked sibling by id
+                        if event.what == EventType::Broadcast && event.command == CM_FOCUS_LINK {
+                            if let Some(link_id) = self.children[i].link_id() {
+                                self.focus_by_view_id(link_id);
+                            }
+                            event.clear();
+                        }
                     }
                 }
 
@@ -601,6 +836,11 @@ impl View for Group {
             // Handle Tab key for focus navigation (after three-phase processing)
             // Only handle if event wasn't consumed by any child
             if event.what == EventType::Keyboard {
+                // A group nested in another one hands Tab to its owner at its first and
+                // last control, so the focus can move on to the controls around it
+                if self.is_focused() && matches!(event.key_code, KB_TAB | KB_SHIFT_TAB) && self.at_focus_edge(event.key_code == KB_TAB) {
+                    return;
+                }
                 if event.key_code == KB_TAB {
                     self.select_next();
                     event.clear();
@@ -686,6 +926,72 @@ impl View for Group {
         self.owner
     }
 
+    fn get_owner_type(&self) -> OwnerType {
+        self.owner_type
+    }
+
+    /// Sets the palette context and passes it on to all children
+    fn set_owner_type(&mut self, owner_type: OwnerType) {
+        self.owner_type = owner_type;
+        for child in &mut self.children {
+            child.set_owner_type(owner_type);
+        }
+    }
+
+    fn has_mouse_capture(&self) -> bool {
+        self.focused < self.children.len() && self.children[self.focused].has_mouse_capture()
+    }
+
+    /// A group nested in another one (a framed box of controls) takes part in
+    /// its owner's focus chain when it holds a focusable control
+    fn can_focus(&self) -> bool {
+        self.children.iter().any(|child| child.can_focus())
+    }
+
+    fn set_focus(&mut self, focused: bool) {
+        // Entering the group (Tab or a click) starts at its first control;
+        // a click then moves the focus on to the control under the mouse
+        self.set_state_flag(SF_FOCUSED, focused);
+        self.clear_all_focus();
+        if focused {
+            self.set_initial_focus();
+        }
+    }
+
+    fn on_modal_enter(&mut self) {
+        for child in &mut self.children {
+            child.on_modal_enter();
+        }
+    }
+
+    fn on_modal_leave(&mut self) {
+        for child in &mut self.children {
+            child.on_modal_leave();
+        }
+    }
+
+    fn on_activate(&mut self, active: bool) {
+        for child in &mut self.children {
+            child.on_activate(active);
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn state(&self) -> StateFlags {
+        self.state
+    }
+
+    fn set_state(&mut self, state: StateFlags) {
+        self.state = state;
+    }
+
     fn get_palette(&self) -> Option<crate::core::palette::Palette> {
         // TGroup has no palette (returns empty palette in Borland)
         // Returning None achieves the same effect - skip to parent's palette
@@ -697,11 +1003,12 @@ impl View for Group {
 pub struct GroupBuilder {
     bounds: Option<Rect>,
     background: Option<Attr>,
+    frame: Option<GroupFrame>,
 }
 
 impl GroupBuilder {
     pub fn new() -> Self {
-        Self { bounds: None, background: None }
+        Self { bounds: None, background: None, frame: None }
     }
 
     #[must_use]
@@ -716,13 +1023,22 @@ impl GroupBuilder {
         self
     }
 
+    /// Draw a frame with an optional title (see [`Group::set_frame()`])
+    #[must_use]
+    pub fn frame(mut self, style: FrameStyle, title: Option<&str>) -> Self {
+        self.frame = Some(GroupFrame { style, title: title.map(str::to_string) });
+        self
+    }
+
     pub fn build(self) -> Group {
         let bounds = self.bounds.expect("Group bounds must be set");
-        if let Some(bg) = self.background {
+        let mut group = if let Some(bg) = self.background {
             Group::with_background(bounds, bg)
         } else {
             Group::new(bounds)
-        }
+        };
+        group.frame = self.frame;
+        group
     }
 
     pub fn build_boxed(self) -> Box<Group> {
@@ -980,4 +1296,140 @@ mod tests {
         assert!(group.child_by_id(id3).is_some());
         assert!(group.child_by_id(new_id).is_some());
     }
+
+    #[test]
+    fn test_add_sets_owner_and_owner_type() {
+        use crate::views::button::Button;
+        use crate::views::input_line::InputLine;
+        use std::rc::Rc;
+        use std::cell::RefCell;
+
+        let mut group = Group::new(Rect::new(0, 0, 40, 10));
+        group.set_owner_type(OwnerType::Window);
+        let button_id = group.add(Box::new(Button::new(Rect::new(1, 1, 11, 3), "OK", 0, false)));
+        let input_id = group.add(Box::new(InputLine::new(Rect::new(1, 4, 21, 5), 20, Rc::new(RefCell::new(String::new())))));
+
+        let group_ptr = &group as *const Group as *const ();
+        for id in [button_id, input_id] {
+            let child = group.child_by_id(id).unwrap();
+            let owner = child.get_owner().expect("owner set by add");
+            assert_eq!(owner as *const (), group_ptr);
+            assert_eq!(child.get_owner_type(), OwnerType::Window);
+        }
+
+        // Changing the context later reaches existing children
+        group.set_owner_type(OwnerType::Dialog);
+        assert_eq!(group.child_by_id(button_id).unwrap().get_owner_type(), OwnerType::Dialog);
+    }
+
+    #[test]
+    fn test_plain_group_keeps_child_owner_type() {
+        use crate::views::button::Button;
+
+        let mut group = Group::new(Rect::new(0, 0, 40, 10));
+        let id = group.add(Box::new(Button::new(Rect::new(1, 1, 11, 3), "OK", 0, false)));
+        assert_eq!(group.child_by_id(id).unwrap().get_owner_type(), OwnerType::Dialog);
+    }
+
+    /// Group with two input lines and a "~N~ame" label linked to the second one
+    fn group_with_linked_label() -> (Group, ViewId, ViewId, ViewId) {
+        use crate::views::input_line::InputLine;
+        use crate::views::label::Label;
+        use std::rc::Rc;
+        use std::cell::RefCell;
+
+        let mut group = Group::new(Rect::new(0, 0, 40, 10));
+        let data = || Rc::new(RefCell::new(String::new()));
+        let first = group.add(Box::new(InputLine::new(Rect::new(10, 1, 30, 2), 20, data())));
+        let second = group.add(Box::new(InputLine::new(Rect::new(10, 3, 30, 4), 20, data())));
+        let mut label = Label::new(Rect::new(1, 3, 9, 4), "~N~ame:");
+        label.set_link_id(second);
+        let label_id = group.add(Box::new(label));
+        group.set_initial_focus();
+        (group, first, second, label_id)
+    }
+
+    #[test]
+    fn test_label_hotkey_link_survives_reordering() {
+        use crate::core::event::KB_ALT_N;
+
+        let (mut group, first, second, _) = group_with_linked_label();
+        assert!(group.child_by_id(first).unwrap().is_focused());
+
+        // Reorder: the linked control moves to the front, then to the back
+        group.bring_to_front(1);
+        group.send_to_back(group.len() - 1);
+
+        let mut event = Event::keyboard(KB_ALT_N);
+        group.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Nothing);
+        assert!(group.child_by_id(second).unwrap().is_focused());
+        assert!(!group.child_by_id(first).unwrap().is_focused());
+    }
+
+    #[test]
+    fn test_label_click_link_survives_moving_group() {
+        use crate::core::geometry::Point;
+
+        let (group, first, second, label_id) = group_with_linked_label();
+        // Moving the group invalidates any raw owner pointers; ids still resolve
+        let mut group = Box::new(group);
+        group.bring_to_front(0);
+
+        let label_pos = group.child_by_id(label_id).unwrap().bounds().a;
+        let mut event = Event::mouse(EventType::MouseDown, Point::new(label_pos.x, label_pos.y), 1, false);
+        group.handle_event(&mut event);
+        assert!(group.child_by_id(second).unwrap().is_focused());
+        assert!(!group.child_by_id(first).unwrap().is_focused());
+    }
+
+    #[test]
+    fn test_frame_insets_children_and_draws_title() {
+        use crate::terminal::TestTerminal;
+        use crate::views::static_text::StaticText;
+
+        let mut group = Group::new(Rect::new(0, 0, 20, 4));
+        group.add(Box::new(StaticText::new(Rect::new(0, 0, 5, 1), "early")));
+        group.set_frame(FrameStyle::Single, Some("Options"));
+        group.add(Box::new(StaticText::new(Rect::new(6, 0, 11, 1), "later")));
+        assert_eq!(group.content_rect(), Rect::new(0, 0, 18, 2));
+        assert_eq!(group.child_at(0).bounds(), Rect::new(1, 1, 6, 2), "re-offset when framed");
+        assert_eq!(group.child_at(1).bounds(), Rect::new(7, 1, 12, 2));
+
+        let mut terminal = TestTerminal::new(20, 4);
+        group.draw(&mut terminal);
+        assert_eq!(terminal.line_text(0), "┌─ Options ────────┐");
+        assert_eq!(terminal.line_text(1), "│early later       │");
+        assert_eq!(terminal.line_text(3), "└──────────────────┘");
+
+        group.clear_frame();
+        assert_eq!(group.child_at(0).bounds(), Rect::new(0, 0, 5, 1));
+    }
+
+    #[test]
+    fn test_tab_leaves_nested_group_at_its_edges() {
+        use crate::core::event::KB_TAB;
+        use crate::views::button::Button;
+
+        let mut outer = Group::new(Rect::new(0, 0, 40, 12));
+        outer.add(Box::new(Button::new(Rect::new(0, 0, 10, 2), "One", 101, false)));
+        let mut inner = GroupBuilder::new().bounds(Rect::new(0, 2, 30, 8)).frame(FrameStyle::Single, None).build();
+        inner.add(Box::new(Button::new(Rect::new(0, 0, 10, 2), "Two", 102, false)));
+        inner.add(Box::new(Button::new(Rect::new(0, 2, 10, 4), "Three", 103, false)));
+        outer.add(Box::new(inner));
+        outer.add(Box::new(Button::new(Rect::new(0, 9, 10, 11), "Four", 104, false)));
+        outer.set_initial_focus();
+
+        // The button focused in the outer group, or in the nested group when it has the focus
+        let focused_command = |outer: &Group| {
+            let focused = outer.focused_child()?;
+            focused.button_command().or_else(|| focused.as_any().downcast_ref::<Group>()?.focused_child()?.button_command())
+        };
+        let mut focus_path = Vec::new();
+        for _ in 0..5 {
+            outer.handle_event(&mut Event::keyboard(KB_TAB));
+            focus_path.push(focused_command(&outer).unwrap());
+        }
+        assert_eq!(focus_path, vec![102, 103, 104, 101, 102]);
+    }
 }
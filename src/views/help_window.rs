@@ -43,6 +43,10 @@ impl View for SharedHelpViewer {
         self.0.borrow().can_focus()
     }
 
+    fn is_opaque(&self) -> bool {
+        self.0.borrow().is_opaque()
+    }
+
     fn state(&self) -> StateFlags {
         self.0.borrow().state()
     }
@@ -264,6 +268,10 @@ impl View for HelpWindow {
         true
     }
 
+    fn is_opaque(&self) -> bool {
+        self.window.is_opaque()
+    }
+
     fn state(&self) -> StateFlags {
         self.window.state()
     }
@@ -276,6 +284,18 @@ impl View for HelpWindow {
     fn get_palette(&self) -> Option<crate::core::palette::Palette> {
         self.window.get_palette()
     }
+
+    fn on_modal_enter(&mut self) {
+        self.window.on_modal_enter();
+    }
+
+    fn on_modal_leave(&mut self) {
+        self.window.on_modal_leave();
+    }
+
+    fn on_activate(&mut self, active: bool) {
+        self.window.on_activate(active);
+    }
 }
 
 #[cfg(test)]
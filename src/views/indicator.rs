@@ -1,6 +1,11 @@
 // (C) 2025 - Enzo Lombardi
 
-//! Indicator view - visual indicator for displaying scroll position or progress.
+//! Indicator view - shows the cursor position and modified state of an editor.
+//!
+//! Matches Borland: TIndicator. The text is `" line:col "` with the colon
+//! kept at column 8, and a `*` in column 0 while the document is modified.
+//! [`Indicator::set_format()`] changes the text; the colon alignment is kept
+//! whenever the custom format contains one.
 
 use crate::core::geometry::{Point, Rect};
 use crate::core::event::Event;
@@ -8,12 +13,19 @@ use crate::core::draw::DrawBuffer;
 use crate::terminal::Terminal;
 use super::view::{View, write_line_to_terminal};
 
-/// Indicator displays window size or cursor position,
+/// Default indicator text (Borland's `" %d:%d "`)
+pub const DEFAULT_INDICATOR_FORMAT: &str = " {line}:{col} ";
+
+/// Column the colon of the indicator text is aligned to (matching Borland)
+const COLON_COLUMN: usize = 8;
+
+/// Indicator displays the 1-based cursor position,
 /// typically shown in the bottom-left of an editor window.
 pub struct Indicator {
     bounds: Rect,
-    location: Point,  // Width x Height for window size display
+    location: Point,  // x = column, y = line (1-based)
     modified: bool,   // Has the document been modified?
+    format: String,
     owner: Option<*const dyn View>,
     owner_type: super::view::OwnerType,
 }
@@ -24,6 +36,7 @@ impl Indicator {
             bounds,
             location: Point::new(1, 1),
             modified: false,
+            format: DEFAULT_INDICATOR_FORMAT.to_string(),
             owner: None,
             owner_type: super::view::OwnerType::None,
         }
@@ -33,6 +46,34 @@ impl Indicator {
         self.location = location;
         self.modified = modified;
     }
+
+    pub fn location(&self) -> Point {
+        self.location
+    }
+
+    pub fn is_modified(&self) -> bool {
+        self.modified
+    }
+
+    /// Set the indicator text format
+    ///
+    /// `{line}` and `{col}` are replaced by the 1-based cursor position and
+    /// `{modified}` by `*` or nothing. Default: [`DEFAULT_INDICATOR_FORMAT`].
+    pub fn set_format(&mut self, format: &str) {
+        self.format = format.to_string();
+    }
+
+    pub fn format(&self) -> &str {
+        &self.format
+    }
+
+    /// The indicator text for the current value, as drawn
+    pub fn text(&self) -> String {
+        self.format
+            .replace("{line}", &self.location.y.to_string())
+            .replace("{col}", &self.location.x.to_string())
+            .replace("{modified}", if self.modified { "*" } else { "" })
+    }
 }
 
 impl View for Indicator {
@@ -64,19 +105,16 @@ impl View for Indicator {
             buf.move_char(0, '*', color, 1);
         }
 
-        // Format: " WxH " (width x height) centered
-        let text = format!(" {}x{} ", self.location.x, self.location.y);
-
-        // Center the text around the 'x' character
-        if let Some(x_pos) = text.find('x') {
-            let start_pos = (8_i32 - x_pos as i32).max(1) as usize;
-            let start_pos = start_pos.min(width.saturating_sub(text.len()));
-            buf.move_str(start_pos, &text, color);
-        } else {
-            // Fallback: center normally if no 'x' found
-            let start_pos = (width / 2).saturating_sub(text.len() / 2);
-            buf.move_str(start_pos, &text, color);
-        }
+        // Keep the colon at a fixed column so the text doesn't jump as the
+        // line number grows (matching Borland); column 0 stays for the star
+        let text = self.text();
+        let text_len = text.chars().count();
+        let start_pos = match text.chars().position(|c| c == ':') {
+            Some(colon) => COLON_COLUMN.saturating_sub(colon).max(1),
+            None => 1,
+        };
+        let start_pos = start_pos.min(width.saturating_sub(text_len));
+        buf.move_str_clipped(start_pos, &text, color, width - start_pos);
 
         write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y, &buf);
     }
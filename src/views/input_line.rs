@@ -1,21 +1,75 @@
 // (C) 2025 - Enzo Lombardi
 
 //! InputLine view - single-line text input with editing and history support.
-
+//!
+//! While focused, the input line consumes (clears) every key it owns, even
+//! when the key has nothing to do, so no other view acts on it:
+//! - Left/Right, Home/End, with or without Shift, and Backspace/Del, also at
+//!   the ends of the text
+//! - PgUp/PgDn and Ins, which a single line has no use for
+//! - printable characters, ASCII or not ('é', '日'), also when the field is
+//!   full or a validator rejects them
+//!
+//! Keys that belong to the dialog pass through: Up/Down (the history list
+//! and a combo box's drop-down open on them), Tab, Esc and Enter (unless
+//! the field keeps Enter, see [`EnterBehavior`]).
+
+use super::text_buffer::{TextBuffer, TextData};
 use super::validator::ValidatorRef;
-use super::view::{write_line_to_terminal, View};
+use super::view::{write_line_to_terminal, DataValue, EnterBehavior, View};
 use crate::core::clipboard;
-use crate::core::draw::DrawBuffer;
+use crate::core::draw::{char_width, str_width, DrawBuffer};
 use crate::core::event::{
-    Event, EventType, KB_BACKSPACE, KB_DEL, KB_END, KB_ENTER, KB_HOME, KB_LEFT, KB_RIGHT,
+    Event, EventType, KeyCode, MB_LEFT_BUTTON, KB_BACKSPACE, KB_DEL, KB_END, KB_ENTER, KB_HOME, KB_INS, KB_LEFT,
+    KB_PGDN, KB_PGUP, KB_RIGHT, KB_SHIFT_END, KB_SHIFT_HOME, KB_SHIFT_LEFT, KB_SHIFT_RIGHT,
 };
+use crossterm::event::KeyModifiers;
 use crate::core::geometry::Rect;
 use crate::core::palette::{INPUT_ARROWS, INPUT_FOCUSED, INPUT_NORMAL, INPUT_SELECTED};
 use crate::core::state::StateFlags;
-use crate::terminal::Terminal;
+use crate::terminal::{CursorStyle, Terminal};
 use std::cell::RefCell;
 use std::rc::Rc;
 
+/// Byte offset of the character before `pos` (positions are byte offsets into the text)
+fn prev_char_boundary(text: &str, pos: usize) -> usize {
+    text[..pos].chars().next_back().map_or(0, |ch| pos - ch.len_utf8())
+}
+
+/// Byte offset of the character after `pos`
+fn next_char_boundary(text: &str, pos: usize) -> usize {
+    text[pos..].chars().next().map_or(pos, |ch| pos + ch.len_utf8())
+}
+
+/// Byte offset just past the characters from `start` that fit in `cols` columns
+fn fit_columns(text: &str, start: usize, cols: usize) -> usize {
+    let mut used = 0;
+    let mut end = start;
+    for ch in text[start..].chars() {
+        used += char_width(ch);
+        if used > cols {
+            break;
+        }
+        end += ch.len_utf8();
+    }
+    end
+}
+
+/// Key code of `event`, with Shift+arrow/Home/End reported as the KB_SHIFT_* codes
+/// (the terminal sends those as the plain key with the Shift modifier)
+fn selection_key_code(event: &Event) -> KeyCode {
+    if !event.key_modifiers.contains(KeyModifiers::SHIFT) {
+        return event.key_code;
+    }
+    match event.key_code {
+        KB_LEFT => KB_SHIFT_LEFT,
+        KB_RIGHT => KB_SHIFT_RIGHT,
+        KB_HOME => KB_SHIFT_HOME,
+        KB_END => KB_SHIFT_END,
+        key_code => key_code,
+    }
+}
+
 // Control key codes
 const KB_CTRL_A: u16 = 0x0001; // Ctrl+A - Select All
 const KB_CTRL_C: u16 = 0x0003; // Ctrl+C - Copy
@@ -24,7 +78,7 @@ const KB_CTRL_X: u16 = 0x0018; // Ctrl+X - Cut
 
 pub struct InputLine {
     bounds: Rect,
-    data: Rc<RefCell<String>>,
+    data: TextData,
     cursor_pos: usize,
     max_length: usize,
     sel_start: usize,                // Selection start position
@@ -34,10 +88,26 @@ pub struct InputLine {
     state: StateFlags,               // View state flags (including SF_FOCUSED)
     owner: Option<*const dyn View>,
     owner_type: super::view::OwnerType,
+    enter_behavior: EnterBehavior,
+    selecting: bool,                 // Left button held after a click: moves extend the selection
 }
 
 impl InputLine {
+    /// Create an InputLine editing a shared `Rc<RefCell<String>>`
+    ///
+    /// Kept for compatibility; prefer [`with_buffer()`](Self::with_buffer), whose
+    /// text can be read from any thread and cannot be left borrowed while the
+    /// field handles an event (see [`text_buffer`](super::text_buffer)).
     pub fn new(bounds: Rect, max_length: usize, data: Rc<RefCell<String>>) -> Self {
+        Self::with_data(bounds, max_length, TextData::Cell(data))
+    }
+
+    /// Create an InputLine editing a [`TextBuffer`]
+    pub fn with_buffer(bounds: Rect, max_length: usize, buffer: TextBuffer) -> Self {
+        Self::with_data(bounds, max_length, TextData::Buffer(buffer))
+    }
+
+    fn with_data(bounds: Rect, max_length: usize, data: TextData) -> Self {
         let cursor_pos = data.borrow().len();
         Self {
             bounds,
@@ -51,6 +121,8 @@ impl InputLine {
             state: 0,
             owner: None,
             owner_type: super::view::OwnerType::Dialog, // InputLine defaults to Dialog context
+            enter_behavior: EnterBehavior::Submit,
+            selecting: false,
         }
     }
 
@@ -67,6 +139,27 @@ impl InputLine {
         input_line
     }
 
+    /// Choose whether Enter presses the dialog's default button (default)
+    /// or is swallowed by the field
+    pub fn set_enter_behavior(&mut self, behavior: EnterBehavior) {
+        self.enter_behavior = behavior;
+    }
+
+    /// The buffer holding the text, unless the field was created with an `Rc<RefCell<String>>`
+    pub fn text_buffer(&self) -> Option<TextBuffer> {
+        self.data.buffer().cloned()
+    }
+
+    /// Name this field in the panic raised when its [`TextBuffer`] is used
+    /// re-entrantly, e.g. `"email"`; no effect with an `Rc<RefCell<String>>`
+    pub fn set_field_id(&mut self, field_id: &str) {
+        self.data.set_field_id(field_id);
+    }
+
+    pub fn enter_behavior(&self) -> EnterBehavior {
+        self.enter_behavior
+    }
+
     /// Set the validator for this InputLine
     pub fn set_validator(&mut self, validator: ValidatorRef) {
         self.validator = Some(validator);
@@ -137,17 +230,88 @@ impl InputLine {
         self.sel_end = 0;
     }
 
+    /// Move the cursor to `pos`, extending the selection from where it started
+    /// (the cursor position when there is no selection yet)
+    fn extend_selection(&mut self, pos: usize) {
+        if !self.has_selection() {
+            self.sel_start = self.cursor_pos;
+        }
+        self.cursor_pos = pos;
+        self.sel_end = pos;
+        self.make_cursor_visible();
+    }
+
+    /// Text position under screen column `x`
+    /// Columns left or right of the field give the position just beyond the
+    /// visible text, so dragging past an edge scrolls one character at a time.
+    fn pos_at_column(&self, x: i16) -> usize {
+        let text = self.data.borrow();
+        let first = self.first_pos.min(text.len());
+        let col = x - self.bounds.a.x;
+        if col < 0 {
+            return prev_char_boundary(&text, first);
+        }
+
+        let width = self.bounds.width_clamped() as usize;
+        let col = col as usize;
+        if col >= width {
+            return next_char_boundary(&text, fit_columns(&text, first, width));
+        }
+        // Clicking the right half of a wide character lands before it
+        fit_columns(&text, first, col)
+    }
+
+    /// Insert a bracketed paste in one step
+    /// Single-line field: the text is cut at the first line break and to
+    /// `max_length`; the validator sees the whole result, so an invalid paste
+    /// leaves the field unchanged.
+    fn insert_paste(&mut self, pasted: &str) {
+        let line = pasted.split(['\n', '\r']).next().unwrap_or("");
+
+        let mut test_text = self.data.borrow().clone();
+        let (mut start, mut end) = (self.cursor_pos, self.cursor_pos);
+        if self.has_selection() {
+            start = self.sel_start.min(self.sel_end);
+            end = self.sel_start.max(self.sel_end);
+        }
+        test_text.replace_range(start..end, "");
+
+        let mut len = self.max_length.saturating_sub(test_text.len()).min(line.len());
+        while !line.is_char_boundary(len) {
+            len -= 1;
+        }
+        if len == 0 {
+            return;
+        }
+        test_text.insert_str(start, &line[..len]);
+
+        if let Some(ref validator) = self.validator {
+            if !validator.borrow().is_valid_input(&test_text, true) {
+                return;
+            }
+        }
+
+        *self.data.borrow_mut() = test_text;
+        self.cursor_pos = start + len;
+        self.sel_start = 0;
+        self.sel_end = 0;
+        self.make_cursor_visible();
+    }
+
     /// Ensure cursor is visible by adjusting first_pos
     fn make_cursor_visible(&mut self) {
         let width = self.bounds.width_clamped() as usize;
+        let text = self.data.borrow();
 
         // If cursor is before the visible area
         if self.cursor_pos < self.first_pos {
             self.first_pos = self.cursor_pos;
         }
-        // If cursor is after the visible area
-        else if self.cursor_pos >= self.first_pos + width {
-            self.first_pos = self.cursor_pos - width + 1;
+        // If cursor is after the visible area (measured in screen columns)
+        else {
+            while self.first_pos < self.cursor_pos && str_width(&text[self.first_pos..self.cursor_pos]) >= width {
+                self.first_pos = next_char_boundary(&text, self.first_pos);
+            }
         }
     }
 }
@@ -189,9 +353,9 @@ impl View for InputLine {
         let text = self.data.borrow();
         let text_len = text.len();
 
-        // Calculate visible range
-        let visible_start = self.first_pos;
-        let visible_end = (visible_start + width).min(text_len);
+        // Calculate visible range (byte offsets of the characters that fit)
+        let visible_start = self.first_pos.min(text_len);
+        let visible_end = fit_columns(&text, visible_start, width);
 
         // Draw text
         if visible_start < text_len {
@@ -199,19 +363,12 @@ impl View for InputLine {
 
             // If there's a selection, draw it with selection color
             if self.has_selection() {
-                let sel_start = self.sel_start.min(self.sel_end);
-                let sel_end = self.sel_start.max(self.sel_end);
-
-                // Draw characters one by one to handle selection highlighting
-                for (i, ch) in visible_text.chars().enumerate() {
-                    let pos = visible_start + i;
-                    let char_attr = if pos >= sel_start && pos < sel_end {
-                        sel_attr
-                    } else {
-                        attr
-                    };
-                    buf.move_char(i, ch, char_attr, 1);
-                }
+                // Selection bounds relative to the visible text
+                let sel_start = self.sel_start.min(self.sel_end).clamp(visible_start, visible_end) - visible_start;
+                let sel_end = self.sel_start.max(self.sel_end).clamp(visible_start, visible_end) - visible_start;
+                let (before, rest) = visible_text.split_at(sel_start);
+                let (selected, after) = rest.split_at(sel_end - sel_start);
+                buf.move_styled(0, &[(before, attr), (selected, sel_attr), (after, attr)]);
             } else {
                 buf.move_str(0, visible_text, attr);
             }
@@ -257,51 +414,90 @@ impl View for InputLine {
             return;
         }
 
+        if event.what == EventType::Paste {
+            self.insert_paste(&event.paste_text().unwrap_or_default());
+            event.clear();
+            return;
+        }
+
+        // Click places the cursor, dragging with the left button selects
+        match event.what {
+            EventType::MouseDown if self.bounds.contains(event.mouse.pos) => {
+                self.cursor_pos = self.pos_at_column(event.mouse.pos.x);
+                self.sel_start = 0;
+                self.sel_end = 0;
+                self.selecting = event.mouse.buttons & MB_LEFT_BUTTON != 0;
+                self.make_cursor_visible();
+                event.clear();
+                return;
+            }
+            EventType::MouseMove if self.selecting => {
+                if event.mouse.buttons & MB_LEFT_BUTTON == 0 {
+                    self.selecting = false;
+                } else {
+                    let pos = self.pos_at_column(event.mouse.pos.x);
+                    self.extend_selection(pos);
+                }
+                event.clear();
+                return;
+            }
+            EventType::MouseUp if self.selecting => {
+                self.selecting = false;
+                event.clear();
+                return;
+            }
+            _ => {}
+        }
+
         if event.what == EventType::Keyboard {
-            match event.key_code {
+            match selection_key_code(event) {
+                // Editing and cursor keys are consumed even when they can't move or delete
                 KB_BACKSPACE => {
                     if self.has_selection() {
                         self.delete_selection();
                         self.make_cursor_visible();
-                        event.clear();
                     } else if self.cursor_pos > 0 {
                         {
                             let mut text = self.data.borrow_mut();
-                            text.remove(self.cursor_pos - 1);
+                            let prev = prev_char_boundary(&text, self.cursor_pos);
+                            text.replace_range(prev..self.cursor_pos, "");
+                            self.cursor_pos = prev;
                         }
-                        self.cursor_pos -= 1;
                         self.make_cursor_visible();
-                        event.clear();
                     }
+                    event.clear();
                 }
                 KB_DEL => {
                     if self.has_selection() {
                         self.delete_selection();
                         self.make_cursor_visible();
-                        event.clear();
                     } else if self.cursor_pos < self.data.borrow().len() {
                         let mut text = self.data.borrow_mut();
                         text.remove(self.cursor_pos);
-                        event.clear();
                     }
+                    event.clear();
                 }
                 KB_LEFT => {
                     if self.cursor_pos > 0 {
-                        self.cursor_pos -= 1;
-                        self.sel_start = 0;
-                        self.sel_end = 0;
+                        self.cursor_pos = prev_char_boundary(&self.data.borrow(), self.cursor_pos);
                         self.make_cursor_visible();
-                        event.clear();
                     }
+                    self.sel_start = 0;
+                    self.sel_end = 0;
+                    event.clear();
                 }
                 KB_RIGHT => {
                     if self.cursor_pos < self.data.borrow().len() {
-                        self.cursor_pos += 1;
-                        self.sel_start = 0;
-                        self.sel_end = 0;
+                        self.cursor_pos = next_char_boundary(&self.data.borrow(), self.cursor_pos);
                         self.make_cursor_visible();
-                        event.clear();
                     }
+                    self.sel_start = 0;
+                    self.sel_end = 0;
+                    event.clear();
+                }
+                // Nothing to page or toggle in one line, but a view behind must not act on them
+                KB_PGUP | KB_PGDN | KB_INS => {
+                    event.clear();
                 }
                 KB_HOME => {
                     self.cursor_pos = 0;
@@ -317,9 +513,33 @@ impl View for InputLine {
                     self.make_cursor_visible();
                     event.clear();
                 }
+                KB_SHIFT_LEFT => {
+                    let pos = prev_char_boundary(&self.data.borrow(), self.cursor_pos);
+                    self.extend_selection(pos);
+                    event.clear();
+                }
+                KB_SHIFT_RIGHT => {
+                    let pos = next_char_boundary(&self.data.borrow(), self.cursor_pos);
+                    self.extend_selection(pos);
+                    event.clear();
+                }
+                KB_SHIFT_HOME => {
+                    self.extend_selection(0);
+                    event.clear();
+                }
+                KB_SHIFT_END => {
+                    let len = self.data.borrow().len();
+                    self.extend_selection(len);
+                    event.clear();
+                }
                 KB_ENTER => {
-                    // Don't handle Enter - let dialog handle it for default button
-                    // Just pass through without clearing
+                    // Leave Enter to the dialog's default button, unless the field was
+                    // set to keep it. Pasted newlines never get here: the terminal
+                    // delivers a paste as one Paste event, which insert_paste() cuts
+                    // at the first line break
+                    if self.enter_behavior == EnterBehavior::Consume {
+                        event.clear();
+                    }
                 }
                 KB_CTRL_A => {
                     self.select_all();
@@ -343,7 +563,11 @@ impl View for InputLine {
                 }
                 KB_CTRL_V => {
                     // Paste from clipboard
-                    let clipboard_text = clipboard::get_clipboard();
+                    // Single-line field: strip line breaks from the pasted text
+                    let clipboard_text: String = clipboard::get_clipboard()
+                        .chars()
+                        .filter(|&c| c != '\n' && c != '\r')
+                        .collect();
                     if !clipboard_text.is_empty() {
                         // Delete selection if any
                         if self.has_selection() {
@@ -354,11 +578,11 @@ impl View for InputLine {
                         {
                             let mut text = self.data.borrow_mut();
                             let remaining_space = self.max_length.saturating_sub(text.len());
-                            let insert_text = if clipboard_text.len() <= remaining_space {
-                                clipboard_text.as_str()
-                            } else {
-                                &clipboard_text[..remaining_space]
-                            };
+                            let mut end = remaining_space.min(clipboard_text.len());
+                            while !clipboard_text.is_char_boundary(end) {
+                                end -= 1;
+                            }
+                            let insert_text = &clipboard_text[..end];
 
                             text.insert_str(self.cursor_pos, insert_text);
                             self.cursor_pos += insert_text.len();
@@ -367,18 +591,16 @@ impl View for InputLine {
                     }
                     event.clear();
                 }
-                // Regular character input
+                // Regular character input, any printable character ('é', '日')
                 key_code => {
-                    if (32..127).contains(&key_code) {
+                    if let Some(ch) = crate::core::event::key_char(key_code) {
                         // Delete selection if any
                         if self.has_selection() {
                             self.delete_selection();
                         }
 
                         let text_len = self.data.borrow().len();
-                        if text_len < self.max_length {
-                            let ch = key_code as u8 as char;
-
+                        if text_len + ch.len_utf8() <= self.max_length {
                             // Check validator before inserting
                             // Matches Borland's TValidator::IsValidInput() pattern
                             if let Some(ref validator) = self.validator {
@@ -399,10 +621,11 @@ impl View for InputLine {
                                 let mut text = self.data.borrow_mut();
                                 text.insert(self.cursor_pos, ch);
                             }
-                            self.cursor_pos += 1;
+                            self.cursor_pos += ch.len_utf8();
                             self.make_cursor_visible();
-                            event.clear();
                         }
+                        // Consumed even when the field is full
+                        event.clear();
                     }
                 }
             }
@@ -413,6 +636,20 @@ impl View for InputLine {
         true
     }
 
+    fn has_mouse_capture(&self) -> bool {
+        self.selecting
+    }
+
+    fn get_data(&self) -> Option<DataValue> {
+        Some(DataValue::Text(self.get_text()))
+    }
+
+    fn set_data(&mut self, value: &DataValue) {
+        if let DataValue::Text(text) = value {
+            self.set_text(text.clone());
+        }
+    }
+
     // set_focus() now uses default implementation from View trait
     // which sets/clears SF_FOCUSED flag
 
@@ -427,10 +664,12 @@ impl View for InputLine {
     fn update_cursor(&self, terminal: &mut Terminal) {
         if self.is_focused() {
             // Calculate cursor position on screen
-            let cursor_x = self.bounds.a.x as usize + (self.cursor_pos - self.first_pos);
+            let text = self.data.borrow();
+            let cursor_x = self.bounds.a.x as usize + text.get(self.first_pos..self.cursor_pos).map_or(0, str_width);
             let cursor_y = self.bounds.a.y;
 
-            // Show cursor at the position
+            // Show cursor at the position, as a bar between characters
+            terminal.set_cursor_style(CursorStyle::Bar { blink: true });
             let _ = terminal.show_cursor(cursor_x as u16, cursor_y as u16);
         } else {
             // Explicitly hide cursor when not focused to prevent it from lingering
@@ -467,15 +706,16 @@ impl View for InputLine {
 ///
 /// ```ignore
 /// use turbo_vision::views::input_line::InputLineBuilder;
+/// use turbo_vision::views::text_buffer::TextBuffer;
 /// use turbo_vision::core::geometry::Rect;
 /// use std::rc::Rc;
 /// use std::cell::RefCell;
 ///
 /// // Create a basic input line
-/// let data = Rc::new(RefCell::new(String::new()));
+/// let name = TextBuffer::default();
 /// let input = InputLineBuilder::new()
 ///     .bounds(Rect::new(10, 5, 50, 6))
-///     .data(data.clone())
+///     .buffer(name.clone())
 ///     .max_length(30)
 ///     .build();
 ///
@@ -490,9 +730,11 @@ impl View for InputLine {
 /// ```
 pub struct InputLineBuilder {
     bounds: Option<Rect>,
-    data: Option<Rc<RefCell<String>>>,
+    data: Option<TextData>,
     max_length: usize,
     validator: Option<ValidatorRef>,
+    enter_behavior: EnterBehavior,
+    field_id: Option<String>,
 }
 
 impl InputLineBuilder {
@@ -503,6 +745,8 @@ impl InputLineBuilder {
             data: None,
             max_length: 255,
             validator: None,
+            enter_behavior: EnterBehavior::Submit,
+            field_id: None,
         }
     }
 
@@ -513,10 +757,19 @@ impl InputLineBuilder {
         self
     }
 
-    /// Sets the shared data reference (required).
+    /// Sets the shared data reference.
+    ///
+    /// Kept for compatibility; prefer [`buffer()`](Self::buffer).
     #[must_use]
     pub fn data(mut self, data: Rc<RefCell<String>>) -> Self {
-        self.data = Some(data);
+        self.data = Some(TextData::Cell(data));
+        self
+    }
+
+    /// Sets the buffer holding the text (default: a new empty [`TextBuffer`]).
+    #[must_use]
+    pub fn buffer(mut self, buffer: TextBuffer) -> Self {
+        self.data = Some(TextData::Buffer(buffer));
         self
     }
 
@@ -534,19 +787,40 @@ impl InputLineBuilder {
         self
     }
 
+    /// Sets what Enter does (default: press the dialog's default button).
+    #[must_use]
+    pub fn enter_behavior(mut self, behavior: EnterBehavior) -> Self {
+        self.enter_behavior = behavior;
+        self
+    }
+
+    /// Sets the name panic messages give the field (see [`InputLine::set_field_id()`]).
+    #[must_use]
+    pub fn field_id(mut self, field_id: &str) -> Self {
+        self.field_id = Some(field_id.to_string());
+        self
+    }
+
     /// Builds the InputLine.
     ///
+    /// Without [`data()`](Self::data) or [`buffer()`](Self::buffer) the text goes
+    /// into a new buffer, available from [`InputLine::text_buffer()`].
+    ///
     /// # Panics
     ///
-    /// Panics if required fields (bounds, data) are not set.
+    /// Panics if bounds are not set.
     pub fn build(self) -> InputLine {
         let bounds = self.bounds.expect("InputLine bounds must be set");
-        let data = self.data.expect("InputLine data must be set");
+        let data = self.data.unwrap_or_else(|| TextData::Buffer(TextBuffer::default()));
 
-        let mut input_line = InputLine::new(bounds, self.max_length, data);
+        let mut input_line = InputLine::with_data(bounds, self.max_length, data);
         if let Some(validator) = self.validator {
             input_line.validator = Some(validator);
         }
+        input_line.enter_behavior = self.enter_behavior;
+        if let Some(field_id) = &self.field_id {
+            input_line.set_field_id(field_id);
+        }
         input_line
     }
 
@@ -561,3 +835,286 @@ impl Default for InputLineBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::state::SF_MODAL;
+    use crate::views::dialog::{standard_buttons, ButtonSet, Dialog};
+    use crate::views::validator::FilterValidatorBuilder;
+
+    fn type_keys(dialog: &mut Dialog, text: &str) {
+        for ch in text.chars() {
+            let key_code = if ch == '\n' { KB_ENTER } else { ch as u16 };
+            let mut event = Event::keyboard(key_code);
+            dialog.handle_event(&mut event);
+        }
+    }
+
+    fn dialog_with_input(data: Rc<RefCell<String>>) -> Dialog {
+        let mut dialog = Dialog::new(Rect::new(0, 0, 40, 8), "Paste");
+        dialog.add(Box::new(InputLine::new(Rect::new(2, 1, 30, 2), 255, data)));
+        standard_buttons(&mut dialog, ButtonSet::OkCancel);
+        dialog.set_initial_focus();
+        let state = dialog.state();
+        dialog.set_state(state | SF_MODAL);
+        dialog
+    }
+
+    #[test]
+    fn test_typed_non_ascii_characters_are_inserted() {
+        let data = Rc::new(RefCell::new(String::new()));
+        let mut dialog = dialog_with_input(Rc::clone(&data));
+
+        type_keys(&mut dialog, "café 日本");
+
+        assert_eq!(*data.borrow(), "café 日本");
+    }
+
+    #[test]
+    fn test_pasted_newline_does_not_submit_dialog() {
+        let data = Rc::new(RefCell::new(String::new()));
+        let mut dialog = dialog_with_input(data.clone());
+
+        dialog.handle_event(&mut Event::paste("first\nsecond\n\nthird"));
+
+        assert_eq!(dialog.get_end_state(), 0, "pasted Enter must not activate the default button");
+        assert_eq!(*data.borrow(), "first");
+    }
+
+    #[test]
+    fn test_typed_enter_submits_dialog() {
+        let data = Rc::new(RefCell::new(String::new()));
+        let mut dialog = dialog_with_input(data.clone());
+
+        type_keys(&mut dialog, "abc\n");
+
+        assert_eq!(dialog.get_end_state(), crate::core::command::CM_OK);
+        assert_eq!(*data.borrow(), "abc");
+    }
+
+    #[test]
+    fn test_scroll_arrows_show_hidden_text() {
+        use crate::terminal::{render_to_cells, row_text};
+
+        let data = Rc::new(RefCell::new("abcdefghijklmnopqrstuvwxyz".to_string()));
+        let mut input = InputLine::new(Rect::new(0, 0, 10, 1), 255, data);
+        input.set_focus(true);
+        let press = |input: &mut InputLine, key| input.handle_event(&mut Event::keyboard(key));
+
+        press(&mut input, KB_HOME);
+        let cells = render_to_cells(&mut input, 10, 1);
+        assert_eq!(row_text(&cells[0]), "abcdefghi>");
+        assert_eq!(cells[0][9].attr, input.map_color(INPUT_ARROWS));
+        assert_ne!(cells[0][8].attr, cells[0][9].attr);
+
+        for _ in 0..12 {
+            press(&mut input, KB_RIGHT);
+        }
+        let row = row_text(&render_to_cells(&mut input, 10, 1)[0]);
+        assert!(row.starts_with('<') && row.ends_with('>'), "hidden text on both sides: {row:?}");
+
+        press(&mut input, KB_END);
+        let cells = render_to_cells(&mut input, 10, 1);
+        assert_eq!(cells[0][0].ch, '<');
+        assert_eq!(cells[0][0].attr, input.map_color(INPUT_ARROWS));
+        assert!(!row_text(&cells[0]).contains('>'));
+    }
+
+    #[test]
+    fn test_wide_text_scrolls_and_edits_by_character() {
+        let data = Rc::new(RefCell::new("日本語ab".to_string()));
+        let mut input = InputLine::new(Rect::new(0, 0, 6, 1), 255, Rc::clone(&data));
+        input.set_focus(true);
+        let press = |input: &mut InputLine, key| input.handle_event(&mut Event::keyboard(key));
+
+        // End scrolls so the 8 columns of text plus the cursor fit in 6
+        press(&mut input, KB_END);
+        let mut terminal = Terminal::new_headless(10, 1);
+        input.draw(&mut terminal);
+        input.update_cursor(&mut terminal);
+        let row: String = (0..6).map(|x| terminal.read_cell(x, 0).unwrap().ch).collect();
+        assert_eq!(row, "< ab  ", "the half-hidden 語 is blanked, not torn");
+        let output = String::from_utf8(terminal.take_output()).unwrap();
+        assert!(output.ends_with("\x1b[1;5H\x1b[?25h"), "cursor after \"ab\": {output:?}");
+
+        for _ in 0..3 {
+            press(&mut input, KB_LEFT);
+        }
+        press(&mut input, KB_BACKSPACE);
+        assert_eq!(*data.borrow(), "日語ab");
+    }
+
+    #[test]
+    fn test_shift_arrows_select_and_plain_arrows_clear() {
+        let data = Rc::new(RefCell::new("hello".to_string()));
+        let mut input = InputLine::new(Rect::new(0, 0, 20, 1), 255, Rc::clone(&data));
+        input.set_focus(true);
+        let press = |input: &mut InputLine, key| input.handle_event(&mut Event::keyboard(key));
+
+        press(&mut input, KB_SHIFT_LEFT);
+        press(&mut input, KB_SHIFT_LEFT);
+        assert_eq!(input.get_selection().as_deref(), Some("lo"));
+        press(&mut input, KB_SHIFT_HOME);
+        assert_eq!(input.get_selection().as_deref(), Some("hello"), "anchored at the end");
+        press(&mut input, KB_SHIFT_RIGHT);
+        assert_eq!(input.get_selection().as_deref(), Some("ello"));
+
+        press(&mut input, KB_RIGHT);
+        assert!(!input.has_selection());
+
+        // Shift+Left as the terminal delivers it: Left with the Shift modifier
+        let mut event = Event::keyboard(KB_LEFT);
+        event.key_modifiers = KeyModifiers::SHIFT;
+        input.handle_event(&mut event);
+        press(&mut input, KB_SHIFT_END);
+        assert_eq!(input.get_selection().as_deref(), Some("llo"));
+        press(&mut input, KB_BACKSPACE);
+        assert_eq!(*data.borrow(), "he");
+    }
+
+    #[test]
+    fn test_click_places_cursor_and_drag_selects() {
+        use crate::core::geometry::Point;
+
+        let data = Rc::new(RefCell::new("0123456789abcdef".to_string()));
+        let mut input = InputLine::new(Rect::new(5, 0, 15, 1), 255, Rc::clone(&data));
+        input.set_focus(true);
+        input.set_text("0123456789abcdef".to_string());
+        input.handle_event(&mut Event::keyboard(KB_END));
+        assert!(input.first_pos > 0, "scrolled to show the end");
+        let first = input.first_pos;
+        let mouse = |input: &mut InputLine, what, x, buttons| {
+            input.handle_event(&mut Event::mouse(what, Point::new(x, 0), buttons, false));
+        };
+
+        // Columns count from the first visible character
+        mouse(&mut input, EventType::MouseDown, 7, MB_LEFT_BUTTON);
+        assert_eq!(input.cursor_pos, first + 2);
+        assert!(input.has_mouse_capture());
+
+        mouse(&mut input, EventType::MouseMove, 9, MB_LEFT_BUTTON);
+        assert_eq!(input.get_selection(), Some(data.borrow()[first + 2..first + 4].to_string()));
+
+        // Past the right edge the index is clamped to the text length
+        for _ in 0..20 {
+            mouse(&mut input, EventType::MouseMove, 30, MB_LEFT_BUTTON);
+        }
+        assert_eq!(input.cursor_pos, 16);
+        mouse(&mut input, EventType::MouseUp, 30, 0);
+        assert!(!input.has_mouse_capture());
+
+        // A new click clears the selection
+        mouse(&mut input, EventType::MouseDown, 5, MB_LEFT_BUTTON);
+        assert!(!input.has_selection());
+        assert_eq!(input.cursor_pos, first);
+    }
+
+    #[test]
+    fn test_bracketed_paste_is_inserted_at_once() {
+        let data = Rc::new(RefCell::new(String::new()));
+        let mut dialog = dialog_with_input(Rc::clone(&data));
+
+        dialog.handle_event(&mut Event::paste("first line\nsecond line"));
+        assert_eq!(*data.borrow(), "first line", "cut at the first newline");
+        assert_eq!(dialog.get_end_state(), 0);
+
+        let mut input = InputLine::new(Rect::new(0, 0, 10, 1), 6, Rc::clone(&data));
+        input.set_focus(true);
+        input.set_text(String::new());
+        input.handle_event(&mut Event::paste("ab日本語"));
+        assert_eq!(*data.borrow(), "ab日", "max_length cuts on a character boundary");
+
+        let digits = FilterValidatorBuilder::new().valid_chars("0123456789").build_ref();
+        input.set_validator(digits);
+        input.set_text(String::new());
+        input.handle_event(&mut Event::paste("12a4"));
+        assert_eq!(*data.borrow(), "", "the validator rejects the whole paste");
+        input.handle_event(&mut Event::paste("1234"));
+        assert_eq!(*data.borrow(), "1234");
+    }
+
+    #[test]
+    fn test_navigation_keys_consumed_or_passed_through() {
+        use crate::core::event::{KB_DOWN, KB_ESC, KB_TAB, KB_UP};
+
+        // (key, Shift held, text, cursor at end, consumed)
+        let cases = [
+            (KB_HOME, false, "abc", true, true),
+            (KB_END, false, "abc", true, true),
+            (KB_HOME, true, "abc", true, true),
+            (KB_END, true, "abc", false, true),
+            (KB_LEFT, false, "", false, true),
+            (KB_RIGHT, false, "abc", true, true),
+            (KB_BACKSPACE, false, "", false, true),
+            (KB_DEL, false, "abc", true, true),
+            (KB_PGUP, false, "abc", true, true),
+            (KB_PGDN, false, "abc", true, true),
+            (KB_INS, false, "abc", true, true),
+            (b'x' as KeyCode, false, "full", true, true),
+            ('é' as KeyCode, false, "full", true, true),
+            ('日' as KeyCode, false, "full", true, true),
+            (KB_UP, false, "abc", true, false),
+            (KB_DOWN, false, "abc", true, false),
+            (KB_TAB, false, "abc", true, false),
+            (KB_ESC, false, "abc", true, false),
+            (KB_ENTER, false, "abc", true, false),
+        ];
+        for (key_code, shift, text, at_end, consumed) in cases {
+            let data = Rc::new(RefCell::new(String::new()));
+            let mut input = InputLine::new(Rect::new(0, 0, 20, 1), 4, Rc::clone(&data));
+            input.set_text(text.to_string());
+            input.set_focus(true);
+            if !at_end {
+                input.handle_event(&mut Event::keyboard(KB_HOME));
+            }
+            let mut event = Event::keyboard(key_code);
+            if shift {
+                event.key_modifiers = KeyModifiers::SHIFT;
+            }
+            input.handle_event(&mut event);
+            let name = crate::core::event::key_name(key_code);
+            assert_eq!(event.what == EventType::Nothing, consumed, "{name:?} (shift {shift}) on {text:?}");
+            assert_eq!(*data.borrow(), text, "{name:?} leaves the text alone");
+        }
+    }
+    #[test]
+    fn test_buffer_is_readable_from_another_thread_while_editing() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        let buffer = TextBuffer::default();
+        let mut dialog = Dialog::new(Rect::new(0, 0, 40, 8), "Buffer");
+        dialog.add(Box::new(InputLineBuilder::new().bounds(Rect::new(2, 1, 30, 2)).buffer(buffer.clone()).build()));
+        dialog.set_initial_focus();
+
+        let done = Arc::new(AtomicBool::new(false));
+        let reader = {
+            let (buffer, done) = (buffer.clone(), Arc::clone(&done));
+            std::thread::spawn(move || {
+                let mut seen = Vec::new();
+                while !done.load(Ordering::Relaxed) {
+                    seen.push(buffer.get());
+                }
+                seen
+            })
+        };
+        type_keys(&mut dialog, "typed while read");
+        done.store(true, Ordering::Relaxed);
+
+        // Every read saw the text between two whole edits
+        let seen = reader.join().unwrap();
+        assert!(seen.iter().all(|text| "typed while read".starts_with(text.as_str())));
+        assert_eq!(buffer.get(), "typed while read");
+        buffer.set("reset");
+        assert_eq!(dialog.child_at(0).get_data(), Some(DataValue::Text("reset".into())));
+    }
+
+    #[test]
+    #[should_panic(expected = "of field 'email' is already in use")]
+    fn test_reentrant_buffer_panic_names_the_field() {
+        let buffer = TextBuffer::new("ada@example.com");
+        let input = InputLineBuilder::new().bounds(Rect::new(0, 0, 30, 1)).buffer(buffer.clone()).field_id("email").build();
+        buffer.map(|_| input.get_text());
+    }
+}
@@ -3,11 +3,11 @@
 //! Label view - static text display with optional linked control focus.
 
 use super::view::{write_line_to_terminal, View, ViewId};
-use super::group::Group;
-use crate::core::draw::DrawBuffer;
+use crate::core::draw::{shortcut_hotkey, DrawBuffer};
+use crate::core::command::CM_FOCUS_LINK;
 use crate::core::event::{Event, EventType};
 use crate::core::geometry::Rect;
-use crate::core::palette::{LABEL_NORMAL, LABEL_SHORTCUT};
+use crate::core::palette::{StyleFlags, LABEL_NORMAL, LABEL_SHORTCUT};
 use crate::core::state::OF_POST_PROCESS;
 use crate::terminal::Terminal;
 
@@ -15,6 +15,7 @@ pub struct Label {
     bounds: Rect,
     text: String,
     link: Option<ViewId>, // ID of linked control
+    text_style: StyleFlags,
     owner: Option<*const dyn View>,
     owner_type: super::view::OwnerType,
     state: u16,
@@ -27,6 +28,7 @@ impl Label {
             bounds,
             text: text.to_string(),
             link: None,
+            text_style: 0,
             owner: None,
             owner_type: super::view::OwnerType::Dialog, // Labels default to Dialog context
             state: 0,
@@ -35,26 +37,36 @@ impl Label {
     }
 
     /// Set the linked control by its ViewId
-    /// Matches Borland: TLabel constructor takes TView* aLink parameter
-    /// When label is clicked, focus transfers to the linked control
+    #[deprecated(note = "use View::set_link_id()")]
     pub fn set_link(&mut self, view_id: ViewId) {
-        self.link = Some(view_id);
+        self.set_link_id(view_id);
+    }
+
+    /// Get the label text, including `~` shortcut markers
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replace the label text; the `~X~` hotkey follows the new text
+    /// The link to the control is kept, so Alt+X now focuses it
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+    }
+
+    /// Draw the text with STYLE_* bits, e.g. STYLE_UNDERLINE for a link-like label
+    pub fn set_text_style(&mut self, style: StyleFlags) {
+        self.text_style = style;
+    }
+
+    pub fn text_style(&self) -> StyleFlags {
+        self.text_style
     }
 
     /// Extract the hotkey character from the label text
-    /// Returns the uppercase character following the first '~', or None if no hotkey
+    /// Returns the first highlighted character in uppercase, or None if no hotkey
     /// Matches Borland: hotKey() function
-    fn get_hotkey(&self) -> Option<char> {
-        let mut chars = self.text.chars();
-        while let Some(ch) = chars.next() {
-            if ch == '~' {
-                // Next character is the hotkey
-                if let Some(hotkey) = chars.next() {
-                    return Some(hotkey.to_uppercase().next().unwrap_or(hotkey));
-                }
-            }
-        }
-        None
+    pub fn get_hotkey(&self) -> Option<char> {
+        shortcut_hotkey(&self.text).map(|hotkey| hotkey.to_uppercase().next().unwrap_or(hotkey))
     }
 }
 
@@ -77,7 +89,12 @@ impl View for Label {
         let shortcut_attr = self.map_color(LABEL_SHORTCUT);
 
         buf.move_char(0, ' ', normal_attr, width);
-        buf.move_str_with_shortcut(0, &self.text, normal_attr, shortcut_attr);
+        buf.move_str_with_shortcut(
+            0,
+            &self.text,
+            normal_attr.with_style(self.text_style),
+            shortcut_attr.with_style(self.text_style),
+        );
 
         write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y, &buf);
     }
@@ -87,7 +104,7 @@ impl View for Label {
         // Matches Borland: TLabel::handleEvent() with ofPostProcess flag
         if event.what == EventType::Keyboard {
             // Check if we have a linked control and a hotkey
-            if let (Some(link_id), Some(hotkey)) = (self.link, self.get_hotkey()) {
+            if let (Some(_), Some(hotkey)) = (self.link, self.get_hotkey()) {
                 // Check if the pressed key matches our Alt+letter shortcut
                 // The key code for Alt+letter is stored in the high byte (scan code)
                 // We need to check if it matches KB_ALT_{LETTER}
@@ -125,27 +142,23 @@ impl View for Label {
 
                 if let Some(expected_code) = alt_code {
                     if event.key_code == expected_code {
-                        // Hotkey matched! Focus the linked control
+                        // Hotkey matched! Ask the owner group to focus the linked control
                         // Matches Borland: TLabel calls link->select()
-                        if let Some(owner_ptr) = self.owner {
-                            // SAFETY: We assume the owner is a Group, which is the case
-                            // for labels added to dialogs/windows
-                            unsafe {
-                                let group = &mut *(owner_ptr as *mut Group);
-                                if group.focus_by_view_id(link_id) {
-                                    event.clear(); // Event consumed
-                                }
-                            }
-                        }
+                        *event = Event::broadcast(CM_FOCUS_LINK);
                     }
                 }
             }
         }
     }
 
-    /// Return the linked control ViewId for this label
+    /// Matches Borland: TLabel constructor takes TView* aLink parameter
+    /// When label is clicked, focus transfers to the linked control
+    fn set_link_id(&mut self, id: ViewId) {
+        self.link = Some(id);
+    }
+
     /// Matches Borland: TLabel::link field
-    fn label_link(&self) -> Option<ViewId> {
+    fn link_id(&self) -> Option<ViewId> {
         self.link
     }
 
@@ -181,6 +194,14 @@ impl View for Label {
         use crate::core::palette::{palettes, Palette};
         Some(Palette::from_slice(palettes::CP_LABEL))
     }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
 }
 
 /// Builder for creating labels with a fluent API.
@@ -188,11 +209,12 @@ pub struct LabelBuilder {
     bounds: Option<Rect>,
     text: Option<String>,
     link: Option<ViewId>,
+    style: StyleFlags,
 }
 
 impl LabelBuilder {
     pub fn new() -> Self {
-        Self { bounds: None, text: None, link: None }
+        Self { bounds: None, text: None, link: None, style: 0 }
     }
 
     #[must_use]
@@ -213,6 +235,13 @@ impl LabelBuilder {
         self
     }
 
+    /// Text style (STYLE_* bits), see [`Label::set_text_style()`]
+    #[must_use]
+    pub fn style(mut self, style: StyleFlags) -> Self {
+        self.style = style;
+        self
+    }
+
     pub fn build(self) -> Label {
         let bounds = self.bounds.expect("Label bounds must be set");
         let text = self.text.expect("Label text must be set");
@@ -220,6 +249,7 @@ impl LabelBuilder {
         if let Some(link) = self.link {
             label.link = Some(link);
         }
+        label.text_style = self.style;
         label
     }
 
@@ -233,3 +263,73 @@ impl Default for LabelBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::draw::Cell;
+    use crate::core::event::{KB_ALT_C, KB_ALT_N};
+
+    fn hotkey_fires(label: &mut Label, key_code: u16) -> bool {
+        let mut event = Event::keyboard(key_code);
+        label.handle_event(&mut event);
+        event.what == EventType::Broadcast && event.command == CM_FOCUS_LINK
+    }
+
+    #[test]
+    fn test_set_text_updates_drawing_and_hotkey() {
+        let mut label = Label::new(Rect::new(0, 0, 10, 1), "~N~ame:");
+        label.set_link_id(ViewId::new());
+        assert!(hotkey_fires(&mut label, KB_ALT_N));
+
+        label.set_text("~C~ity:");
+        assert_eq!(label.text(), "~C~ity:");
+        assert_eq!(label.get_hotkey(), Some('C'));
+        assert!(!hotkey_fires(&mut label, KB_ALT_N));
+        assert!(hotkey_fires(&mut label, KB_ALT_C));
+
+        let mut terminal = Terminal::new_headless(10, 1);
+        label.draw(&mut terminal);
+        let row: String = (0..10).map(|x| terminal.read_cell(x, 0).unwrap().ch).collect();
+        assert_eq!(row, "City:     ");
+    }
+
+    #[test]
+    fn test_text_style_covers_text_only() {
+        use crate::core::palette::STYLE_UNDERLINE;
+
+        let mut label = LabelBuilder::new().bounds(Rect::new(0, 0, 10, 1)).text("~H~elp").style(STYLE_UNDERLINE).build();
+        let mut terminal = Terminal::new_headless(10, 1);
+        label.draw(&mut terminal);
+        assert!(terminal.read_cell(0, 0).unwrap().attr.has_style(STYLE_UNDERLINE), "shortcut");
+        assert!(terminal.read_cell(3, 0).unwrap().attr.has_style(STYLE_UNDERLINE));
+        assert_eq!(terminal.read_cell(4, 0).unwrap().attr.style, 0, "padding");
+    }
+
+    #[test]
+    fn test_wide_characters_take_two_columns() {
+        let mut label = Label::new(Rect::new(0, 0, 10, 1), "~日~本語:");
+        assert_eq!(label.get_hotkey(), Some('日'));
+        let mut terminal = Terminal::new_headless(10, 1);
+        label.draw(&mut terminal);
+        let cells: Vec<Cell> = (0..10).map(|x| terminal.read_cell(x, 0).unwrap()).collect();
+        let glyphs: String = cells.iter().map(|c| if c.is_continuation() { '+' } else { c.ch }).collect();
+        assert_eq!(glyphs, "日+本+語+:   ", "text after the wide characters does not drift");
+
+        // Each glyph reaches the terminal once
+        terminal.flush().unwrap();
+        let output = String::from_utf8(terminal.take_output()).unwrap();
+        assert!(output.contains("本語:"));
+        assert_eq!("日本語".chars().map(|ch| output.matches(ch).count()).sum::<usize>(), 3);
+
+        // A wide character that would straddle the right edge is not drawn in half
+        let mut label = Label::new(Rect::new(0, 0, 5, 1), "日本語");
+        let mut terminal = Terminal::new_headless(10, 1);
+        label.draw(&mut terminal);
+        let glyphs: String = (0..6)
+            .map(|x| terminal.read_cell(x, 0).unwrap())
+            .map(|c| if c.is_continuation() { '+' } else { c.ch })
+            .collect();
+        assert_eq!(glyphs, "日+本+  ");
+    }
+}
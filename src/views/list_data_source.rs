@@ -0,0 +1,86 @@
+// (C) 2025 - Enzo Lombardi
+
+//! List data sources - lazily fetched item text for list views.
+//!
+//! A [`ListBox`](super::listbox::ListBox) asks its data source only for the
+//! items it is about to draw, so very large lists never need to be
+//! materialized as a `Vec<String>`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::borrow::Cow;
+//! use turbo_vision::core::geometry::Rect;
+//! use turbo_vision::views::list_data_source::ListDataSource;
+//! use turbo_vision::views::listbox::ListBox;
+//!
+//! struct Numbers;
+//!
+//! impl ListDataSource for Numbers {
+//!     fn len(&self) -> usize {
+//!         1_000_000
+//!     }
+//!
+//!     fn item(&self, index: usize) -> Cow<'_, str> {
+//!         Cow::Owned(format!("Line {index}"))
+//!     }
+//! }
+//!
+//! let mut listbox = ListBox::new(Rect::new(0, 0, 30, 10), 0);
+//! listbox.set_data_source(Box::new(Numbers));
+//! assert_eq!(listbox.item_count(), 1_000_000);
+//! ```
+
+use std::borrow::Cow;
+
+/// Source of list items, fetched by index on demand
+pub trait ListDataSource {
+    /// Number of items
+    fn len(&self) -> usize;
+
+    /// Text of the item at `index` (only called with `index < len()`)
+    fn item(&self, index: usize) -> Cow<'_, str>;
+
+    /// Returns true if there are no items
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Mutable access to the items, for sources backed by a `Vec<String>`.
+    /// Lazily computed sources return `None`.
+    fn items_mut(&mut self) -> Option<&mut Vec<String>> {
+        None
+    }
+}
+
+/// Data source over an in-memory `Vec<String>`
+#[derive(Debug, Clone, Default)]
+pub struct VecDataSource {
+    items: Vec<String>,
+}
+
+impl VecDataSource {
+    pub fn new(items: Vec<String>) -> Self {
+        Self { items }
+    }
+}
+
+impl ListDataSource for VecDataSource {
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    fn item(&self, index: usize) -> Cow<'_, str> {
+        Cow::Borrowed(&self.items[index])
+    }
+
+    fn items_mut(&mut self) -> Option<&mut Vec<String>> {
+        Some(&mut self.items)
+    }
+}
+
+impl From<Vec<String>> for VecDataSource {
+    fn from(items: Vec<String>) -> Self {
+        Self::new(items)
+    }
+}
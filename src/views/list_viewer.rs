@@ -19,8 +19,11 @@
 // Rust composition:
 //   View trait + ListViewer trait → ListBox (embeds ListViewerState)
 
-use crate::core::event::{Event, EventType, KB_UP, KB_DOWN, KB_PGUP, KB_PGDN, KB_HOME, KB_END, KB_ENTER, MB_LEFT_BUTTON};
+use crate::core::event::{Event, EventType, KB_UP, KB_DOWN, KB_LEFT, KB_RIGHT, KB_PGUP, KB_PGDN, KB_HOME, KB_END, KB_ENTER, MB_LEFT_BUTTON};
+use crate::core::geometry::Point;
+use crossterm::event::KeyModifiers;
 use super::view::View;
+use std::collections::BTreeSet;
 
 /// State management for list viewer components
 ///
@@ -43,12 +46,24 @@ pub struct ListViewerState {
     pub range: usize,
 
     /// Number of columns for multi-column lists
+    ///
+    /// Items flow top to bottom, then on to the next column. With more than
+    /// one column `top_item` always starts a column.
     /// Matches Borland: TListViewer::numCols
     pub num_cols: u16,
 
     /// Whether space bar selects items
     /// Matches Borland: TListViewer::handleSpace
     pub handle_space: bool,
+
+    /// Whether clicks mark items (Ctrl+click toggles, Shift+click marks a range)
+    pub multi_select: bool,
+
+    /// Marked items of a multi-select list
+    pub marked: BTreeSet<usize>,
+
+    /// Item a Shift+click range starts from: the last plain-clicked one
+    pub anchor: Option<usize>,
 }
 
 impl ListViewerState {
@@ -60,6 +75,9 @@ impl ListViewerState {
             range: 0,
             num_cols: 1,
             handle_space: true,
+            multi_select: false,
+            marked: BTreeSet::new(),
+            anchor: None,
         }
     }
 
@@ -71,6 +89,9 @@ impl ListViewerState {
             range,
             num_cols: 1,
             handle_space: true,
+            multi_select: false,
+            marked: BTreeSet::new(),
+            anchor: None,
         }
     }
 
@@ -93,6 +114,60 @@ impl ListViewerState {
         if self.top_item >= range && range > 0 {
             self.top_item = range - 1;
         }
+
+        self.marked.retain(|&item| item < range);
+        if self.anchor.is_some_and(|anchor| anchor >= range) {
+            self.anchor = None;
+        }
+    }
+
+    /// Update the marks for a click on `item`
+    ///
+    /// Ctrl toggles the item and keeps the other marks, Shift marks the range
+    /// from the anchor (adding to the marks with Ctrl+Shift), and a plain click
+    /// marks only the item and makes it the anchor.
+    pub fn click_mark(&mut self, item: usize, modifiers: KeyModifiers) {
+        if modifiers.contains(KeyModifiers::SHIFT) {
+            let anchor = *self.anchor.get_or_insert(item);
+            if !modifiers.contains(KeyModifiers::CONTROL) {
+                self.marked.clear();
+            }
+            self.marked.extend(anchor.min(item)..=anchor.max(item));
+        } else if modifiers.contains(KeyModifiers::CONTROL) {
+            if !self.marked.remove(&item) {
+                self.marked.insert(item);
+            }
+        } else {
+            self.marked.clear();
+            self.marked.insert(item);
+            self.anchor = Some(item);
+        }
+    }
+
+    /// Unmark every item and forget the anchor
+    pub fn clear_marks(&mut self) {
+        self.marked.clear();
+        self.anchor = None;
+    }
+
+    /// Number of columns, at least one
+    pub fn columns(&self) -> usize {
+        usize::from(self.num_cols.max(1))
+    }
+
+    /// Number of items shown at once: every row of every column
+    pub fn page_items(&self, visible_rows: usize) -> usize {
+        visible_rows.max(1) * self.columns()
+    }
+
+    /// Largest top item that still fills the view
+    pub fn max_top(&self, visible_rows: usize) -> usize {
+        if self.columns() == 1 {
+            return self.range.saturating_sub(visible_rows);
+        }
+        let rows = visible_rows.max(1);
+        let last_column = self.range.saturating_sub(1) / rows;
+        last_column.saturating_sub(self.columns() - 1) * rows
     }
 
     /// Focus a specific item
@@ -105,16 +180,28 @@ impl ListViewerState {
 
         self.focused = Some(item);
 
-        // Scroll if needed to make item visible
+        // Scroll if needed to make item visible; multi-column lists scroll by whole columns
+        let (first, last) = match visible_rows.max(1) {
+            rows if self.columns() > 1 => (item - item % rows, item - item % rows + rows - 1),
+            _ => (item, item),
+        };
         if item < self.top_item {
             // Item is above viewport - scroll up
-            self.top_item = item;
-        } else if item >= self.top_item + visible_rows {
+            self.top_item = first;
+        } else if item >= self.top_item + self.page_items(visible_rows) {
             // Item is below viewport - scroll down
-            self.top_item = item - visible_rows + 1;
+            self.top_item = (last + 1).saturating_sub(self.page_items(visible_rows));
         }
     }
 
+    /// Scroll the view by `lines` (negative scrolls up) without moving the focus
+    ///
+    /// A multi-column list scrolls by whole columns instead of lines.
+    pub fn scroll_by(&mut self, lines: isize, visible_rows: usize) {
+        let step = if self.columns() == 1 { 1 } else { visible_rows.max(1) as isize };
+        self.top_item = self.top_item.saturating_add_signed(lines * step).min(self.max_top(visible_rows));
+    }
+
     /// Focus item and center it in viewport
     ///
     /// Matches Borland: TListViewer::focusItemCentered()
@@ -168,26 +255,87 @@ impl ListViewerState {
         }
     }
 
+    /// Number of items a page scroll moves: one row of context is kept
+    fn page_step(visible_rows: usize) -> usize {
+        visible_rows.saturating_sub(1).max(1)
+    }
+
     /// Move focus down one page
+    ///
+    /// Scrolls by `visible_rows - 1` so the previous last row stays visible as
+    /// context, then focuses the last visible row.
     pub fn focus_page_down(&mut self, visible_rows: usize) {
-        if let Some(focused) = self.focused {
-            let new_focused = (focused + visible_rows).min(self.range.saturating_sub(1));
-            self.focus_item(new_focused, visible_rows);
-        } else if self.range > 0 {
+        if self.range == 0 {
+            return;
+        }
+        if self.focused.is_none() {
             self.focus_item(0, visible_rows);
+            return;
+        }
+        if let Some(focused) = self.focused.filter(|_| self.columns() > 1) {
+            let item = (focused + self.page_items(visible_rows)).min(self.range - 1);
+            self.focus_item(item, visible_rows);
+            return;
         }
+        let max_top = self.range.saturating_sub(visible_rows);
+        self.top_item = (self.top_item + Self::page_step(visible_rows)).min(max_top);
+        let last_visible = (self.top_item + visible_rows.max(1) - 1).min(self.range - 1);
+        self.focus_item(last_visible, visible_rows);
     }
 
     /// Move focus up one page
+    ///
+    /// Scrolls by `visible_rows - 1` so the previous first row stays visible as
+    /// context, then focuses the first visible row.
     pub fn focus_page_up(&mut self, visible_rows: usize) {
+        if self.range == 0 {
+            return;
+        }
+        if self.focused.is_none() {
+            self.focus_item(0, visible_rows);
+            return;
+        }
+        if let Some(focused) = self.focused.filter(|_| self.columns() > 1) {
+            self.focus_item(focused.saturating_sub(self.page_items(visible_rows)), visible_rows);
+            return;
+        }
+        self.top_item = self.top_item.saturating_sub(Self::page_step(visible_rows));
+        self.focus_item(self.top_item, visible_rows);
+    }
+
+    /// Move focus one column left, to the same row of the previous column
+    ///
+    /// Matches Borland: TListViewer::handleEvent() kbLeft with numCols > 1
+    pub fn focus_left(&mut self, visible_rows: usize) {
         if let Some(focused) = self.focused {
-            let new_focused = focused.saturating_sub(visible_rows);
-            self.focus_item(new_focused, visible_rows);
+            self.focus_item(focused.saturating_sub(visible_rows.max(1)), visible_rows);
         } else if self.range > 0 {
             self.focus_item(0, visible_rows);
         }
     }
 
+    /// Move focus one column right, stopping at the last item
+    ///
+    /// Matches Borland: TListViewer::handleEvent() kbRight with numCols > 1
+    pub fn focus_right(&mut self, visible_rows: usize) {
+        if let Some(focused) = self.focused {
+            if self.range > 0 {
+                self.focus_item((focused + visible_rows.max(1)).min(self.range - 1), visible_rows);
+            }
+        } else if self.range > 0 {
+            self.focus_item(0, visible_rows);
+        }
+    }
+
+    /// Item drawn at `row` of `column`, if there is one
+    pub fn item_at(&self, column: usize, row: usize, visible_rows: usize) -> Option<usize> {
+        if row >= visible_rows || column >= self.columns() {
+            return None;
+        }
+        let item = self.top_item + column * visible_rows + row;
+        (item < self.range).then_some(item)
+    }
+
     /// Move focus to first item
     pub fn focus_first(&mut self, visible_rows: usize) {
         if self.range > 0 {
@@ -231,9 +379,14 @@ pub trait ListViewer: View {
     /// Check if an item is selected
     ///
     /// Matches Borland: TListViewer::isSelected()
-    /// Default: focused item is selected
+    /// Default: the marked items of a multi-select list, else the focused item
     fn is_selected(&self, item: usize) -> bool {
-        Some(item) == self.list_state().focused
+        let state = self.list_state();
+        if state.multi_select {
+            state.marked.contains(&item)
+        } else {
+            Some(item) == state.focused
+        }
     }
 
     /// Select an item (for multi-select lists)
@@ -280,6 +433,22 @@ pub trait ListViewer: View {
         self.bounds().height_clamped() as usize
     }
 
+    /// Width of one column, divider included
+    fn column_width(&self) -> usize {
+        (self.bounds().width_clamped() as usize / self.list_state().columns()).max(1)
+    }
+
+    /// Item under a screen position, accounting for the columns
+    fn item_at_point(&self, pos: Point) -> Option<usize> {
+        let bounds = self.bounds();
+        if !bounds.contains(pos) {
+            return None;
+        }
+        let state = self.list_state();
+        let column = ((pos.x - bounds.a.x) as usize / self.column_width()).min(state.columns() - 1);
+        state.item_at(column, (pos.y - bounds.a.y) as usize, self.visible_rows())
+    }
+
     /// Handle standard list navigation events
     ///
     /// Matches Borland: TListViewer::handleEvent() navigation logic
@@ -301,6 +470,17 @@ pub trait ListViewer: View {
                         event.clear();
                         true
                     }
+                    // Left/Right only move across columns; single-column lists leave them alone
+                    KB_LEFT if state.columns() > 1 => {
+                        state.focus_left(visible_rows);
+                        event.clear();
+                        true
+                    }
+                    KB_RIGHT if state.columns() > 1 => {
+                        state.focus_right(visible_rows);
+                        event.clear();
+                        true
+                    }
                     KB_PGUP => {
                         state.focus_page_up(visible_rows);
                         event.clear();
@@ -330,24 +510,24 @@ pub trait ListViewer: View {
             }
             EventType::MouseDown => {
                 if event.mouse.buttons & MB_LEFT_BUTTON != 0 {
-                    let mouse_pos = event.mouse.pos;
-                    let bounds = self.bounds();
-
-                    // Check if click is within bounds
-                    if bounds.contains(mouse_pos) {
-                        // Calculate which item was clicked
-                        let relative_y = (mouse_pos.y - bounds.a.y) as usize;
-                        let clicked_item = self.list_state().top_item + relative_y;
-
-                        if clicked_item < self.item_count() {
-                            self.select_item(clicked_item);
-                            event.clear();
-                            return true;
+                    if let Some(clicked_item) = self.item_at_point(event.mouse.pos) {
+                        if self.list_state().multi_select {
+                            self.list_state_mut().click_mark(clicked_item, event.mouse.modifiers);
                         }
+                        self.select_item(clicked_item);
+                        event.clear();
+                        return true;
                     }
                 }
                 false
             }
+            // The wheel scrolls the view; the focused item stays where it is
+            EventType::MouseWheelUp | EventType::MouseWheelDown if self.bounds().contains(event.mouse.pos) => {
+                let visible_rows = self.visible_rows();
+                self.list_state_mut().scroll_by(event.wheel_lines() as isize, visible_rows);
+                event.clear();
+                true
+            }
             _ => false,
         }
     }
@@ -423,21 +603,51 @@ mod tests {
         // Start at 0
         assert_eq!(state.focused, Some(0));
 
-        // Page down
+        // Page down scrolls by 4 (one row of context) and focuses the last visible row
         state.focus_page_down(visible_rows);
-        assert_eq!(state.focused, Some(5));
+        assert_eq!((state.top_item, state.focused), (4, Some(8)));
 
         // Page down again
         state.focus_page_down(visible_rows);
-        assert_eq!(state.focused, Some(10));
+        assert_eq!((state.top_item, state.focused), (8, Some(12)));
 
-        // Page up
+        // Page up focuses the first visible row
         state.focus_page_up(visible_rows);
-        assert_eq!(state.focused, Some(5));
+        assert_eq!((state.top_item, state.focused), (4, Some(4)));
 
         // Page up again
         state.focus_page_up(visible_rows);
-        assert_eq!(state.focused, Some(0));
+        assert_eq!((state.top_item, state.focused), (0, Some(0)));
+    }
+
+    #[test]
+    fn test_page_navigation_10_rows_35_items() {
+        let mut state = ListViewerState::with_range(35);
+        let visible_rows = 10;
+
+        let mut pages = Vec::new();
+        for _ in 0..4 {
+            state.focus_page_down(visible_rows);
+            pages.push((state.top_item, state.focused));
+        }
+        // Last page clamps to the end of the list
+        assert_eq!(pages, vec![(9, Some(18)), (18, Some(27)), (25, Some(34)), (25, Some(34))]);
+
+        let mut pages = Vec::new();
+        for _ in 0..4 {
+            state.focus_page_up(visible_rows);
+            pages.push((state.top_item, state.focused));
+        }
+        assert_eq!(pages, vec![(16, Some(16)), (7, Some(7)), (0, Some(0)), (0, Some(0))]);
+    }
+
+    #[test]
+    fn test_page_navigation_short_list() {
+        let mut state = ListViewerState::with_range(4);
+        state.focus_page_down(10);
+        assert_eq!((state.top_item, state.focused), (0, Some(3)));
+        state.focus_page_up(10);
+        assert_eq!((state.top_item, state.focused), (0, Some(0)));
     }
 
     #[test]
@@ -476,4 +686,29 @@ mod tests {
         assert_eq!(state.focused, Some(2));
         assert_eq!(state.top_item, 0);
     }
+
+    #[test]
+    fn test_multi_column_focus_scrolls_by_columns() {
+        let mut state = ListViewerState::with_range(20);
+        state.num_cols = 2;
+        let visible_rows = 4;
+
+        // Two columns of four rows show eight items
+        state.focus_item(7, visible_rows);
+        assert_eq!(state.top_item, 0);
+        state.focus_item(9, visible_rows);
+        assert_eq!(state.top_item, 4);
+        state.focus_right(visible_rows);
+        assert_eq!((state.focused, state.top_item), (Some(13), 8));
+        state.focus_left(visible_rows);
+        assert_eq!((state.focused, state.top_item), (Some(9), 8));
+        state.focus_item(2, visible_rows);
+        assert_eq!(state.top_item, 0);
+
+        // The last column ends the scroll range
+        state.scroll_by(10, visible_rows);
+        assert_eq!(state.top_item, 12);
+        assert_eq!(state.item_at(1, 3, visible_rows), Some(19));
+        assert_eq!(state.item_at(1, 0, visible_rows), Some(16));
+    }
 }
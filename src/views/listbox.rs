@@ -1,29 +1,76 @@
 // (C) 2025 - Enzo Lombardi
 
 //! ListBox view - scrollable list with single selection support.
-
+//!
+//! [`ListBox::show_scrollbar()`] adds a vertical scroll bar in the rightmost
+//! column: its thumb follows the top item, clicking the track pages and
+//! dragging the thumb scrolls the list.
+//!
+//! Keys for log-like and result lists:
+//! - Ctrl+C copies the selected item's text to the clipboard
+//! - Ctrl+Shift+C copies every shown item, one per line (many terminals
+//!   keep Ctrl+Shift+C for themselves or report it as plain Ctrl+C)
+//! - Ctrl+F opens an inline filter on the bottom row: typing hides the items
+//!   that don't contain the text, with an "n of m" count; ESC clears it.
+//!   Indices (selection, `find_all()`, the select command) still refer to the
+//!   full list.
+//!
+//! [`ListBox::set_columns()`] lays the items out in several columns, filling
+//! each one top to bottom before moving on to the next, as the classic file
+//! lists do. Left/Right then move across columns.
+
+use super::list_data_source::{ListDataSource, VecDataSource};
 use super::list_viewer::{ListViewer, ListViewerState};
-use super::view::{write_line_to_terminal, View};
+use super::scrollbar::ScrollBar;
+use super::view::{write_line_to_terminal, DataValue, View};
+use crate::core::clipboard;
 use crate::core::command::CommandId;
 use crate::core::draw::DrawBuffer;
-use crate::core::event::{Event, EventType, KB_ENTER, MB_LEFT_BUTTON};
+use crate::core::event::{key_char, Event, EventType, KB_BACKSPACE, KB_CTRL_C, KB_CTRL_F, KB_ENTER, KB_ESC, MB_LEFT_BUTTON};
 use crate::core::geometry::Rect;
-use crate::core::palette::{LISTBOX_FOCUSED, LISTBOX_NORMAL, LISTBOX_SELECTED};
+use crate::core::palette::{Attr, LISTBOX_DIVIDER, LISTBOX_FOCUSED, LISTBOX_NORMAL, LISTBOX_SELECTED};
 use crate::core::state::StateFlags;
 use crate::terminal::Terminal;
+use crossterm::event::KeyModifiers;
+use std::borrow::Cow;
 
 /// ListBox - A scrollable list of selectable items
 ///
 /// Now implements ListViewer trait for standard navigation behavior.
+/// Items come from a [`ListDataSource`]; only the visible ones are fetched when drawing.
 /// Matches Borland: TListBox (extends TListViewer)
 pub struct ListBox {
     bounds: Rect,
-    items: Vec<String>,
+    items: Box<dyn ListDataSource>,
     list_state: ListViewerState, // Embedded state from ListViewer
     state: StateFlags,
     on_select_command: CommandId,
     owner: Option<*const dyn View>,
     owner_type: super::view::OwnerType,
+    search: Option<ListSearch>, // Active find_all() query, highlighted in draw
+    filter: Option<ListFilter>, // Inline filter; list_state then counts shown rows, not items
+    v_scrollbar: Option<Box<ScrollBar>>,
+}
+
+/// Inline filter opened with Ctrl+F
+struct ListFilter {
+    text: String,
+    rows: Vec<usize>, // Indices of the items shown, in list order
+}
+
+impl ListFilter {
+    /// Case-insensitive substring match; an empty filter lets everything through
+    fn matches(&self, item: &str) -> bool {
+        self.text.is_empty() || !match_positions(item, &self.text, false).is_empty()
+    }
+}
+
+/// Result of the last find_all() call
+struct ListSearch {
+    query: String,
+    case_sensitive: bool,
+    matches: Vec<usize>,
+    current: Option<usize>, // Index into matches of the selected match
 }
 
 impl ListBox {
@@ -31,50 +78,220 @@ impl ListBox {
     pub fn new(bounds: Rect, on_select_command: CommandId) -> Self {
         Self {
             bounds,
-            items: Vec::new(),
+            items: Box::new(VecDataSource::default()),
             list_state: ListViewerState::new(),
             state: 0,
             on_select_command,
             owner: None,
             owner_type: super::view::OwnerType::None,
+            search: None,
+            filter: None,
+            v_scrollbar: None,
+        }
+    }
+
+    /// Show or hide a vertical scroll bar in the rightmost column
+    ///
+    /// While shown, the items are drawn one column narrower.
+    pub fn show_scrollbar(&mut self, show: bool) {
+        self.v_scrollbar = show.then(|| Box::new(ScrollBar::new_vertical(self.scrollbar_bounds())));
+        self.sync_scrollbar();
+    }
+
+    pub fn has_scrollbar(&self) -> bool {
+        self.v_scrollbar.is_some()
+    }
+
+    /// Lay the items out in `columns` columns (at least one)
+    ///
+    /// Matches Borland: TListViewer::numCols
+    pub fn set_columns(&mut self, columns: usize) {
+        self.list_state.num_cols = columns.clamp(1, usize::from(u16::MAX)) as u16;
+        if let Some(row) = self.list_state.focused {
+            let visible_rows = self.visible_rows();
+            self.list_state.top_item = 0;
+            self.list_state.focus_item(row, visible_rows);
+        }
+    }
+
+    pub fn columns(&self) -> usize {
+        self.list_state.columns()
+    }
+
+    /// Let clicks mark several items: Ctrl+click toggles one, Shift+click
+    /// marks the range from the last plain-clicked item
+    ///
+    /// Turning it off clears the marks.
+    pub fn set_multi_select(&mut self, multi_select: bool) {
+        self.list_state.multi_select = multi_select;
+        if !multi_select {
+            self.list_state.clear_marks();
+        }
+    }
+
+    pub fn is_multi_select(&self) -> bool {
+        self.list_state.multi_select
+    }
+
+    /// Indices of the marked items, in list order
+    pub fn marked_items(&self) -> Vec<usize> {
+        self.list_state.marked.iter().filter_map(|&row| self.item_at_row(row)).collect()
+    }
+
+    /// Whether the item at `index` is marked
+    pub fn is_marked(&self, index: usize) -> bool {
+        self.row_of_item(index).is_some_and(|row| self.list_state.marked.contains(&row))
+    }
+
+    pub fn clear_marks(&mut self) {
+        self.list_state.clear_marks();
+    }
+
+    /// Index of the first visible item
+    pub fn top_item(&self) -> usize {
+        self.list_state.top_item
+    }
+
+    /// Column of the scroll bar, above the filter row when one is shown
+    fn scrollbar_bounds(&self) -> Rect {
+        let bottom = self.bounds.b.y - i16::from(self.filter.is_some());
+        Rect::new(self.bounds.b.x - 1, self.bounds.a.y, self.bounds.b.x, bottom)
+    }
+
+    fn update_scrollbar_bounds(&mut self) {
+        let v_bounds = self.scrollbar_bounds();
+        if let Some(v_bar) = self.v_scrollbar.as_mut() {
+            v_bar.set_bounds(v_bounds);
+        }
+    }
+
+    /// Number of rows to scroll through: the items, or those the filter lets through
+    fn row_count(&self) -> usize {
+        self.filter.as_ref().map_or(self.items.len(), |filter| filter.rows.len())
+    }
+
+    /// Index of the item shown on `row`
+    fn item_at_row(&self, row: usize) -> Option<usize> {
+        match self.filter {
+            Some(ref filter) => filter.rows.get(row).copied(),
+            None => (row < self.items.len()).then_some(row),
+        }
+    }
+
+    /// Row showing the item at `index`, if the filter lets it through
+    fn row_of_item(&self, index: usize) -> Option<usize> {
+        match self.filter {
+            Some(ref filter) => filter.rows.binary_search(&index).ok(),
+            None => (index < self.items.len()).then_some(index),
         }
     }
 
+    /// Match the scroll bar to the item count and the top item
+    fn sync_scrollbar(&mut self) {
+        let visible_rows = self.visible_rows();
+        let max_top = self.list_state.max_top(visible_rows);
+        let top_item = self.list_state.top_item;
+        let columns = self.list_state.columns();
+        if let Some(v_bar) = self.v_scrollbar.as_mut() {
+            let page = if columns == 1 { visible_rows.saturating_sub(1).max(1) } else { visible_rows * (columns - 1) };
+            let step = if columns == 1 { 1 } else { visible_rows.max(1) };
+            v_bar.set_params(top_item as i32, 0, max_top as i32, page as i32, step as i32);
+        }
+    }
+
+    /// Give mouse events aimed at the scroll bar to it; true if it handled the event
+    fn handle_scrollbar_event(&mut self, event: &mut Event) -> bool {
+        let targeted = self.v_scrollbar.as_ref().is_some_and(|v_bar| match event.what {
+            EventType::MouseDown => v_bar.bounds().contains(event.mouse.pos),
+            EventType::MouseMove | EventType::MouseAuto | EventType::MouseUp => v_bar.has_mouse_capture(),
+            _ => false,
+        });
+        if !targeted {
+            return false;
+        }
+        // The list may have scrolled by keyboard since the last draw
+        self.sync_scrollbar();
+        let Some(v_bar) = self.v_scrollbar.as_mut() else {
+            return false;
+        };
+        v_bar.handle_event(event);
+        let value = v_bar.get_value().max(0) as usize;
+        let visible_rows = self.visible_rows();
+        // Multi-column lists keep a whole column at the left
+        let value = if self.list_state.columns() == 1 { value } else { value - value % visible_rows.max(1) };
+        self.list_state.top_item = value.min(self.list_state.max_top(visible_rows));
+        true
+    }
+
     /// Set the items in the list
     pub fn set_items(&mut self, items: Vec<String>) {
-        self.items = items;
-        self.list_state.set_range(self.items.len());
+        self.set_data_source(Box::new(VecDataSource::new(items)));
+    }
+
+    /// Set a data source; items are fetched lazily, only for the visible rows
+    pub fn set_data_source(&mut self, source: Box<dyn ListDataSource>) {
+        self.items = source;
+        self.search = None;
+        self.refilter();
     }
 
     /// Add an item to the list
+    /// A lazily computed data source is materialized into a `Vec` first.
     pub fn add_item(&mut self, item: String) {
-        self.items.push(item);
-        self.list_state.set_range(self.items.len());
+        if let Some(items) = self.items.items_mut() {
+            items.push(item);
+        } else {
+            let mut items: Vec<String> = (0..self.items.len()).map(|i| self.items.item(i).into_owned()).collect();
+            items.push(item);
+            self.items = Box::new(VecDataSource::new(items));
+        }
+        let index = self.items.len() - 1;
+        if let Some(filter) = self.filter.as_mut() {
+            if filter.matches(&self.items.item(index)) {
+                filter.rows.push(index);
+            }
+        }
+        self.list_state.set_range(self.row_count());
     }
 
     /// Clear all items
     pub fn clear(&mut self) {
-        self.items.clear();
-        self.list_state.set_range(0);
+        self.items = Box::new(VecDataSource::default());
+        self.search = None;
+        self.refilter();
     }
 
     /// Get the currently selected item index
+    ///
+    /// This is the index in the full list, also while a filter hides items.
     pub fn get_selection(&self) -> Option<usize> {
-        self.list_state.focused
+        self.list_state.focused.and_then(|row| self.item_at_row(row))
     }
 
     /// Get the currently selected item text
+    ///
+    /// `None` also when the item is built on demand by a
+    /// [data source](Self::set_data_source); use
+    /// [`selected_item_text()`](Self::selected_item_text) with those.
     pub fn get_selected_item(&self) -> Option<&str> {
-        self.list_state
-            .focused
-            .and_then(|idx| self.items.get(idx).map(|s| s.as_str()))
+        match self.items.item(self.get_selection()?) {
+            Cow::Borrowed(text) => Some(text),
+            Cow::Owned(_) => None,
+        }
+    }
+
+    /// Text of the currently selected item, from any data source
+    pub fn selected_item_text(&self) -> Option<Cow<'_, str>> {
+        self.get_selection().map(|idx| self.items.item(idx))
     }
 
     /// Set the selected item by index
+    ///
+    /// Ignored for an index out of range or hidden by the filter.
     pub fn set_selection(&mut self, index: usize) {
-        if index < self.items.len() {
-            let visible_rows = self.bounds.height_clamped() as usize;
-            self.list_state.focus_item(index, visible_rows);
+        if let Some(row) = self.row_of_item(index) {
+            let visible_rows = self.visible_rows();
+            self.list_state.focus_item(row, visible_rows);
         }
     }
 
@@ -83,42 +300,207 @@ impl ListBox {
         self.items.len()
     }
 
+    /// Find all items containing `query`, in list order
+    ///
+    /// The matched substrings are highlighted when drawing until
+    /// [`clear_search()`](Self::clear_search) is called. An empty query clears the search.
+    pub fn find_all(&mut self, query: &str, case_sensitive: bool) -> Vec<usize> {
+        if query.is_empty() {
+            self.search = None;
+            return Vec::new();
+        }
+
+        let matches: Vec<usize> = (0..self.items.len())
+            .filter(|&i| !match_positions(&self.items.item(i), query, case_sensitive).is_empty())
+            .collect();
+        self.search = Some(ListSearch {
+            query: query.to_string(),
+            case_sensitive,
+            matches: matches.clone(),
+            current: None,
+        });
+        matches
+    }
+
+    /// Select the `n`-th match of the last find_all(), wrapping around
+    /// Returns the selected item index, or None if there are no matches.
+    pub fn select_match(&mut self, n: usize) -> Option<usize> {
+        let search = self.search.as_mut()?;
+        if search.matches.is_empty() {
+            return None;
+        }
+        let n = n % search.matches.len();
+        search.current = Some(n);
+        let item = search.matches[n];
+        self.set_selection(item);
+        Some(item)
+    }
+
+    /// Select the match after the current one (the first match if none is selected yet)
+    pub fn select_next_match(&mut self) -> Option<usize> {
+        let next = match self.search.as_ref()?.current {
+            Some(current) => current + 1,
+            None => 0,
+        };
+        self.select_match(next)
+    }
+
+    /// Select the match before the current one, wrapping to the last match
+    pub fn select_prev_match(&mut self) -> Option<usize> {
+        let search = self.search.as_ref()?;
+        let count = search.matches.len();
+        let prev = match search.current {
+            Some(current) if current > 0 => current - 1,
+            _ => count.saturating_sub(1),
+        };
+        self.select_match(prev)
+    }
+
+    /// Stop highlighting the matches of the last find_all()
+    pub fn clear_search(&mut self) {
+        self.search = None;
+    }
+
+    /// Show only the items containing `text` (case-insensitive)
+    ///
+    /// The filter row appears at the bottom of the list, even for an empty
+    /// text. The selected item stays selected if it is still shown.
+    pub fn set_filter(&mut self, text: &str) {
+        let opened = self.filter.is_none();
+        let all_rows = || ListFilter { text: String::new(), rows: (0..self.items.len()).collect() };
+        self.filter.get_or_insert_with(all_rows).text = text.to_string();
+        self.refilter();
+        if opened {
+            self.update_scrollbar_bounds();
+        }
+    }
+
+    /// Show every item again, keeping the selected one selected
+    pub fn clear_filter(&mut self) {
+        if self.filter.is_some() {
+            let selected = self.get_selection();
+            self.filter = None;
+            self.list_state.set_range(self.items.len());
+            if let Some(index) = selected {
+                self.set_selection(index);
+            }
+            self.update_scrollbar_bounds();
+        }
+    }
+
+    /// Text of the inline filter, if one is open
+    pub fn filter_text(&self) -> Option<&str> {
+        self.filter.as_ref().map(|filter| filter.text.as_str())
+    }
+
+    /// Number of items shown (all of them unless a filter hides some)
+    pub fn shown_count(&self) -> usize {
+        self.row_count()
+    }
+
+    /// Recompute the rows the filter lets through after the items or the text changed
+    ///
+    /// The marks are cleared, as they refer to rows.
+    fn refilter(&mut self) {
+        self.list_state.clear_marks();
+        if self.filter.is_none() {
+            self.list_state.set_range(self.items.len());
+            return;
+        }
+        let selected = self.get_selection();
+        if let Some(filter) = self.filter.as_mut() {
+            let items = &self.items;
+            filter.rows = (0..items.len()).filter(|&i| filter.matches(&items.item(i))).collect();
+        }
+        self.list_state.set_range(self.row_count());
+        match selected.and_then(|index| self.row_of_item(index)) {
+            Some(row) => {
+                let visible_rows = self.visible_rows();
+                self.list_state.focus_item(row, visible_rows);
+            }
+            None if self.row_count() > 0 => self.list_state.focus_item(0, self.visible_rows()),
+            None => {}
+        }
+    }
+
+    /// Copy the selected item's text to the clipboard
+    pub fn copy_selected_item(&self) {
+        if let Some(text) = self.selected_item_text() {
+            clipboard::set_clipboard(&text);
+        }
+    }
+
+    /// Copy the shown items to the clipboard, one per line
+    pub fn copy_shown_items(&self) {
+        let text: Vec<Cow<'_, str>> = (0..self.row_count())
+            .filter_map(|row| self.item_at_row(row))
+            .map(|index| self.items.item(index))
+            .collect();
+        clipboard::set_clipboard(&text.join("\n"));
+    }
+
+    /// Copy and filter keys; returns true if the key was used
+    fn handle_filter_key(&mut self, event: &mut Event) -> bool {
+        match event.key_code {
+            KB_CTRL_C if event.key_modifiers.contains(KeyModifiers::SHIFT) => self.copy_shown_items(),
+            KB_CTRL_C => self.copy_selected_item(),
+            KB_CTRL_F => {
+                let text = self.filter_text().unwrap_or_default().to_string();
+                self.set_filter(&text);
+            }
+            KB_ESC if self.filter.is_some() => self.clear_filter(),
+            KB_BACKSPACE if self.filter.is_some() => {
+                let mut text = self.filter_text().unwrap_or_default().to_string();
+                text.pop();
+                self.set_filter(&text);
+            }
+            key_code if self.filter.is_some() && key_char(key_code).is_some() => {
+                let mut text = self.filter_text().unwrap_or_default().to_string();
+                text.extend(key_char(key_code));
+                self.set_filter(&text);
+            }
+            _ => return false,
+        }
+        event.clear();
+        true
+    }
+
     // Convenience methods for compatibility with existing code
     // These delegate to ListViewerState methods
 
     /// Move selection up (convenience method)
     pub fn select_prev(&mut self) {
-        let visible_rows = self.bounds.height_clamped() as usize;
+        let visible_rows = self.visible_rows();
         self.list_state.focus_prev(visible_rows);
     }
 
     /// Move selection down (convenience method)
     pub fn select_next(&mut self) {
-        let visible_rows = self.bounds.height_clamped() as usize;
+        let visible_rows = self.visible_rows();
         self.list_state.focus_next(visible_rows);
     }
 
     /// Select first item (convenience method)
     pub fn select_first(&mut self) {
-        let visible_rows = self.bounds.height_clamped() as usize;
+        let visible_rows = self.visible_rows();
         self.list_state.focus_first(visible_rows);
     }
 
     /// Select last item (convenience method)
     pub fn select_last(&mut self) {
-        let visible_rows = self.bounds.height_clamped() as usize;
+        let visible_rows = self.visible_rows();
         self.list_state.focus_last(visible_rows);
     }
 
     /// Page up (convenience method)
     pub fn page_up(&mut self) {
-        let visible_rows = self.bounds.height_clamped() as usize;
+        let visible_rows = self.visible_rows();
         self.list_state.focus_page_up(visible_rows);
     }
 
     /// Page down (convenience method)
     pub fn page_down(&mut self) {
-        let visible_rows = self.bounds.height_clamped() as usize;
+        let visible_rows = self.visible_rows();
         self.list_state.focus_page_down(visible_rows);
     }
 }
@@ -130,11 +512,13 @@ impl View for ListBox {
 
     fn set_bounds(&mut self, bounds: Rect) {
         self.bounds = bounds;
+        self.update_scrollbar_bounds();
     }
 
     fn draw(&mut self, terminal: &mut Terminal) {
-        let width = self.bounds.width_clamped() as usize;
-        let height = self.bounds.height_clamped() as usize;
+        let scrollbar_width = usize::from(self.v_scrollbar.is_some());
+        let width = (self.bounds.width_clamped() as usize).saturating_sub(scrollbar_width);
+        let height = self.visible_rows();
 
         // ListBox palette indices:
         // 1: Normal, 2: Focused, 3: Selected, 4: Divider
@@ -144,38 +528,92 @@ impl View for ListBox {
             self.map_color(LISTBOX_NORMAL) // Normal
         };
         let color_selected = self.map_color(LISTBOX_SELECTED); // Selected
+        let color_divider = self.map_color(LISTBOX_DIVIDER); // Divider
+
+        // Columns share the width; all but the last end with a divider
+        let columns = self.list_state.columns();
+        let column_width = self.column_width();
 
         // Draw visible items
         for i in 0..height {
             let mut buf = DrawBuffer::new(width);
-            let item_idx = self.list_state.top_item + i;
 
-            if item_idx < self.items.len() {
-                let is_selected = Some(item_idx) == self.list_state.focused;
-                let color = if is_selected {
-                    color_selected
+            for column in 0..columns {
+                let x = column * column_width;
+                if x >= width {
+                    break;
+                }
+                let last = column + 1 == columns;
+                let cell_width = if last { width - x } else { column_width.saturating_sub(1) };
+                let mut cell = DrawBuffer::new(cell_width);
+                let row = self.list_state.top_item + column * height + i;
+
+                if let Some(item_idx) = self.item_at_row(row) {
+                    // A multi-select list shows its marks; the focused row is drawn inverted
+                    let is_focused = Some(row) == self.list_state.focused;
+                    let color = if self.is_selected(row) {
+                        color_selected
+                    } else if is_focused && self.list_state.multi_select {
+                        color_normal.swap()
+                    } else {
+                        color_normal
+                    };
+
+                    let text = self.items.item(item_idx);
+
+                    // Highlight the substrings matched by find_all()
+                    let text_len = match self.search {
+                        Some(ref search) => {
+                            let query_len = search.query.chars().count();
+                            let positions = match_positions(&text, &search.query, search.case_sensitive);
+                            cell.move_styled(0, &highlight_runs(&text, &positions, query_len, color, color.swap()))
+                        }
+                        None => cell.move_str_clipped(0, &text, color, cell_width),
+                    };
+
+                    // Fill rest of the column with spaces
+                    if text_len < cell_width {
+                        cell.move_char(text_len, ' ', color, cell_width - text_len);
+                    }
                 } else {
-                    color_normal
-                };
-
-                let text = &self.items[item_idx];
-                buf.move_str(0, text, color);
+                    // Empty cell
+                    cell.move_char(0, ' ', color_normal, cell_width);
+                }
 
-                // Fill rest of line with spaces
-                let text_len = text.len();
-                if text_len < width {
-                    buf.move_char(text_len, ' ', color, width - text_len);
+                buf.move_buf(x, &cell.data, cell_width);
+                if !last {
+                    buf.move_char(x + cell_width, '│', color_divider, 1);
                 }
-            } else {
-                // Empty line
-                buf.move_char(0, ' ', color_normal, width);
             }
 
             write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y + i as i16, &buf);
         }
+
+        self.sync_scrollbar();
+        if let Some(v_bar) = self.v_scrollbar.as_mut() {
+            v_bar.draw(terminal);
+        }
+
+        // Filter row: the typed text, and how many items it lets through
+        if let Some(ref filter) = self.filter {
+            let width = self.bounds.width_clamped() as usize;
+            let mut buf = DrawBuffer::new(width);
+            buf.move_char(0, ' ', color_selected, width);
+            let count = format!(" {} of {} ", filter.rows.len(), self.items.len());
+            let count_width = count.chars().count();
+            buf.move_str_clipped(0, &format!("Filter: {}", filter.text), color_selected, width.saturating_sub(count_width));
+            if count_width <= width {
+                buf.move_str(width - count_width, &count, color_selected);
+            }
+            write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.b.y - 1, &buf);
+        }
     }
 
     fn handle_event(&mut self, event: &mut Event) {
+        if self.handle_scrollbar_event(event) {
+            return;
+        }
+
         // Handle double-click BEFORE handle_list_event consumes it
         // This ensures double-click triggers the command even though single-click is handled
         if event.what == EventType::MouseDown {
@@ -189,12 +627,16 @@ impl View for ListBox {
                     // Without this, the selection would still point to the previously selected item,
                     // causing FileDialog to act on the wrong file/directory (e.g., double-clicking a
                     // folder would close the dialog instead of navigating into it)
-                    let relative_y = (mouse_pos.y - self.bounds.a.y) as usize;
-                    let clicked_item = self.list_state.top_item + relative_y;
+                    let clicked_row = event.local_mouse(self.bounds).y as usize;
+                    let visible_rows = self.visible_rows();
+                    if clicked_row >= visible_rows {
+                        // The filter row
+                        event.clear();
+                        return;
+                    }
 
                     // Update the selection to the double-clicked item
-                    if clicked_item < self.items.len() {
-                        let visible_rows = self.bounds.height_clamped() as usize;
+                    if let Some(clicked_item) = self.item_at_point(mouse_pos) {
                         self.list_state.focus_item(clicked_item, visible_rows);
                     }
 
@@ -205,6 +647,11 @@ impl View for ListBox {
             }
         }
 
+        // Clipboard and inline filter keys
+        if event.what == EventType::Keyboard && self.is_focused() && self.handle_filter_key(event) {
+            return;
+        }
+
         // First try standard list navigation (from ListViewer trait)
         // This handles single-click, arrow keys, etc.
         if self.handle_list_event(event) {
@@ -220,23 +667,9 @@ impl View for ListBox {
                 }
             }
             EventType::MouseDown => {
-                // Single click is already handled by handle_list_event above
+                // Single click and the wheel are already handled by handle_list_event above
                 // This code path is for any MouseDown events that weren't handled
             }
-            EventType::MouseWheelUp => {
-                let mouse_pos = event.mouse.pos;
-                if self.bounds.contains(mouse_pos) {
-                    self.select_prev();
-                    event.clear();
-                }
-            }
-            EventType::MouseWheelDown => {
-                let mouse_pos = event.mouse.pos;
-                if self.bounds.contains(mouse_pos) {
-                    self.select_next();
-                    event.clear();
-                }
-            }
             _ => {}
         }
     }
@@ -245,6 +678,10 @@ impl View for ListBox {
         true
     }
 
+    fn has_mouse_capture(&self) -> bool {
+        self.v_scrollbar.as_ref().is_some_and(|v_bar| v_bar.has_mouse_capture())
+    }
+
     fn state(&self) -> StateFlags {
         self.state
     }
@@ -258,7 +695,17 @@ impl View for ListBox {
     }
 
     fn get_list_selection(&self) -> usize {
-        self.list_state.focused.unwrap_or(0)
+        self.get_selection().unwrap_or(0)
+    }
+
+    fn get_data(&self) -> Option<DataValue> {
+        Some(DataValue::Selection(self.get_selection()))
+    }
+
+    fn set_data(&mut self, value: &DataValue) {
+        if let DataValue::Selection(Some(index)) = value {
+            self.set_selection(*index);
+        }
     }
 
     fn set_owner(&mut self, owner: *const dyn View) {
@@ -301,11 +748,68 @@ impl ListViewer for ListBox {
         &mut self.list_state
     }
 
+    /// `item` is a row: with a filter, the item-th shown item
     fn get_text(&self, item: usize, _max_len: usize) -> String {
-        self.items.get(item).cloned().unwrap_or_default()
+        self.item_at_row(item).map(|index| self.items.item(index).into_owned()).unwrap_or_default()
+    }
+
+    /// One row fewer while the filter row is shown
+    fn visible_rows(&self) -> usize {
+        (self.bounds.height_clamped() as usize).saturating_sub(usize::from(self.filter.is_some()))
+    }
+
+    /// The columns share the width left of the scroll bar
+    fn column_width(&self) -> usize {
+        let width = (self.bounds.width_clamped() as usize).saturating_sub(usize::from(self.v_scrollbar.is_some()));
+        (width / self.list_state.columns()).max(1)
     }
 }
 
+/// Split `text` into runs alternating `normal` and `highlight` attributes,
+/// highlighting `len` characters at each of the (sorted) char `positions`
+fn highlight_runs<'a>(text: &'a str, positions: &[usize], len: usize, normal: Attr, highlight: Attr) -> Vec<(&'a str, Attr)> {
+    let byte_offset = |chars: usize| text.char_indices().nth(chars).map_or(text.len(), |(i, _)| i);
+    let mut runs = Vec::with_capacity(positions.len() * 2 + 1);
+    let mut last = 0;
+    for &pos in positions {
+        let start = byte_offset(pos);
+        let end = byte_offset(pos + len);
+        runs.push((&text[last..start], normal));
+        runs.push((&text[start..end], highlight));
+        last = end;
+    }
+    runs.push((&text[last..], normal));
+    runs
+}
+
+/// Character positions of the non-overlapping occurrences of `query` in `text`
+fn match_positions(text: &str, query: &str, case_sensitive: bool) -> Vec<usize> {
+    let text: Vec<char> = text.chars().collect();
+    let query: Vec<char> = query.chars().collect();
+    let same = |a: char, b: char| {
+        if case_sensitive {
+            a == b
+        } else {
+            a.to_lowercase().eq(b.to_lowercase())
+        }
+    };
+
+    let mut positions = Vec::new();
+    if query.is_empty() {
+        return positions;
+    }
+    let mut i = 0;
+    while i + query.len() <= text.len() {
+        if text[i..i + query.len()].iter().zip(&query).all(|(&a, &b)| same(a, b)) {
+            positions.push(i);
+            i += query.len();
+        } else {
+            i += 1;
+        }
+    }
+    positions
+}
+
 /// Builder for creating listboxes with a fluent API.
 pub struct ListBoxBuilder {
     bounds: Option<Rect>,
@@ -436,4 +940,426 @@ mod tests {
         assert_eq!(listbox.item_count(), 0);
         assert_eq!(listbox.get_selection(), None);
     }
+
+    #[test]
+    fn test_listbox_page_keys_keep_one_row_of_context() {
+        use crate::core::event::{KB_PGDN, KB_PGUP};
+
+        let mut listbox = ListBox::new(Rect::new(0, 0, 20, 10), 1000);
+        listbox.set_items((0..35).map(|i| format!("Item {i}")).collect());
+
+        let mut event = Event::keyboard(KB_PGDN);
+        listbox.handle_event(&mut event);
+        assert_eq!(listbox.get_selection(), Some(18));
+        assert_eq!(listbox.top_item(), 9);
+
+        listbox.page_down();
+        listbox.page_down();
+        assert_eq!(listbox.get_selection(), Some(34));
+        assert_eq!(listbox.top_item(), 25);
+
+        let mut event = Event::keyboard(KB_PGUP);
+        listbox.handle_event(&mut event);
+        assert_eq!(listbox.get_selection(), Some(16));
+        assert_eq!(listbox.top_item(), 16);
+    }
+
+    #[test]
+    fn test_wheel_scrolls_without_moving_selection() {
+        let mut listbox = ListBox::new(Rect::new(0, 0, 20, 10), 1000);
+        listbox.set_items((0..35).map(|i| format!("Item {i}")).collect());
+        listbox.set_selection(2);
+
+        use crate::core::geometry::Point;
+
+        let mut event = Event::wheel(Point::new(5, 5), 6);
+        listbox.handle_event(&mut event);
+        assert_eq!(listbox.top_item(), 6);
+        assert_eq!(listbox.get_selection(), Some(2));
+
+        // Clamped at both ends
+        let mut event = Event::wheel(Point::new(5, 5), 20);
+        listbox.handle_event(&mut event);
+        assert_eq!(listbox.top_item(), 25);
+        let mut event = Event::wheel(Point::new(5, 5), -30);
+        listbox.handle_event(&mut event);
+        assert_eq!(listbox.top_item(), 0);
+    }
+
+    #[test]
+    fn test_only_double_click_activates() {
+        use crate::core::geometry::Point;
+
+        let mut listbox = ListBox::new(Rect::new(0, 0, 20, 10), 1000);
+        listbox.set_items((0..5).map(|i| format!("Item {i}")).collect());
+
+        // Clicking the already selected item again is just a click
+        for _ in 0..2 {
+            let mut event = Event::mouse(EventType::MouseDown, Point::new(3, 2), MB_LEFT_BUTTON, false);
+            listbox.handle_event(&mut event);
+            assert_eq!(event.what, EventType::Nothing);
+            assert_eq!(listbox.get_selection(), Some(2));
+        }
+
+        let mut event = Event::mouse(EventType::MouseDown, Point::new(3, 3), MB_LEFT_BUTTON, true);
+        listbox.handle_event(&mut event);
+        assert_eq!((event.what, event.command), (EventType::Command, 1000));
+        assert_eq!(listbox.get_selection(), Some(3));
+    }
+
+    #[test]
+    fn test_double_click_row_is_relative_to_listbox() {
+        use crate::core::geometry::Point;
+
+        let mut listbox = ListBox::new(Rect::new(5, 4, 25, 14), 1000);
+        listbox.set_items((0..5).map(|i| format!("Item {i}")).collect());
+
+        let mut event = Event::mouse(EventType::MouseDown, Point::new(7, 6), MB_LEFT_BUTTON, true);
+        assert_eq!(event.local_mouse(listbox.bounds), Point::new(2, 2));
+        listbox.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Command);
+        assert_eq!(listbox.get_selection(), Some(2));
+    }
+
+    /// Data source that computes items on demand and records which indices were fetched
+    struct ComputedSource {
+        len: usize,
+        requested: std::rc::Rc<std::cell::RefCell<Vec<usize>>>,
+    }
+
+    impl ListDataSource for ComputedSource {
+        fn len(&self) -> usize {
+            self.len
+        }
+
+        fn item(&self, index: usize) -> Cow<'_, str> {
+            self.requested.borrow_mut().push(index);
+            Cow::Owned(format!("Row {index}"))
+        }
+    }
+
+    #[test]
+    fn test_data_source_fetches_only_visible_items() {
+        let requested = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let mut listbox = ListBox::new(Rect::new(0, 0, 20, 5), 1000);
+        listbox.set_data_source(Box::new(ComputedSource {
+            len: 100_000,
+            requested: requested.clone(),
+        }));
+        assert_eq!(listbox.item_count(), 100_000);
+
+        listbox.set_selection(50_000);
+        let top = listbox.top_item();
+        let mut terminal = Terminal::new_headless(20, 5);
+        listbox.draw(&mut terminal);
+
+        let fetched = requested.borrow().clone();
+        assert_eq!(fetched, (top..top + 5).collect::<Vec<_>>());
+        assert_eq!(terminal.read_cell(0, (50_000 - top) as i16).unwrap().ch, 'R');
+        assert_eq!(listbox.selected_item_text().as_deref(), Some("Row 50000"));
+        assert_eq!(listbox.get_selected_item(), None, "built on demand, nothing to borrow");
+    }
+
+    #[test]
+    fn test_add_item_materializes_computed_source() {
+        let requested = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let mut listbox = ListBox::new(Rect::new(0, 0, 20, 5), 1000);
+        listbox.set_data_source(Box::new(ComputedSource { len: 3, requested }));
+
+        listbox.add_item("Extra".to_string());
+        assert_eq!(listbox.item_count(), 4);
+        assert_eq!(listbox.get_text(0, 20), "Row 0");
+        assert_eq!(listbox.get_text(3, 20), "Extra");
+    }
+
+    fn fruit_listbox() -> ListBox {
+        let mut listbox = ListBox::new(Rect::new(0, 0, 20, 5), 1000);
+        listbox.set_items(
+            ["Apple", "banana", "Pineapple", "APPLE pie", "Cherry"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        );
+        listbox
+    }
+
+    #[test]
+    fn test_find_all_case_sensitivity() {
+        let mut listbox = fruit_listbox();
+        assert_eq!(listbox.find_all("apple", true), vec![2]);
+        assert_eq!(listbox.find_all("apple", false), vec![0, 2, 3]);
+        assert!(listbox.find_all("kiwi", false).is_empty());
+        assert!(listbox.find_all("", false).is_empty());
+    }
+
+    #[test]
+    fn test_select_match_cycles() {
+        let mut listbox = fruit_listbox();
+        listbox.find_all("apple", false);
+
+        assert_eq!(listbox.select_next_match(), Some(0));
+        assert_eq!(listbox.select_next_match(), Some(2));
+        assert_eq!(listbox.select_next_match(), Some(3));
+        assert_eq!(listbox.select_next_match(), Some(0));
+        assert_eq!(listbox.get_selection(), Some(0));
+        assert_eq!(listbox.select_prev_match(), Some(3));
+        assert_eq!(listbox.select_match(4), Some(2));
+    }
+
+    #[test]
+    fn test_selected_row_drawn_in_selected_color() {
+        use crate::terminal::{render_to_cells, row_text};
+
+        let mut listbox = fruit_listbox();
+        listbox.set_focus(true);
+        listbox.set_selection(2);
+        let cells = render_to_cells(&mut listbox, 20, 5);
+
+        assert_eq!(row_text(&cells[2]).trim_end(), "Pineapple");
+        let selected = listbox.map_color(LISTBOX_SELECTED);
+        let focused = listbox.map_color(LISTBOX_FOCUSED);
+        assert_ne!(selected, focused);
+        for (y, row) in cells.iter().enumerate() {
+            let expected = if y == 2 { selected } else { focused };
+            assert!(row.iter().all(|cell| cell.attr == expected), "row {y} fills the width in one color");
+        }
+    }
+
+    #[test]
+    fn test_scrollbar_pages_and_drags() {
+        use crate::core::geometry::Point;
+        use crate::terminal::{render_to_cells, row_text};
+
+        let mut listbox = ListBox::new(Rect::new(0, 0, 20, 10), 1000);
+        listbox.set_items((0..50).map(|i| format!("Item {i:02} with a long name")).collect());
+        listbox.show_scrollbar(true);
+
+        let cells = render_to_cells(&mut listbox, 20, 10);
+        assert_eq!(row_text(&cells[0]), "Item 00 with a long▲");
+        assert_eq!(row_text(&cells[1]).chars().last(), Some('█'), "thumb at the top");
+        assert_eq!(row_text(&cells[9]).chars().last(), Some('▼'));
+
+        let mouse = |what, y, buttons| Event::mouse(what, Point::new(19, y), buttons, false);
+
+        // Clicking the track below the thumb pages down
+        listbox.handle_event(&mut mouse(EventType::MouseDown, 5, MB_LEFT_BUTTON));
+        assert_eq!(listbox.top_item(), 9);
+        assert_eq!(listbox.get_selection(), Some(0));
+
+        // Dragging the thumb to the bottom of the track shows the last page
+        let thumb_y = (1..9).find(|&y| row_text(&render_to_cells(&mut listbox, 20, 10)[y]).ends_with('█')).unwrap();
+        listbox.handle_event(&mut mouse(EventType::MouseDown, thumb_y as i16, MB_LEFT_BUTTON));
+        assert!(listbox.has_mouse_capture());
+        listbox.handle_event(&mut mouse(EventType::MouseMove, 4, MB_LEFT_BUTTON));
+        let halfway = listbox.top_item();
+        assert!(halfway > 9 && halfway < 40, "{halfway}");
+        listbox.handle_event(&mut mouse(EventType::MouseUp, 8, 0));
+        assert!(!listbox.has_mouse_capture());
+        assert_eq!(listbox.top_item(), 40);
+        assert!(row_text(&render_to_cells(&mut listbox, 20, 10)[8]).ends_with('█'), "thumb at the bottom");
+    }
+
+    #[test]
+    fn test_matches_highlighted_in_draw() {
+        let mut listbox = fruit_listbox();
+        listbox.find_all("app", false);
+        let mut terminal = Terminal::new_headless(20, 5);
+        listbox.draw(&mut terminal);
+
+        // "Pineapple" on row 2: "app" at columns 4..7
+        let plain = terminal.read_cell(0, 2).unwrap().attr;
+        assert_eq!(terminal.read_cell(3, 2).unwrap().attr, plain);
+        assert_eq!(terminal.read_cell(4, 2).unwrap().attr, plain.swap());
+        assert_eq!(terminal.read_cell(6, 2).unwrap().attr, plain.swap());
+        assert_eq!(terminal.read_cell(7, 2).unwrap().attr, plain);
+
+        listbox.clear_search();
+        listbox.draw(&mut terminal);
+        assert_eq!(terminal.read_cell(4, 2).unwrap().attr, plain);
+    }
+
+    fn log_listbox() -> ListBox {
+        let mut listbox = ListBox::new(Rect::new(0, 0, 30, 5), 1000);
+        listbox.set_items(
+            ["open ok", "read ERROR", "close ok", "write error", "seek ok", "sync error"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        );
+        listbox.set_focus(true);
+        listbox
+    }
+
+    fn type_keys(listbox: &mut ListBox, keys: &[u16]) {
+        for &key in keys {
+            let mut event = Event::keyboard(key);
+            listbox.handle_event(&mut event);
+            assert_eq!(event.what, EventType::Nothing, "key {key:#x} not used");
+        }
+    }
+
+    #[test]
+    fn test_filter_keeps_true_indices() {
+        use crate::core::event::KB_DOWN;
+        use crate::terminal::{render_to_cells, row_text};
+
+        let mut listbox = log_listbox();
+        listbox.set_selection(3);
+        type_keys(&mut listbox, &[KB_CTRL_F, 'e' as u16, 'r' as u16, 'r' as u16]);
+        assert_eq!(listbox.filter_text(), Some("err"));
+        assert_eq!(listbox.shown_count(), 3);
+        assert_eq!(listbox.get_selection(), Some(3), "the selected item is still shown");
+
+        let cells = render_to_cells(&mut listbox, 30, 5);
+        assert_eq!(row_text(&cells[0]).trim_end(), "read ERROR");
+        assert_eq!(row_text(&cells[2]).trim_end(), "sync error");
+        assert_eq!(row_text(&cells[3]).trim_end(), "");
+        assert_eq!(row_text(&cells[4]), "Filter: err            3 of 6 ");
+
+        // Moving and activating reports the index in the full list
+        type_keys(&mut listbox, &[KB_DOWN]);
+        assert_eq!(listbox.get_selected_item(), Some("sync error"));
+        let mut event = Event::keyboard(KB_ENTER);
+        listbox.handle_event(&mut event);
+        assert_eq!((event.what, event.command), (EventType::Command, 1000));
+        assert_eq!(listbox.get_selection(), Some(5));
+        assert_eq!(listbox.get_list_selection(), 5);
+
+        // Hidden items can't be selected
+        listbox.set_selection(0);
+        assert_eq!(listbox.get_selection(), Some(5));
+
+        // A filter hiding the selected item selects the first match
+        type_keys(&mut listbox, &[KB_BACKSPACE, KB_BACKSPACE, KB_BACKSPACE, 'W' as u16, 'r' as u16]);
+        assert_eq!(listbox.filter_text(), Some("Wr"));
+        assert_eq!(listbox.get_selection(), Some(3));
+
+        type_keys(&mut listbox, &[KB_ESC]);
+        assert_eq!(listbox.filter_text(), None);
+        assert_eq!(listbox.shown_count(), 6);
+        assert_eq!(listbox.get_selection(), Some(3));
+        assert_eq!(row_text(&render_to_cells(&mut listbox, 30, 5)[4]).trim_end(), "seek ok");
+    }
+
+    #[test]
+    fn test_filter_accepts_non_ascii_characters() {
+        let mut listbox = ListBox::new(Rect::new(0, 0, 30, 5), 1000);
+        listbox.set_items(vec!["Café".to_string(), "Cafe".to_string(), "東京".to_string()]);
+        listbox.set_focus(true);
+
+        type_keys(&mut listbox, &[KB_CTRL_F, 'é' as u16]);
+        assert_eq!(listbox.filter_text(), Some("é"));
+        assert_eq!(listbox.get_selected_item(), Some("Café"));
+
+        type_keys(&mut listbox, &[KB_BACKSPACE, '京' as u16]);
+        assert_eq!(listbox.filter_text(), Some("京"));
+        assert_eq!((listbox.shown_count(), listbox.get_selected_item()), (1, Some("東京")));
+    }
+
+    #[test]
+    fn test_ctrl_c_copies_selected_or_shown_items() {
+        let mut listbox = log_listbox();
+        listbox.set_selection(2);
+
+        // The OSC 52 queue is per thread, so other tests using the clipboard don't interfere
+        clipboard::enable_osc52(true);
+        type_keys(&mut listbox, &[KB_CTRL_C]);
+        let selected = clipboard::take_pending_osc52();
+
+        listbox.set_filter("error");
+        let mut event = Event::keyboard(KB_CTRL_C);
+        event.key_modifiers = KeyModifiers::CONTROL | KeyModifiers::SHIFT;
+        listbox.handle_event(&mut event);
+        let shown = clipboard::take_pending_osc52();
+        clipboard::enable_osc52(false);
+
+        assert_eq!(selected, clipboard::osc52_sequence("close ok"));
+        assert_eq!(shown, clipboard::osc52_sequence("read ERROR\nwrite error\nsync error"));
+    }
+
+    #[test]
+    fn test_columns_layout_navigation_and_clicks() {
+        use crate::core::event::{KB_DOWN, KB_LEFT, KB_PGDN, KB_RIGHT};
+        use crate::core::geometry::Point;
+        use crate::terminal::{render_to_cells, row_text};
+
+        let mut listbox = ListBox::new(Rect::new(0, 0, 21, 4), 1000);
+        listbox.set_items((0..20).map(|i| format!("Item {i}")).collect());
+        listbox.set_columns(3);
+        assert_eq!(listbox.columns(), 3);
+
+        // Items flow down each column, columns are separated by a divider
+        let cells = render_to_cells(&mut listbox, 21, 4);
+        assert_eq!(row_text(&cells[0]), "Item 0│Item 4│Item 8 ");
+        assert_eq!(row_text(&cells[3]), "Item 3│Item 7│Item 11");
+
+        let key = |listbox: &mut ListBox, key_code| listbox.handle_event(&mut Event::keyboard(key_code));
+        key(&mut listbox, KB_RIGHT);
+        assert_eq!(listbox.get_selection(), Some(4));
+        key(&mut listbox, KB_DOWN);
+        assert_eq!(listbox.get_selection(), Some(5));
+        key(&mut listbox, KB_LEFT);
+        assert_eq!(listbox.get_selection(), Some(1));
+
+        // Moving past the last column scrolls by a whole column
+        key(&mut listbox, KB_RIGHT);
+        key(&mut listbox, KB_RIGHT);
+        key(&mut listbox, KB_RIGHT);
+        assert_eq!((listbox.get_selection(), listbox.top_item()), (Some(13), 4));
+        key(&mut listbox, KB_PGDN);
+        assert_eq!((listbox.get_selection(), listbox.top_item()), (Some(19), 8));
+
+        // Clicks pick the column under the mouse
+        let mut event = Event::mouse(EventType::MouseDown, Point::new(9, 2), MB_LEFT_BUTTON, false);
+        listbox.handle_event(&mut event);
+        assert_eq!(listbox.get_selection(), Some(14));
+        let mut event = Event::mouse(EventType::MouseDown, Point::new(16, 3), MB_LEFT_BUTTON, true);
+        listbox.handle_event(&mut event);
+        assert_eq!((event.what, listbox.get_selection()), (EventType::Command, Some(19)));
+    }
+
+    #[test]
+    fn test_ctrl_and_shift_clicks_mark_items() {
+        use crate::core::geometry::Point;
+
+        let mut listbox = ListBox::new(Rect::new(0, 0, 20, 20), 1000);
+        listbox.set_items((0..20).map(|i| format!("Item {i}")).collect());
+        listbox.set_multi_select(true);
+        let click = |listbox: &mut ListBox, item: i16, modifiers| {
+            listbox.handle_event(&mut Event::mouse_with(EventType::MouseDown, Point::new(2, item), MB_LEFT_BUTTON, modifiers));
+        };
+
+        // A plain click marks one item and sets the anchor
+        click(&mut listbox, 3, KeyModifiers::empty());
+        assert_eq!(listbox.marked_items(), vec![3]);
+
+        // Ctrl+click toggles single items, keeping the others
+        click(&mut listbox, 7, KeyModifiers::CONTROL);
+        click(&mut listbox, 12, KeyModifiers::CONTROL);
+        assert_eq!(listbox.marked_items(), vec![3, 7, 12]);
+        click(&mut listbox, 7, KeyModifiers::CONTROL);
+        assert_eq!(listbox.marked_items(), vec![3, 12]);
+        assert_eq!(listbox.get_selection(), Some(7));
+
+        // Shift+click marks the range from the anchor, in either direction
+        click(&mut listbox, 6, KeyModifiers::SHIFT);
+        assert_eq!(listbox.marked_items(), vec![3, 4, 5, 6]);
+        click(&mut listbox, 0, KeyModifiers::SHIFT);
+        assert_eq!(listbox.marked_items(), vec![0, 1, 2, 3]);
+        click(&mut listbox, 18, KeyModifiers::CONTROL | KeyModifiers::SHIFT);
+        assert_eq!(listbox.marked_items(), (0..=18).collect::<Vec<_>>());
+
+        // A plain click starts over
+        click(&mut listbox, 15, KeyModifiers::empty());
+        assert_eq!(listbox.marked_items(), vec![15]);
+        click(&mut listbox, 17, KeyModifiers::SHIFT);
+        assert_eq!(listbox.marked_items(), vec![15, 16, 17]);
+        assert!(listbox.is_marked(16) && !listbox.is_marked(14));
+
+        // A single-select list ignores the modifiers
+        listbox.set_multi_select(false);
+        click(&mut listbox, 5, KeyModifiers::CONTROL);
+        assert!(listbox.marked_items().is_empty());
+        assert_eq!(listbox.get_selection(), Some(5));
+    }
 }
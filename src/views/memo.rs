@@ -1,16 +1,25 @@
 // (C) 2025 - Enzo Lombardi
 
 //! Memo view - multi-line text input with scrolling and editing support.
+//!
+//! For notes and comments fields in dialogs: Enter inserts a line break and
+//! Tab moves on to the next control. Bind a [`TextBuffer`] with
+//! [`Memo::bind_buffer()`] (or [`MemoBuilder::buffer()`]) to read the text
+//! back, from any thread, as with InputLine. [`Memo::bind_data()`] still takes
+//! an `Rc<RefCell<String>>`; see [`text_buffer`](super::text_buffer) for moving over.
 
 use crate::core::geometry::{Point, Rect};
 use crate::core::event::{Event, EventType, KB_UP, KB_DOWN, KB_LEFT, KB_RIGHT, KB_PGUP, KB_PGDN, KB_HOME, KB_END, KB_ENTER, KB_BACKSPACE, KB_DEL, KB_TAB};
 use crate::core::draw::DrawBuffer;
 use crate::core::clipboard;
 use crate::core::state::StateFlags;
-use crate::terminal::Terminal;
-use super::view::{View, write_line_to_terminal};
+use crate::terminal::{CursorStyle, Terminal};
+use super::view::{DataValue, EnterBehavior, View, write_line_to_terminal};
 use super::scrollbar::ScrollBar;
+use super::text_buffer::{TextBuffer, TextData};
+use std::cell::RefCell;
 use std::cmp::min;
+use std::rc::Rc;
 
 // Control key codes
 const KB_CTRL_A: u16 = 0x0001;  // Ctrl+A - Select All
@@ -37,6 +46,9 @@ pub struct Memo {
     tab_size: usize,
     owner: Option<*const dyn View>,
     owner_type: super::view::OwnerType,
+    enter_behavior: EnterBehavior,
+    /// Shared copy of the text, updated after every edit
+    data: Option<TextData>,
 }
 
 impl Memo {
@@ -57,6 +69,41 @@ impl Memo {
             tab_size: 4,
             owner: None,
             owner_type: super::view::OwnerType::None,
+            enter_behavior: EnterBehavior::Consume,
+            data: None,
+        }
+    }
+
+    /// Edit the text in `data`, keeping it up to date as the user types
+    ///
+    /// Kept for compatibility; prefer [`bind_buffer()`](Self::bind_buffer).
+    pub fn bind_data(&mut self, data: Rc<RefCell<String>>) {
+        self.bind(TextData::Cell(data));
+    }
+
+    /// Edit the text in `buffer`, keeping it up to date as the user types
+    pub fn bind_buffer(&mut self, buffer: TextBuffer) {
+        self.bind(TextData::Buffer(buffer));
+    }
+
+    /// Name this field in the panic raised when its bound [`TextBuffer`] is
+    /// used re-entrantly, e.g. `"notes"`; call it after binding the buffer
+    pub fn set_field_id(&mut self, field_id: &str) {
+        if let Some(data) = &self.data {
+            data.set_field_id(field_id);
+        }
+    }
+
+    fn bind(&mut self, data: TextData) {
+        let text = data.borrow().clone();
+        self.data = Some(data);
+        self.set_text(&text);
+    }
+
+    /// Copy the text to the bound data, if any
+    fn store_data(&self) {
+        if let Some(data) = &self.data {
+            *data.borrow_mut() = self.get_text();
         }
     }
 
@@ -99,6 +146,16 @@ impl Memo {
         self.tab_size = tab_size.max(1);
     }
 
+    /// Choose whether Enter inserts a line break (default) or presses the
+    /// dialog's default button
+    pub fn set_enter_behavior(&mut self, behavior: EnterBehavior) {
+        self.enter_behavior = behavior;
+    }
+
+    pub fn enter_behavior(&self) -> EnterBehavior {
+        self.enter_behavior
+    }
+
     /// Get the text content
     pub fn get_text(&self) -> String {
         self.lines.join("\n")
@@ -115,6 +172,7 @@ impl Memo {
         self.selection_start = None;
         self.modified = false;
         self.update_scrollbars();
+        self.store_data();
     }
 
     /// Check if text has been modified
@@ -337,17 +395,6 @@ impl Memo {
         self.ensure_cursor_visible();
     }
 
-    /// Insert tab (as spaces)
-    fn insert_tab(&mut self) {
-        if self.read_only {
-            return;
-        }
-
-        for _ in 0..self.tab_size {
-            self.insert_char(' ');
-        }
-    }
-
     /// Move cursor
     fn move_cursor(&mut self, dx: i16, dy: i16, extend_selection: bool) {
         if !extend_selection {
@@ -488,6 +535,8 @@ impl Memo {
             self.delete_selection();
         }
 
+        // Tabs (from pasted text) become spaces
+        let text = text.replace('\t', &" ".repeat(self.tab_size));
         let lines_to_insert: Vec<&str> = text.lines().collect();
         if lines_to_insert.is_empty() {
             return;
@@ -694,8 +743,10 @@ impl View for Memo {
                         event.clear();
                     }
                     KB_ENTER => {
-                        self.insert_newline();
-                        event.clear();
+                        if self.enter_behavior == EnterBehavior::Consume {
+                            self.insert_newline();
+                            event.clear();
+                        }
                     }
                     KB_BACKSPACE => {
                         if self.has_selection() {
@@ -713,10 +764,8 @@ impl View for Memo {
                         }
                         event.clear();
                     }
-                    KB_TAB => {
-                        self.insert_tab();
-                        event.clear();
-                    }
+                    // Tab is left to the dialog, to move the focus on
+                    KB_TAB => {}
                     KB_CTRL_A => {
                         self.select_all();
                         event.clear();
@@ -753,6 +802,9 @@ impl View for Memo {
                         }
                     }
                 }
+                if self.modified {
+                    self.store_data();
+                }
             }
             EventType::MouseWheelUp => {
                 let mouse_pos = event.mouse.pos;
@@ -786,6 +838,16 @@ impl View for Memo {
         true
     }
 
+    fn get_data(&self) -> Option<DataValue> {
+        Some(DataValue::Text(self.get_text()))
+    }
+
+    fn set_data(&mut self, value: &DataValue) {
+        if let DataValue::Text(text) = value {
+            self.set_text(text);
+        }
+    }
+
     // set_focus() now uses default implementation from View trait
     // which sets/clears SF_FOCUSED flag
 
@@ -803,7 +865,8 @@ impl View for Memo {
             let cursor_x = self.bounds.a.x + (self.cursor.x - self.delta.x) as i16;
             let cursor_y = self.bounds.a.y + (self.cursor.y - self.delta.y) as i16;
 
-            // Show cursor at the position
+            // Show cursor at the position, as a bar between characters
+            terminal.set_cursor_style(CursorStyle::Bar { blink: true });
             let _ = terminal.show_cursor(cursor_x as u16, cursor_y as u16);
         }
     }
@@ -828,6 +891,14 @@ impl View for Memo {
     fn set_owner_type(&mut self, owner_type: super::view::OwnerType) {
         self.owner_type = owner_type;
     }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
 }
 
 #[cfg(test)]
@@ -939,6 +1010,26 @@ mod tests {
 
         assert_eq!(memo.get_text(), "Hello");
     }
+
+    #[test]
+    fn test_memo_keeps_bound_buffer_up_to_date() {
+        let buffer = TextBuffer::new("Hello");
+        let mut memo = MemoBuilder::new().bounds(Rect::new(0, 0, 40, 10)).buffer(buffer.clone()).build();
+        memo.set_focus(true);
+        memo.cursor = Point::new(5, 0);
+
+        memo.handle_event(&mut Event::keyboard('!' as u16));
+        let reader = buffer.clone();
+        assert_eq!(std::thread::spawn(move || reader.get()).join().unwrap(), "Hello!");
+    }
+
+    #[test]
+    #[should_panic(expected = "of field 'notes' is already in use")]
+    fn test_reentrant_buffer_panic_names_the_field() {
+        let buffer = TextBuffer::new("Hello");
+        let mut memo = MemoBuilder::new().bounds(Rect::new(0, 0, 40, 10)).buffer(buffer.clone()).field_id("notes").build();
+        buffer.map(|_| memo.set_text("Bye"));
+    }
 }
 
 /// Builder for creating memos with a fluent API.
@@ -948,6 +1039,9 @@ pub struct MemoBuilder {
     max_length: Option<usize>,
     read_only: bool,
     tab_size: usize,
+    enter_behavior: EnterBehavior,
+    data: Option<TextData>,
+    field_id: Option<String>,
 }
 
 impl MemoBuilder {
@@ -958,6 +1052,9 @@ impl MemoBuilder {
             max_length: None,
             read_only: false,
             tab_size: 4,
+            enter_behavior: EnterBehavior::Consume,
+            data: None,
+            field_id: None,
         }
     }
 
@@ -991,12 +1088,49 @@ impl MemoBuilder {
         self
     }
 
+    /// What Enter does (default: insert a line break)
+    #[must_use]
+    pub fn enter_behavior(mut self, behavior: EnterBehavior) -> Self {
+        self.enter_behavior = behavior;
+        self
+    }
+
+    /// Text to edit, kept up to date as the user types
+    ///
+    /// Kept for compatibility; prefer [`buffer()`](Self::buffer).
+    #[must_use]
+    pub fn data(mut self, data: Rc<RefCell<String>>) -> Self {
+        self.data = Some(TextData::Cell(data));
+        self
+    }
+
+    /// Buffer to edit, kept up to date as the user types
+    #[must_use]
+    pub fn buffer(mut self, buffer: TextBuffer) -> Self {
+        self.data = Some(TextData::Buffer(buffer));
+        self
+    }
+
+    /// Name panic messages give the field (see [`Memo::set_field_id()`])
+    #[must_use]
+    pub fn field_id(mut self, field_id: &str) -> Self {
+        self.field_id = Some(field_id.to_string());
+        self
+    }
+
     pub fn build(self) -> Memo {
         let bounds = self.bounds.expect("Memo bounds must be set");
         let mut memo = Memo::new(bounds).with_scrollbars(self.with_scrollbars);
         memo.set_max_length(self.max_length);
         memo.set_read_only(self.read_only);
         memo.set_tab_size(self.tab_size);
+        memo.set_enter_behavior(self.enter_behavior);
+        if let Some(data) = self.data {
+            memo.bind(data);
+        }
+        if let Some(field_id) = &self.field_id {
+            memo.set_field_id(field_id);
+        }
         memo
     }
 
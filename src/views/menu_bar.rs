@@ -1,6 +1,10 @@
 // (C) 2025 - Enzo Lombardi
 
 //! MenuBar view - horizontal top menu bar with dropdown submenus.
+//!
+//! [`standard_menus()`] builds the classic File/Edit/Search/Window/Help bar
+//! wired to the standard `CM_*` commands. As for any menu, items follow the
+//! global command set: an application enables only what it implements.
 // MenuBar - Horizontal menu bar
 //
 // Matches Borland: TMenuBar (menubar.h, tmenubar.cc)
@@ -14,14 +18,20 @@
 use super::menu_box::MenuBox;
 use super::menu_viewer::{MenuViewer, MenuViewerState};
 use super::view::{View, write_line_to_terminal};
+use crate::core::command::{
+    CommandId, CM_ABOUT, CM_CASCADE, CM_COPY, CM_CUT, CM_FIND, CM_NEW, CM_NEXT, CM_OPEN, CM_PASTE, CM_PREV, CM_QUIT, CM_REDO,
+    CM_REPLACE, CM_SAVE, CM_SAVE_AS, CM_SEARCH_AGAIN, CM_SELECT_ALL, CM_TILE, CM_UNDO,
+};
 use crate::core::command_set;
-use crate::core::draw::DrawBuffer;
+use crate::core::draw::{shortcut_hotkey, str_width, strip_shortcut_markers, DrawBuffer};
 use crate::core::event::{
     Event, EventType, KB_ALT_A, KB_ALT_B, KB_ALT_C, KB_ALT_D, KB_ALT_E, KB_ALT_F, KB_ALT_G, KB_ALT_H, KB_ALT_I, KB_ALT_J, KB_ALT_K, KB_ALT_L, KB_ALT_M, KB_ALT_N, KB_ALT_O, KB_ALT_P, KB_ALT_Q,
-    KB_ALT_R, KB_ALT_S, KB_ALT_T, KB_ALT_U, KB_ALT_V, KB_ALT_W, KB_ALT_X, KB_ALT_Y, KB_ALT_Z, KB_ENTER, KB_ESC, KB_ESC_ESC, KB_F1, KB_F10, KB_LEFT, KB_RIGHT, KeyCode, MB_LEFT_BUTTON,
+    KB_ALT_R, KB_ALT_S, KB_ALT_T, KB_ALT_U, KB_ALT_V, KB_ALT_W, KB_ALT_X, KB_ALT_Y, KB_ALT_Z, KB_CTRL_A, KB_CTRL_C, KB_CTRL_F, KB_CTRL_H, KB_CTRL_L,
+    KB_CTRL_N, KB_CTRL_V, KB_CTRL_X, KB_CTRL_Y, KB_CTRL_Z, KB_DOWN, KB_ENTER, KB_ESC, KB_ESC_ESC, KB_F2, KB_F3, KB_F6, KB_F10, KB_LEFT, KB_RIGHT,
+    KB_SHIFT_F6, KeyCode, MB_LEFT_BUTTON,
 };
 use crate::core::geometry::{Point, Rect};
-use crate::core::menu_data::{Menu, MenuItem};
+use crate::core::menu_data::{Menu, MenuBuilder, MenuItem};
 use crate::core::state::StateFlags;
 use crate::terminal::Terminal;
 
@@ -43,31 +53,6 @@ impl SubMenu {
     }
 }
 
-/// Extract the hotkey character from a menu name with ~X~ markers
-///
-/// Given a string like "~F~ile" or "~W~indow", returns the character between the tildes.
-/// Returns None if no valid hotkey marker is found.
-///
-/// Examples:
-/// - "~F~ile" -> Some('F')
-/// - "~W~indow" -> Some('W')
-/// - "No hotkey" -> None
-fn extract_hotkey(text: &str) -> Option<char> {
-    let mut chars = text.chars();
-    while let Some(ch) = chars.next() {
-        if ch == '~' {
-            // Found opening ~, next character is the hotkey
-            if let Some(hotkey_ch) = chars.next() {
-                // Verify it's followed by closing ~
-                if chars.next() == Some('~') {
-                    return Some(hotkey_ch);
-                }
-            }
-        }
-    }
-    None
-}
-
 /// MenuBar - Horizontal menu bar at top of screen
 ///
 /// Matches Borland: TMenuBar
@@ -76,6 +61,7 @@ pub struct MenuBar {
     submenus: Vec<SubMenu>,
     menu_positions: Vec<i16>,       // X positions of each menu for dropdown placement
     active_menu_idx: Option<usize>, // Which submenu is currently open
+    bar_focus: Option<usize>,       // Highlighted menu while the bar has keyboard focus (F10), none open
     menu_state: MenuViewerState,    // State for dropdown menu items
     state: StateFlags,
     owner: Option<*const dyn View>,
@@ -88,6 +74,7 @@ impl MenuBar {
             submenus: Vec::new(),
             menu_positions: Vec::new(),
             active_menu_idx: None,
+            bar_focus: None,
             menu_state: MenuViewerState::new(),
             state: 0,
             owner: None,
@@ -99,9 +86,33 @@ impl MenuBar {
         self.menu_positions.push(0); // Will be updated during draw
     }
 
+    /// Command of the enabled item whose shortcut is `key_code`, searching every menu
+    ///
+    /// The application asks for this after the windows had the key, so a
+    /// focused view still gets first pick of e.g. Ctrl+C.
+    /// Matches Borland: TMenuView::hotKey()
+    pub fn hot_key_command(&self, key_code: KeyCode) -> Option<CommandId> {
+        fn find(menu: &Menu, key_code: KeyCode) -> Option<CommandId> {
+            menu.items.iter().find_map(|item| match item {
+                MenuItem::Regular { command, key_code: item_key, enabled: true, .. }
+                    if *item_key == key_code && command_set::command_enabled(*command) =>
+                {
+                    Some(*command)
+                }
+                MenuItem::SubMenu { menu, .. } => find(menu, key_code),
+                _ => None,
+            })
+        }
+        if key_code == 0 {
+            return None;
+        }
+        self.submenus.iter().find_map(|submenu| find(&submenu.menu, key_code))
+    }
+
     /// Open a specific submenu by index
     fn open_menu(&mut self, menu_idx: usize) {
         if menu_idx < self.submenus.len() {
+            self.bar_focus = None;
             self.active_menu_idx = Some(menu_idx);
             self.menu_state.set_menu(self.submenus[menu_idx].menu.clone());
         }
@@ -113,6 +124,46 @@ impl MenuBar {
         self.menu_state = MenuViewerState::new();
     }
 
+    /// True while the bar has keyboard focus (after F10) without an open menu
+    pub fn is_bar_focused(&self) -> bool {
+        self.bar_focus.is_some()
+    }
+
+    /// Keyboard handling while the bar has focus
+    ///
+    /// Left/Right move the highlight, Down/Enter or a menu's hotkey letter
+    /// opens a menu, Esc/F10 leave. All keys are consumed so they do not
+    /// reach the desktop.
+    /// Matches Borland: TMenuView::execute() on a TMenuBar
+    fn handle_bar_focus_key(&mut self, bar_idx: usize, event: &mut Event) {
+        let count = self.submenus.len();
+        match event.key_code {
+            KB_LEFT => self.bar_focus = Some((bar_idx + count - 1) % count),
+            KB_RIGHT => self.bar_focus = Some((bar_idx + 1) % count),
+            KB_DOWN | KB_ENTER => self.open_menu(bar_idx),
+            KB_ESC | KB_ESC_ESC | KB_F10 => self.bar_focus = None,
+            key_code => {
+                let by_letter = if key_code < 0x80 {
+                    self.find_menu_by_char(key_code as u8 as char)
+                } else {
+                    None
+                };
+                if let Some(idx) = by_letter.or_else(|| self.find_menu_by_hotkey(key_code)) {
+                    self.open_menu(idx);
+                }
+            }
+        }
+        event.clear();
+    }
+
+    /// Find a submenu index by its ~X~ hotkey letter (case-insensitive)
+    fn find_menu_by_char(&self, ch: char) -> Option<usize> {
+        let ch = ch.to_ascii_lowercase();
+        self.submenus
+            .iter()
+            .position(|submenu| shortcut_hotkey(&submenu.name).is_some_and(|hotkey| hotkey.to_ascii_lowercase() == ch))
+    }
+
     /// Find a submenu index by matching Alt+Letter hotkey with ~X~ markers in menu names
     ///
     /// Scans all submenus for a name containing ~X~ where X matches the Alt+Letter keypress.
@@ -152,17 +203,7 @@ impl MenuBar {
             _ => return None, // Not an Alt+Letter key
         };
 
-        // Search through all submenus for a matching hotkey
-        for (idx, submenu) in self.submenus.iter().enumerate() {
-            // Extract the hotkey character from between ~X~ markers
-            if let Some(menu_hotkey) = extract_hotkey(&submenu.name) {
-                if menu_hotkey.to_ascii_lowercase() == hotkey_char {
-                    return Some(idx);
-                }
-            }
-        }
-
-        None
+        self.find_menu_by_char(hotkey_char)
     }
 
     /// Show a cascading submenu for the currently selected item
@@ -192,14 +233,14 @@ impl MenuBar {
             for item in &parent_menu.items {
                 match item {
                     MenuItem::Regular { text, shortcut, .. } => {
-                        let text_len = text.replace('~', "").len();
+                        let text_len = str_width(&strip_shortcut_markers(text));
                         max_text_width = max_text_width.max(text_len);
                         if let Some(s) = shortcut {
                             max_text_width = max_text_width.max(text_len + s.len() + 2);
                         }
                     }
                     MenuItem::SubMenu { text, .. } => {
-                        let text_len = text.replace('~', "").len();
+                        let text_len = str_width(&strip_shortcut_markers(text));
                         max_text_width = max_text_width.max(text_len + 3);
                     }
                     MenuItem::Separator => {}
@@ -220,6 +261,14 @@ impl MenuBar {
         None
     }
 
+    /// Menu whose name covers column `x` of the bar (relative to the bar)
+    fn bar_menu_at(&self, x: i16) -> Option<usize> {
+        self.menu_positions.iter().zip(&self.submenus).position(|(&menu_x, submenu)| {
+            let menu_width = str_width(&strip_shortcut_markers(&submenu.name)) as i16 + 2;
+            x >= menu_x && x < menu_x + menu_width
+        })
+    }
+
     /// Draw the dropdown menu
     fn draw_dropdown(&self, terminal: &mut Terminal, menu_idx: usize) {
         if menu_idx >= self.submenus.len() || menu_idx >= self.menu_positions.len() {
@@ -241,14 +290,14 @@ impl MenuBar {
         for item in &menu.items {
             match item {
                 MenuItem::Regular { text, shortcut, .. } => {
-                    let text_len = text.replace('~', "").len();
+                    let text_len = str_width(&strip_shortcut_markers(text));
                     max_text_width = max_text_width.max(text_len);
                     if let Some(s) = shortcut {
                         max_shortcut_width = max_shortcut_width.max(s.len());
                     }
                 }
                 MenuItem::SubMenu { text, .. } => {
-                    let text_len = text.replace('~', "").len();
+                    let text_len = str_width(&strip_shortcut_markers(text));
                     max_text_width = max_text_width.max(text_len + 3); // +3 for arrow
                 }
                 MenuItem::Separator => {}
@@ -303,35 +352,15 @@ impl MenuBar {
                         item_buf.put_char(j, ' ', attr);
                     }
 
-                    // Draw text with accelerator
-                    let mut x = 1;
-                    let mut chars = text.chars();
-                    while let Some(ch) = chars.next() {
-                        if x >= dropdown_width - 1 {
-                            break;
-                        }
-                        if ch == '~' {
-                            let item_shortcut_attr = if is_selected && is_enabled {
-                                selected_attr
-                            } else if !is_enabled {
-                                disabled_attr
-                            } else {
-                                shortcut_attr
-                            };
-                            while let Some(sc) = chars.next() {
-                                if sc == '~' {
-                                    break;
-                                }
-                                if x < dropdown_width - 1 {
-                                    item_buf.put_char(x, sc, item_shortcut_attr);
-                                    x += 1;
-                                }
-                            }
-                        } else {
-                            item_buf.put_char(x, ch, attr);
-                            x += 1;
-                        }
-                    }
+                    // Draw text with accelerator (the right border is drawn over any overflow)
+                    let item_shortcut_attr = if is_selected && is_enabled {
+                        selected_attr
+                    } else if !is_enabled {
+                        disabled_attr
+                    } else {
+                        shortcut_attr
+                    };
+                    item_buf.move_str_with_shortcut(1, text, attr, item_shortcut_attr);
 
                     // Draw shortcut right-aligned
                     if let Some(shortcut_text) = shortcut {
@@ -354,14 +383,7 @@ impl MenuBar {
                     }
 
                     // Draw text
-                    let mut x = 1;
-                    for ch in text.replace('~', "").chars() {
-                        if x >= dropdown_width - 2 {
-                            break;
-                        }
-                        item_buf.put_char(x, ch, attr);
-                        x += 1;
-                    }
+                    item_buf.move_str_clipped(1, &strip_shortcut_markers(text), attr, dropdown_width.saturating_sub(3));
 
                     // Draw arrow
                     item_buf.put_char(dropdown_width - 2, '►', attr);
@@ -387,6 +409,86 @@ impl MenuBar {
     }
 }
 
+/// Which menus [`standard_menus()`] includes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MenuFeatures {
+    /// File: New, Open, Save, Save As, Exit
+    pub file: bool,
+    /// Edit: Undo, Redo, Cut, Copy, Paste, Select All
+    pub edit: bool,
+    /// Search: Find, Replace, Find Next
+    pub search: bool,
+    /// Window: Tile, Cascade, Next, Previous
+    pub window: bool,
+    /// Help: About
+    pub help: bool,
+}
+
+impl Default for MenuFeatures {
+    /// Every menu
+    fn default() -> Self {
+        Self { file: true, edit: true, search: true, window: true, help: true }
+    }
+}
+
+/// The classic File/Edit/Search/Window/Help menu bar
+///
+/// Items send the standard `CM_*` commands and show their shortcut keys,
+/// which also work while the menus are closed. An item is shown disabled
+/// while its command is disabled in the global command set, so disable the
+/// commands the application does not implement (e.g. CM_FIND). Menus left
+/// out of `features` are dropped entirely.
+pub fn standard_menus(bounds: Rect, features: MenuFeatures) -> MenuBar {
+    let mut menu_bar = MenuBar::new(bounds);
+    if features.file {
+        let file_menu = MenuBuilder::new()
+            .item_with_key("~N~ew", CM_NEW, KB_CTRL_N)
+            .item_with_key("~O~pen...", CM_OPEN, KB_F3)
+            .item_with_key("~S~ave", CM_SAVE, KB_F2)
+            .item("Save ~a~s...", CM_SAVE_AS, 0)
+            .separator()
+            .item_with_key("E~x~it", CM_QUIT, KB_ALT_X)
+            .build();
+        menu_bar.add_submenu(SubMenu::new("~F~ile", file_menu));
+    }
+    if features.edit {
+        let edit_menu = MenuBuilder::new()
+            .item_with_key("~U~ndo", CM_UNDO, KB_CTRL_Z)
+            .item_with_key("~R~edo", CM_REDO, KB_CTRL_Y)
+            .separator()
+            .item_with_key("Cu~t~", CM_CUT, KB_CTRL_X)
+            .item_with_key("~C~opy", CM_COPY, KB_CTRL_C)
+            .item_with_key("~P~aste", CM_PASTE, KB_CTRL_V)
+            .separator()
+            .item_with_key("Select ~a~ll", CM_SELECT_ALL, KB_CTRL_A)
+            .build();
+        menu_bar.add_submenu(SubMenu::new("~E~dit", edit_menu));
+    }
+    if features.search {
+        let search_menu = MenuBuilder::new()
+            .item_with_key("~F~ind...", CM_FIND, KB_CTRL_F)
+            .item_with_key("~R~eplace...", CM_REPLACE, KB_CTRL_H)
+            .item_with_key("Find ~n~ext", CM_SEARCH_AGAIN, KB_CTRL_L)
+            .build();
+        menu_bar.add_submenu(SubMenu::new("~S~earch", search_menu));
+    }
+    if features.window {
+        let window_menu = MenuBuilder::new()
+            .item("~T~ile", CM_TILE, 0)
+            .item("C~a~scade", CM_CASCADE, 0)
+            .separator()
+            .item_with_key("~N~ext", CM_NEXT, KB_F6)
+            .item_with_key("~P~revious", CM_PREV, KB_SHIFT_F6)
+            .build();
+        menu_bar.add_submenu(SubMenu::new("~W~indow", window_menu));
+    }
+    if features.help {
+        let help_menu = MenuBuilder::new().item("~A~bout...", CM_ABOUT, 0).build();
+        menu_bar.add_submenu(SubMenu::new("~H~elp", help_menu));
+    }
+    menu_bar
+}
+
 impl View for MenuBar {
     fn bounds(&self) -> Rect {
         self.bounds
@@ -414,29 +516,15 @@ impl View for MenuBar {
                 self.menu_positions[i] = x as i16;
             }
 
-            let attr = if self.active_menu_idx == Some(i) { selected_attr } else { normal_attr };
+            let highlighted = self.active_menu_idx.or(self.bar_focus) == Some(i);
+            let attr = if highlighted { selected_attr } else { normal_attr };
 
-            // Parse ~X~ for highlighting
             buf.put_char(x, ' ', attr);
             x += 1;
 
-            let mut chars = submenu.name.chars();
-            while let Some(ch) = chars.next() {
-                if ch == '~' {
-                    // Read all characters until closing ~ in shortcut color
-                    let menu_shortcut_attr = if self.active_menu_idx == Some(i) { selected_attr } else { shortcut_attr };
-                    while let Some(shortcut_ch) = chars.next() {
-                        if shortcut_ch == '~' {
-                            break;
-                        }
-                        buf.put_char(x, shortcut_ch, menu_shortcut_attr);
-                        x += 1;
-                    }
-                } else {
-                    buf.put_char(x, ch, attr);
-                    x += 1;
-                }
-            }
+            // Name with its ~X~ hotkey highlighted
+            let menu_shortcut_attr = if highlighted { selected_attr } else { shortcut_attr };
+            x += buf.move_str_with_shortcut(x, &submenu.name, attr, menu_shortcut_attr).0;
 
             buf.put_char(x, ' ', attr);
             x += 1;
@@ -453,23 +541,19 @@ impl View for MenuBar {
     fn handle_event(&mut self, event: &mut Event) {
         match event.what {
             EventType::MouseDown if event.mouse.buttons & MB_LEFT_BUTTON != 0 => {
-                let mouse_pos = event.mouse.pos;
+                self.bar_focus = None;
+                let local = event.local_mouse(self.bounds);
 
                 // Click on menu bar - toggle/switch menus
-                if mouse_pos.y == self.bounds.a.y {
-                    for (i, &menu_x) in self.menu_positions.iter().enumerate() {
-                        if i < self.submenus.len() {
-                            let menu_width = self.submenus[i].name.replace('~', "").len() as i16 + 2;
-                            if mouse_pos.x >= menu_x && mouse_pos.x < menu_x + menu_width {
-                                if self.active_menu_idx == Some(i) {
-                                    self.close_menu();
-                                } else {
-                                    self.open_menu(i);
-                                }
-                                event.clear();
-                                return;
-                            }
+                if local.y == 0 {
+                    if let Some(i) = self.bar_menu_at(local.x) {
+                        if self.active_menu_idx == Some(i) {
+                            self.close_menu();
+                        } else {
+                            self.open_menu(i);
                         }
+                        event.clear();
+                        return;
                     }
                     // Clicked on bar but not on a menu - close
                     if self.active_menu_idx.is_some() {
@@ -588,40 +672,40 @@ impl View for MenuBar {
             }
             EventType::MouseMove => {
                 if let Some(menu_idx) = self.active_menu_idx {
-                    let mouse_pos = event.mouse.pos;
+                    let local = event.local_mouse(self.bounds);
 
                     // Hover over dropdown items
-                    if mouse_pos.y > self.bounds.a.y {
+                    if local.y > 0 {
                         self.handle_menu_event(event);
                     }
 
                     // Hover over different menu on bar - switch
-                    if mouse_pos.y == self.bounds.a.y {
-                        for (i, &menu_x) in self.menu_positions.iter().enumerate() {
-                            if i < self.submenus.len() && i != menu_idx {
-                                let menu_width = self.submenus[i].name.replace('~', "").len() as i16 + 2;
-                                if mouse_pos.x >= menu_x && mouse_pos.x < menu_x + menu_width {
-                                    self.open_menu(i);
-                                    break;
-                                }
-                            }
+                    if local.y == 0 {
+                        if let Some(i) = self.bar_menu_at(local.x).filter(|&i| i != menu_idx) {
+                            self.open_menu(i);
                         }
                     }
                 }
             }
             EventType::Keyboard => {
+                if let Some(bar_idx) = self.bar_focus {
+                    self.handle_bar_focus_key(bar_idx, event);
+                    return;
+                }
+
                 // Hot keys to open specific menus
                 if self.active_menu_idx.is_none() {
-                    // Special case: F10 always opens first menu, F1 always opens last (Help)
-                    let menu_to_open = match event.key_code {
-                        KB_F10 if !self.submenus.is_empty() => Some(0),
-                        KB_F1 if !self.submenus.is_empty() => Some(self.submenus.len() - 1),
-                        _ => {
-                            // Dynamically match Alt+Letter based on ~X~ hotkeys in menu names
-                            // This extracts the hotkey character from menu names like "~F~ile", "~W~indow", etc.
-                            self.find_menu_by_hotkey(event.key_code)
-                        }
-                    };
+                    // F10 focuses the bar on the first menu without opening it
+                    if event.key_code == KB_F10 && !self.submenus.is_empty() {
+                        self.bar_focus = Some(0);
+                        event.clear();
+                        return;
+                    }
+
+                    // Dynamically match Alt+Letter based on ~X~ hotkeys in menu names
+                    // This extracts the hotkey character from menu names like "~F~ile", "~W~indow", etc.
+                    // F1 is not a menu key; the application turns it into CM_HELP
+                    let menu_to_open = self.find_menu_by_hotkey(event.key_code);
 
                     if let Some(idx) = menu_to_open {
                         self.open_menu(idx);
@@ -633,7 +717,7 @@ impl View for MenuBar {
                 // Handle dropdown navigation
                 if let Some(menu_idx) = self.active_menu_idx {
                     match event.key_code {
-                        KB_ESC | KB_ESC_ESC => {
+                        KB_ESC | KB_ESC_ESC | KB_F10 => {
                             self.close_menu();
                             event.clear();
                         }
@@ -737,3 +821,137 @@ impl MenuViewer for MenuBar {
         crate::core::geometry::Rect::new(0, 0, 0, 0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar_click_is_relative_to_menu_bar() {
+        let mut menu_bar = MenuBar::new(Rect::new(10, 3, 60, 4));
+        menu_bar.add_submenu(SubMenu::new("~F~ile", Menu::from_items(vec![MenuItem::new("~N~ew", 100, 0, 0)])));
+        menu_bar.add_submenu(SubMenu::new("~E~dit", Menu::from_items(vec![MenuItem::new("~U~ndo", 101, 0, 0)])));
+        let mut terminal = Terminal::new_headless(80, 25);
+        menu_bar.draw(&mut terminal);
+
+        // " File  Edit": Edit covers bar columns 7..13, i.e. screen columns 17..23
+        let mut event = Event::mouse(EventType::MouseDown, Point::new(18, 3), MB_LEFT_BUTTON, false);
+        menu_bar.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Nothing);
+        assert_eq!(menu_bar.active_menu_idx, Some(1));
+
+        let mut event = Event::mouse(EventType::MouseDown, Point::new(12, 3), MB_LEFT_BUTTON, false);
+        menu_bar.handle_event(&mut event);
+        assert_eq!(menu_bar.active_menu_idx, Some(0));
+    }
+
+    fn three_menus() -> MenuBar {
+        let mut menu_bar = MenuBar::new(Rect::new(0, 0, 80, 1));
+        for (i, name) in ["~F~ile", "~E~dit", "~H~elp"].into_iter().enumerate() {
+            menu_bar.add_submenu(SubMenu::new(name, Menu::from_items(vec![MenuItem::new("~I~tem", 100 + i as u16, 0, 0)])));
+        }
+        menu_bar
+    }
+
+    /// Send a key and return true if the menu bar consumed it
+    fn press(menu_bar: &mut MenuBar, key_code: KeyCode) -> bool {
+        let mut event = Event::keyboard(key_code);
+        menu_bar.handle_event(&mut event);
+        event.what == EventType::Nothing
+    }
+
+    #[test]
+    fn test_dropdown_borders_and_separator_junctions() {
+        use crate::terminal::{render_to_cells, row_text};
+
+        let mut menu_bar = MenuBar::new(Rect::new(0, 0, 40, 1));
+        let items = vec![MenuItem::new("~N~ew", 100, 0, 0), MenuItem::separator(), MenuItem::new("E~x~it", 101, 0, 0)];
+        menu_bar.add_submenu(SubMenu::new("~F~ile", Menu::from_items(items)));
+        menu_bar.open_menu(0);
+        let cells = render_to_cells(&mut menu_bar, 40, 8);
+
+        // 14 columns wide (12 text + 2 borders), starting under " File"
+        let rows: Vec<String> = (1..6).map(|y| row_text(&cells[y][1..15])).collect();
+        assert_eq!(rows[0], format!("\u{250C}{}\u{2510}", "\u{2500}".repeat(12)));
+        assert_eq!(rows[1], format!("\u{2502}New{}\u{2502}", " ".repeat(9)));
+        assert_eq!(rows[2], format!("\u{251C}{}\u{2524}", "\u{2500}".repeat(12)));
+        assert_eq!(rows[4], format!("\u{2514}{}\u{2518}", "\u{2500}".repeat(12)));
+        assert_eq!(cells[3][1].attr, menu_bar.map_color(MENU_NORMAL));
+    }
+
+    #[test]
+    fn test_f1_does_not_open_a_menu() {
+        use crate::core::event::KB_F1;
+
+        let mut menu_bar = three_menus();
+        assert!(!press(&mut menu_bar, KB_F1), "F1 is left for the help command");
+        assert_eq!(menu_bar.active_menu_idx, None);
+    }
+
+    #[test]
+    fn test_f10_focuses_bar_and_arrows_move_highlight() {
+        let mut menu_bar = three_menus();
+        assert!(press(&mut menu_bar, KB_F10));
+        assert!(menu_bar.is_bar_focused());
+        assert_eq!(menu_bar.active_menu_idx, None, "F10 does not open a menu");
+        assert_eq!(menu_bar.bar_focus, Some(0));
+
+        press(&mut menu_bar, KB_RIGHT);
+        assert_eq!(menu_bar.bar_focus, Some(1));
+        press(&mut menu_bar, KB_LEFT);
+        press(&mut menu_bar, KB_LEFT);
+        assert_eq!(menu_bar.bar_focus, Some(2), "wraps around");
+
+        // Keys without a meaning on the bar are swallowed
+        assert!(press(&mut menu_bar, 'x' as KeyCode));
+        assert!(menu_bar.is_bar_focused());
+
+        assert!(press(&mut menu_bar, KB_DOWN));
+        assert_eq!(menu_bar.active_menu_idx, Some(2));
+        assert!(!menu_bar.is_bar_focused());
+
+        press(&mut menu_bar, KB_ESC);
+        assert_eq!(menu_bar.active_menu_idx, None);
+        assert!(!press(&mut menu_bar, 'e' as KeyCode), "letters only open menus while the bar is focused");
+    }
+
+    #[test]
+    fn test_bar_focus_opens_menu_by_letter_or_enter() {
+        let mut menu_bar = three_menus();
+        press(&mut menu_bar, KB_F10);
+        press(&mut menu_bar, 'e' as KeyCode);
+        assert_eq!(menu_bar.active_menu_idx, Some(1));
+
+        press(&mut menu_bar, KB_F10);
+        assert_eq!(menu_bar.active_menu_idx, None, "F10 closes an open menu");
+
+        press(&mut menu_bar, KB_F10);
+        press(&mut menu_bar, KB_ENTER);
+        assert_eq!(menu_bar.active_menu_idx, Some(0));
+
+        // Enter on the open menu runs its item
+        let mut event = Event::keyboard(KB_ENTER);
+        menu_bar.handle_event(&mut event);
+        assert_eq!((event.what, event.command), (EventType::Command, 100));
+
+        press(&mut menu_bar, KB_F10);
+        press(&mut menu_bar, KB_ESC);
+        assert!(!menu_bar.is_bar_focused());
+    }
+
+    #[test]
+    fn test_standard_menus_drop_unwanted_menus() {
+        let names = |menu_bar: &MenuBar| menu_bar.submenus.iter().map(|submenu| submenu.name.clone()).collect::<Vec<_>>();
+        let bounds = Rect::new(0, 0, 80, 1);
+
+        let all = standard_menus(bounds, MenuFeatures::default());
+        assert_eq!(names(&all), ["~F~ile", "~E~dit", "~S~earch", "~W~indow", "~H~elp"]);
+        assert_eq!(all.hot_key_command(KB_CTRL_V), Some(CM_PASTE));
+        assert_eq!(all.hot_key_command(KB_ALT_X), Some(CM_QUIT));
+        assert_eq!(all.hot_key_command(0), None);
+
+        let file_only = standard_menus(bounds, MenuFeatures { file: true, edit: false, search: false, window: false, help: false });
+        assert_eq!(names(&file_only), ["~F~ile"]);
+        assert_eq!(file_only.hot_key_command(KB_CTRL_V), None);
+    }
+}
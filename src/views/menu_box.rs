@@ -14,7 +14,7 @@
 use super::menu_viewer::{MenuViewer, MenuViewerState};
 use super::view::{write_line_to_terminal, View};
 use crate::core::command::CommandId;
-use crate::core::draw::DrawBuffer;
+use crate::core::draw::{str_width, strip_shortcut_markers, DrawBuffer};
 use crate::core::event::{Event, EventType, KB_ENTER, KB_ESC, KB_ESC_ESC, MB_LEFT_BUTTON};
 use crate::core::geometry::{Point, Rect};
 use crate::core::menu_data::{Menu, MenuItem};
@@ -69,12 +69,12 @@ impl MenuBox {
         for item in &menu.items {
             let item_width = match item {
                 MenuItem::Regular { text, shortcut, .. } => {
-                    let text_len = text.replace('~', "").len();
+                    let text_len = str_width(&strip_shortcut_markers(text));
                     let shortcut_len = shortcut.as_ref().map(|s| s.len() + 2).unwrap_or(0);
                     text_len + shortcut_len + 6 // Padding
                 }
                 MenuItem::SubMenu { text, .. } => {
-                    let text_len = text.replace('~', "").len();
+                    let text_len = str_width(&strip_shortcut_markers(text));
                     text_len + 6 + 3 // Padding + submenu arrow
                 }
                 MenuItem::Separator => 4, // Just borders
@@ -213,28 +213,8 @@ impl View for MenuBox {
                     }
 
                     // Draw text with accelerator highlighting
-                    let mut x = 2;
-                    let mut chars = text.chars();
-                    while let Some(ch) = chars.next() {
-                        if ch == '~' {
-                            // Read accelerator
-                            if let Some(accel_ch) = chars.next() {
-                                if accel_ch == '~' {
-                                    break; // End of accelerator
-                                }
-                                let accel_color = if is_selected {
-                                    selected_attr
-                                } else {
-                                    shortcut_attr
-                                };
-                                buf.put_char(x, accel_ch, accel_color);
-                                x += 1;
-                            }
-                        } else {
-                            buf.put_char(x, ch, color);
-                            x += 1;
-                        }
-                    }
+                    let accel_color = if is_selected { selected_attr } else { shortcut_attr };
+                    buf.move_str_with_shortcut(2, text, color, accel_color);
 
                     // Draw shortcut right-aligned
                     if let Some(shortcut_text) = shortcut {
@@ -263,27 +243,8 @@ impl View for MenuBox {
                     }
 
                     // Draw text
-                    let mut x = 2;
-                    let mut chars = text.chars();
-                    while let Some(ch) = chars.next() {
-                        if ch == '~' {
-                            if let Some(accel_ch) = chars.next() {
-                                if accel_ch == '~' {
-                                    break;
-                                }
-                                let accel_color = if is_selected {
-                                    selected_attr
-                                } else {
-                                    shortcut_attr
-                                };
-                                buf.put_char(x, accel_ch, accel_color);
-                                x += 1;
-                            }
-                        } else {
-                            buf.put_char(x, ch, color);
-                            x += 1;
-                        }
-                    }
+                    let accel_color = if is_selected { selected_attr } else { shortcut_attr };
+                    buf.move_str_with_shortcut(2, text, color, accel_color);
 
                     // Draw submenu arrow
                     buf.put_char(width - 2, '►', color);
@@ -22,6 +22,9 @@
 //! - [`Button`](button::Button) - Clickable button that emits commands
 //! - [`CheckBox`](checkbox::CheckBox) - Binary on/off checkbox
 //! - [`RadioButton`](radiobutton::RadioButton) - Mutually exclusive radio buttons
+//! - [`ComboBox`](combobox::ComboBox) - Input line with a drop-down list of choices
+//! - [`Spinner`](spinner::Spinner) - Numeric field with up/down arrows
+//! - [`TextBuffer`](text_buffer::TextBuffer) - Thread-safe text bound to an input line or memo
 //!
 //! ## Display Widgets
 //! - [`StaticText`](static_text::StaticText) - Non-interactive text label
@@ -32,9 +35,13 @@
 //! ## Menus and Status
 //! - [`MenuBar`](menu_bar::MenuBar) - Top menu bar with pull-down menus
 //! - [`StatusLine`](status_line::StatusLine) - Bottom status line with key hints
+//! - [`CommandBar`](command_bar::CommandBar) - vi-style `:` command line
+//! - [`NotifyStrip`](notify_strip::NotifyStrip) - Non-modal notifications above the status line
+//! - [`ActivityIndicator`](activity_indicator::ActivityIndicator) - Spinner for work of unknown length
 //!
 //! ## Dialogs and Utilities
 //! - [`FileDialog`](file_dialog::FileDialog) - File selection dialog
+//! - [`DirReader`](dir_reader::DirReader) - Chunked, cancelable directory listing
 //! - [`msgbox`] - Message boxes and confirmation dialogs
 //! - [`HelpWindow`](help_window::HelpWindow) - Context-sensitive help system
 //!
@@ -65,6 +72,7 @@ pub mod menu_box;
 pub mod button;
 pub mod static_text;
 pub mod input_line;
+pub mod text_buffer;
 pub mod label;
 pub mod scrollbar;
 pub mod scroller;
@@ -74,8 +82,14 @@ pub mod cluster;
 pub mod checkbox;
 pub mod radiobutton;
 pub mod listbox;
+pub mod combobox;
+pub mod spinner;
 pub mod sorted_listbox;
 pub mod list_viewer;
+pub mod list_data_source;
+pub mod command_bar;
+pub mod notify_strip;
+pub mod activity_indicator;
 pub mod history_viewer;
 pub mod history_window;
 pub mod history;
@@ -88,6 +102,7 @@ pub mod file_editor;
 pub mod file_dialog;
 pub mod file_list;
 pub mod dir_listbox;
+pub mod dir_reader;
 pub mod msgbox;
 pub mod validator;
 pub mod lookup_validator;
@@ -106,7 +121,7 @@ pub mod color_selector;
 pub mod color_dialog;
 
 #[doc(inline)]
-pub use view::{View, ViewId, IdleView};
+pub use view::{DataValue, EnterBehavior, View, ViewId, IdleView};
 #[doc(inline)]
 pub use list_viewer::{ListViewer, ListViewerState};
 #[doc(inline)]
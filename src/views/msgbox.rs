@@ -2,16 +2,19 @@
 
 //! MsgBox - message box utilities for displaying alerts and confirmations.
 
-use super::button::Button;
-use super::dialog::Dialog;
+use super::checkbox::CheckBox;
+use super::dialog::{ButtonSet, Dialog, StandardButton, standard_button_row, standard_button_row_with_default, standard_buttons};
 use super::input_line::InputLine;
 use super::label::Label;
-use super::static_text::StaticText;
+use super::static_text::{wrap_text, StaticText};
+use super::text_buffer::TextBuffer;
+use super::View;
 use crate::app::Application;
-use crate::core::command::{CM_CANCEL, CM_NO, CM_OK, CM_YES, CommandId};
+use crate::core::command::{CM_OK, CommandId};
+use crate::core::draw::str_width;
+use super::view::ViewId;
 use crate::core::geometry::Rect;
-use std::cell::RefCell;
-use std::rc::Rc;
+use crate::core::state::OF_CENTERED;
 
 // Message box types
 pub const MF_WARNING: u16 = 0x0000;
@@ -45,11 +48,17 @@ pub fn message_box(app: &mut Application, message: &str, options: u16) -> Comman
 
     let bounds = Rect::new(x, y, x + width as i16, y + height as i16);
 
-    message_box_rect(app, bounds, message, options)
+    message_box_impl(app, bounds, message, options, true)
 }
 
 /// Display a message box at a specific location
 pub fn message_box_rect(app: &mut Application, bounds: Rect, message: &str, options: u16) -> CommandId {
+    message_box_impl(app, bounds, message, options, false)
+}
+
+/// Shared implementation of message_box() and message_box_rect()
+/// `centered` marks the dialog to be re-centered when the terminal is resized
+fn message_box_impl(app: &mut Application, bounds: Rect, message: &str, options: u16, centered: bool) -> CommandId {
     // Determine title based on message type
     let title = match options & 0x03 {
         MF_WARNING => "Warning",
@@ -60,40 +69,27 @@ pub fn message_box_rect(app: &mut Application, bounds: Rect, message: &str, opti
     };
 
     let mut dialog = Dialog::new(bounds, title);
+    if centered {
+        dialog.set_options(dialog.options() | OF_CENTERED);
+    }
 
     // Add static text with message (one row higher)
     let text_bounds = Rect::new(3, 1, bounds.width() - 2, bounds.height() - 4);
     dialog.add(Box::new(StaticText::new_centered(text_bounds, message)));
 
-    // Determine which buttons to show
-    let button_configs = [
-        (MF_YES_BUTTON, " ~Y~es", CM_YES),
-        (MF_NO_BUTTON, " ~N~o", CM_NO),
-        (MF_OK_BUTTON, " ~O~K", CM_OK),
-        (MF_CANCEL_BUTTON, " ~C~ancel", CM_CANCEL),
+    // Add the button row for the requested buttons
+    let button_flags = [
+        (MF_YES_BUTTON, StandardButton::Yes),
+        (MF_NO_BUTTON, StandardButton::No),
+        (MF_OK_BUTTON, StandardButton::Ok),
+        (MF_CANCEL_BUTTON, StandardButton::Cancel),
     ];
-
-    let mut buttons = Vec::new();
-    for (flag, label, cmd) in &button_configs {
-        if options & flag != 0 {
-            buttons.push((*label, *cmd));
-        }
-    }
-
-    // Calculate button positions (one row higher)
-    let button_y = bounds.height() - 4;
-    let total_width: usize = buttons.iter().map(|(label, _)| label.len() + 2).sum();
-    let mut x = (bounds.width_clamped() as usize - total_width) / 2;
-
-    // Add buttons
-    let is_default = buttons.len() == 1 || (options & MF_OK_BUTTON != 0);
-    for (i, (label, cmd)) in buttons.iter().enumerate() {
-        let button_width = label.len() as i16;
-        let button_bounds = Rect::new(x as i16, button_y, x as i16 + button_width, button_y + 2);
-        let is_this_default = is_default && (i == 0 || *cmd == CM_OK);
-        dialog.add(Box::new(Button::new(button_bounds, label, *cmd, is_this_default)));
-        x += button_width as usize + 2;
-    }
+    let buttons: Vec<StandardButton> = button_flags
+        .iter()
+        .filter(|(flag, _)| options & flag != 0)
+        .map(|(_, button)| *button)
+        .collect();
+    standard_button_row(&mut dialog, &buttons);
 
     dialog.set_initial_focus();
     dialog.execute(app)
@@ -166,6 +162,268 @@ pub fn confirmation_box_ok_cancel(app: &mut Application, message: &str) -> Comma
     message_box(app, message, MF_CONFIRMATION | MF_OK_CANCEL)
 }
 
+/// Options for [`confirmation_box_with()`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfirmOptions {
+    pub title: String,
+    pub buttons: ButtonSet,
+    /// Button chosen by Enter (and focused initially)
+    pub default_button: StandardButton,
+    /// Show a "Don't ask again" checkbox
+    pub dont_ask_again: bool,
+    /// Label of that checkbox, with `~` around the hot key
+    pub checkbox_label: String,
+}
+
+impl ConfirmOptions {
+    /// Yes/No/Cancel with Yes as default, titled "Confirm"
+    pub fn new() -> Self {
+        Self {
+            title: "Confirm".to_string(),
+            buttons: ButtonSet::YesNoCancel,
+            default_button: StandardButton::Yes,
+            dont_ask_again: false,
+            checkbox_label: "~D~on't ask again".to_string(),
+        }
+    }
+
+    /// Yes/No with No as default, for prompts such as "Delete 14 files?"
+    pub fn destructive() -> Self {
+        Self::new().buttons(ButtonSet::YesNo).default_button(StandardButton::No)
+    }
+
+    #[must_use]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    #[must_use]
+    pub fn buttons(mut self, buttons: ButtonSet) -> Self {
+        self.buttons = buttons;
+        self
+    }
+
+    #[must_use]
+    pub fn default_button(mut self, button: StandardButton) -> Self {
+        self.default_button = button;
+        self
+    }
+
+    #[must_use]
+    pub fn dont_ask_again(mut self, show: bool) -> Self {
+        self.dont_ask_again = show;
+        self
+    }
+
+    #[must_use]
+    pub fn checkbox_label(mut self, label: impl Into<String>) -> Self {
+        self.checkbox_label = label.into();
+        self
+    }
+}
+
+impl Default for ConfirmOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Answer from [`confirmation_box_with()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfirmResult {
+    /// CM_YES, CM_NO or CM_CANCEL
+    pub command: CommandId,
+    /// State of the "Don't ask again" checkbox (false if it was not shown)
+    pub dont_ask_again: bool,
+}
+
+/// Display a confirmation dialog with configurable title, buttons and default button
+///
+/// Long messages are word-wrapped and the dialog grows to fit them.
+/// The caller is responsible for persisting `dont_ask_again`.
+///
+/// # Example
+/// ```ignore
+/// use turbo_vision::views::msgbox::{confirmation_box_with, ConfirmOptions};
+///
+/// let answer = confirmation_box_with(&mut app, "Delete 14 files?", &ConfirmOptions::destructive().title("Delete"));
+/// if answer.command == CM_YES { /* delete */ }
+/// ```
+pub fn confirmation_box_with(app: &mut Application, message: &str, options: &ConfirmOptions) -> ConfirmResult {
+    let (screen_w, screen_h) = app.terminal.size();
+    let (mut dialog, checkbox_id) = confirmation_dialog(message, options, screen_w, screen_h);
+    let command = dialog.execute(app);
+    let dont_ask_again = checkbox_id
+        .and_then(|id| dialog.child_by_id(id))
+        .and_then(|view| view.as_any().downcast_ref::<CheckBox>())
+        .is_some_and(CheckBox::is_checked);
+    ConfirmResult { command, dont_ask_again }
+}
+
+/// Display a Yes/No/Cancel confirmation with an "Apply to all" checkbox
+///
+/// For bulk operations such as "Overwrite all?": returns the button pressed and
+/// whether the box was ticked, so the caller can skip the remaining prompts.
+///
+/// # Example
+/// ```ignore
+/// use turbo_vision::views::msgbox::confirmation_box_remember;
+///
+/// let (command, apply_to_all) = confirmation_box_remember(&mut app, "Overwrite readme.txt?");
+/// ```
+pub fn confirmation_box_remember(app: &mut Application, message: &str) -> (CommandId, bool) {
+    let options = ConfirmOptions::new().dont_ask_again(true).checkbox_label("~A~pply to all");
+    let answer = confirmation_box_with(app, message, &options);
+    (answer.command, answer.dont_ask_again)
+}
+
+/// Widest message line in a confirmation box
+const CONFIRM_MAX_TEXT_WIDTH: usize = 54;
+
+/// Build the confirmation dialog centered on a screen of the given size.
+/// Returns the dialog and the "Don't ask again" checkbox id, if shown.
+fn confirmation_dialog(message: &str, options: &ConfirmOptions, screen_w: i16, screen_h: i16) -> (Dialog, Option<ViewId>) {
+    let lines: Vec<String> = message.lines().flat_map(|line| wrap_text(line, CONFIRM_MAX_TEXT_WIDTH)).collect();
+    let text_width = lines.iter().map(|l| str_width(l)).max().unwrap_or(0);
+    let width = (text_width + 6).max(MIN_BOX_WIDTH).min(screen_w.max(0) as usize) as i16;
+    // Frame (2) + top margin (1) + gap (1) + button row (2), plus checkbox and gap
+    let checkbox_rows = if options.dont_ask_again { 2 } else { 0 };
+    let height = ((lines.len() + 6 + checkbox_rows) as i16).min(screen_h);
+
+    let x = (screen_w - width) / 2;
+    let y = (screen_h - height) / 2;
+    let mut dialog = Dialog::new(Rect::new(x, y, x + width, y + height), &options.title);
+    dialog.set_options(dialog.options() | OF_CENTERED);
+
+    let text_rows = lines.len() as i16;
+    dialog.add(Box::new(StaticText::new_centered(Rect::new(2, 1, width - 4, 1 + text_rows), &lines.join("\n"))));
+
+    let checkbox_id = options.dont_ask_again.then(|| {
+        let label_width = options.checkbox_label.replace('~', "").chars().count() as i16 + 6;
+        let cx = ((width - 2 - label_width) / 2).max(1);
+        dialog.add(Box::new(CheckBox::new(Rect::new(cx, 2 + text_rows, cx + label_width, 3 + text_rows), &options.checkbox_label)))
+    });
+
+    // Focus the default button so Enter and Space agree on the answer
+    let first_button = dialog.child_count();
+    let buttons = options.buttons.buttons();
+    standard_button_row_with_default(&mut dialog, buttons, options.default_button);
+    match buttons.iter().position(|b| *b == options.default_button) {
+        Some(index) => dialog.set_focus_to_child(first_button + index),
+        None => dialog.set_initial_focus(),
+    }
+    (dialog, checkbox_id)
+}
+
+/// Application details shown by [`about_box()`]
+///
+/// Use the [`app_info!`](crate::app_info) macro to fill in the version from
+/// your crate's `Cargo.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AppInfo {
+    pub name: String,
+    pub version: String,
+    pub copyright: String,
+    /// Additional text below the copyright line, wrapped to the dialog width
+    pub extra_lines: Vec<String>,
+}
+
+impl AppInfo {
+    pub fn new(name: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn copyright(mut self, copyright: impl Into<String>) -> Self {
+        self.copyright = copyright.into();
+        self
+    }
+
+    #[must_use]
+    pub fn extra_line(mut self, line: impl Into<String>) -> Self {
+        self.extra_lines.push(line.into());
+        self
+    }
+}
+
+/// Creates an [`AppInfo`] with the calling crate's `CARGO_PKG_VERSION`
+///
+/// # Example
+/// ```
+/// let info = turbo_vision::app_info!("My Editor").copyright("(C) 2025 Me");
+/// assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+/// ```
+#[macro_export]
+macro_rules! app_info {
+    ($name:expr) => {
+        $crate::views::msgbox::AppInfo::new($name, env!("CARGO_PKG_VERSION"))
+    };
+}
+
+/// Widest text line in an about box (the dialog is 6 columns wider)
+const ABOUT_MAX_TEXT_WIDTH: usize = 60;
+const MIN_BOX_WIDTH: usize = 30;
+
+/// Display the standard about dialog: centered, framed, with an OK button
+///
+/// Matches Borland: the TVDEMO "About" box (name, version, copyright).
+/// The dialog sizes itself to the text; extra lines are word-wrapped.
+///
+/// Returns CM_OK when dismissed.
+///
+/// # Example
+/// ```ignore
+/// use turbo_vision::views::msgbox::about_box;
+///
+/// about_box(&mut app, &turbo_vision::app_info!("My Editor").copyright("(C) 2025 Me"));
+/// ```
+pub fn about_box(app: &mut Application, info: &AppInfo) -> CommandId {
+    let (screen_w, screen_h) = app.terminal.size();
+    let mut dialog = about_dialog(info, screen_w, screen_h);
+    dialog.set_initial_focus();
+    dialog.execute(app)
+}
+
+/// Build the about dialog centered on a screen of the given size
+fn about_dialog(info: &AppInfo, screen_w: i16, screen_h: i16) -> Dialog {
+    let max_text_width = ABOUT_MAX_TEXT_WIDTH.min((screen_w.max(0) as usize).saturating_sub(6)).max(1);
+
+    let mut lines = vec![info.name.clone()];
+    if !info.version.is_empty() {
+        lines.push(format!("Version {}", info.version));
+    }
+    if !info.copyright.is_empty() {
+        lines.push(info.copyright.clone());
+    }
+    if !info.extra_lines.is_empty() {
+        lines.push(String::new());
+        for line in &info.extra_lines {
+            lines.extend(wrap_text(line, max_text_width));
+        }
+    }
+
+    let text_width = lines.iter().map(|l| str_width(l)).max().unwrap_or(0).min(max_text_width);
+    let width = (text_width + 6).max(MIN_BOX_WIDTH).min(screen_w.max(0) as usize) as i16;
+    // Frame (2) + top margin (1) + gap (1) + button row (2)
+    let height = ((lines.len() + 6) as i16).min(screen_h);
+
+    let x = (screen_w - width) / 2;
+    let y = (screen_h - height) / 2;
+    let mut dialog = Dialog::new(Rect::new(x, y, x + width, y + height), "About");
+    dialog.set_options(dialog.options() | OF_CENTERED);
+
+    // Child coordinates are relative to the dialog interior
+    let text_bounds = Rect::new(1, 1, width - 3, height - 4);
+    dialog.add(Box::new(StaticText::new_centered(text_bounds, &lines.join("\n"))));
+    standard_buttons(&mut dialog, ButtonSet::Ok);
+    dialog
+}
+
 /// Display an input box that prompts the user for a string
 pub fn input_box(app: &mut Application, title: &str, label: &str, initial: &str, max_length: usize) -> Option<String> {
     // Calculate dialog size
@@ -180,15 +438,23 @@ pub fn input_box(app: &mut Application, title: &str, label: &str, initial: &str,
 
     let bounds = Rect::new(x, y, x + width as i16, y + height as i16);
 
-    input_box_rect(app, bounds, title, label, initial, max_length)
+    input_box_impl(app, bounds, title, label, initial, max_length, true)
 }
 
 /// Display an input box at a specific location
 pub fn input_box_rect(app: &mut Application, bounds: Rect, title: &str, label: &str, initial: &str, max_length: usize) -> Option<String> {
+    input_box_impl(app, bounds, title, label, initial, max_length, false)
+}
+
+/// Shared implementation of input_box() and input_box_rect()
+fn input_box_impl(app: &mut Application, bounds: Rect, title: &str, label: &str, initial: &str, max_length: usize, centered: bool) -> Option<String> {
     let mut dialog = Dialog::new(bounds, title);
+    if centered {
+        dialog.set_options(dialog.options() | OF_CENTERED);
+    }
 
     // Create shared data for input line
-    let data = Rc::new(RefCell::new(initial.to_string()));
+    let data = TextBuffer::new(initial);
 
     // Add label
     let label_x = 2;
@@ -200,24 +466,16 @@ pub fn input_box_rect(app: &mut Application, bounds: Rect, title: &str, label: &
     let input_x = label_x + label_width + 1;
     let input_width = (bounds.width() - input_x - 3).min(max_length as i16 + 2);
     let input_bounds = Rect::new(input_x, 2, input_x + input_width, 3);
-    dialog.add(Box::new(InputLine::new(input_bounds, max_length, data.clone())));
-
-    // Add OK button
-    let button_y = bounds.height() - 4;
-    let ok_x = bounds.width() / 2 - 11;
-    let ok_bounds = Rect::new(ok_x, button_y, ok_x + 10, button_y + 2);
-    dialog.add(Box::new(Button::new(ok_bounds, " ~O~K", CM_OK, true)));
+    dialog.add(Box::new(InputLine::with_buffer(input_bounds, max_length, data.clone())));
 
-    // Add Cancel button
-    let cancel_x = ok_x + 12;
-    let cancel_bounds = Rect::new(cancel_x, button_y, cancel_x + 10, button_y + 2);
-    dialog.add(Box::new(Button::new(cancel_bounds, " ~C~ancel", CM_CANCEL, false)));
+    // Add OK/Cancel buttons
+    standard_buttons(&mut dialog, ButtonSet::OkCancel);
 
     dialog.set_initial_focus();
 
     let result = dialog.execute(app);
 
-    if result == CM_OK { Some(data.borrow().clone()) } else { None }
+    if result == CM_OK { Some(data.get()) } else { None }
 }
 
 /// Display a search dialog that prompts the user for search text
@@ -245,9 +503,10 @@ pub fn search_box(app: &mut Application, title: &str) -> Option<String> {
     let bounds = Rect::new(x, y, x + width, y + height);
 
     let mut dialog = Dialog::new(bounds, title);
+    dialog.set_options(dialog.options() | OF_CENTERED);
 
     // Create shared data for input line
-    let data = Rc::new(RefCell::new(String::new()));
+    let data = TextBuffer::default();
 
     // Add label
     let label_bounds = Rect::new(2, 2, 20, 3);
@@ -255,22 +514,17 @@ pub fn search_box(app: &mut Application, title: &str) -> Option<String> {
 
     // Add input line
     let input_bounds = Rect::new(2, 3, width - 4, 4);
-    dialog.add(Box::new(InputLine::new(input_bounds, 100, data.clone())));
+    dialog.add(Box::new(InputLine::with_buffer(input_bounds, 100, data.clone())));
 
-    // Add OK button
-    let ok_bounds = Rect::new(15, 5, 25, 7);
-    dialog.add(Box::new(Button::new(ok_bounds, " ~O~K", CM_OK, true)));
-
-    // Add Cancel button
-    let cancel_bounds = Rect::new(27, 5, 37, 7);
-    dialog.add(Box::new(Button::new(cancel_bounds, " ~C~ancel", CM_CANCEL, false)));
+    // Add OK/Cancel buttons
+    standard_buttons(&mut dialog, ButtonSet::OkCancel);
 
     dialog.set_initial_focus();
 
     let result = dialog.execute(app);
 
     if result == CM_OK {
-        let text = data.borrow().clone();
+        let text = data.get();
         if !text.is_empty() { Some(text) } else { None }
     } else {
         None
@@ -302,10 +556,11 @@ pub fn search_replace_box(app: &mut Application, title: &str) -> Option<(String,
     let bounds = Rect::new(x, y, x + width, y + height);
 
     let mut dialog = Dialog::new(bounds, title);
+    dialog.set_options(dialog.options() | OF_CENTERED);
 
     // Create shared data for input lines
-    let find_data = Rc::new(RefCell::new(String::new()));
-    let replace_data = Rc::new(RefCell::new(String::new()));
+    let find_data = TextBuffer::default();
+    let replace_data = TextBuffer::default();
 
     // Add find label
     let label1_bounds = Rect::new(2, 2, 20, 3);
@@ -313,7 +568,7 @@ pub fn search_replace_box(app: &mut Application, title: &str) -> Option<(String,
 
     // Add find input line
     let input1_bounds = Rect::new(2, 3, width - 4, 4);
-    dialog.add(Box::new(InputLine::new(input1_bounds, 100, find_data.clone())));
+    dialog.add(Box::new(InputLine::with_buffer(input1_bounds, 100, find_data.clone())));
 
     // Add replace label
     let label2_bounds = Rect::new(2, 5, 20, 6);
@@ -321,24 +576,19 @@ pub fn search_replace_box(app: &mut Application, title: &str) -> Option<(String,
 
     // Add replace input line
     let input2_bounds = Rect::new(2, 6, width - 4, 7);
-    dialog.add(Box::new(InputLine::new(input2_bounds, 100, replace_data.clone())));
-
-    // Add OK button
-    let ok_bounds = Rect::new(15, 9, 25, 11);
-    dialog.add(Box::new(Button::new(ok_bounds, " ~O~K", CM_OK, true)));
+    dialog.add(Box::new(InputLine::with_buffer(input2_bounds, 100, replace_data.clone())));
 
-    // Add Cancel button
-    let cancel_bounds = Rect::new(27, 9, 37, 11);
-    dialog.add(Box::new(Button::new(cancel_bounds, " ~C~ancel", CM_CANCEL, false)));
+    // Add OK/Cancel buttons
+    standard_buttons(&mut dialog, ButtonSet::OkCancel);
 
     dialog.set_initial_focus();
 
     let result = dialog.execute(app);
 
     if result == CM_OK {
-        let find_text = find_data.borrow().clone();
+        let find_text = find_data.get();
         if !find_text.is_empty() {
-            let replace_text = replace_data.borrow().clone();
+            let replace_text = replace_data.get();
             Some((find_text, replace_text))
         } else {
             None
@@ -373,9 +623,10 @@ pub fn goto_line_box(app: &mut Application, title: &str) -> Option<usize> {
     let bounds = Rect::new(x, y, x + width, y + height);
 
     let mut dialog = Dialog::new(bounds, title);
+    dialog.set_options(dialog.options() | OF_CENTERED);
 
     // Create shared data for input line
-    let data = Rc::new(RefCell::new(String::new()));
+    let data = TextBuffer::default();
 
     // Add label
     let label_bounds = Rect::new(2, 2, 20, 3);
@@ -383,24 +634,207 @@ pub fn goto_line_box(app: &mut Application, title: &str) -> Option<usize> {
 
     // Add input line
     let input_bounds = Rect::new(2, 3, width - 4, 4);
-    dialog.add(Box::new(InputLine::new(input_bounds, 10, data.clone())));
+    dialog.add(Box::new(InputLine::with_buffer(input_bounds, 10, data.clone())));
 
-    // Add OK button
-    let ok_bounds = Rect::new(10, 5, 20, 7);
-    dialog.add(Box::new(Button::new(ok_bounds, " ~O~K", CM_OK, true)));
-
-    // Add Cancel button
-    let cancel_bounds = Rect::new(22, 5, 32, 7);
-    dialog.add(Box::new(Button::new(cancel_bounds, " ~C~ancel", CM_CANCEL, false)));
+    // Add OK/Cancel buttons
+    standard_buttons(&mut dialog, ButtonSet::OkCancel);
 
     dialog.set_initial_focus();
 
     let result = dialog.execute(app);
 
     if result == CM_OK {
-        let text = data.borrow().clone();
+        let text = data.get();
         text.parse::<usize>().ok()
     } else {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::event::{Event, KB_ESC_ESC};
+    use crate::terminal::Terminal;
+
+    /// Position of `ch` in the last recorded draw operation that wrote it
+    fn last_drawn(app: &Application, ch: char) -> Option<(usize, usize)> {
+        app.terminal.recorder()?.ops().iter().rev().find_map(|op| {
+            let (x, y) = op.position()?;
+            let offset = op.text().chars().position(|c| c == ch)?;
+            Some((x as usize + offset, y as usize))
+        })
+    }
+
+    #[test]
+    fn test_input_box_stays_visible_after_resize() {
+        let mut app = Application::with_terminal(Terminal::new_headless(100, 30));
+        app.terminal.start_recording();
+        app.terminal.push_input(Event::resize(60, 18));
+        app.terminal.push_input(Event::keyboard(KB_ESC_ESC));
+
+        let result = input_box(&mut app, "Name", "Name:", "", 20);
+        assert_eq!(result, None);
+        assert_eq!(app.terminal.size(), (60, 18));
+
+        // The last frame drawn before closing shows the whole dialog on screen
+        let (left, top) = last_drawn(&app, '╔').expect("top-left corner visible");
+        let (right, bottom) = last_drawn(&app, '╝').expect("bottom-right corner visible");
+        assert_eq!(right - left + 1, 37);
+        assert_eq!(bottom - top + 1, 8);
+        assert!(right < 60 && bottom < 18);
+    }
+
+    #[test]
+    fn test_message_box_restores_screen_beneath() {
+        use crate::core::draw::Cell;
+        use crate::core::palette::Attr;
+
+        // Screen content the application never redraws
+        let mut app = Application::with_terminal(Terminal::new_headless(80, 25));
+        for y in 0..25 {
+            for x in 0..80 {
+                app.terminal.write_cell(x, y, Cell::new('Z', Attr::from_u8(0x1E)));
+            }
+        }
+        app.terminal.flush().unwrap();
+        app.terminal.push_input(Event::keyboard(KB_ESC_ESC));
+
+        message_box_ok(&mut app, "Done");
+
+        assert_eq!(app.terminal.save_under_depth(), 0);
+        // The dialog area shows the original content again
+        let (width, height) = (app.terminal.size().0 as usize, app.terminal.size().1 as usize);
+        let center = &app.terminal.buffer()[height / 2][width / 2];
+        assert_eq!(center.ch, 'Z');
+        assert_eq!(center.attr, Attr::from_u8(0x1E));
+    }
+
+    #[test]
+    fn test_destructive_confirmation_defaults_to_no() {
+        use crate::core::command::CM_NO;
+        use crate::core::event::KB_ENTER;
+
+        let mut app = Application::with_terminal(Terminal::new_headless(80, 25));
+        app.terminal.push_input(Event::keyboard(KB_ENTER));
+
+        let answer = confirmation_box_with(&mut app, "Delete 14 files?", &ConfirmOptions::destructive().title("Delete"));
+        assert_eq!(answer, ConfirmResult { command: CM_NO, dont_ask_again: false });
+    }
+
+    #[test]
+    fn test_confirmation_returns_dont_ask_again() {
+        use crate::core::command::CM_YES;
+        use crate::core::event::{KB_ENTER, KB_SHIFT_TAB};
+
+        let mut app = Application::with_terminal(Terminal::new_headless(80, 25));
+        // Focus starts on Yes; back to the checkbox, tick it, then accept
+        for key in [KB_SHIFT_TAB, ' ' as u16, KB_ENTER] {
+            app.terminal.push_input(Event::keyboard(key));
+        }
+
+        let answer = confirmation_box_with(&mut app, "Overwrite?", &ConfirmOptions::new().dont_ask_again(true));
+        assert_eq!(answer, ConfirmResult { command: CM_YES, dont_ask_again: true });
+    }
+
+    #[test]
+    fn test_confirmation_box_remember_returns_choice_and_checkbox() {
+        use crate::core::command::{CM_CANCEL, CM_NO};
+        use crate::core::event::{KB_ENTER, KB_ESC_ESC, KB_SHIFT_TAB, KB_TAB};
+
+        let mut app = Application::with_terminal(Terminal::new_headless(80, 25));
+        // Tick "Apply to all", move on to No and press it
+        for key in [KB_SHIFT_TAB, ' ' as u16, KB_TAB, KB_TAB, KB_ENTER] {
+            app.terminal.push_input(Event::keyboard(key));
+        }
+        assert_eq!(confirmation_box_remember(&mut app, "Overwrite all?"), (CM_NO, true));
+
+        app.terminal.push_input(Event::keyboard(KB_ESC_ESC));
+        assert_eq!(confirmation_box_remember(&mut app, "Overwrite all?"), (CM_CANCEL, false));
+    }
+
+    #[test]
+    fn test_confirmation_grows_for_long_message() {
+        let message = "This operation cannot be undone. ".repeat(8);
+        let (short, _) = confirmation_dialog("Sure?", &ConfirmOptions::new(), 80, 25);
+        let (long, _) = confirmation_dialog(&message, &ConfirmOptions::new(), 80, 25);
+
+        assert_eq!(short.bounds().height(), 7);
+        assert!(long.bounds().width() as usize <= CONFIRM_MAX_TEXT_WIDTH + 6);
+        assert!(long.bounds().height() > short.bounds().height() + 3);
+    }
+
+    #[test]
+    fn test_confirmation_fits_wide_character_message() {
+        use crate::terminal::row_text;
+
+        let message = "このファイルを上書きしますか？ 変更は元に戻せません。".repeat(3);
+        let (mut dialog, _) = confirmation_dialog(&message, &ConfirmOptions::new(), 80, 25);
+        let bounds = dialog.bounds();
+        assert!(bounds.width() as usize <= CONFIRM_MAX_TEXT_WIDTH + 6);
+
+        // Every character of the message is drawn inside the frame
+        let mut terminal = Terminal::new_headless(80, 25);
+        dialog.draw(&mut terminal);
+        let expected: String = message.split_whitespace().collect();
+        let shown: String = (bounds.a.y..bounds.b.y)
+            .flat_map(|y| row_text(&terminal.buffer()[y as usize][bounds.a.x as usize..bounds.b.x as usize]).chars().collect::<Vec<_>>())
+            .filter(|&ch| crate::core::draw::char_width(ch) == 2)
+            .collect();
+        assert_eq!(shown, expected);
+    }
+
+    #[test]
+    fn test_about_box_sizes_to_content() {
+        let info = AppInfo::new("Editor", "1.2.3").extra_line("x".repeat(130));
+        let dialog = about_dialog(&info, 80, 24);
+        let bounds = dialog.bounds();
+
+        // Wrapped to the maximum width and centered on screen
+        assert_eq!(bounds.width() as usize, ABOUT_MAX_TEXT_WIDTH + 6);
+        assert_eq!(bounds.a.x, (80 - bounds.width()) / 2);
+        // Name, version, blank, 3 wrapped lines, plus frame, margins and buttons
+        assert_eq!(bounds.height(), 6 + 6);
+    }
+
+    #[test]
+    fn test_about_box_wraps_wide_characters_in_columns() {
+        // 40 wide characters take 80 columns: two lines of the 60-column maximum
+        let info = AppInfo::new("Editor", "1.2.3").extra_line("日本語".repeat(13) + "日");
+        let dialog = about_dialog(&info, 80, 24);
+        let bounds = dialog.bounds();
+
+        assert_eq!(bounds.width() as usize, ABOUT_MAX_TEXT_WIDTH + 6);
+        // Name, version, blank, 2 wrapped lines, plus frame, margins and buttons
+        assert_eq!(bounds.height(), 5 + 6);
+    }
+
+    /// Golden ANSI dump of the about box at 80x24.
+    /// Regenerate with `UPDATE_GOLDEN=1 cargo test about_box_golden`.
+    #[test]
+    fn test_about_box_golden() {
+        use crate::core::ansi_dump;
+
+        let info = AppInfo::new("Turbo Vision Demo", "1.0.0")
+            .copyright("(C) 2025 - Enzo Lombardi")
+            .extra_line("A Rust implementation of the classic Borland Turbo Vision text-mode UI framework, with windows, dialogs and menus.");
+
+        let mut app = Application::with_terminal(Terminal::new_headless(80, 24));
+        let mut dialog = about_dialog(&info, 80, 24);
+        dialog.set_initial_focus();
+        app.desktop.draw(&mut app.terminal);
+        dialog.draw(&mut app.terminal);
+
+        let mut dump = Vec::new();
+        ansi_dump::dump_buffer(&mut dump, app.terminal.buffer(), 80, 24).unwrap();
+        let dump = String::from_utf8(dump).unwrap();
+
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden/about_box_80x24.ans");
+        if std::env::var_os("UPDATE_GOLDEN").is_some() {
+            std::fs::create_dir_all(std::path::Path::new(path).parent().unwrap()).unwrap();
+            std::fs::write(path, &dump).unwrap();
+        }
+        let expected = std::fs::read_to_string(path).expect("golden file exists");
+        assert_eq!(dump, expected);
+    }
+}
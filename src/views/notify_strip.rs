@@ -0,0 +1,273 @@
+// (C) 2025 - Enzo Lombardi
+
+//! NotifyStrip view - non-modal notification bar above the status line.
+//!
+//! Shows one-line messages ("Saved 3 files", "Connection lost") without
+//! opening a dialog: the strip overlays the bottom row of the desktop, so
+//! windows draw beneath it, and it never takes the focus. Up to
+//! [`MAX_NOTIFICATIONS`] messages are stacked; the newest one is shown, with
+//! a `+N` count of the older ones on the right. Each message expires on its
+//! own timer; a click on the strip or the dismiss key (ESC by default)
+//! dismisses the newest one.
+//!
+//! The application owns a strip; post to it with
+//! [`Application::notify()`](crate::app::Application::notify).
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use turbo_vision::app::Application;
+//! use turbo_vision::views::notify_strip::Severity;
+//! use std::time::Duration;
+//!
+//! let mut app = Application::new()?;
+//! app.notify("Cannot reach the build server", Severity::Error, Duration::from_secs(5));
+//! # Ok::<(), turbo_vision::core::error::TurboVisionError>(())
+//! ```
+
+use super::view::{write_line_to_terminal, IdleView, View};
+use crate::core::draw::{str_width, DrawBuffer};
+use crate::core::event::{Event, EventType, KeyCode, KB_ESC};
+use crate::core::geometry::Rect;
+use crate::core::palette::{NOTIFY_ERROR, NOTIFY_INFO, NOTIFY_WARNING};
+use crate::terminal::Terminal;
+use std::time::{Duration, Instant};
+
+/// Messages kept at most; posting more drops the oldest
+pub const MAX_NOTIFICATIONS: usize = 8;
+
+/// How a notification is colored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// Index into the strip palette
+    fn color_index(self) -> u8 {
+        match self {
+            Severity::Info => NOTIFY_INFO,
+            Severity::Warning => NOTIFY_WARNING,
+            Severity::Error => NOTIFY_ERROR,
+        }
+    }
+}
+
+/// A message waiting in the strip
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    pub message: String,
+    pub severity: Severity,
+    expires: Instant,
+}
+
+impl Notification {
+    /// When the message disappears on its own
+    pub fn expires(&self) -> Instant {
+        self.expires
+    }
+}
+
+/// One-line notification bar
+pub struct NotifyStrip {
+    bounds: Rect,
+    /// Oldest first
+    notifications: Vec<Notification>,
+    dismiss_key: KeyCode,
+}
+
+impl NotifyStrip {
+    /// Creates an empty strip; only the first row of `bounds` is used
+    pub fn new(bounds: Rect) -> Self {
+        Self {
+            bounds,
+            notifications: Vec::new(),
+            dismiss_key: KB_ESC,
+        }
+    }
+
+    /// Show `message` for `duration`, on top of the messages already shown
+    pub fn push(&mut self, message: &str, severity: Severity, duration: Duration) {
+        self.push_at(message, severity, duration, Instant::now());
+    }
+
+    /// Like [`push()`](Self::push), with an explicit current time
+    pub fn push_at(&mut self, message: &str, severity: Severity, duration: Duration, now: Instant) {
+        if self.notifications.len() == MAX_NOTIFICATIONS {
+            self.notifications.remove(0);
+        }
+        self.notifications.push(Notification {
+            message: message.to_string(),
+            severity,
+            expires: now + duration,
+        });
+    }
+
+    /// Pending messages, oldest first
+    pub fn notifications(&self) -> &[Notification] {
+        &self.notifications
+    }
+
+    /// True when nothing is shown (the strip draws nothing then)
+    pub fn is_empty(&self) -> bool {
+        self.notifications.is_empty()
+    }
+
+    /// Remove the newest message, uncovering the previous one
+    pub fn dismiss(&mut self) -> Option<Notification> {
+        self.notifications.pop()
+    }
+
+    pub fn clear(&mut self) {
+        self.notifications.clear();
+    }
+
+    /// Drop the messages that have expired at `now`; returns true if any did
+    pub fn expire(&mut self, now: Instant) -> bool {
+        let before = self.notifications.len();
+        self.notifications.retain(|n| n.expires > now);
+        self.notifications.len() != before
+    }
+
+    /// Key that dismisses the newest message while one is shown (default ESC)
+    pub fn set_dismiss_key(&mut self, key_code: KeyCode) {
+        self.dismiss_key = key_code;
+    }
+}
+
+impl View for NotifyStrip {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        let width = self.bounds.width_clamped() as usize;
+        let Some(newest) = self.notifications.last() else {
+            return;
+        };
+        if width == 0 || self.bounds.height_clamped() == 0 {
+            return;
+        }
+
+        let attr = self.map_color(newest.severity.color_index());
+        let mut buf = DrawBuffer::new(width);
+        buf.move_char(0, ' ', attr, width);
+
+        // Count of the messages hidden beneath, right-aligned: " +2 "
+        let older = self.notifications.len() - 1;
+        let count = if older > 0 { format!(" +{older} ") } else { String::new() };
+        let count_width = str_width(&count);
+        let text_width = width.saturating_sub(count_width + 1);
+        buf.move_str_clipped(1, &newest.message, attr, text_width);
+        if count_width > 0 && count_width < width {
+            buf.move_str(width - count_width, &count, attr);
+        }
+        write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y, &buf);
+    }
+
+    fn handle_event(&mut self, event: &mut Event) {
+        if self.is_empty() {
+            return;
+        }
+        let dismiss = match event.what {
+            EventType::MouseDown => event.mouse.pos.y == self.bounds.a.y && self.bounds.contains(event.mouse.pos),
+            EventType::Keyboard => event.key_code == self.dismiss_key,
+            _ => false,
+        };
+        if dismiss {
+            self.dismiss();
+            event.clear();
+        }
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        use crate::core::palette::{Palette, palettes};
+        Some(Palette::from_slice(palettes::CP_NOTIFY_STRIP))
+    }
+}
+
+impl IdleView for NotifyStrip {
+    fn idle(&mut self) {
+        self.expire(Instant::now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::event::MB_LEFT_BUTTON;
+    use crate::core::geometry::Point;
+    use crate::core::palette::Attr;
+    use crate::terminal::{render_to_cells, row_text};
+
+    const SECOND: Duration = Duration::from_secs(1);
+
+    #[test]
+    fn test_newest_shown_with_count_and_severity_colors() {
+        let mut strip = NotifyStrip::new(Rect::new(0, 2, 30, 3));
+        assert!(render_to_cells(&mut strip, 30, 3)[2].iter().all(|c| c.ch == ' ' && c.attr == Attr::from_u8(0x07)));
+
+        strip.push("Build started", Severity::Info, SECOND);
+        let cells = render_to_cells(&mut strip, 30, 3);
+        assert_eq!(row_text(&cells[2]).trim_end(), " Build started");
+        assert_eq!(cells[2][0].attr, Attr::from_u8(0x30));
+
+        strip.push("Disk almost full", Severity::Warning, SECOND);
+        strip.push("Build failed", Severity::Error, SECOND);
+        let cells = render_to_cells(&mut strip, 30, 3);
+        assert_eq!(row_text(&cells[2]), " Build failed              +2 ");
+        assert_eq!(cells[2][29].attr, Attr::from_u8(0x74));
+
+        for i in 0..MAX_NOTIFICATIONS {
+            strip.push(&format!("Message {i}"), Severity::Info, SECOND);
+        }
+        assert_eq!(strip.notifications().len(), MAX_NOTIFICATIONS);
+        assert_eq!(strip.notifications()[0].message, "Message 0");
+    }
+
+    #[test]
+    fn test_messages_expire_on_their_own_timers() {
+        let now = Instant::now();
+        let mut strip = NotifyStrip::new(Rect::new(0, 0, 30, 1));
+        strip.push_at("short", Severity::Info, SECOND, now);
+        strip.push_at("long", Severity::Info, 3 * SECOND, now);
+
+        assert!(!strip.expire(now));
+        assert!(strip.expire(now + 2 * SECOND));
+        assert_eq!(strip.notifications()[0].message, "long");
+        assert!(strip.expire(now + 3 * SECOND));
+        assert!(strip.is_empty());
+    }
+
+    #[test]
+    fn test_click_or_key_dismisses_newest() {
+        let mut strip = NotifyStrip::new(Rect::new(0, 10, 30, 11));
+        strip.push("first", Severity::Info, SECOND);
+        strip.push("second", Severity::Info, SECOND);
+
+        let mut outside = Event::mouse(EventType::MouseDown, Point::new(5, 9), MB_LEFT_BUTTON, false);
+        strip.handle_event(&mut outside);
+        assert_eq!(outside.what, EventType::MouseDown);
+
+        let mut click = Event::mouse(EventType::MouseDown, Point::new(5, 10), MB_LEFT_BUTTON, false);
+        strip.handle_event(&mut click);
+        assert_eq!(click.what, EventType::Nothing);
+        assert_eq!(strip.notifications()[0].message, "first");
+
+        let mut key = Event::keyboard(KB_ESC);
+        strip.handle_event(&mut key);
+        assert_eq!(key.what, EventType::Nothing);
+        assert!(strip.is_empty());
+
+        // Nothing shown: the key passes through
+        let mut key = Event::keyboard(KB_ESC);
+        strip.handle_event(&mut key);
+        assert_eq!(key.what, EventType::Keyboard);
+    }
+}
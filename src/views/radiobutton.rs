@@ -9,6 +9,13 @@
 // in a group can be selected at a time. Radio buttons with the same group_id
 // form a mutually exclusive group.
 //
+// Exclusion is coordinated by the owner Group: a radio button that becomes
+// selected from the keyboard turns the event into a CM_RADIO_SELECTED
+// broadcast, and the Group deselects its other children with the same
+// group_id (Group::deselect_radio_group). Buttons of one group must therefore
+// share the same owner. select() / set_selected() called from code only
+// change the button itself.
+//
 // Visual appearance:
 //   ( ) Unselected option
 //   (•) Selected option
@@ -22,11 +29,12 @@
 //       1,  // group_id
 //   );
 
+use crate::core::command::CM_RADIO_SELECTED;
 use crate::core::event::Event;
 use crate::core::geometry::Rect;
 use crate::core::state::StateFlags;
 use crate::terminal::Terminal;
-use super::view::View;
+use super::view::{DataValue, View};
 use super::cluster::{Cluster, ClusterState};
 
 /// RadioButton - A mutually exclusive selection control with a label
@@ -68,7 +76,9 @@ impl RadioButton {
         self.cluster_state.value != 0
     }
 
-    /// Select this radio button (should deselect others in the group)
+    /// Select this radio button
+    ///
+    /// Only the owner Group deselects the others, when the user selects a button.
     pub fn select(&mut self) {
         self.cluster_state.set_value(1);
     }
@@ -90,7 +100,13 @@ impl View for RadioButton {
 
     fn handle_event(&mut self, event: &mut Event) {
         // Use Cluster trait's standard event handling
+        let was_selected = self.is_selected();
         self.handle_cluster_event(event);
+
+        // Ask the owner group to deselect the other buttons of this group
+        if !was_selected && self.is_selected() {
+            *event = Event::broadcast(CM_RADIO_SELECTED);
+        }
     }
 
     fn draw(&mut self, terminal: &mut Terminal) {
@@ -102,6 +118,16 @@ impl View for RadioButton {
         true
     }
 
+    fn get_data(&self) -> Option<DataValue> {
+        Some(DataValue::Value(self.cluster_state.value))
+    }
+
+    fn set_data(&mut self, value: &DataValue) {
+        if let DataValue::Value(value) = value {
+            self.cluster_state.set_value(*value);
+        }
+    }
+
     fn state(&self) -> StateFlags {
         self.state
     }
@@ -123,6 +149,14 @@ impl View for RadioButton {
         Some(Palette::from_slice(palettes::CP_CLUSTER))
     }
 
+    fn as_radio_button(&self) -> Option<&RadioButton> {
+        Some(self)
+    }
+
+    fn as_radio_button_mut(&mut self) -> Option<&mut RadioButton> {
+        Some(self)
+    }
+
     fn get_owner_type(&self) -> super::view::OwnerType {
         self.owner_type
     }
@@ -157,7 +191,6 @@ impl Cluster for RadioButton {
     /// Radio buttons select (don't toggle) on space
     fn on_space_pressed(&mut self) {
         self.select();
-        // TODO: Parent should deselect other radio buttons in the same group
     }
 }
 
@@ -328,4 +361,30 @@ mod tests {
 
         assert!(radio.is_selected());
     }
+
+    #[test]
+    fn test_selecting_deselects_same_group_only() {
+        use crate::core::event::{EventType, KeyCode};
+        use crate::views::group::Group;
+
+        let mut group = Group::new(Rect::new(0, 0, 30, 5));
+        for (y, group_id, selected) in [(0, 1, true), (1, 1, false), (2, 1, false), (3, 2, true)] {
+            let mut radio = RadioButton::new(Rect::new(0, y, 20, y + 1), "Option", group_id);
+            radio.set_selected(selected);
+            group.add(Box::new(radio));
+        }
+        let selected = |group: &Group| -> Vec<bool> {
+            (0..group.len()).map(|i| group.child_at(i).as_radio_button().unwrap().is_selected()).collect()
+        };
+
+        group.set_focus_to(2);
+        let mut event = Event::keyboard(' ' as KeyCode);
+        group.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Nothing);
+        assert_eq!(selected(&group), vec![false, false, true, true]);
+
+        // Selecting the already selected button changes nothing
+        group.handle_event(&mut Event::keyboard(' ' as KeyCode));
+        assert_eq!(selected(&group), vec![false, false, true, true]);
+    }
 }
@@ -1,8 +1,14 @@
 // (C) 2025 - Enzo Lombardi
 
 //! ScrollBar view - vertical or horizontal scrollbar with draggable indicator.
+//!
+//! Holding the mouse button on an arrow keeps scrolling: the first repeat
+//! comes after a short delay, then at a steady (accelerating) rate. The
+//! timing is configurable with [`ScrollBar::set_auto_repeat()`]. Dragging the
+//! indicator (thumb) sets the value from the pointer position.
 
 use super::view::{write_line_to_terminal, View};
+use crate::core::auto_repeat::AutoRepeat;
 use crate::core::draw::DrawBuffer;
 use crate::core::event::{
     Event, EventType, KB_DOWN, KB_END, KB_HOME, KB_LEFT, KB_PGDN, KB_PGUP, KB_RIGHT, KB_UP,
@@ -11,6 +17,7 @@ use crate::core::event::{
 use crate::core::geometry::{Point, Rect};
 use crate::core::palette::{SCROLLBAR_INDICATOR, SCROLLBAR_PAGE};
 use crate::terminal::Terminal;
+use std::time::Instant;
 
 /// Scroll bar part codes (used by getPartCode() method)
 const SB_INDICATOR: i16 = 0;
@@ -48,6 +55,11 @@ pub struct ScrollBar {
     is_vertical: bool,
     owner: Option<*const dyn View>,
     owner_type: super::view::OwnerType,
+    repeat: AutoRepeat,
+    /// Arrow held down while auto-repeating
+    repeat_part: Option<i16>,
+    /// The indicator is being dragged
+    dragging: bool,
 }
 
 impl ScrollBar {
@@ -63,6 +75,9 @@ impl ScrollBar {
             is_vertical: true,
             owner: None,
             owner_type: super::view::OwnerType::Window, // Default to Window context
+            repeat: AutoRepeat::default(),
+            repeat_part: None,
+            dragging: false,
         }
     }
 
@@ -78,6 +93,9 @@ impl ScrollBar {
             is_vertical: false,
             owner: None,
             owner_type: super::view::OwnerType::Window, // Default to Window context
+            repeat: AutoRepeat::default(),
+            repeat_part: None,
+            dragging: false,
         }
     }
 
@@ -111,6 +129,11 @@ impl ScrollBar {
         self.value
     }
 
+    /// Timing used while an arrow is held down
+    pub fn set_auto_repeat(&mut self, repeat: AutoRepeat) {
+        self.repeat = repeat;
+    }
+
     /// Get the size of the scrollbar track (not including arrows)
     fn get_size(&self) -> i32 {
         if self.is_vertical {
@@ -132,11 +155,19 @@ impl ScrollBar {
         }
     }
 
+    /// Value for the indicator dropped at `p` (inverse of `get_pos()`)
+    fn value_at(&self, p: Point) -> i32 {
+        let s = self.get_size();
+        let rel = if self.is_vertical { p.y - self.bounds.a.y } else { p.x - self.bounds.a.x };
+        let pos = (rel as i32 - 1).clamp(0, s - 1);
+        if s <= 1 {
+            return self.min_val;
+        }
+        let range = self.max_val - self.min_val;
+        self.min_val + (pos * range + (s - 1) / 2) / (s - 1)
+    }
+
     /// Get the part of the scrollbar at a given position
-    #[expect(
-        dead_code,
-        reason = "Borland TV API - reserved for advanced scrollbar interaction"
-    )]
     fn get_part_at(&self, p: Point) -> i16 {
         let rel_x = p.x - self.bounds.a.x;
         let rel_y = p.y - self.bounds.a.y;
@@ -173,10 +204,6 @@ impl ScrollBar {
     }
 
     /// Scroll by a given part
-    #[expect(
-        dead_code,
-        reason = "Borland TV API - reserved for advanced scrollbar interaction"
-    )]
     fn scroll_step(&mut self, part: i16) -> i32 {
         match part {
             SB_UP_ARROW => -self.ar_step,
@@ -186,6 +213,33 @@ impl ScrollBar {
             _ => 0,
         }
     }
+
+    /// Step once for an arrow click and start repeating while it is held
+    fn press_arrow(&mut self, part: i16) {
+        let step = self.scroll_step(part);
+        self.set_value(self.value + step);
+        self.repeat_part = Some(part);
+        self.repeat.start(Instant::now());
+    }
+
+    /// Apply the repeats due at `now` while the button is held at `pos`
+    /// Matches Borland: TScrollBar::handleEvent() do { ... } while (mouseEvent(event, evMouseAuto))
+    fn auto_step(&mut self, pos: Point, now: Instant) {
+        let Some(part) = self.repeat_part else {
+            return;
+        };
+        let steps = self.repeat.poll(now);
+        // Only scroll while the pointer is still over the pressed arrow
+        if steps > 0 && self.bounds.contains(pos) && self.get_part_at(pos) == part {
+            let step = self.scroll_step(part);
+            self.set_value(self.value.saturating_add(step.saturating_mul(steps as i32)));
+        }
+    }
+
+    fn stop_repeat(&mut self) {
+        self.repeat.stop();
+        self.repeat_part = None;
+    }
 }
 
 impl View for ScrollBar {
@@ -312,89 +366,38 @@ impl View for ScrollBar {
             }
         } else if event.what == EventType::MouseDown && (event.mouse.buttons & MB_LEFT_BUTTON) != 0 {
             let mouse_pos = event.mouse.pos;
-
-            if self.is_vertical {
-                // Check if mouse is within scrollbar bounds
-                if mouse_pos.x >= self.bounds.a.x && mouse_pos.x < self.bounds.b.x
-                    && mouse_pos.y >= self.bounds.a.y && mouse_pos.y < self.bounds.b.y {
-
-                    let rel_y = mouse_pos.y - self.bounds.a.y;
-                    let height = self.bounds.height();
-
-                    if rel_y == 0 {
-                        // Up arrow clicked
-                        self.value = (self.value - self.ar_step).max(self.min_val);
-                        event.clear();
-                    } else if rel_y == height - 1 {
-                        // Down arrow clicked
-                        self.value = (self.value + self.ar_step).min(self.max_val);
-                        event.clear();
-                    } else {
-                        // Page area clicked - calculate position
-                        let range = self.max_val - self.min_val;
-                        if range > 0 {
-                            let thumb_size = 1;
-                            let track_size = (height - 2 - thumb_size).max(1) as i32;
-                            let thumb_pos = if range > 0 {
-                                ((self.value - self.min_val) * track_size / range) as i16
-                            } else {
-                                0
-                            };
-
-                            if rel_y < thumb_pos + 1 {
-                                // Clicked above thumb - page up
-                                self.value = (self.value - self.pg_step).max(self.min_val);
-                            } else if rel_y > thumb_pos + 1 {
-                                // Clicked below thumb - page down
-                                self.value = (self.value + self.pg_step).min(self.max_val);
-                            }
-                            event.clear();
-                        }
-                    }
-                }
-            } else {
-                // Horizontal scrollbar
-                if mouse_pos.y >= self.bounds.a.y && mouse_pos.y < self.bounds.b.y
-                    && mouse_pos.x >= self.bounds.a.x && mouse_pos.x < self.bounds.b.x {
-
-                    let rel_x = mouse_pos.x - self.bounds.a.x;
-                    let width = self.bounds.width();
-
-                    if rel_x == 0 {
-                        // Left arrow clicked
-                        self.value = (self.value - self.ar_step).max(self.min_val);
-                        event.clear();
-                    } else if rel_x == width - 1 {
-                        // Right arrow clicked
-                        self.value = (self.value + self.ar_step).min(self.max_val);
-                        event.clear();
-                    } else {
+            if self.bounds.contains(mouse_pos) {
+                match self.get_part_at(mouse_pos) {
+                    part @ (SB_UP_ARROW | SB_DOWN_ARROW) => self.press_arrow(part),
+                    SB_INDICATOR => self.dragging = true,
+                    part => {
                         // Page area clicked
-                        let range = self.max_val - self.min_val;
-                        if range > 0 {
-                            let thumb_size = 1;
-                            let track_size = (width - 2 - thumb_size).max(1) as i32;
-                            let thumb_pos = if range > 0 {
-                                ((self.value - self.min_val) * track_size / range) as i16
-                            } else {
-                                0
-                            };
-
-                            if rel_x < thumb_pos + 1 {
-                                // Clicked left of thumb - page left
-                                self.value = (self.value - self.pg_step).max(self.min_val);
-                            } else if rel_x > thumb_pos + 1 {
-                                // Clicked right of thumb - page right
-                                self.value = (self.value + self.pg_step).min(self.max_val);
-                            }
-                            event.clear();
-                        }
+                        let step = self.scroll_step(part);
+                        self.set_value(self.value + step);
                     }
                 }
+                event.clear();
+            }
+        } else if self.dragging && matches!(event.what, EventType::MouseMove | EventType::MouseUp) {
+            // Matches Borland: TScrollBar::handleEvent() tracks the indicator until release
+            self.set_value(self.value_at(event.mouse.pos));
+            if event.what == EventType::MouseUp {
+                self.dragging = false;
             }
+            event.clear();
+        } else if event.what == EventType::MouseAuto && self.repeat_part.is_some() {
+            self.auto_step(event.mouse.pos, Instant::now());
+            event.clear();
+        } else if event.what == EventType::MouseUp && self.repeat_part.is_some() {
+            self.stop_repeat();
+            event.clear();
         }
     }
 
+    fn has_mouse_capture(&self) -> bool {
+        self.repeat_part.is_some() || self.dragging
+    }
+
     fn set_owner(&mut self, owner: *const dyn View) {
         self.owner = Some(owner);
     }
@@ -549,6 +552,9 @@ impl ScrollBarBuilder {
             is_vertical: self.is_vertical,
             owner: None,
             owner_type: super::view::OwnerType::Window,
+            repeat: AutoRepeat::default(),
+            repeat_part: None,
+            dragging: false,
         }
     }
 
@@ -563,3 +569,40 @@ impl Default for ScrollBarBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_held_arrow_repeats() {
+        let mut sb = ScrollBar::new_vertical(Rect::new(10, 0, 11, 10));
+        sb.set_params(0, 0, 100, 10, 1);
+        sb.set_auto_repeat(AutoRepeat::new(Duration::from_millis(300), Duration::from_millis(50)));
+
+        let down_arrow = Point::new(10, 9);
+        let mut event = Event::mouse(EventType::MouseDown, down_arrow, MB_LEFT_BUTTON, false);
+        sb.handle_event(&mut event);
+        assert_eq!(sb.get_value(), 1);
+        assert!(sb.has_mouse_capture());
+
+        // Held for the delay plus 4 intervals: 5 more steps (fake clock)
+        let start = Instant::now();
+        sb.repeat.start(start);
+        for ms in (0..=500).step_by(20) {
+            sb.auto_step(down_arrow, start + Duration::from_millis(ms));
+        }
+        assert_eq!(sb.get_value(), 6);
+
+        // No scrolling while the pointer is off the arrow
+        sb.auto_step(Point::new(10, 5), start + Duration::from_millis(1000));
+        assert_eq!(sb.get_value(), 6);
+
+        let mut event = Event::mouse(EventType::MouseUp, down_arrow, 0, false);
+        sb.handle_event(&mut event);
+        assert!(!sb.has_mouse_capture());
+        sb.auto_step(down_arrow, start + Duration::from_millis(2000));
+        assert_eq!(sb.get_value(), 6);
+    }
+}
@@ -27,7 +27,7 @@ use crate::core::event::Event;
 use crate::core::state::StateFlags;
 use crate::core::command::CommandId;
 use crate::terminal::Terminal;
-use super::view::View;
+use super::view::{DataValue, View};
 use super::list_viewer::{ListViewer, ListViewerState};
 
 /// SortedListBox - A list that maintains items in sorted order
@@ -320,6 +320,16 @@ impl View for SortedListBox {
         self.list_state.focused.unwrap_or(0)
     }
 
+    fn get_data(&self) -> Option<DataValue> {
+        Some(DataValue::Selection(self.list_state.focused))
+    }
+
+    fn set_data(&mut self, value: &DataValue) {
+        if let DataValue::Selection(Some(index)) = value {
+            self.set_selection(*index);
+        }
+    }
+
     fn set_owner(&mut self, owner: *const dyn View) {
         self.owner = Some(owner);
     }
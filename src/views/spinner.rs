@@ -0,0 +1,445 @@
+// (C) 2025 - Enzo Lombardi
+
+//! Spinner view - numeric field with up/down arrows.
+//!
+//! Holds an `i64` between a minimum and a maximum. Up/Down and clicks on the
+//! `▲`/`▼` glyphs in the last two columns change the value by the step,
+//! clamped to the range. Digits can also be typed into the field, which is an
+//! [`InputLine`] filtered by a [`RangeValidator`]: the text is checked when
+//! Enter is pressed or the focus leaves, and text outside the range brings
+//! back the previous value.
+//!
+//! While focused the field edits like any input line; otherwise the value is
+//! drawn right-aligned. Changes made with the arrows or Enter turn the event
+//! into the spinner's command, if it has one.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use turbo_vision::core::geometry::Rect;
+//! use turbo_vision::views::dialog::Dialog;
+//! use turbo_vision::views::spinner::SpinnerBuilder;
+//!
+//! const CM_WIDTH_CHANGED: u16 = 1200;
+//!
+//! let mut dialog = Dialog::new(Rect::new(10, 5, 40, 12), "Page");
+//! dialog.add(
+//!     SpinnerBuilder::new()
+//!         .bounds(Rect::new(2, 2, 12, 3))
+//!         .range(20, 200)
+//!         .step(5)
+//!         .value(80)
+//!         .command(CM_WIDTH_CHANGED)
+//!         .build_boxed(),
+//! );
+//! ```
+
+use super::input_line::InputLine;
+use super::validator::{RangeValidator, Validator};
+use super::view::{write_line_to_terminal, DataValue, View};
+use crate::core::command::CommandId;
+use crate::core::draw::DrawBuffer;
+use crate::core::event::{Event, EventType, KB_DOWN, KB_ENTER, KB_UP, MB_LEFT_BUTTON};
+use crate::core::geometry::Rect;
+use crate::core::palette::{INPUT_ARROWS, INPUT_NORMAL};
+use crate::core::state::{StateFlags, SF_FOCUSED};
+use crate::terminal::Terminal;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Characters an `i64` takes at most, sign included
+const MAX_DIGITS: usize = 20;
+
+/// Numeric field with up/down arrows
+pub struct Spinner {
+    bounds: Rect,
+    input: InputLine,
+    validator: Rc<RefCell<RangeValidator>>,
+    value: i64,
+    min: i64,
+    max: i64,
+    step: i64,
+    command: CommandId,
+    state: StateFlags,
+    owner: Option<*const dyn View>,
+    owner_type: super::view::OwnerType,
+}
+
+impl Spinner {
+    /// Creates a spinner for `min..=max`, starting at `min` with a step of 1
+    ///
+    /// `command` is sent when the arrows or Enter change the value; 0 sends nothing.
+    pub fn new(bounds: Rect, min: i64, max: i64, command: CommandId) -> Self {
+        let (min, max) = (min.min(max), min.max(max));
+        let validator = Rc::new(RefCell::new(RangeValidator::new(min, max)));
+        let data = Rc::new(RefCell::new(min.to_string()));
+        let input = InputLine::with_validator(Self::input_bounds(bounds), MAX_DIGITS, data, Rc::<RefCell<RangeValidator>>::clone(&validator));
+        Self {
+            bounds,
+            input,
+            validator,
+            value: min,
+            min,
+            max,
+            step: 1,
+            command,
+            state: 0,
+            owner: None,
+            owner_type: super::view::OwnerType::Dialog,
+        }
+    }
+
+    /// The field, left of the two arrow columns
+    fn input_bounds(bounds: Rect) -> Rect {
+        Rect::new(bounds.a.x, bounds.a.y, bounds.b.x - 2, bounds.a.y + 1)
+    }
+
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    /// Set the value, clamped to the range; no command is sent
+    pub fn set_value(&mut self, value: i64) {
+        self.apply(value);
+    }
+
+    pub fn range(&self) -> (i64, i64) {
+        (self.min, self.max)
+    }
+
+    /// Change the range, clamping the value into it
+    pub fn set_range(&mut self, min: i64, max: i64) {
+        (self.min, self.max) = (min.min(max), min.max(max));
+        self.validator = Rc::new(RefCell::new(RangeValidator::new(self.min, self.max)));
+        self.input.set_validator(Rc::<RefCell<RangeValidator>>::clone(&self.validator));
+        self.apply(self.value);
+    }
+
+    pub fn step(&self) -> i64 {
+        self.step
+    }
+
+    /// Amount the arrows add or subtract (at least 1)
+    pub fn set_step(&mut self, step: i64) {
+        self.step = step.max(1);
+    }
+
+    /// Store `value` clamped to the range and show it; returns true if it changed
+    fn apply(&mut self, value: i64) -> bool {
+        let value = value.clamp(self.min, self.max);
+        let changed = value != self.value;
+        self.value = value;
+        self.input.set_text(value.to_string());
+        changed
+    }
+
+    /// Take the typed text as the value if the validator accepts it, else show the value again
+    fn commit_text(&mut self) -> bool {
+        let text = self.input.get_text();
+        let validator = self.validator.borrow();
+        let typed = validator.is_valid(&text).then(|| validator.parse_value(&text).ok()).flatten();
+        drop(validator);
+        self.apply(typed.unwrap_or(self.value))
+    }
+
+    /// Move by `steps` steps from the committed text
+    fn spin(&mut self, steps: i64) -> bool {
+        let typed = self.commit_text();
+        let value = self.value.saturating_add(self.step.saturating_mul(steps));
+        self.apply(value) || typed
+    }
+
+    /// Turn a handled event into the command, or just consume it
+    fn finish(&self, event: &mut Event, changed: bool) {
+        if changed && self.command != 0 {
+            *event = Event::command(self.command);
+        } else {
+            event.clear();
+        }
+    }
+}
+
+impl View for Spinner {
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn set_bounds(&mut self, bounds: Rect) {
+        self.bounds = bounds;
+        self.input.set_bounds(Self::input_bounds(bounds));
+    }
+
+    fn draw(&mut self, terminal: &mut Terminal) {
+        if self.bounds.width_clamped() < 3 {
+            return;
+        }
+        if self.is_focused() {
+            self.input.draw(terminal);
+        } else {
+            let width = self.bounds.width_clamped() as usize - 2;
+            let text = self.value.to_string();
+            let mut buf = DrawBuffer::new(width);
+            let color = self.map_color(INPUT_NORMAL);
+            buf.move_char(0, ' ', color, width);
+            buf.move_str_clipped(width.saturating_sub(text.len()), &text, color, width);
+            write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y, &buf);
+        }
+
+        let mut buf = DrawBuffer::new(2);
+        buf.move_str(0, "▲▼", self.map_color(INPUT_ARROWS));
+        write_line_to_terminal(terminal, self.bounds.b.x - 2, self.bounds.a.y, &buf);
+    }
+
+    fn handle_event(&mut self, event: &mut Event) {
+        match event.what {
+            EventType::MouseDown
+                if event.mouse.buttons & MB_LEFT_BUTTON != 0
+                    && event.mouse.pos.y == self.bounds.a.y
+                    && event.mouse.pos.x >= self.bounds.b.x - 2
+                    && event.mouse.pos.x < self.bounds.b.x =>
+            {
+                let steps = if event.mouse.pos.x == self.bounds.b.x - 2 { 1 } else { -1 };
+                let changed = self.spin(steps);
+                self.finish(event, changed);
+            }
+            EventType::Keyboard if self.is_focused() && matches!(event.key_code, KB_UP | KB_DOWN) => {
+                let changed = self.spin(if event.key_code == KB_UP { 1 } else { -1 });
+                self.finish(event, changed);
+            }
+            // Enter applies typed text; with nothing to apply it goes on to the dialog
+            EventType::Keyboard if self.is_focused() && event.key_code == KB_ENTER => {
+                if self.commit_text() {
+                    self.finish(event, true);
+                }
+            }
+            _ => self.input.handle_event(event),
+        }
+    }
+
+    fn can_focus(&self) -> bool {
+        true
+    }
+
+    fn has_mouse_capture(&self) -> bool {
+        self.input.has_mouse_capture()
+    }
+
+    fn state(&self) -> StateFlags {
+        self.state
+    }
+
+    /// Losing the focus validates the typed text
+    fn set_state(&mut self, state: StateFlags) {
+        let lost_focus = self.state & SF_FOCUSED != 0 && state & SF_FOCUSED == 0;
+        self.state = state;
+        self.input.set_state(state);
+        if lost_focus {
+            self.commit_text();
+        }
+    }
+
+    fn update_cursor(&self, terminal: &mut Terminal) {
+        self.input.update_cursor(terminal);
+    }
+
+    fn get_data(&self) -> Option<DataValue> {
+        Some(DataValue::Text(self.value.to_string()))
+    }
+
+    fn set_data(&mut self, value: &DataValue) {
+        if let DataValue::Text(text) = value {
+            if let Ok(value) = self.validator.borrow().parse_value(text) {
+                self.value = value;
+            }
+        }
+        self.apply(self.value);
+    }
+
+    fn set_owner(&mut self, owner: *const dyn View) {
+        self.owner = Some(owner);
+        self.input.set_owner(owner);
+    }
+
+    fn get_owner(&self) -> Option<*const dyn View> {
+        self.owner
+    }
+
+    fn get_palette(&self) -> Option<crate::core::palette::Palette> {
+        use crate::core::palette::{palettes, Palette};
+        Some(Palette::from_slice(palettes::CP_INPUT_LINE))
+    }
+
+    fn get_owner_type(&self) -> super::view::OwnerType {
+        self.owner_type
+    }
+
+    fn set_owner_type(&mut self, owner_type: super::view::OwnerType) {
+        self.owner_type = owner_type;
+        self.input.set_owner_type(owner_type);
+    }
+}
+
+/// Builder for creating spinners with a fluent API.
+pub struct SpinnerBuilder {
+    bounds: Option<Rect>,
+    min: i64,
+    max: i64,
+    step: i64,
+    value: Option<i64>,
+    command: CommandId,
+}
+
+impl SpinnerBuilder {
+    pub fn new() -> Self {
+        Self {
+            bounds: None,
+            min: 0,
+            max: 100,
+            step: 1,
+            value: None,
+            command: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn bounds(mut self, bounds: Rect) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Sets the allowed values (default 0 to 100).
+    #[must_use]
+    pub fn range(mut self, min: i64, max: i64) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    #[must_use]
+    pub fn step(mut self, step: i64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the initial value (default: the minimum).
+    #[must_use]
+    pub fn value(mut self, value: i64) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Sets the command sent when the value changes (default: none).
+    #[must_use]
+    pub fn command(mut self, command: CommandId) -> Self {
+        self.command = command;
+        self
+    }
+
+    pub fn build(self) -> Spinner {
+        let bounds = self.bounds.expect("Spinner bounds must be set");
+        let mut spinner = Spinner::new(bounds, self.min, self.max, self.command);
+        spinner.set_step(self.step);
+        if let Some(value) = self.value {
+            spinner.set_value(value);
+        }
+        spinner
+    }
+
+    pub fn build_boxed(self) -> Box<Spinner> {
+        Box::new(self.build())
+    }
+}
+
+impl Default for SpinnerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::event::KB_BACKSPACE;
+    use crate::core::geometry::Point;
+    use crate::terminal::{render_to_cells, row_text};
+
+    const CM_CHANGED: CommandId = 1200;
+
+    fn spinner() -> Spinner {
+        SpinnerBuilder::new().bounds(Rect::new(0, 0, 8, 1)).range(-10, 10).step(4).value(2).command(CM_CHANGED).build()
+    }
+
+    fn click(x: i16) -> Event {
+        Event::mouse(EventType::MouseDown, Point::new(x, 0), MB_LEFT_BUTTON, false)
+    }
+
+    #[test]
+    fn test_arrows_step_and_clamp() {
+        let mut spinner = spinner();
+        spinner.set_focus(true);
+
+        let mut event = Event::keyboard(KB_UP);
+        spinner.handle_event(&mut event);
+        assert_eq!((spinner.value(), event.what, event.command), (6, EventType::Command, CM_CHANGED));
+        spinner.handle_event(&mut Event::keyboard(KB_UP));
+        assert_eq!(spinner.value(), 10, "clamped to the maximum");
+
+        // No change at the limit, so no command either
+        let mut event = Event::keyboard(KB_UP);
+        spinner.handle_event(&mut event);
+        assert_eq!((spinner.value(), event.what), (10, EventType::Nothing));
+
+        // ▲ in column 6, ▼ in column 7
+        spinner.handle_event(&mut click(7));
+        spinner.handle_event(&mut click(7));
+        assert_eq!(spinner.value(), 2);
+        spinner.handle_event(&mut click(6));
+        assert_eq!(spinner.value(), 6);
+        for _ in 0..5 {
+            spinner.handle_event(&mut Event::keyboard(KB_DOWN));
+        }
+        assert_eq!(spinner.value(), -10, "clamped to the minimum");
+    }
+
+    #[test]
+    fn test_typed_value_validated_on_focus_out() {
+        let mut spinner = spinner();
+        spinner.set_focus(true);
+        let type_text = |spinner: &mut Spinner, text: &str| {
+            for _ in 0..3 {
+                spinner.handle_event(&mut Event::keyboard(KB_BACKSPACE));
+            }
+            for ch in text.chars() {
+                spinner.handle_event(&mut Event::keyboard(ch as u16));
+            }
+        };
+
+        type_text(&mut spinner, "-7");
+        spinner.set_focus(false);
+        assert_eq!(spinner.value(), -7);
+
+        // Out of range brings back the previous value
+        spinner.set_focus(true);
+        type_text(&mut spinner, "-70");
+        spinner.set_focus(false);
+        assert_eq!((spinner.value(), spinner.get_data()), (-7, Some(DataValue::Text("-7".into()))));
+
+        // Enter applies the text and reports the change
+        spinner.set_focus(true);
+        type_text(&mut spinner, "9");
+        let mut event = Event::keyboard(KB_ENTER);
+        spinner.handle_event(&mut event);
+        assert_eq!((spinner.value(), event.what), (9, EventType::Command));
+        let mut event = Event::keyboard(KB_ENTER);
+        spinner.handle_event(&mut event);
+        assert_eq!(event.what, EventType::Keyboard, "nothing to apply, Enter goes on");
+    }
+
+    #[test]
+    fn test_value_drawn_right_aligned() {
+        let mut spinner = spinner();
+        spinner.set_value(-3);
+        let cells = render_to_cells(&mut spinner, 8, 1);
+        assert_eq!(row_text(&cells[0]), "    -3▲▼");
+    }
+}
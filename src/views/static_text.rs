@@ -3,7 +3,7 @@
 //! StaticText view - multi-line static text display with word wrapping.
 
 use super::view::{write_line_to_terminal, View};
-use crate::core::draw::DrawBuffer;
+use crate::core::draw::{char_width, str_width, DrawBuffer};
 use crate::core::event::Event;
 use crate::core::geometry::Rect;
 use crate::core::palette::STATIC_TEXT_NORMAL;
@@ -37,6 +37,17 @@ impl StaticText {
             owner_type: super::view::OwnerType::Dialog, // StaticText defaults to Dialog context
         }
     }
+
+    /// Get the displayed text
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replace the displayed text; shown on the next draw
+    /// Reach a StaticText inside a dialog with `child_at_mut(i).as_any_mut().downcast_mut::<StaticText>()`
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+    }
 }
 
 impl View for StaticText {
@@ -64,7 +75,7 @@ impl View for StaticText {
 
             // Calculate starting position based on centering
             let start_pos = if self.centered {
-                let line_len = line.len();
+                let line_len = str_width(line);
                 if width > line_len {
                     (width - line_len) / 2
                 } else {
@@ -104,6 +115,14 @@ impl View for StaticText {
         use crate::core::palette::{palettes, Palette};
         Some(Palette::from_slice(palettes::CP_STATIC_TEXT))
     }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
 }
 
 /// Builder for creating static text views with a fluent API.
@@ -193,3 +212,90 @@ impl Default for StaticTextBuilder {
         Self::new()
     }
 }
+
+/// Word-wrap a line to `width` screen columns, breaking words wider than that
+///
+/// Widths are measured with [`str_width()`], as StaticText draws them, so
+/// wide (CJK, emoji) characters count as two columns.
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0;
+    for mut word in text.split_whitespace() {
+        while str_width(word) > width {
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+                current_width = 0;
+            }
+            // Cut before the character that would overflow; always keep at least one
+            let mut columns = 0;
+            let cut = word
+                .char_indices()
+                .find(|&(i, ch)| {
+                    columns += char_width(ch);
+                    i > 0 && columns > width
+                })
+                .map_or(word.len(), |(i, _)| i);
+            lines.push(word[..cut].to_string());
+            word = &word[cut..];
+        }
+        let word_width = str_width(word);
+        if current_width > 0 && current_width + 1 + word_width > width {
+            lines.push(std::mem::take(&mut current));
+            current_width = 0;
+        }
+        if !current.is_empty() {
+            current.push(' ');
+            current_width += 1;
+        }
+        current.push_str(word);
+        current_width += word_width;
+    }
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(terminal: &Terminal, y: i16, width: i16) -> String {
+        (0..width).map(|x| terminal.read_cell(x, y).unwrap().ch).collect()
+    }
+
+    #[test]
+    fn test_set_text_changes_rendering() {
+        let mut terminal = Terminal::new_headless(20, 3);
+        let mut view: Box<dyn View> = Box::new(StaticText::new(Rect::new(0, 0, 20, 2), "Ready"));
+        view.draw(&mut terminal);
+        assert_eq!(row(&terminal, 0, 20).trim_end(), "Ready");
+
+        let text = view.as_any_mut().downcast_mut::<StaticText>().unwrap();
+        text.set_text("Saving\nfile.txt");
+        assert_eq!(text.text(), "Saving\nfile.txt");
+
+        // Shorter lines clear what was there before
+        view.draw(&mut terminal);
+        assert_eq!(row(&terminal, 0, 20).trim_end(), "Saving");
+        assert_eq!(row(&terminal, 1, 20).trim_end(), "file.txt");
+    }
+
+    #[test]
+    fn test_wrap_text() {
+        assert_eq!(wrap_text("one two three", 7), vec!["one two", "three"]);
+        assert_eq!(wrap_text("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        assert_eq!(wrap_text("", 10), vec![""]);
+    }
+
+    #[test]
+    fn test_wrap_text_counts_wide_characters_as_two_columns() {
+        assert_eq!(wrap_text("日本語 テキスト", 8), vec!["日本語", "テキスト"]);
+        assert_eq!(wrap_text("日本語テキスト", 5), vec!["日本", "語テ", "キス", "ト"]);
+        // A wide character never fits in one column; it gets a line of its own
+        assert_eq!(wrap_text("日本", 1), vec!["日", "本"]);
+        assert!(wrap_text("🎉 party time for everyone", 10).iter().all(|line| str_width(line) <= 10));
+    }
+}
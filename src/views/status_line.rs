@@ -4,11 +4,12 @@
 
 use crate::core::geometry::Rect;
 use crate::core::event::{Event, EventType, KeyCode, MB_LEFT_BUTTON};
-use crate::core::draw::DrawBuffer;
+use crate::core::draw::{str_width, strip_shortcut_markers, DrawBuffer};
 use crate::core::command::CommandId;
 use crate::core::palette::{STATUSLINE_NORMAL, STATUSLINE_SHORTCUT, STATUSLINE_SELECTED, STATUSLINE_SELECTED_SHORTCUT};
 use crate::terminal::Terminal;
-use super::view::{View, write_line_to_terminal};
+use super::view::{IdleView, View, write_line_to_terminal};
+use std::time::{Duration, Instant};
 
 pub struct StatusItem {
     pub text: String,
@@ -32,6 +33,7 @@ pub struct StatusLine {
     item_positions: Vec<(i16, i16)>, // (start_x, end_x) for each item
     selected_item: Option<usize>,    // Currently hovered/selected item
     hint_text: Option<String>,       // Context-sensitive help text
+    message: Option<(String, Instant)>, // Transient message shown instead of the items, until it expires
     options: u16,
     owner: Option<*const dyn View>,
 }
@@ -46,6 +48,7 @@ impl StatusLine {
             item_positions: Vec::new(),
             selected_item: None,
             hint_text: None,
+            message: None,
             options: OF_PRE_PROCESS,  // Status line processes in pre-process phase (matches Borland)
             owner: None,
         }
@@ -56,6 +59,28 @@ impl StatusLine {
         self.hint_text = hint;
     }
 
+    /// Show `message` instead of the items for `duration`, e.g. "File saved"
+    ///
+    /// The items come back once the message has expired; the application's
+    /// idle processing redraws the status line at that point.
+    pub fn flash_message(&mut self, message: &str, duration: Duration) {
+        self.message = Some((message.to_string(), Instant::now() + duration));
+    }
+
+    /// True while a flashed message is shown
+    pub fn is_flashing(&self) -> bool {
+        self.message.is_some()
+    }
+
+    /// Drop the flashed message if it has expired at `now`; returns true if it did
+    fn expire_message(&mut self, now: Instant) -> bool {
+        let expired = self.message.as_ref().is_some_and(|(_, until)| now >= *until);
+        if expired {
+            self.message = None;
+        }
+        expired
+    }
+
     /// Draw the status line with optional selected item highlighting
     fn draw_select(&mut self, terminal: &mut Terminal, selected: Option<usize>) {
         let width = self.bounds.width_clamped() as usize;
@@ -75,7 +100,7 @@ impl StatusLine {
 
         let mut x = 0;  // Start at position 0 (Borland starts at i=0)
         for (idx, item) in self.items.iter().enumerate() {
-            if x + item.text.len() + 4 < width {  // Need space for: space + text + space + separator
+            if x + str_width(&strip_shortcut_markers(&item.text)) + 4 < width {  // Need space for: space + text + space + separator
                 // Hit area starts at the leading space (matches Borland tstatusl.cc:204)
                 let start_x = x as i16;
 
@@ -96,23 +121,8 @@ impl StatusLine {
                 buf.put_char(x, ' ', item_normal);
                 x += 1;
 
-                // Parse ~X~ for highlighting - everything between tildes is highlighted
-                let mut chars = item.text.chars();
-                while let Some(ch) = chars.next() {
-                    if ch == '~' {
-                        // Read all characters until closing ~ in highlight color
-                        while let Some(shortcut_ch) = chars.next() {
-                            if shortcut_ch == '~' {
-                                break;  // Found closing tilde
-                            }
-                            buf.put_char(x, shortcut_ch, item_shortcut);
-                            x += 1;
-                        }
-                    } else {
-                        buf.put_char(x, ch, item_normal);
-                        x += 1;
-                    }
-                }
+                // Everything between tildes is highlighted
+                x += buf.move_str_with_shortcut(x, &item.text, item_normal, item_shortcut).0;
 
                 // Draw trailing space (Borland: b.moveChar(i+l+1, ' ', color, 1))
                 buf.put_char(x, ' ', item_normal);
@@ -169,6 +179,17 @@ impl View for StatusLine {
     }
 
     fn draw(&mut self, terminal: &mut Terminal) {
+        self.expire_message(Instant::now());
+        if let Some((ref message, _)) = self.message {
+            let width = self.bounds.width_clamped() as usize;
+            let normal_attr = self.map_color(STATUSLINE_NORMAL);
+            let mut buf = DrawBuffer::new(width);
+            buf.move_char(0, ' ', normal_attr, width);
+            buf.move_str_clipped(1, message, normal_attr, width.saturating_sub(1));
+            write_line_to_terminal(terminal, self.bounds.a.x, self.bounds.a.y, &buf);
+            return;
+        }
+
         // Draw with current selection (if any)
         self.draw_select(terminal, self.selected_item);
     }
@@ -254,3 +275,52 @@ impl View for StatusLine {
         Some(Palette::from_slice(palettes::CP_STATUSLINE))
     }
 }
+
+impl IdleView for StatusLine {
+    fn idle(&mut self) {
+        self.expire_message(Instant::now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::event::{KB_ALT_X, KB_F1};
+    use crate::terminal::{render_to_cells, row_text};
+
+    #[test]
+    fn test_draw_highlights_text_between_tildes() {
+        let mut status_line = StatusLine::new(
+            Rect::new(0, 0, 40, 1),
+            vec![StatusItem::new("~F1~ Help", KB_F1, 1), StatusItem::new("~Alt-X~ Exit", KB_ALT_X, 2)],
+        );
+        let cells = render_to_cells(&mut status_line, 40, 1);
+        let row = &cells[0];
+
+        assert!(row_text(row).starts_with(" F1 Help \u{2502}  Alt-X Exit \u{2502} "));
+        let normal = status_line.map_color(STATUSLINE_NORMAL);
+        let shortcut = status_line.map_color(STATUSLINE_SHORTCUT);
+        assert_ne!(normal, shortcut);
+        let attrs: Vec<_> = row[..9].iter().map(|cell| cell.attr).collect();
+        assert_eq!(attrs, vec![normal, shortcut, shortcut, normal, normal, normal, normal, normal, normal]);
+        // "Alt-X" starts after the separator and the next item's leading space
+        assert!(row[12..17].iter().all(|cell| cell.attr == shortcut));
+        assert_eq!(row[17].attr, normal);
+    }
+
+    #[test]
+    fn test_flashed_message_reverts_after_duration() {
+        let mut status_line = StatusLine::new(Rect::new(0, 0, 40, 1), vec![StatusItem::new("~F1~ Help", KB_F1, 1)]);
+        status_line.flash_message("File saved", Duration::from_secs(2));
+        assert!(status_line.is_flashing());
+        let cells = render_to_cells(&mut status_line, 40, 1);
+        assert_eq!(row_text(&cells[0]).trim_end(), " File saved");
+
+        let (_, until) = status_line.message.clone().unwrap();
+        assert!(!status_line.expire_message(until - Duration::from_millis(1)));
+        assert!(status_line.expire_message(until));
+        assert!(!status_line.is_flashing());
+        let cells = render_to_cells(&mut status_line, 40, 1);
+        assert!(row_text(&cells[0]).starts_with(" F1 Help "));
+    }
+}
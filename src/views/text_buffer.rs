@@ -0,0 +1,283 @@
+// (C) 2025 - Enzo Lombardi
+
+//! TextBuffer - text shared between a text control and the rest of the program.
+//!
+//! An [`InputLine`](super::input_line::InputLine) or [`Memo`](super::memo::Memo)
+//! bound to a `TextBuffer` can be read and written from any thread while its
+//! dialog runs. All access goes through [`get()`](TextBuffer::get),
+//! [`set()`](TextBuffer::set) and [`map()`](TextBuffer::map), which hold the
+//! lock only for the call, so no borrow can stay open while the dialog
+//! handles an event.
+//!
+//! # Migrating from `Rc<RefCell<String>>`
+//!
+//! The `Rc<RefCell<String>>` constructors keep working, but a `borrow()` still
+//! alive when the control handles an event panics, and the handle cannot be
+//! sent to another thread. To move over:
+//! - `Rc::new(RefCell::new(text))` becomes `TextBuffer::new(text)`
+//! - `InputLine::new(bounds, len, data)` becomes `InputLine::with_buffer(bounds, len, buffer)`,
+//!   and the builders' `.data(data)` becomes `.buffer(buffer)`
+//! - `data.borrow().clone()` becomes `buffer.get()`
+//! - `*data.borrow_mut() = text` becomes `buffer.set(text)`
+//!
+//! ```rust
+//! use turbo_vision::core::geometry::Rect;
+//! use turbo_vision::views::input_line::InputLineBuilder;
+//! use turbo_vision::views::text_buffer::TextBuffer;
+//!
+//! let name = TextBuffer::new("Ada");
+//! let input = InputLineBuilder::new().bounds(Rect::new(2, 2, 30, 3)).buffer(name.clone()).build();
+//!
+//! // A worker thread may read the field while the dialog edits it
+//! let reader = name.clone();
+//! std::thread::spawn(move || reader.map(|text| text.len())).join().unwrap();
+//! assert_eq!(input.get_text(), name.get());
+//! ```
+
+use std::cell::{Ref, RefCell, RefMut};
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, TryLockError};
+
+thread_local! {
+    /// Ids of the buffers this thread holds locked, to catch re-entrant use
+    static HELD: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Cloneable, `Send` handle to the text of a field
+///
+/// Clones share the same text. See the [module docs](self) for how it
+/// replaces `Rc<RefCell<String>>`.
+#[derive(Debug, Clone)]
+pub struct TextBuffer {
+    text: Arc<Mutex<String>>,
+    field_id: Arc<Mutex<Option<String>>>,
+    id: usize,
+}
+
+impl TextBuffer {
+    /// Creates a buffer holding `text`, with a new id
+    pub fn new(text: impl Into<String>) -> Self {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(1);
+        Self {
+            text: Arc::new(Mutex::new(text.into())),
+            field_id: Arc::default(),
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+
+    /// Id shared by all clones; panic messages name the buffer by it
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Names the field editing this buffer in panic messages
+    ///
+    /// Set by the owning control, see
+    /// [`InputLine::set_field_id()`](super::input_line::InputLine::set_field_id)
+    /// and [`Memo::set_field_id()`](super::memo::Memo::set_field_id).
+    pub(crate) fn set_field_id(&self, field_id: &str) {
+        *self.field_id.lock().unwrap_or_else(PoisonError::into_inner) = Some(field_id.to_string());
+    }
+
+    /// The buffer as panic messages name it: by its field if it has one
+    fn describe(&self) -> String {
+        match &*self.field_id.lock().unwrap_or_else(PoisonError::into_inner) {
+            Some(field_id) => format!("TextBuffer #{} of field '{field_id}'", self.id),
+            None => format!("TextBuffer #{}", self.id),
+        }
+    }
+
+    /// Copy of the current text
+    pub fn get(&self) -> String {
+        self.lock().clone()
+    }
+
+    /// Replaces the text
+    pub fn set(&self, text: impl Into<String>) {
+        *self.lock() = text.into();
+    }
+
+    /// Runs `f` on the text with the lock held and returns its result
+    ///
+    /// # Panics
+    ///
+    /// Panics if `f` uses this buffer again (the lock is not re-entrant).
+    pub fn map<R>(&self, f: impl FnOnce(&mut String) -> R) -> R {
+        f(&mut self.lock())
+    }
+
+    /// Locks the text until the guard is dropped
+    ///
+    /// Another thread holds the lock only for the length of one get/set/map,
+    /// so waiting for it is short; the same thread locking twice would wait
+    /// forever and panics instead. A panic on another thread while it held the
+    /// lock does not make the text unreadable.
+    pub(crate) fn lock(&self) -> TextGuard<'_> {
+        let guard = match self.text.try_lock() {
+            Ok(guard) => guard,
+            Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+            Err(TryLockError::WouldBlock) => {
+                assert!(
+                    !HELD.with(|held| held.borrow().contains(&self.id)),
+                    "{} is already in use on this thread: it was used again from inside map() or while the field read it",
+                    self.describe()
+                );
+                self.text.lock().unwrap_or_else(PoisonError::into_inner)
+            }
+        };
+        HELD.with(|held| held.borrow_mut().push(self.id));
+        TextGuard { guard, id: self.id }
+    }
+}
+
+impl Default for TextBuffer {
+    fn default() -> Self {
+        Self::new(String::new())
+    }
+}
+
+/// Locked text of a [`TextBuffer`]
+pub(crate) struct TextGuard<'a> {
+    guard: MutexGuard<'a, String>,
+    id: usize,
+}
+
+impl Deref for TextGuard<'_> {
+    type Target = String;
+
+    fn deref(&self) -> &String {
+        &self.guard
+    }
+}
+
+impl DerefMut for TextGuard<'_> {
+    fn deref_mut(&mut self) -> &mut String {
+        &mut self.guard
+    }
+}
+
+impl Drop for TextGuard<'_> {
+    fn drop(&mut self) {
+        HELD.with(|held| {
+            let mut held = held.borrow_mut();
+            if let Some(pos) = held.iter().rposition(|&id| id == self.id) {
+                held.remove(pos);
+            }
+        });
+    }
+}
+
+/// Where a text control keeps its text: the legacy shared cell or a [`TextBuffer`]
+#[derive(Clone)]
+pub(crate) enum TextData {
+    Cell(Rc<RefCell<String>>),
+    Buffer(TextBuffer),
+}
+
+impl TextData {
+    /// Reads the text
+    pub(crate) fn borrow(&self) -> TextRef<'_> {
+        match self {
+            Self::Cell(cell) => TextRef::Cell(cell.borrow()),
+            Self::Buffer(buffer) => TextRef::Buffer(buffer.lock()),
+        }
+    }
+
+    /// Writes the text
+    pub(crate) fn borrow_mut(&self) -> TextRefMut<'_> {
+        match self {
+            Self::Cell(cell) => TextRefMut::Cell(cell.borrow_mut()),
+            Self::Buffer(buffer) => TextRefMut::Buffer(buffer.lock()),
+        }
+    }
+
+    /// The buffer, if the text lives in one
+    pub(crate) fn buffer(&self) -> Option<&TextBuffer> {
+        match self {
+            Self::Cell(_) => None,
+            Self::Buffer(buffer) => Some(buffer),
+        }
+    }
+
+    /// Names the owning field in the buffer's panic messages (nothing to do for a cell)
+    pub(crate) fn set_field_id(&self, field_id: &str) {
+        if let Self::Buffer(buffer) = self {
+            buffer.set_field_id(field_id);
+        }
+    }
+}
+
+/// Read access returned by [`TextData::borrow()`]
+pub(crate) enum TextRef<'a> {
+    Cell(Ref<'a, String>),
+    Buffer(TextGuard<'a>),
+}
+
+impl Deref for TextRef<'_> {
+    type Target = String;
+
+    fn deref(&self) -> &String {
+        match self {
+            Self::Cell(text) => text,
+            Self::Buffer(text) => text,
+        }
+    }
+}
+
+/// Write access returned by [`TextData::borrow_mut()`]
+pub(crate) enum TextRefMut<'a> {
+    Cell(RefMut<'a, String>),
+    Buffer(TextGuard<'a>),
+}
+
+impl Deref for TextRefMut<'_> {
+    type Target = String;
+
+    fn deref(&self) -> &String {
+        match self {
+            Self::Cell(text) => text,
+            Self::Buffer(text) => text,
+        }
+    }
+}
+
+impl DerefMut for TextRefMut<'_> {
+    fn deref_mut(&mut self) -> &mut String {
+        match self {
+            Self::Cell(text) => text,
+            Self::Buffer(text) => text,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clones_share_text_across_threads() {
+        let buffer = TextBuffer::new("abc");
+        let other = buffer.clone();
+        std::thread::spawn(move || other.map(|text| text.push('d'))).join().unwrap();
+        assert_eq!(buffer.get(), "abcd");
+        assert_eq!(buffer.id(), buffer.clone().id());
+        assert_ne!(buffer.id(), TextBuffer::default().id());
+    }
+
+    #[test]
+    #[should_panic(expected = "is already in use on this thread")]
+    fn test_reentrant_use_panics_instead_of_deadlocking() {
+        let buffer = TextBuffer::new("abc");
+        buffer.map(|_| buffer.get());
+    }
+
+    #[test]
+    #[should_panic(expected = "of field 'email' is already in use")]
+    fn test_panic_names_the_owning_field() {
+        let buffer = TextBuffer::new("abc");
+        TextData::Buffer(buffer.clone()).set_field_id("email");
+        buffer.map(|_| buffer.get());
+    }
+}
@@ -171,7 +171,7 @@ impl RangeValidator {
 
     /// Parse input string to i64, supporting hex (0x) and octal (0) prefixes
     /// Matches Borland's get_val() and get_uval() functions (trangeva.cc:59-69)
-    fn parse_value(&self, input: &str) -> Result<i64, std::num::ParseIntError> {
+    pub(crate) fn parse_value(&self, input: &str) -> Result<i64, std::num::ParseIntError> {
         let trimmed = input.trim();
 
         // Support hexadecimal (0x prefix)
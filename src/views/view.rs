@@ -6,7 +6,7 @@ use crate::core::command::CommandId;
 use crate::core::draw::DrawBuffer;
 use crate::core::event::Event;
 use crate::core::geometry::Rect;
-use crate::core::state::{StateFlags, SF_FOCUSED, SF_SHADOW, SHADOW_ATTR, SHADOW_SIZE};
+use crate::core::state::{StateFlags, SF_DRAGGING, SF_FOCUSED, SF_RESIZING, SF_SHADOW, SHADOW_OFFSET, SHADOW_SIZE};
 use crate::terminal::Terminal;
 use std::io;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -31,6 +31,31 @@ pub enum OwnerType {
     Dialog, // Inside a Dialog
 }
 
+/// What a focused control does with Enter inside a dialog
+///
+/// Single-line fields default to `Submit`, so Enter presses the dialog's
+/// default button; multi-line editors default to `Consume`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnterBehavior {
+    /// Leave Enter unhandled so the dialog activates its default button
+    Submit,
+    /// Keep Enter: multi-line controls insert a line break, others ignore it
+    Consume,
+}
+
+/// Value held by a data control, read with [`View::get_data()`]
+///
+/// Matches Borland: the record filled by TView::getData() and read by setData()
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataValue {
+    /// Text of an input line or memo
+    Text(String),
+    /// Cluster value (CheckBox: 0 or 1, RadioButton: 1 when selected)
+    Value(u32),
+    /// Focused item of a list box
+    Selection(Option<usize>),
+}
+
 /// View trait - all UI components implement this
 ///
 /// ## Owner/Parent Communication Pattern
@@ -110,19 +135,34 @@ pub trait View {
         (self.state() & flag) == flag
     }
 
+    /// Returns true while the view is tracking the mouse (dragging, resizing,
+    /// a pressed button). Its owner then sends it MouseMove/MouseUp events
+    /// even when the mouse is outside its bounds.
+    fn has_mouse_capture(&self) -> bool {
+        (self.state() & (SF_DRAGGING | SF_RESIZING)) != 0
+    }
+
     /// Check if view has shadow enabled
     fn has_shadow(&self) -> bool {
         (self.state() & SF_SHADOW) != 0
     }
 
     /// Get bounds including shadow area
+    ///
+    /// Near the right or bottom screen edge this reaches past the screen;
+    /// clip it with [`Terminal::clip_to_screen()`] before reading cells.
     fn shadow_bounds(&self) -> Rect {
-        let mut bounds = self.bounds();
         if self.has_shadow() {
-            bounds.b.x += SHADOW_SIZE.0;
-            bounds.b.y += SHADOW_SIZE.1;
+            shadow_extent(self.bounds())
+        } else {
+            self.bounds()
         }
-        bounds
+    }
+
+    /// True if drawing the view paints every cell of its bounds
+    /// Owners may skip drawing views hidden behind opaque ones; only Window and Dialog report true
+    fn is_opaque(&self) -> bool {
+        false
     }
 
     /// Update cursor state (called after draw)
@@ -169,7 +209,7 @@ pub trait View {
 
     /// Dump this view's region of the terminal buffer to an ANSI file for debugging
     fn dump_to_file(&self, terminal: &Terminal, path: &str) -> io::Result<()> {
-        let bounds = self.shadow_bounds();
+        let bounds = terminal.clip_to_screen(self.shadow_bounds());
         terminal.dump_region(
             bounds.a.x as u16,
             bounds.a.y as u16,
@@ -185,6 +225,12 @@ pub trait View {
         false
     }
 
+    /// Check if Enter may fall back to this button when the default button is disabled
+    /// Only buttons marked with Button::set_ok_on_enter() return true
+    fn is_ok_on_enter_button(&self) -> bool {
+        false
+    }
+
     /// Get the command ID for this button (if it's a button)
     /// Returns None if not a button
     /// Used by Dialog to activate default button on Enter key
@@ -192,6 +238,18 @@ pub trait View {
         None
     }
 
+    /// Access this view as a radio button
+    /// Only implemented by RadioButton, other views return None
+    fn as_radio_button(&self) -> Option<&super::radiobutton::RadioButton> {
+        None
+    }
+
+    /// Access this view as a radio button (mutable)
+    /// Used by Group to deselect the other buttons of a radio group
+    fn as_radio_button_mut(&mut self) -> Option<&mut super::radiobutton::RadioButton> {
+        None
+    }
+
     /// Set the selection index for listbox views
     /// Only implemented by ListBox, other views ignore this
     fn set_list_selection(&mut self, _index: usize) {
@@ -204,6 +262,18 @@ pub trait View {
         0
     }
 
+    /// Current value of a data control (input line, cluster, list box, memo)
+    /// Matches Borland: TView::getData() - returns None for views without data
+    fn get_data(&self) -> Option<DataValue> {
+        None
+    }
+
+    /// Restore a value previously returned by get_data()
+    /// Matches Borland: TView::setData() - values of another kind are ignored
+    fn set_data(&mut self, _value: &DataValue) {
+        // Default: do nothing (no data)
+    }
+
     /// Get the union rect of previous and current bounds for redrawing
     /// Matches Borland: TView::locate() calculates union of old and new bounds
     /// Returns None if the view hasn't moved since last redraw
@@ -271,61 +341,27 @@ pub trait View {
     /// Shadow is semi-transparent - darkens the underlying content by 50%
     /// This matches the Borland Turbo Vision behavior more closely
     fn draw_shadow(&self, terminal: &mut Terminal) {
-        use crate::core::palette::Attr;
-
-        const SHADOW_FACTOR: f32 = 0.5; // Darken to 50% of original brightness
+        draw_shadow_bounds(terminal, self.bounds());
+    }
 
-        let bounds = self.bounds();
-        let mut buf = DrawBuffer::new(SHADOW_SIZE.0 as usize);
-
-        // Draw right edge shadow (1 column wide, offset by 1 vertically)
-        // Read existing cells and darken them for semi-transparency
-        for y in (bounds.a.y + 1)..(bounds.b.y + 1) {
-            for i in 0..SHADOW_SIZE.0 {
-                let x = bounds.b.x + i;
-
-                // Read the existing cell at this position
-                if let Some(existing_cell) = terminal.read_cell(x, y) {
-                    // Darken the existing cell's attribute
-                    let darkened_attr = existing_cell.attr.darken(SHADOW_FACTOR);
-                    buf.put_char(i as usize, existing_cell.ch, darkened_attr);
-                } else {
-                    // Out of bounds - use default shadow
-                    let default_attr = Attr::from_u8(SHADOW_ATTR);
-                    buf.put_char(i as usize, ' ', default_attr);
-                }
-            }
-            write_line_to_terminal(terminal, bounds.b.x, y, &buf);
-        }
+    /// Link this view to a sibling control by its ViewId (as returned by `add`)
+    /// Matches Borland: TLabel::link field, but the owner Group resolves the id
+    /// at event time, so links survive reordering and moving the group
+    fn set_link_id(&mut self, _id: ViewId) {
+        // Default: views without links ignore this
+    }
 
-        // Draw bottom edge shadow (offset by 1 horizontally, excludes right shadow area to prevent double-darkening)
-        let bottom_width = (bounds.b.x - bounds.a.x - 1) as usize;
-        let mut bottom_buf = DrawBuffer::new(bottom_width);
-
-        let shadow_y = bounds.b.y;
-        for i in 0..bottom_width {
-            let x = bounds.a.x + 1 + i as i16;
-
-            // Read the existing cell at this position
-            if let Some(existing_cell) = terminal.read_cell(x, shadow_y) {
-                // Darken the existing cell's attribute
-                let darkened_attr = existing_cell.attr.darken(SHADOW_FACTOR);
-                bottom_buf.put_char(i, existing_cell.ch, darkened_attr);
-            } else {
-                // Out of bounds - use default shadow
-                let default_attr = Attr::from_u8(SHADOW_ATTR);
-                bottom_buf.put_char(i, ' ', default_attr);
-            }
-        }
-        write_line_to_terminal(terminal, bounds.a.x + 1, bounds.b.y, &bottom_buf);
+    /// Get the linked control ViewId
+    /// Returns Some(ViewId) for a label with a linked control, None otherwise
+    /// Used by Group to implement focus transfer when clicking labels
+    fn link_id(&self) -> Option<ViewId> {
+        None // Default: not a label or no link
     }
 
     /// Get the linked control ViewId for labels
-    /// Matches Borland: TLabel::link field
-    /// Returns Some(ViewId) if this is a label with a linked control, None otherwise
-    /// Used by Group to implement focus transfer when clicking labels
+    #[deprecated(note = "use link_id()")]
     fn label_link(&self) -> Option<ViewId> {
-        None // Default: not a label or no link
+        self.link_id()
     }
 
     /// Initialize internal owner pointers after view is added to parent and won't move
@@ -336,6 +372,33 @@ pub trait View {
         // Default: no action needed
     }
 
+    /// A modal view opened on top of this one (Dialog::execute(), Application::exec_view())
+    ///
+    /// Views behind the modal view are inactive until `on_modal_leave()`: stop
+    /// animations, dim, etc. Containers pass the call on to their children.
+    /// Nested modal views call it once per level.
+    fn on_modal_enter(&mut self) {}
+
+    /// The modal view opened on top of this one closed
+    fn on_modal_leave(&mut self) {}
+
+    /// Added to a group, at its final bounds
+    ///
+    /// Called by Group::add() (so also Window::add() and Desktop::add()) after
+    /// `set_owner()`, with the same owner pointer. The view itself may still move
+    /// until `init_after_add()`; register accelerators or start timers here.
+    fn on_insert(&mut self, _owner: *const dyn View) {}
+
+    /// Removed from its group, just before it is dropped or handed back
+    fn on_remove(&mut self) {}
+
+    /// The window holding this view became (true) or stopped being (false) the
+    /// active one, the topmost focused window on the desktop
+    ///
+    /// Matches Borland: TView::setState(sfActive). Windows and groups pass the
+    /// call on to their children.
+    fn on_activate(&mut self, _active: bool) {}
+
     /// Constrain view bounds to parent/owner bounds
     /// Used after positioning (e.g., centering) to ensure view stays within valid area
     /// Matches Borland: TView::locate() constrains position to owner bounds
@@ -530,50 +593,49 @@ pub fn write_line_to_terminal(terminal: &mut Terminal, x: i16, y: i16, buf: &Dra
 /// This standalone function is only for special cases where you're drawing shadows
 /// for elements that aren't views (e.g., temporary dropdowns).
 pub fn draw_shadow_bounds(terminal: &mut Terminal, bounds: Rect) {
-    use crate::core::palette::Attr;
-
     const SHADOW_FACTOR: f32 = 0.5; // Darken to 50% of original brightness
 
-    let mut buf = DrawBuffer::new(SHADOW_SIZE.0 as usize);
-
-    // Draw right edge shadow (1 column wide, offset by 1 vertically)
-    // Read existing cells and darken them for semi-transparency
-    for y in (bounds.a.y + 1)..(bounds.b.y + 1) {
-        for i in 0..SHADOW_SIZE.0 {
-            let x = bounds.b.x + i;
-
-            // Read the existing cell at this position
-            if let Some(existing_cell) = terminal.read_cell(x, y) {
-                // Darken the existing cell's attribute
-                let darkened_attr = existing_cell.attr.darken(SHADOW_FACTOR);
-                buf.put_char(i as usize, existing_cell.ch, darkened_attr);
-            } else {
-                // Out of bounds - use default shadow
-                let default_attr = Attr::from_u8(SHADOW_ATTR);
-                buf.put_char(i as usize, ' ', default_attr);
-            }
+    // Read existing cells and darken them for semi-transparency; cells off
+    // screen are skipped, so the shadow never reaches past the buffer
+    for strip in shadow_strips(bounds) {
+        let strip = terminal.clip_to_screen(strip);
+        for y in strip.a.y..strip.b.y {
+            let cells: Vec<_> = (strip.a.x..strip.b.x)
+                .filter_map(|x| terminal.read_cell(x, y))
+                .map(|mut cell| {
+                    cell.attr = cell.attr.darken(SHADOW_FACTOR);
+                    cell
+                })
+                .collect();
+            terminal.write_line(strip.a.x as u16, y as u16, &cells);
         }
-        write_line_to_terminal(terminal, bounds.b.x, y, &buf);
     }
+}
 
-    // Draw bottom edge shadow (offset by 1 horizontally, excludes right shadow area to prevent double-darkening)
-    let bottom_width = (bounds.b.x - bounds.a.x - 1) as usize;
-    let mut bottom_buf = DrawBuffer::new(bottom_width);
+/// The cells a drop shadow covers for `bounds`: the right strip, then the bottom strip
+///
+/// The right strip includes the bottom-right corner, so no cell is darkened twice.
+/// Shared by [`draw_shadow_bounds()`] and [`View::shadow_bounds()`] so what is
+/// drawn and what is saved or dumped always agree.
+pub fn shadow_strips(bounds: Rect) -> [Rect; 2] {
+    let right = Rect::new(
+        bounds.b.x,
+        bounds.a.y + SHADOW_OFFSET.1,
+        bounds.b.x + SHADOW_SIZE.0,
+        bounds.b.y + SHADOW_SIZE.1,
+    );
+    let bottom = Rect::new(bounds.a.x + SHADOW_OFFSET.0, bounds.b.y, bounds.b.x, bounds.b.y + SHADOW_SIZE.1);
+    [right, bottom]
+}
 
-    let shadow_y = bounds.b.y;
-    for i in 0..bottom_width {
-        let x = bounds.a.x + 1 + i as i16;
+/// `bounds` plus its drop shadow
+pub fn shadow_extent(bounds: Rect) -> Rect {
+    shadow_strips(bounds).iter().fold(bounds, |extent, strip| extent.union(strip))
+}
 
-        // Read the existing cell at this position
-        if let Some(existing_cell) = terminal.read_cell(x, shadow_y) {
-            // Darken the existing cell's attribute
-            let darkened_attr = existing_cell.attr.darken(SHADOW_FACTOR);
-            bottom_buf.put_char(i, existing_cell.ch, darkened_attr);
-        } else {
-            // Out of bounds - use default shadow
-            let default_attr = Attr::from_u8(SHADOW_ATTR);
-            bottom_buf.put_char(i, ' ', default_attr);
-        }
-    }
-    write_line_to_terminal(terminal, bounds.a.x + 1, bounds.b.y, &bottom_buf);
+/// The face of a view that draws its shadow inside its own bounds (like Button)
+///
+/// The inverse of [`shadow_extent()`]: the face plus its shadow fills `bounds`.
+pub fn shadow_face(bounds: Rect) -> Rect {
+    Rect::new(bounds.a.x, bounds.a.y, bounds.b.x - SHADOW_SIZE.0, bounds.b.y - SHADOW_SIZE.1)
 }
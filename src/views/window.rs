@@ -8,7 +8,7 @@ use super::view::{View, ViewId};
 use crate::core::command::{CM_CANCEL, CM_CLOSE};
 use crate::core::event::{Event, EventType};
 use crate::core::geometry::{Point, Rect};
-use crate::core::state::{SF_DRAGGING, SF_MODAL, SF_RESIZING, SF_SHADOW, StateFlags};
+use crate::core::state::{SF_DRAGGING, SF_FOCUSED, SF_MODAL, SF_RESIZING, SF_SHADOW, StateFlags};
 use crate::terminal::Terminal;
 
 pub struct Window {
@@ -38,6 +38,8 @@ pub struct Window {
     /// Explicit drag limits (for modal dialogs not added to desktop)
     /// Used when owner is None but we still want to constrain dragging
     explicit_drag_limits: Option<Rect>,
+    /// Space between the frame and the interior: (left, top, right, bottom)
+    content_padding: (i16, i16, i16, i16),
 }
 
 #[derive(Clone, Copy)]
@@ -89,7 +91,11 @@ impl Window {
         let mut interior_bounds = bounds;
         interior_bounds.grow(-1, -1);
         // Don't use background - the Frame fills the interior space (matching Borland)
-        let interior = Group::new(interior_bounds);
+        let mut interior = Group::new(interior_bounds);
+        interior.set_owner_type(match window_palette {
+            WindowPaletteType::Dialog => super::view::OwnerType::Dialog,
+            _ => super::view::OwnerType::Window,
+        });
 
         let mut window = Self {
             bounds,
@@ -106,6 +112,7 @@ impl Window {
             owner: None,
             palette_type: window_palette,
             explicit_drag_limits: None,
+            content_padding: (0, 0, 0, 0),
         };
 
         // Set the interior's owner to the window for palette chain resolution
@@ -114,15 +121,9 @@ impl Window {
         window
     }
 
-    pub fn add(&mut self, mut view: Box<dyn View>) -> ViewId {
-        // Set the owner type based on whether this is a Dialog or regular Window
-        let owner_type = match self.palette_type {
-            WindowPaletteType::Dialog => super::view::OwnerType::Dialog,
-            _ => super::view::OwnerType::Window,
-        };
-        view.set_owner_type(owner_type);
-
-        // Add to interior group (which will set owner pointer for palette chain)
+    pub fn add(&mut self, view: Box<dyn View>) -> ViewId {
+        // The interior group sets the owner pointer and the owner type
+        // (Dialog or Window) used for palette remapping
         self.interior.add(view)
     }
 
@@ -180,6 +181,38 @@ impl Window {
         self.interior.set_initial_focus();
     }
 
+    /// Set the space kept free between the frame and the content
+    ///
+    /// Child coordinates are relative to the padded interior, so a child at
+    /// (0, 0) sits `left` columns right of and `top` rows below the frame.
+    /// Set the padding before adding children: the interior is resized like
+    /// a window resize, which moves and stretches existing children.
+    pub fn set_content_padding(&mut self, left: i16, top: i16, right: i16, bottom: i16) {
+        self.content_padding = (left, top, right, bottom);
+        self.interior.set_bounds(self.interior_bounds());
+    }
+
+    /// Padding between the frame and the content: (left, top, right, bottom)
+    pub fn content_padding(&self) -> (i16, i16, i16, i16) {
+        self.content_padding
+    }
+
+    /// Area available to children, in the coordinates passed to add()
+    pub fn content_rect(&self) -> Rect {
+        self.interior.content_rect()
+    }
+
+    /// Absolute interior bounds: inset by 1 for the frame, then by the padding
+    fn interior_bounds(&self) -> Rect {
+        let (left, top, right, bottom) = self.content_padding;
+        Rect::new(
+            self.bounds.a.x + 1 + left,
+            self.bounds.a.y + 1 + top,
+            self.bounds.b.x - 1 - right,
+            self.bounds.b.y - 1 - bottom,
+        )
+    }
+
     /// Set the window title
     /// Matches Borland: TWindow allows title mutation via setTitle()
     /// The frame will be redrawn on the next draw() call
@@ -261,9 +294,7 @@ impl Window {
 
             // Update frame and interior bounds
             self.frame.set_bounds(self.bounds);
-            let mut interior_bounds = self.bounds;
-            interior_bounds.grow(-1, -1);
-            self.interior.set_bounds(interior_bounds);
+            self.interior.set_bounds(self.interior_bounds());
         }
     }
 
@@ -381,10 +412,8 @@ impl View for Window {
         self.bounds = bounds;
         self.frame.set_bounds(bounds);
 
-        // Update interior bounds (absolute, inset by 1 for frame)
-        let mut interior_bounds = bounds;
-        interior_bounds.grow(-1, -1);
-        self.interior.set_bounds(interior_bounds);
+        // Update interior bounds (absolute, inset by the frame and padding)
+        self.interior.set_bounds(self.interior_bounds());
 
         // NOTE: We do NOT automatically update frame_children here
         // Subclasses like EditWindow handle frame_children positioning manually
@@ -486,9 +515,7 @@ impl View for Window {
 
                 // Update frame and interior bounds
                 self.frame.set_bounds(self.bounds);
-                let mut interior_bounds = self.bounds;
-                interior_bounds.grow(-1, -1);
-                self.interior.set_bounds(interior_bounds);
+                self.interior.set_bounds(self.interior_bounds());
 
                 event.clear(); // Mark event as handled
                 return;
@@ -528,9 +555,7 @@ impl View for Window {
 
                 // Update frame and interior bounds
                 self.frame.set_bounds(self.bounds);
-                let mut interior_bounds = self.bounds;
-                interior_bounds.grow(-1, -1);
-                self.interior.set_bounds(interior_bounds);
+                self.interior.set_bounds(self.interior_bounds());
 
                 event.clear(); // Mark event as handled
                 return;
@@ -597,6 +622,8 @@ impl View for Window {
     }
 
     fn set_focus(&mut self, focused: bool) {
+        // Track it on the window too, so the desktop can tell which window is current
+        self.set_state_flag(SF_FOCUSED, focused);
         // Propagate focus to the interior group
         // When the window gets focus, set focus on its first focusable child
         if focused {
@@ -606,6 +633,11 @@ impl View for Window {
         }
     }
 
+    fn is_opaque(&self) -> bool {
+        // The frame fills the interior, so the whole bounds are painted
+        true
+    }
+
     fn state(&self) -> StateFlags {
         self.state
     }
@@ -614,6 +646,22 @@ impl View for Window {
         self.state = state;
     }
 
+    fn has_mouse_capture(&self) -> bool {
+        (self.state & (SF_DRAGGING | SF_RESIZING)) != 0 || self.interior.has_mouse_capture()
+    }
+
+    fn on_modal_enter(&mut self) {
+        self.interior.on_modal_enter();
+    }
+
+    fn on_modal_leave(&mut self) {
+        self.interior.on_modal_leave();
+    }
+
+    fn on_activate(&mut self, active: bool) {
+        self.interior.on_activate(active);
+    }
+
     fn options(&self) -> u16 {
         self.options
     }
@@ -655,9 +703,7 @@ impl View for Window {
 
         // Update frame and interior
         self.frame.set_bounds(self.bounds);
-        let mut interior_bounds = self.bounds;
-        interior_bounds.grow(-1, -1);
-        self.interior.set_bounds(interior_bounds);
+        self.interior.set_bounds(self.interior_bounds());
     }
 
     /// Validate window before closing with given command
@@ -799,3 +845,119 @@ impl Default for WindowBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::event::MB_LEFT_BUTTON;
+    use crate::terminal::DrawOp;
+    use crate::views::static_text::StaticText;
+
+    #[test]
+    fn test_window_draw_order() {
+        let mut terminal = Terminal::new_headless(40, 12);
+        let mut window = Window::new(Rect::new(2, 1, 22, 7), "Test");
+        window.add(Box::new(StaticText::new(Rect::new(1, 1, 10, 2), "Hello")));
+
+        terminal.start_recording();
+        window.draw(&mut terminal);
+        let recorder = terminal.stop_recording().unwrap();
+        let ops = recorder.ops();
+
+        // Frame top line comes first, at the window origin
+        assert_eq!(ops[0].position(), Some((2, 1)));
+        assert!(ops[0].text().starts_with('╔'));
+
+        // Frame bottom line is drawn before the interior clip is pushed
+        let frame_bottom = recorder.position(|op| op.position() == Some((2, 6))).unwrap();
+        let clip = recorder.position(|op| matches!(op, DrawOp::PushClip(_))).unwrap();
+        assert!(frame_bottom < clip);
+
+        // Children are drawn inside the interior clip
+        let child = recorder.position_of_text("Hello").unwrap();
+        let pop = recorder.position(|op| *op == DrawOp::PopClip).unwrap();
+        assert!(clip < child && child < pop);
+
+        // Shadow is drawn last: right edge first, then the row below the window
+        let right_shadow = recorder.position(|op| op.position() == Some((22, 2))).unwrap();
+        assert!(pop < right_shadow);
+        assert_eq!(ops.last().unwrap().position(), Some((3, 7)));
+    }
+
+    #[test]
+    fn test_shadow_at_screen_corner_dumps_and_restores() {
+        use crate::core::ansi_dump;
+        use crate::core::palette::Attr;
+        use crate::views::view::{shadow_extent, shadow_face};
+
+        let mut terminal = Terminal::new_headless(20, 8);
+        let mut window = Window::new(Rect::new(12, 4, 20, 8), "Edge");
+        assert_eq!(window.shadow_bounds(), Rect::new(12, 4, 21, 9));
+        assert_eq!(terminal.clip_to_screen(window.shadow_bounds()), Rect::new(12, 4, 20, 8));
+        assert_eq!(shadow_extent(shadow_face(Rect::new(3, 3, 9, 5))), Rect::new(3, 3, 9, 5));
+
+        // The shadow falls entirely off screen; nothing else is touched
+        let before = terminal.buffer().to_vec();
+        terminal.save_under(window.shadow_bounds());
+        window.draw(&mut terminal);
+        assert_eq!(terminal.buffer()[3], before[3]);
+        assert_eq!(terminal.buffer()[7][11], before[7][11]);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("edge.ans");
+        window.dump_to_file(&terminal, path.to_str().unwrap()).unwrap();
+        let dump = ansi_dump::load_ansi_file(&path, Attr::from_u8(0x07)).unwrap();
+        assert_eq!(dump.len(), 4);
+        assert!(dump.iter().all(|row| row.len() == 8));
+        assert_eq!(dump[0], terminal.buffer()[4][12..20]);
+
+        assert!(terminal.restore_under().unwrap());
+        assert_eq!(terminal.buffer(), before.as_slice());
+    }
+
+    fn mouse(window: &mut Window, what: EventType, x: i16, y: i16) -> Event {
+        let buttons = if what == EventType::MouseUp { 0 } else { MB_LEFT_BUTTON };
+        let mut event = Event::mouse(what, Point::new(x, y), buttons, false);
+        window.handle_event(&mut event);
+        event
+    }
+
+    #[test]
+    fn test_drag_title_bar_moves_window_within_limits() {
+        let mut window = WindowBuilder::new().bounds(Rect::new(10, 5, 30, 15)).title("Drag").build();
+        window.set_drag_limits(Rect::new(0, 1, 80, 24));
+
+        assert_eq!(mouse(&mut window, EventType::MouseDown, 15, 5).what, EventType::Nothing);
+        mouse(&mut window, EventType::MouseMove, 20, 8);
+        assert_eq!(window.bounds(), Rect::new(15, 8, 35, 18));
+
+        // The title bar cannot leave the top of the drag area
+        mouse(&mut window, EventType::MouseMove, 20, -3);
+        assert_eq!(window.bounds().a.y, 1);
+
+        let up = mouse(&mut window, EventType::MouseUp, 20, -3);
+        assert_eq!(up.what, EventType::Nothing, "no command is emitted");
+        mouse(&mut window, EventType::MouseMove, 40, 12);
+        assert_eq!(window.bounds(), Rect::new(15, 1, 35, 11), "released");
+    }
+
+    #[test]
+    fn test_resize_corner_only_on_resizable_windows() {
+        let mut window = WindowBuilder::new().bounds(Rect::new(10, 5, 30, 15)).title("Grow").build();
+        window.set_drag_limits(Rect::new(0, 1, 80, 24));
+        mouse(&mut window, EventType::MouseDown, 29, 14);
+        mouse(&mut window, EventType::MouseMove, 39, 19);
+        assert_eq!(window.bounds(), Rect::new(10, 5, 40, 20));
+
+        // Not below the minimum size
+        mouse(&mut window, EventType::MouseMove, 12, 6);
+        assert_eq!(window.bounds(), Rect::new(10, 5, 26, 11));
+        assert_eq!(mouse(&mut window, EventType::MouseUp, 12, 6).what, EventType::Nothing);
+
+        let mut fixed = WindowBuilder::new().bounds(Rect::new(10, 5, 30, 15)).title("Fixed").resizable(false).build();
+        fixed.set_drag_limits(Rect::new(0, 1, 80, 24));
+        mouse(&mut fixed, EventType::MouseDown, 29, 14);
+        mouse(&mut fixed, EventType::MouseMove, 39, 19);
+        assert_eq!(fixed.bounds(), Rect::new(10, 5, 30, 15));
+    }
+}
@@ -0,0 +1,63 @@
+// (C) 2025 - Enzo Lombardi
+// Visual regression test: the first frame of each snapshot-able example must
+// match its golden file in tests/golden.
+//
+// Regenerate the goldens with `cargo run --example snapshots -- --update`
+// (or UPDATE_GOLDEN=1 cargo test --test example_snapshots).
+
+mod snapshot;
+
+use snapshot::{check_snapshot, diff_screens, example_snapshots, SnapshotOutcome};
+use std::path::Path;
+use turbo_vision::core::draw::Cell;
+use turbo_vision::core::palette::Attr;
+
+#[test]
+fn examples_match_golden_snapshots() {
+    let golden_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+    let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+
+    let mut failures = Vec::new();
+    for snapshot in example_snapshots() {
+        match check_snapshot(snapshot, &golden_dir, update).unwrap() {
+            SnapshotOutcome::Match | SnapshotOutcome::Updated => {}
+            SnapshotOutcome::Missing => failures.push(format!("{}: no golden file", snapshot.name)),
+            SnapshotOutcome::Differs(diffs) => {
+                failures.push(format!("{}: {} cells differ", snapshot.name, diffs.len()));
+                failures.extend(diffs.iter().take(20).map(|diff| format!("    {diff}")));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "snapshot mismatches:\n{}", failures.join("\n"));
+}
+
+#[test]
+fn diff_reports_coordinates_and_attrs() {
+    let a = Attr::from_u8(0x1F);
+    let b = Attr::from_u8(0x70);
+    let expected = vec![vec![Cell::new('x', a), Cell::new('y', a)]];
+    let actual = vec![vec![Cell::new('x', a), Cell::new('y', b)], vec![Cell::new('z', a)]];
+
+    let diffs = diff_screens(&expected, &actual);
+    assert_eq!(diffs.len(), 2);
+    assert_eq!((diffs[0].x, diffs[0].y, diffs[0].expected.attr, diffs[0].actual.attr), (1, 0, a, b));
+    assert_eq!((diffs[1].x, diffs[1].y, diffs[1].expected.ch, diffs[1].actual.ch), (0, 1, ' ', 'z'));
+}
+
+#[test]
+fn update_then_check_matches() {
+    let dir = tempfile::tempdir().unwrap();
+    let snapshot = &example_snapshots()[0];
+    assert_eq!(check_snapshot(snapshot, dir.path(), false).unwrap(), SnapshotOutcome::Missing);
+    assert_eq!(check_snapshot(snapshot, dir.path(), true).unwrap(), SnapshotOutcome::Updated);
+    assert_eq!(check_snapshot(snapshot, dir.path(), false).unwrap(), SnapshotOutcome::Match);
+
+    // A broken golden file is reported cell by cell
+    let path = snapshot.golden_path(dir.path());
+    let golden = std::fs::read_to_string(&path).unwrap().replacen("Minimal", "Maximal", 1);
+    std::fs::write(&path, golden).unwrap();
+    let SnapshotOutcome::Differs(diffs) = check_snapshot(snapshot, dir.path(), false).unwrap() else {
+        panic!("edited golden file must differ");
+    };
+    assert_eq!(diffs.iter().map(|d| d.expected.ch).collect::<String>(), "ax");
+}
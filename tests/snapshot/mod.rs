@@ -0,0 +1,147 @@
+// (C) 2025 - Enzo Lombardi
+// Snapshots - visual regression checks for the examples
+//
+// Examples that support screenshot mode build their UI in a `setup` function,
+// and this module includes their sources so the same code can be called
+// in-process. check_snapshot() renders the first frame of an example on a
+// headless 80x25 terminal, dumps it as ANSI text, and compares it cell by
+// cell against a stored golden .ans file read back with
+// turbo_vision::core::ansi_dump::parse_ansi().
+//
+// Shared by tests/example_snapshots.rs and examples/snapshots.rs, which
+// includes it with #[path]. Run the checks with
+// `cargo run --example snapshots` (add `--update` to regenerate the goldens)
+// or `cargo test --test example_snapshots`.
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use turbo_vision::app::Application;
+use turbo_vision::core::ansi_dump;
+use turbo_vision::core::draw::Cell;
+use turbo_vision::core::palette::Attr;
+use turbo_vision::terminal::Terminal;
+
+#[expect(dead_code, reason = "Only setup() is called here; main() runs the example")]
+#[path = "../../examples/editor_app.rs"]
+mod editor_app;
+#[expect(dead_code, reason = "Only setup() is called here; main() runs the example")]
+#[path = "../../examples/minimal_app.rs"]
+mod minimal_app;
+
+/// Terminal size the snapshots are rendered at
+pub const SNAPSHOT_WIDTH: u16 = 80;
+pub const SNAPSHOT_HEIGHT: u16 = 25;
+
+/// An example whose first frame can be rendered in-process
+#[derive(Debug, Clone, Copy)]
+pub struct ExampleSnapshot {
+    /// Example name (`cargo run --example <name>`)
+    pub name: &'static str,
+    /// Builds the example's UI on an application
+    pub setup: fn(&mut Application),
+}
+
+impl ExampleSnapshot {
+    /// Golden file for this example inside `dir`
+    pub fn golden_path(&self, dir: &Path) -> PathBuf {
+        dir.join(format!("{}_{SNAPSHOT_WIDTH}x{SNAPSHOT_HEIGHT}.ans", self.name))
+    }
+}
+
+/// All examples that support screenshot mode
+pub fn example_snapshots() -> &'static [ExampleSnapshot] {
+    &[
+        ExampleSnapshot { name: "minimal_app", setup: minimal_app::setup },
+        ExampleSnapshot {
+            name: "editor_app",
+            setup: |app| {
+                editor_app::setup(app);
+                app.new_file();
+            },
+        },
+    ]
+}
+
+/// Render the first frame of `snapshot` on a headless terminal
+pub fn render_first_frame(snapshot: &ExampleSnapshot) -> Vec<Vec<Cell>> {
+    let mut app = Application::with_terminal(Terminal::new_headless(SNAPSHOT_WIDTH, SNAPSHOT_HEIGHT));
+    (snapshot.setup)(&mut app);
+    app.draw();
+    app.terminal.buffer().to_vec()
+}
+
+/// A cell that differs between the golden file and the rendered frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellDiff {
+    pub x: usize,
+    pub y: usize,
+    pub expected: Cell,
+    pub actual: Cell,
+}
+
+impl fmt::Display for CellDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "({}, {}): expected {:?} {:?}, got {:?} {:?}",
+            self.x, self.y, self.expected.ch, self.expected.attr, self.actual.ch, self.actual.attr
+        )
+    }
+}
+
+/// Result of comparing one example against its golden file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotOutcome {
+    Match,
+    /// The golden file was (re)written
+    Updated,
+    /// No golden file yet
+    Missing,
+    Differs(Vec<CellDiff>),
+}
+
+/// Compare two cell grids; cells missing from the shorter grid count as blanks
+pub fn diff_screens(expected: &[Vec<Cell>], actual: &[Vec<Cell>]) -> Vec<CellDiff> {
+    let blank = Cell::new(' ', Attr::from_u8(0x07));
+    let height = expected.len().max(actual.len());
+    let mut diffs = Vec::new();
+    for y in 0..height {
+        let expected_row = expected.get(y).map_or(&[][..], Vec::as_slice);
+        let actual_row = actual.get(y).map_or(&[][..], Vec::as_slice);
+        for x in 0..expected_row.len().max(actual_row.len()) {
+            let expected = expected_row.get(x).copied().unwrap_or(blank);
+            let actual = actual_row.get(x).copied().unwrap_or(blank);
+            if expected != actual {
+                diffs.push(CellDiff { x, y, expected, actual });
+            }
+        }
+    }
+    diffs
+}
+
+/// Render `snapshot` and compare it with its golden file in `golden_dir`
+///
+/// With `update`, the golden file is written instead. Both sides go through
+/// the ANSI dump and parser, so only what a golden file can hold is compared.
+pub fn check_snapshot(snapshot: &ExampleSnapshot, golden_dir: &Path, update: bool) -> io::Result<SnapshotOutcome> {
+    let frame = render_first_frame(snapshot);
+    let mut dump = Vec::new();
+    ansi_dump::dump_buffer(&mut dump, &frame, SNAPSHOT_WIDTH as usize, SNAPSHOT_HEIGHT as usize)?;
+
+    let path = snapshot.golden_path(golden_dir);
+    if update {
+        std::fs::create_dir_all(golden_dir)?;
+        std::fs::write(&path, &dump)?;
+        return Ok(SnapshotOutcome::Updated);
+    }
+    if !path.exists() {
+        return Ok(SnapshotOutcome::Missing);
+    }
+
+    let default_attr = Attr::from_u8(0x07);
+    let expected = ansi_dump::load_ansi_file(&path, default_attr)?;
+    let actual = ansi_dump::parse_ansi(&String::from_utf8_lossy(&dump), default_attr);
+    let diffs = diff_screens(&expected, &actual);
+    Ok(if diffs.is_empty() { SnapshotOutcome::Match } else { SnapshotOutcome::Differs(diffs) })
+}